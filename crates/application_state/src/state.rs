@@ -5,6 +5,7 @@ pub enum ApplicationState {
     #[default]
     Startup,
     MainMenu,
+    NewGame,
     PreGameplay,
     Gameplay,
 }
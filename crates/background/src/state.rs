@@ -9,6 +9,10 @@ impl ComputedStates for BackgroundState {
     type SourceStates = (ApplicationState, Option<LoadingIndicatorState>);
 
     fn compute((application_state, loading_state): Self::SourceStates) -> Option<Self> {
-        (application_state == ApplicationState::MainMenu || loading_state.is_some()).then_some(Self)
+        (matches!(
+            application_state,
+            ApplicationState::MainMenu | ApplicationState::NewGame
+        ) || loading_state.is_some())
+        .then_some(Self)
     }
 }
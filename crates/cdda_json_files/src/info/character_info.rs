@@ -1,4 +1,8 @@
-use crate::{Flags, Ignored, InfoId, ItemName, UntypedInfoId};
+use crate::{
+    AttackEffect, DamageUnit, Flags, Harvest, Ignored, InfoId, ItemName, OptionalLinkedLater,
+    UntypedInfoId,
+};
+use bevy_platform::collections::HashMap;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
@@ -31,12 +35,20 @@ pub struct CharacterInfo {
     #[serde(default)]
     pub flags: Flags,
 
+    pub harvest: OptionalLinkedLater<Harvest>,
+
     pub absorb_ml_per_hp: Option<u8>,
     pub absorb_move_cost_max: Option<u16>,
     pub absorb_move_cost_per_ml: Option<f32>,
     pub aggression: Option<i8>,
     pub aggro_character: Option<bool>,
     pub anger_triggers: Option<Vec<JsonValue>>,
+
+    /// Armor per damage type, like `{ "bash": 4, "cut": 6 }`
+    #[serde(default)]
+    pub armor: HashMap<Arc<str>, f32>,
+
+    // Older variant of 'armor'
     pub armor_acid: Option<u16>,
     pub armor_bash: Option<u16>,
     pub armor_bullet: Option<u16>,
@@ -49,7 +61,9 @@ pub struct CharacterInfo {
     #[serde(alias = "attack_cost ")]
     pub attack_cost: Option<u16>,
 
-    pub attack_effs: Option<Vec<JsonValue>>,
+    #[serde(default)]
+    pub attack_effs: Vec<AttackEffect>,
+
     pub baby_flags: Flags,
     pub biosignature: Option<JsonValue>,
     pub bleed_rate: Option<u8>,
@@ -60,23 +74,24 @@ pub struct CharacterInfo {
     pub colour: Option<Arc<str>>,
     pub death_drops: Option<JsonValue>,
     pub death_function: Option<JsonValue>,
-    pub delete: Option<JsonValue>,
     pub diff: Option<u8>,
     pub dissect: Option<Arc<str>>,
     pub dodge: Option<u8>,
     pub emit_fields: Option<Vec<JsonValue>>,
-    pub extend: Option<JsonValue>,
     pub families: Option<Vec<JsonValue>>,
     pub fear_triggers: Option<Vec<JsonValue>>,
     pub fungalize_into: Option<Arc<str>>,
     pub grab_strength: Option<u8>,
-    pub harvest: Option<Arc<str>>,
     pub luminance: Option<u16>,
     pub material: Option<Vec<JsonValue>>,
     pub mech_battery: Option<Arc<str>>,
     pub mech_str_bonus: Option<u8>,
     pub mech_weapon: Option<Arc<str>>,
-    pub melee_damage: Option<Vec<JsonValue>>,
+
+    /// Added to the damage of the melee dice
+    #[serde(default)]
+    pub melee_damage: Vec<DamageUnit>,
+
     pub melee_skill: Option<u8>,
     pub melee_training_cap: Option<u8>,
     pub morale: Option<i16>,
@@ -85,12 +100,10 @@ pub struct CharacterInfo {
     pub petfood: Option<JsonValue>,
     pub phase: Option<Arc<str>>,
     pub placate_triggers: Option<Vec<JsonValue>>,
-    pub proportional: Option<JsonValue>,
     pub regen_morale: Option<bool>,
     pub regenerates: Option<u8>,
     pub regenerates_in_dark: Option<bool>,
     pub regeneration_modifiers: Option<Vec<JsonValue>>,
-    pub relative: Option<JsonValue>,
     pub reproduction: Option<JsonValue>,
     pub revert_to_itype: Option<Arc<str>>,
     pub scents_ignored: Option<Vec<JsonValue>>,
@@ -112,6 +125,27 @@ pub struct CharacterInfo {
     pub ignored: Ignored<Self>,
 }
 
+impl CharacterInfo {
+    /// Natural armor against the given CDDA damage type, like "bash"
+    #[must_use]
+    pub fn armor(&self, damage_type: &str) -> f32 {
+        self.armor.get(damage_type).copied().unwrap_or_else(|| {
+            match damage_type {
+                "acid" => self.armor_acid,
+                "bash" => self.armor_bash,
+                "bullet" => self.armor_bullet,
+                "cold" => self.armor_cold,
+                "cut" => self.armor_cut,
+                "electric" => self.armor_elec,
+                "heat" => self.armor_fire,
+                "stab" => self.armor_stab,
+                _ => None,
+            }
+            .map_or(0.0, f32::from)
+        })
+    }
+}
+
 #[cfg(test)]
 mod character_tests {
     use super::*;
@@ -123,4 +157,28 @@ mod character_tests {
         let result = from_json_str::<CharacterInfo>(json);
         assert!(result.is_ok(), "{result:?}");
     }
+
+    #[test]
+    fn armor_works() {
+        let json = include_str!("test_data/mon_bee.json");
+        let character_info = from_json_str::<CharacterInfo>(json).expect("Valid json");
+        assert_eq!(
+            character_info.armor("cut").round() as u16,
+            6,
+            "{:?}",
+            character_info.armor
+        );
+        assert_eq!(
+            character_info.armor("stab").round() as u16,
+            0,
+            "{:?}",
+            character_info.armor
+        );
+        assert_eq!(
+            character_info.melee_damage.len(),
+            1,
+            "{:?}",
+            character_info.melee_damage
+        );
+    }
 }
@@ -0,0 +1,184 @@
+use crate::info::practice::ActivityLevel;
+use crate::{
+    Alternative, FurnitureInfo, Ignored, InfoId, ItemName, RequiredComponent, RequiredLinkedLater,
+    RequiredQualities, RequiredTool, TerrainInfo, UntypedInfoId, Using,
+};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use units::Duration;
+
+// PartialEq, Eq, and Hash manually implemented below
+#[derive(Debug, Deserialize)]
+pub struct Construction {
+    pub id: InfoId<Self>,
+    pub group: RequiredLinkedLater<ConstructionGroup>,
+    pub category: RequiredLinkedLater<ConstructionCategory>,
+
+    #[serde(default)]
+    pub required_skills: Vec<(Arc<str>, u8)>,
+
+    #[serde(default)]
+    pub time: Duration,
+
+    #[serde(default)]
+    pub qualities: RequiredQualities,
+
+    #[serde(default)]
+    pub tools: Vec<Vec<Alternative<RequiredTool>>>,
+
+    #[serde(default)]
+    pub components: Vec<Vec<Alternative<RequiredComponent>>>,
+
+    #[serde(default)]
+    pub using: Vec<Using>,
+
+    /// The terrain or furniture that has to be present
+    pub pre_terrain: Option<ConstructionTarget>,
+
+    /// The terrain or furniture that replaces the original one
+    pub post_terrain: Option<ConstructionTarget>,
+
+    pub activity_level: Option<ActivityLevel>,
+    pub byproducts: Option<Vec<JsonValue>>,
+    pub dark_craftable: Option<bool>,
+    pub do_turn_special: Option<Arc<str>>,
+    pub explain_failure: Option<Arc<str>>,
+    pub on_display: Option<bool>,
+    pub post_flags: Option<JsonValue>,
+    pub post_special: Option<Arc<str>>,
+    pub pre_flags: Option<JsonValue>,
+    pub pre_note: Option<Arc<str>>,
+    pub pre_special: Option<Arc<str>>,
+    pub strict: Option<bool>,
+    pub vehicle_start: Option<bool>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl Construction {
+    /// Whether this construction can be started on a tile with the given terrain and furniture
+    #[must_use]
+    pub fn applicable(&self, terrain: &TerrainInfo, furniture: Option<&FurnitureInfo>) -> bool {
+        match &self.pre_terrain {
+            Some(pre_terrain) => pre_terrain.matches(terrain, furniture),
+            // TODO support the other pre_special values
+            None => {
+                self.pre_special.as_deref() == Some("check_empty")
+                    && furniture.is_none()
+                    && terrain.flags.flat()
+            }
+        }
+    }
+}
+
+impl PartialEq for Construction {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Construction {}
+
+impl Hash for Construction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// Constructions with the same result, like building a wooden wall from logs or from planks
+#[derive(Debug, Deserialize)]
+pub struct ConstructionGroup {
+    pub id: InfoId<Self>,
+    pub name: ItemName,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConstructionCategory {
+    pub id: InfoId<Self>,
+    pub name: ItemName,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+/// CDDA uses the `pre_terrain` and `post_terrain` fields for both terrain and furniture.
+/// Furniture ids start with `f_`.
+#[derive(Debug, Deserialize)]
+#[serde(from = "UntypedInfoId")]
+pub enum ConstructionTarget {
+    Terrain(RequiredLinkedLater<TerrainInfo>),
+    Furniture(RequiredLinkedLater<FurnitureInfo>),
+
+    /// `f_null`, to remove the furniture
+    NoFurniture,
+}
+
+impl ConstructionTarget {
+    #[must_use]
+    pub fn matches(&self, terrain: &TerrainInfo, furniture: Option<&FurnitureInfo>) -> bool {
+        match self {
+            Self::Terrain(required) => required.get().is_ok_and(|pre| pre.id == terrain.id),
+            Self::Furniture(required) => required
+                .get()
+                .is_ok_and(|pre| furniture.is_some_and(|present| pre.id == present.id)),
+            Self::NoFurniture => furniture.is_none(),
+        }
+    }
+}
+
+impl From<UntypedInfoId> for ConstructionTarget {
+    fn from(id: UntypedInfoId) -> Self {
+        if id == UntypedInfoId::new("f_null") {
+            Self::NoFurniture
+        } else if id.starts_with("f_") {
+            Self::Furniture(RequiredLinkedLater::from(InfoId::from(id)))
+        } else {
+            Self::Terrain(RequiredLinkedLater::from(InfoId::from(id)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod construction_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn wooden_wall_works() {
+        let json = include_str!("test_data/constr_wall_wood.json");
+        let result = from_json_str::<Construction>(json);
+        assert!(
+            result.as_ref().is_ok_and(|construction| matches!(
+                (&construction.pre_terrain, &construction.post_terrain),
+                (
+                    Some(ConstructionTarget::Terrain(_)),
+                    Some(ConstructionTarget::Terrain(_))
+                )
+            ) && construction.time
+                == Duration::MINUTE * 90),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn furniture_works() {
+        let json = include_str!("test_data/constr_table.json");
+        let result = from_json_str::<Construction>(json);
+        assert!(
+            result
+                .as_ref()
+                .is_ok_and(|construction| construction.pre_terrain.is_none()
+                    && matches!(
+                        construction.post_terrain,
+                        Some(ConstructionTarget::Furniture(_))
+                    )),
+            "{result:?}"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Ignored, MaybeFlatVec};
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Part of an attack, like `{ "damage_type": "cut", "amount": 4 }`
+#[derive(Debug, Deserialize)]
+pub struct DamageUnit {
+    /// Like "bash", "cut", "stab", or "bullet"
+    pub damage_type: Arc<str>,
+
+    #[serde(default)]
+    pub amount: f32,
+
+    #[serde(default)]
+    pub armor_penetration: f32,
+
+    pub armor_multiplier: Option<f32>,
+    pub damage_multiplier: Option<f32>,
+    pub constant_armor_multiplier: Option<f32>,
+    pub constant_damage_multiplier: Option<f32>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+/// Damage of a projectile or a gun, either a single [`DamageUnit`], a list of them, or a list wrapped in `{ "values": [...] }`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RangedDamage {
+    Values { values: Vec<DamageUnit> },
+    Units(MaybeFlatVec<DamageUnit>),
+}
+
+impl RangedDamage {
+    #[must_use]
+    pub fn units(&self) -> &[DamageUnit] {
+        match self {
+            Self::Values { values } => values,
+            Self::Units(MaybeFlatVec(units)) => units,
+        }
+    }
+
+    /// The sum of all damage units, which can be negative for guns that weaken ammo
+    #[must_use]
+    pub fn amount(&self) -> f32 {
+        self.units().iter().map(|unit| unit.amount).sum()
+    }
+
+    /// The damage type with the highest amount
+    #[must_use]
+    pub fn main_damage_type(&self) -> Option<&str> {
+        self.units()
+            .iter()
+            .max_by(|a, b| a.amount.total_cmp(&b.amount))
+            .map(|unit| &*unit.damage_type)
+    }
+}
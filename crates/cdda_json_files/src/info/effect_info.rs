@@ -0,0 +1,131 @@
+use crate::{Flags, Ignored, InfoId, ItemName, RequiredLinkedLater};
+use bevy_platform::collections::HashMap;
+use serde::Deserialize;
+use std::sync::Arc;
+use units::Duration;
+
+#[derive(Debug, Deserialize)]
+pub struct EffectInfo {
+    pub id: InfoId<Self>,
+
+    /// One name per intensity, the last name is used for higher intensities
+    ///
+    /// Effects without a name are not shown.
+    #[serde(default)]
+    pub name: Vec<ItemName>,
+
+    #[serde(default)]
+    pub rating: EffectRating,
+
+    pub apply_message: Option<Arc<str>>,
+    pub remove_message: Option<Arc<str>>,
+
+    #[serde(default = "one")]
+    pub max_intensity: u8,
+
+    pub max_duration: Option<Duration>,
+
+    /// When present, the intensity follows from the remaining duration
+    pub int_dur_factor: Option<Duration>,
+
+    /// Modifiers at intensity 1, like `speed_mod`, `hurt_amount`, and `hurt_tick`
+    #[serde(default)]
+    base_mods: HashMap<Arc<str>, Vec<f32>>,
+
+    /// Extra modifiers per intensity above 1
+    #[serde(default)]
+    scaling_mods: HashMap<Arc<str>, Vec<f32>>,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl EffectInfo {
+    #[must_use]
+    pub fn name(&self, intensity: u8) -> Option<&ItemName> {
+        self.name
+            .get(usize::from(intensity.saturating_sub(1)))
+            .or_else(|| self.name.last())
+    }
+
+    /// Zero when the modifier is not present
+    #[must_use]
+    pub fn modifier(&self, key: &str, intensity: u8) -> f32 {
+        let first = |mods: &HashMap<Arc<str>, Vec<f32>>| {
+            mods.get(key)
+                .and_then(|values| values.first())
+                .copied()
+                .unwrap_or(0.0)
+        };
+        first(&self.base_mods) + first(&self.scaling_mods) * f32::from(intensity.saturating_sub(1))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EffectRating {
+    Good,
+    #[default]
+    Neutral,
+    Bad,
+    Mixed,
+}
+
+/// Effect of a monster attack
+#[derive(Debug, Deserialize)]
+pub struct AttackEffect {
+    pub id: RequiredLinkedLater<EffectInfo>,
+
+    #[serde(alias = "dur")]
+    #[serde(default)]
+    pub duration: Duration,
+
+    #[serde(default = "one")]
+    pub intensity: u8,
+
+    /// Percentage
+    #[serde(default = "hundred")]
+    pub chance: u8,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+const fn one() -> u8 {
+    1
+}
+
+const fn hundred() -> u8 {
+    100
+}
+
+#[cfg(test)]
+mod effect_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/effect_bleed.json");
+        let result = from_json_str::<EffectInfo>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let effect_info = result.expect("Checked above");
+        assert_eq!(effect_info.rating, EffectRating::Bad, "{effect_info:?}");
+        assert_eq!(
+            effect_info.name(2).map(|name| &*name.single),
+            Some("Bad Bleeding"),
+            "{effect_info:?}"
+        );
+        assert_eq!(
+            effect_info.name(30).map(|name| &*name.single),
+            Some("Heavy Bleeding"),
+            "{effect_info:?}"
+        );
+        let hurt = effect_info.modifier("hurt_amount", 3);
+        assert!((hurt - 2.0).abs() < f32::EPSILON, "{hurt:?}");
+    }
+}
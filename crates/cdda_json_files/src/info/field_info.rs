@@ -1,8 +1,9 @@
-use crate::{Ignored, InfoId, ItemName, UntypedInfoId};
+use crate::{EffectInfo, Ignored, InfoId, ItemName, RequiredLinkedLater, UntypedInfoId};
 use bevy_platform::collections::HashMap;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
+use units::Duration;
 
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type")]
@@ -61,17 +62,49 @@ impl FieldInfo {
             .as_ref()
             .expect("Named first level")
     }
+
+    /// Effects on characters in the field, at its lowest intensity
+    #[must_use]
+    pub fn effects(&self) -> &[FieldEffect] {
+        self.intensity_levels
+            .first()
+            .map_or(&[], |intensity_level| &intensity_level.effects)
+    }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct IntensityLevel {
     name: Option<ItemName>,
 
+    #[serde(default)]
+    effects: Vec<FieldEffect>,
+
     #[expect(unused)]
     #[serde(flatten)]
     extra: HashMap<Arc<str>, JsonValue>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct FieldEffect {
+    pub effect_id: RequiredLinkedLater<EffectInfo>,
+
+    #[serde(default = "get_one")]
+    pub intensity: u8,
+
+    #[serde(default)]
+    pub min_duration: Duration,
+
+    #[serde(default)]
+    pub max_duration: Duration,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+const fn get_one() -> u8 {
+    1
+}
+
 const fn get_true() -> bool {
     true
 }
@@ -8,7 +8,7 @@ use std::sync::Arc;
 pub struct Flags(Vec<Arc<str>>);
 
 impl Flags {
-    fn contains(&self, value: &str) -> bool {
+    pub(crate) fn contains(&self, value: &str) -> bool {
         self.0.contains(&Arc::from(value))
     }
 
@@ -17,6 +17,11 @@ impl Flags {
         self.contains("AQUATIC")
     }
 
+    #[must_use]
+    pub fn flat(&self) -> bool {
+        self.contains("FLAT")
+    }
+
     #[must_use]
     pub fn goes_up(&self) -> bool {
         self.contains("GOES_UP") || self.contains("RAMP_UP")
@@ -0,0 +1,132 @@
+use crate::{CommonItemInfo, Flags, Ignored, InfoId, OptionalLinkedLater, RequiredLinkedLater};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use units::Mass;
+
+/// What a corpse yields when it gets butchered or dissected
+#[derive(Debug, Deserialize)]
+pub struct Harvest {
+    pub id: InfoId<Self>,
+
+    #[serde(default)]
+    pub entries: Vec<HarvestEntry>,
+
+    pub butchery_requirement: Option<Arc<str>>,
+    pub leftovers: Option<Arc<str>>,
+    pub message: Option<Arc<str>>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl Harvest {
+    /// The entries that butchering yields, so without dissection and item groups
+    pub fn butchery_entries(&self) -> impl Iterator<Item = &HarvestEntry> {
+        self.entries.iter().filter(|entry| entry.butchered())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HarvestEntry {
+    /// Only linked for [`Self::butchered`] entries
+    pub drop: RequiredLinkedLater<CommonItemInfo>,
+
+    #[serde(rename = "type")]
+    pub drop_type: OptionalLinkedLater<HarvestDropType>,
+
+    pub base_num: Option<(f32, f32)>,
+    pub scale_num: Option<(f32, f32)>,
+    pub max: Option<u32>,
+
+    /// Part of the corpse mass
+    pub mass_ratio: Option<f32>,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    pub faults: Option<Vec<JsonValue>>,
+}
+
+impl HarvestEntry {
+    /// Not dissection only, and not an item group
+    #[must_use]
+    pub fn butchered(&self) -> bool {
+        self.drop_type
+            .get()
+            .is_none_or(|drop_type| !drop_type.group && !drop_type.dissect_only)
+    }
+
+    /// The number of dropped items, without randomness and skill effects
+    #[must_use]
+    pub fn amount(&self, corpse_mass: Option<Mass>, item_mass: Option<Mass>) -> u32 {
+        let amount = match (self.mass_ratio, corpse_mass, item_mass) {
+            (Some(mass_ratio), Some(corpse_mass), Some(item_mass)) if Mass::ZERO < item_mass => {
+                ((corpse_mass / item_mass) as f32 * mass_ratio).round() as u32
+            }
+            _ => {
+                let (base_min, base_max) = self.base_num.unwrap_or((1.0, 1.0));
+                let (scale_min, scale_max) = self.scale_num.unwrap_or((0.0, 0.0));
+                (0.5 * (base_min + base_max + scale_min + scale_max)).round() as u32
+            }
+        };
+        self.max.map_or(amount, |max| amount.min(max))
+    }
+}
+
+/// Category of harvest drops, like flesh, bone, or skin
+#[derive(Debug, Deserialize)]
+pub struct HarvestDropType {
+    pub id: InfoId<Self>,
+
+    /// The drop is an item group instead of an item
+    #[serde(default)]
+    pub group: bool,
+
+    #[serde(default)]
+    pub dissect_only: bool,
+
+    #[serde(default)]
+    pub harvest_skills: Vec<Arc<str>>,
+
+    pub msg_butcher_fail: Option<Arc<str>>,
+    pub msg_butcher_success: Option<Arc<str>>,
+    pub msg_dissect_fail: Option<Arc<str>>,
+    pub msg_dissect_success: Option<Arc<str>>,
+    pub msg_fielddress_fail: Option<Arc<str>>,
+    pub msg_fielddress_success: Option<Arc<str>>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[cfg(test)]
+mod harvest_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/mammal_small_fur.json");
+        let result = from_json_str::<Harvest>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let harvest = result.expect("Checked above");
+        assert_eq!(harvest.entries.len(), 12, "{harvest:?}");
+    }
+
+    #[test]
+    fn amount_works() {
+        let json = include_str!("test_data/mammal_small_fur.json");
+        let harvest = from_json_str::<Harvest>(json).expect("Valid json");
+        let corpse_mass = Mass::try_from("10 kg").ok();
+
+        let meat = &harvest.entries[0];
+        let meat_mass = Mass::try_from("100 g").ok();
+        assert_eq!(meat.amount(corpse_mass, meat_mass), 32, "{meat:?}");
+        assert_eq!(meat.amount(None, meat_mass), 1, "{meat:?}");
+
+        let stomach = &harvest.entries[7];
+        assert_eq!(stomach.amount(corpse_mass, None), 1, "{stomach:?}");
+    }
+}
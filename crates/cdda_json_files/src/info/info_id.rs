@@ -1,16 +1,16 @@
 use bevy_log::warn;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 use std::hash::{Hash, Hasher};
 use std::{any::type_name, fmt, marker::PhantomData, sync::Arc};
 
 use crate::{
-    CommonItemInfo, ItemMigration, ItemWithCommonInfo, OvermapTerrainInfo, TerrainInfo,
-    VehiclePartInfo, VehiclePartMigration,
+    CommonItemInfo, ItemMigration, ItemWithCommonInfo, OvermapTerrainInfo, Recipe, TerrainInfo,
+    Uncraft, VehiclePartInfo, VehiclePartMigration,
 };
 
 /// Use [`InfoId`] wherever possible.
 /// Note that different info types may use the same ids.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
 pub struct UntypedInfoId(Arc<str>);
 
 impl UntypedInfoId {
@@ -138,6 +138,20 @@ impl InfoId<OvermapTerrainInfo> {
     pub fn is_road_zone(&self) -> bool {
         self.untyped.starts_with("road_")
     }
+
+    /// The id without its direction suffix, and the number of clockwise quarter turns of that direction
+    #[must_use]
+    pub fn without_direction(&self) -> (Self, u8) {
+        [("_north", 0), ("_east", 1), ("_south", 2), ("_west", 3)]
+            .into_iter()
+            .find_map(|(suffix, turns)| {
+                self.untyped
+                    .0
+                    .strip_suffix(suffix)
+                    .map(|base| (Self::new(base), turns))
+            })
+            .unwrap_or_else(|| (self.clone(), 0))
+    }
 }
 
 impl InfoId<TerrainInfo> {
@@ -170,6 +184,12 @@ impl<T> From<UntypedInfoId> for InfoId<T> {
     }
 }
 
+impl<T> Serialize for InfoId<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.untyped.serialize(serializer)
+    }
+}
+
 impl<T> Hash for InfoId<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.untyped.hash(state);
@@ -202,6 +222,20 @@ impl From<InfoId<CommonItemInfo>> for InfoId<ItemMigration> {
     }
 }
 
+/// Recipes without an id suffix are identified by their result
+impl From<InfoId<CommonItemInfo>> for InfoId<Recipe> {
+    fn from(info_id: InfoId<CommonItemInfo>) -> Self {
+        Self::from(info_id.untyped)
+    }
+}
+
+/// Uncrafts are identified by their result
+impl From<InfoId<CommonItemInfo>> for InfoId<Uncraft> {
+    fn from(info_id: InfoId<CommonItemInfo>) -> Self {
+        Self::from(info_id.untyped)
+    }
+}
+
 impl From<InfoId<VehiclePartInfo>> for InfoId<VehiclePartMigration> {
     fn from(info_id: InfoId<VehiclePartInfo>) -> Self {
         Self::from(info_id.untyped)
@@ -1,4 +1,7 @@
-use crate::{Flags, Ignored, InfoId, ItemQuality, MaybeFlatVec, UntypedInfoId, UseAction};
+use crate::{
+    DetailedUseAction, Flags, Ignored, InfoId, ItemMaterial, ItemQuality, Material, MaterialResist,
+    MaybeFlatVec, RangedDamage, RepairItemDetail, RequiredLinkedLater, UntypedInfoId, UseAction,
+};
 use bevy_platform::collections::HashMap;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
@@ -19,8 +22,7 @@ pub struct Ammo {
     pub casing: Option<UntypedInfoId>,
     pub critical_multiplier: Option<u8>,
 
-    // example: { "damage_type": "bullet", "amount": 28, "armor_penetration": 4 }
-    pub damage: Option<JsonValue>,
+    pub damage: Option<RangedDamage>,
 
     pub drop: Option<Arc<str>>,
     pub effects: Option<Vec<Arc<str>>>,
@@ -108,8 +110,9 @@ pub struct Clothing {
     pub sided: Option<bool>,
     pub power_armor: Option<bool>,
 
+    /// Older variant of the 'covers' of [`ArmorPortion`]
     #[serde(default)]
-    pub covers: Vec<JsonValue>,
+    pub covers: Vec<Arc<str>>,
 
     #[serde(default)]
     pub valid_mods: Vec<JsonValue>,
@@ -124,6 +127,129 @@ impl ItemWithCommonInfo for Clothing {
     }
 }
 
+/// Part of the 'armor' of an item
+#[derive(Debug, Deserialize)]
+pub struct ArmorPortion {
+    /// Body parts, like `torso` or `arm_l`
+    #[serde(default)]
+    pub covers: Vec<Arc<str>>,
+
+    /// Percentage of the body parts that is covered
+    pub coverage: Option<u8>,
+
+    #[serde(default)]
+    pub material: Vec<ArmorMaterial>,
+
+    pub specifically_covers: Option<JsonValue>,
+
+    /// When a range is given, the first value is used
+    pub encumbrance: Option<MaybeFlatVec<u16>>,
+
+    pub max_encumbrance: Option<JsonValue>,
+    pub encumbrance_modifiers: Option<JsonValue>,
+    pub layers: Option<JsonValue>,
+    pub rigid_layer_only: Option<JsonValue>,
+    pub breathability: Option<JsonValue>,
+    pub volume_encumber_modifier: Option<JsonValue>,
+    pub cover_melee: Option<JsonValue>,
+    pub cover_ranged: Option<JsonValue>,
+    pub cover_vitals: Option<JsonValue>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl ArmorPortion {
+    #[must_use]
+    pub fn covers(&self, body_part: &str) -> bool {
+        self.covers.iter().any(|covered| &**covered == body_part)
+    }
+
+    #[must_use]
+    pub fn encumbrance(&self) -> u16 {
+        self.encumbrance
+            .as_ref()
+            .and_then(|encumbrance| encumbrance.0.first().copied())
+            .unwrap_or(0)
+    }
+}
+
+/// The layers of worn clothing, from the inside out
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClothingLayer {
+    Personal,
+    Skintight,
+    Normal,
+    Waist,
+    Outer,
+    Belted,
+    Aura,
+}
+
+impl ClothingLayer {
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Personal => "personal",
+            Self::Skintight => "skintight",
+            Self::Normal => "normal",
+            Self::Waist => "waist",
+            Self::Outer => "outer",
+            Self::Belted => "belted",
+            Self::Aura => "aura",
+        }
+    }
+}
+
+/// A material of an [`ArmorPortion`]
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ArmorMaterial {
+    Simple(RequiredLinkedLater<Material>),
+    Complex {
+        #[serde(rename(deserialize = "type"))]
+        type_: RequiredLinkedLater<Material>,
+
+        /// Percentage of the armor portion, assume 100 when missing
+        covered_by_mat: Option<u8>,
+
+        /// In mm, assume 0 when missing
+        thickness: Option<f32>,
+    },
+}
+
+impl ArmorMaterial {
+    #[must_use]
+    pub const fn material(&self) -> &RequiredLinkedLater<Material> {
+        match self {
+            Self::Simple(material)
+            | Self::Complex {
+                type_: material, ..
+            } => material,
+        }
+    }
+
+    /// Between 0 and 1
+    #[must_use]
+    pub fn covered_fraction(&self) -> f32 {
+        match self {
+            Self::Simple(_) => 1.0,
+            Self::Complex { covered_by_mat, .. } => {
+                f32::from(covered_by_mat.unwrap_or(100).min(100)) / 100.0
+            }
+        }
+    }
+
+    /// In mm
+    #[must_use]
+    pub fn thickness(&self) -> f32 {
+        match self {
+            Self::Simple(_) => 0.0,
+            Self::Complex { thickness, .. } => thickness.unwrap_or(0.0),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Comestible {
     pub comestible_type: Arc<str>,
@@ -182,6 +308,13 @@ pub struct Comestible {
     pub common: Arc<CommonItemInfo>,
 }
 
+impl Comestible {
+    #[must_use]
+    pub fn is_drink(&self) -> bool {
+        &*self.comestible_type == "DRINK"
+    }
+}
+
 impl ItemWithCommonInfo for Comestible {
     fn common(&self) -> Arc<CommonItemInfo> {
         self.common.clone()
@@ -255,7 +388,7 @@ pub struct Gun {
     #[serde(default)]
     pub modes: Arc<[JsonValue]>,
 
-    pub ranged_damage: Option<JsonValue>,
+    pub ranged_damage: Option<RangedDamage>,
     pub reload: Option<u16>,
     pub reload_noise: Option<Arc<str>>,
     pub reload_noise_volume: Option<u8>,
@@ -629,12 +762,6 @@ pub struct CommonItemInfo {
 
     pub category: Option<Arc<str>>,
 
-    // example: { "price": 0.7, "damage": { "damage_type": "bullet", "amount": 0.9 }, "dispersion": 1.1 }
-    pub proportional: Option<JsonValue>,
-
-    // example: { "damage": { "damage_type": "bullet", "amount": -1, "armor_penetration": 2 } }
-    pub relative: Option<JsonValue>,
-
     pub count: Option<u32>,
     pub stack_size: Option<u8>,
     pub range: Option<i16>, // examples: -6, 140
@@ -659,6 +786,11 @@ pub struct CommonItemInfo {
 
     pub bashing: Option<u16>,
     pub cutting: Option<u16>,
+
+    /// Like `{ "bash": 5, "cut": 29 }`, newer variant of 'bashing' and 'cutting'
+    #[serde(default)]
+    pub melee_damage: HashMap<Arc<str>, u16>,
+
     pub to_hit: Option<ToHit>,
     pub variant_type: Option<JsonValue>,
     pub variants: Option<JsonValue>,
@@ -677,7 +809,7 @@ pub struct CommonItemInfo {
     pub degradation_multiplier: Option<JsonValue>,
 
     pub color: Option<Arc<str>>,
-    pub material: Option<MaybeFlatVec<Material>>,
+    pub material: Option<MaybeFlatVec<ItemMaterial>>,
     pub material_thickness: Option<f32>,
     pub chat_topics: Option<JsonValue>,
 
@@ -696,12 +828,6 @@ pub struct CommonItemInfo {
     #[serde(default)]
     pub repairs_with: Vec<JsonValue>,
 
-    // example: { "effects": [ "RECYCLED" ] }
-    pub extend: Option<JsonValue>,
-
-    // example: { "effects": [ "NEVER_MISFIRES" ], "flags": [ "IRREPLACEABLE_CONSUMABLE" ] }
-    pub delete: Option<JsonValue>,
-
     pub properties: Option<JsonValue>,
     pub techniques: Option<JsonValue>,
     pub max_charges: Option<u16>,
@@ -724,7 +850,11 @@ pub struct CommonItemInfo {
     pub milling: Option<JsonValue>,
     pub gunmod_data: Option<JsonValue>,
     pub pocket_data: Option<Vec<Arc<PocketInfo>>>,
-    pub armor: Option<Vec<JsonValue>>,
+
+    /// Parts of clothing, each with its own body parts and materials
+    #[serde(default)]
+    pub armor: Vec<ArmorPortion>,
+
     pub snippet_category: Option<JsonValue>,
 
     // Plenty of fields already availalble
@@ -733,9 +863,284 @@ pub struct CommonItemInfo {
 }
 
 impl CommonItemInfo {
+    /// The CDDA damage type, like "bash", and the amount of the strongest melee damage
+    fn strongest_melee_damage(&self) -> (&str, u16) {
+        [("bash", self.bashing), ("cut", self.cutting)]
+            .into_iter()
+            .filter_map(|(damage_type, amount)| amount.map(|amount| (damage_type, amount)))
+            .chain(
+                self.melee_damage
+                    .iter()
+                    .map(|(damage_type, amount)| (&**damage_type, *amount)),
+            )
+            .fold(("bash", 0), |strongest, current| {
+                if strongest.1 < current.1 {
+                    current
+                } else {
+                    strongest
+                }
+            })
+    }
+
     #[must_use]
     pub fn melee_damage(&self) -> u16 {
-        self.bashing.unwrap_or(0).max(self.cutting.unwrap_or(0))
+        self.strongest_melee_damage().1
+    }
+
+    /// The CDDA damage type of hitting with this item, like "cut"
+    #[must_use]
+    pub fn melee_damage_type(&self) -> &str {
+        self.strongest_melee_damage().0
+    }
+
+    /// The skill that is trained by fighting with this item
+    #[must_use]
+    pub fn melee_skill(&self) -> &'static str {
+        match self.melee_damage_type() {
+            "cut" => "cutting",
+            "stab" => "stabbing",
+            _ => "bashing",
+        }
+    }
+
+    /// The linked materials, with portions that add up to 1
+    ///
+    /// Materials that failed to link, were already reported while linking, and are skipped.
+    pub fn materials(&self) -> impl Iterator<Item = (Arc<Material>, f32)> {
+        let item_materials = self.material.iter().flat_map(|materials| &materials.0);
+        let total = item_materials
+            .clone()
+            .map(ItemMaterial::portion)
+            .sum::<f32>();
+        item_materials.filter_map(move |item_material| {
+            item_material
+                .material()
+                .get()
+                .ok()
+                .map(|material| (material, item_material.portion() / total))
+        })
+    }
+
+    /// Weighted by the portions of the materials, zero without materials
+    #[must_use]
+    pub fn material_average(&self, property: impl Fn(&Material) -> f32) -> f32 {
+        self.materials()
+            .map(|(material, portion)| property(&material) * portion)
+            .sum()
+    }
+
+    fn armor_portion(&self, body_part: &str) -> Option<&ArmorPortion> {
+        self.armor
+            .iter()
+            .find(|armor_portion| armor_portion.covers(body_part))
+    }
+
+    /// For clothing that uses the older 'covers' field
+    fn legacy_covers(&self) -> &[Arc<str>] {
+        match self.type_details.get() {
+            Some(ItemTypeDetails::Clothing(clothing)) => &clothing.covers,
+            Some(ItemTypeDetails::ToolClothing(tool_clothing)) => &tool_clothing.clothing.covers,
+            _ => &[],
+        }
+    }
+
+    fn legacy_covered(&self, body_part: &str) -> bool {
+        self.legacy_covers()
+            .iter()
+            .any(|covered| &**covered == body_part)
+    }
+
+    /// Whether this can be worn, like clothing, bags, and armor
+    #[must_use]
+    pub fn wearable(&self) -> bool {
+        !self.armor.is_empty()
+            || matches!(
+                self.type_details.get(),
+                Some(ItemTypeDetails::Clothing(_) | ItemTypeDetails::ToolClothing(_))
+            )
+    }
+
+    /// The body parts that are covered when worn, without duplicates
+    #[must_use]
+    pub fn covered_body_parts(&self) -> Vec<&str> {
+        let mut body_parts = self
+            .armor
+            .iter()
+            .flat_map(|armor_portion| &armor_portion.covers)
+            .chain(self.legacy_covers())
+            .map(|body_part| &**body_part)
+            .collect::<Vec<_>>();
+        body_parts.sort_unstable();
+        body_parts.dedup();
+        body_parts
+    }
+
+    #[must_use]
+    pub fn clothing_layer(&self) -> ClothingLayer {
+        [
+            ("PERSONAL", ClothingLayer::Personal),
+            ("SKINTIGHT", ClothingLayer::Skintight),
+            ("WAIST", ClothingLayer::Waist),
+            ("OUTER", ClothingLayer::Outer),
+            ("BELTED", ClothingLayer::Belted),
+            ("AURA", ClothingLayer::Aura),
+        ]
+        .into_iter()
+        .find(|(flag, _)| self.flags.contains(flag))
+        .map_or(ClothingLayer::Normal, |(_, layer)| layer)
+    }
+
+    /// Items with this flag can not be worn on the same layer as other items
+    #[must_use]
+    pub fn one_per_layer(&self) -> bool {
+        self.flags.contains("ONE_PER_LAYER")
+    }
+
+    /// How much wearing this hinders the body part
+    #[must_use]
+    pub fn encumbrance(&self, body_part: &str) -> u16 {
+        self.armor_portion(body_part)
+            .map_or(0, ArmorPortion::encumbrance)
+    }
+
+    /// The percentage of the body part that is covered when worn
+    #[must_use]
+    pub fn coverage(&self, body_part: &str) -> u8 {
+        if let Some(armor_portion) = self.armor_portion(body_part) {
+            armor_portion.coverage.unwrap_or(0)
+        } else if self.legacy_covered(body_part) {
+            100
+        } else {
+            0
+        }
+    }
+
+    /// Protection of the body part when worn, based on the materials and their thickness
+    ///
+    /// `resist` selects the resistance per mm of material for a damage type.
+    #[must_use]
+    pub fn protection(&self, body_part: &str, resist: impl Fn(&MaterialResist) -> f32) -> f32 {
+        if let Some(armor_portion) = self.armor_portion(body_part) {
+            armor_portion
+                .material
+                .iter()
+                .filter_map(|armor_material| {
+                    armor_material.material().get().ok().map(|material| {
+                        resist(&material.resist)
+                            * armor_material.thickness()
+                            * armor_material.covered_fraction()
+                    })
+                })
+                .sum()
+        } else if self.legacy_covered(body_part) {
+            self.material_average(|material| resist(&material.resist))
+                * self.material_thickness.unwrap_or(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether this is a repair tool for the given material
+    #[must_use]
+    pub fn repairs(&self, material: &Material) -> bool {
+        self.use_action
+            .0
+            .iter()
+            .filter_map(|use_action| match use_action {
+                UseAction::Typed(box_) => match &box_.details {
+                    DetailedUseAction::RepairItem(RepairItemDetail { materials, .. }) => {
+                        Some(materials)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .flatten()
+            .any(|repaired| repaired.get().is_ok_and(|repaired| *repaired == *material))
+    }
+
+    /// Damage per second in the weakest fire, zero for fireproof items
+    #[must_use]
+    pub fn burn_rate(&self) -> f32 {
+        self.material_average(Material::burn_rate)
+    }
+
+    /// Insulation when worn, zero for items that are not clothing
+    #[must_use]
+    pub fn warmth(&self) -> u8 {
+        match self.type_details.get() {
+            Some(ItemTypeDetails::Clothing(clothing)) => clothing.warmth,
+            Some(ItemTypeDetails::ToolClothing(tool_clothing)) => tool_clothing.clothing.warmth,
+            _ => None,
+        }
+        .unwrap_or(0)
+    }
+
+    /// `None` for items that are not food or drinks
+    #[must_use]
+    pub fn comestible(&self) -> Option<&Arc<Comestible>> {
+        if let Some(ItemTypeDetails::Comestible(comestible)) = self.type_details.get() {
+            Some(comestible)
+        } else {
+            None
+        }
+    }
+
+    /// `None` for items that are not guns
+    #[must_use]
+    pub fn gun(&self) -> Option<&Arc<Gun>> {
+        if let Some(ItemTypeDetails::Gun(gun)) = self.type_details.get() {
+            Some(gun)
+        } else {
+            None
+        }
+    }
+
+    /// `None` for items that are not ammo
+    #[must_use]
+    pub fn ammo(&self) -> Option<&Arc<Ammo>> {
+        if let Some(ItemTypeDetails::Ammo(ammo)) = self.type_details.get() {
+            Some(ammo)
+        } else {
+            None
+        }
+    }
+
+    /// `None` for items that are not magazines
+    #[must_use]
+    pub fn magazine(&self) -> Option<&Arc<Magazine>> {
+        if let Some(ItemTypeDetails::Magazine(magazine)) = self.type_details.get() {
+            Some(magazine)
+        } else {
+            None
+        }
+    }
+
+    /// The ammo types of ammo, magazines, and tools, empty for other items
+    #[must_use]
+    pub fn ammo_types(&self) -> &[UntypedInfoId] {
+        self.type_details
+            .get()
+            .and_then(ItemTypeDetails::ammo_type)
+            .map_or(&[], |ammo_types| &ammo_types.0)
+    }
+
+    /// Whether ammo or a magazine can be loaded into this item
+    #[must_use]
+    pub fn reloadable(&self) -> bool {
+        self.pocket_data.iter().flatten().any(|pocket_info| {
+            matches!(
+                pocket_info.pocket_type,
+                PocketType::Magazine | PocketType::MagazineWell
+            )
+        })
+    }
+
+    /// In degrees Celsius, `None` for items that are not food or drinks
+    #[must_use]
+    pub fn freezing_point(&self) -> Option<f32> {
+        self.comestible()
+            .map(|comestible| comestible.freezing_point.unwrap_or(0.0))
     }
 }
 
@@ -812,21 +1217,6 @@ impl From<CddaItemName> for ItemName {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-#[serde(deny_unknown_fields)]
-#[serde(untagged)]
-pub enum Material {
-    Simple(Arc<str>),
-    Complex {
-        #[serde(rename(deserialize = "type"))]
-        type_: Arc<str>,
-
-        /// assume 1 when missing
-        // TODO What does a fractional value mean?
-        portion: Option<f32>,
-    },
-}
-
 #[derive(Clone, Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
@@ -928,6 +1318,34 @@ pub struct PocketInfo {
     pub ignored: Ignored<Self>,
 }
 
+impl PocketInfo {
+    /// How many rounds of this ammo type fit, `None` when the ammo type is not allowed
+    #[must_use]
+    pub fn ammo_capacity(&self, ammo_type: &UntypedInfoId) -> Option<u32> {
+        self.ammo_restriction
+            .as_ref()?
+            .get(&*ammo_type.fallback_name())
+            .copied()
+    }
+
+    /// The largest capacity of any allowed ammo type
+    #[must_use]
+    pub fn max_ammo_capacity(&self) -> Option<u32> {
+        self.ammo_restriction.as_ref()?.values().max().copied()
+    }
+
+    /// Whether the item and flag restrictions allow this item, like magazines in a magazine well
+    #[must_use]
+    pub fn allows(&self, item: &CommonItemInfo) -> bool {
+        (self.item_restriction.is_empty() || self.item_restriction.contains(&item.id))
+            && (self.flag_restriction.is_empty()
+                || self
+                    .flag_restriction
+                    .iter()
+                    .any(|flag| item.flags.contains(flag)))
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, VariantArray,
 )]
@@ -978,6 +1396,62 @@ mod item_tests {
         assert!(result.is_ok(), "{result:?}");
     }
 
+    #[test]
+    fn jacket_leather_works() {
+        let json = include_str!("test_data/jacket_leather.json");
+        let clothing = from_json_str::<Clothing>(json).expect("Valid json");
+        assert_eq!(
+            clothing.common.coverage("arm_l"),
+            90,
+            "{:?}",
+            clothing.common.armor
+        );
+        assert_eq!(
+            clothing.common.coverage("head"),
+            0,
+            "{:?}",
+            clothing.common.armor
+        );
+        assert_eq!(
+            clothing.common.encumbrance("torso"),
+            14,
+            "{:?}",
+            clothing.common.armor
+        );
+        assert_eq!(
+            clothing.common.clothing_layer(),
+            ClothingLayer::Outer,
+            "{:?}",
+            clothing.common.flags
+        );
+        assert_eq!(
+            clothing.common.covered_body_parts(),
+            ["arm_l", "arm_r", "torso"],
+            "{:?}",
+            clothing.common.armor
+        );
+    }
+
+    #[test]
+    fn mc_jian_melee_damage_works() {
+        let json = include_str!("test_data/mc_jian.json");
+        let item = from_json_str::<CommonItemInfo>(json).expect("Valid json");
+        assert_eq!(item.melee_damage(), 29, "{:?}", item.melee_damage);
+        assert_eq!(item.melee_skill(), "cutting", "{:?}", item.melee_damage);
+    }
+
+    #[test]
+    fn mc_jian_materials_work() {
+        let json = include_str!("test_data/mc_jian.json");
+        let item = from_json_str::<CommonItemInfo>(json).expect("Valid json");
+        let materials = &item.material.as_ref().expect("Materials present").0;
+        assert_eq!(materials.len(), 2, "{materials:?}");
+        assert!(
+            (materials[1].portion() - 96.0).abs() < f32::EPSILON,
+            "{materials:?}"
+        );
+    }
+
     #[test]
     fn mutagen_works() {
         let json = include_str!("test_data/mutagen_beast.json");
@@ -991,4 +1465,78 @@ mod item_tests {
         let result = from_json_str::<PocketInfo>(json);
         assert!(result.is_ok(), "{result:?}");
     }
+
+    #[test]
+    fn ammo_9mm_works() {
+        let json = include_str!("test_data/9mm.json");
+        let ammo = from_json_str::<Ammo>(json).expect("Valid json");
+        let damage = ammo.damage.as_ref().expect("Has damage");
+        assert!((damage.amount() - 24.0).abs() < f32::EPSILON, "{damage:?}");
+        assert_eq!(damage.main_damage_type(), Some("bullet"), "{damage:?}");
+        assert_eq!(ammo.common.range, Some(14), "{:?}", ammo.common.range);
+    }
+
+    #[test]
+    fn glock_19_works() {
+        let json = include_str!("test_data/glock_19.json");
+        let gun = from_json_str::<Gun>(json).expect("Valid json");
+        assert_eq!(&*gun.skill, "pistol", "{:?}", gun.skill);
+        assert!(
+            gun.ranged_damage
+                .as_ref()
+                .is_some_and(|damage| damage.amount().abs() < f32::EPSILON),
+            "{:?}",
+            gun.ranged_damage
+        );
+        assert_eq!(
+            gun.common.dispersion,
+            Some(480),
+            "{:?}",
+            gun.common.dispersion
+        );
+    }
+
+    #[test]
+    fn glockmag_works() {
+        let json = include_str!("test_data/glockmag.json");
+        let magazine = from_json_str::<Magazine>(json).expect("Valid json");
+        assert_eq!(magazine.capacity, Some(15), "{:?}", magazine.capacity);
+        assert_eq!(
+            magazine.reload_time,
+            Some(140),
+            "{:?}",
+            magazine.reload_time
+        );
+
+        let pocket_data = magazine.common.pocket_data.as_ref().expect("Has pockets");
+        let pocket = pocket_data.first().expect("Has a pocket");
+        assert_eq!(pocket.pocket_type, PocketType::Magazine, "{pocket:?}");
+        assert_eq!(
+            pocket.ammo_capacity(&UntypedInfoId::new("9mm")),
+            Some(15),
+            "{pocket:?}"
+        );
+        assert_eq!(
+            pocket.ammo_capacity(&UntypedInfoId::new("45")),
+            None,
+            "{pocket:?}"
+        );
+        assert_eq!(pocket.max_ammo_capacity(), Some(15), "{pocket:?}");
+    }
+
+    #[test]
+    fn glock_19_magazine_well_works() {
+        let gun =
+            from_json_str::<Gun>(include_str!("test_data/glock_19.json")).expect("Valid gun json");
+        let magazine = from_json_str::<Magazine>(include_str!("test_data/glockmag.json"))
+            .expect("Valid magazine json");
+        let ammo =
+            from_json_str::<Ammo>(include_str!("test_data/9mm.json")).expect("Valid ammo json");
+
+        let pocket_data = gun.common.pocket_data.as_ref().expect("Has pockets");
+        let well = pocket_data.first().expect("Has a pocket");
+        assert_eq!(well.pocket_type, PocketType::MagazineWell, "{well:?}");
+        assert!(well.allows(&magazine.common), "{well:?}");
+        assert!(!well.allows(&ammo.common), "{well:?}");
+    }
 }
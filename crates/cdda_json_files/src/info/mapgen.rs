@@ -0,0 +1,402 @@
+use crate::{
+    CharacterInfo, CommonItemInfo, CountRange, FurnitureInfo, Ignored, InfoId, ItemGroup,
+    MaybeFlatVec, MonsterGroup, OvermapTerrainInfo, TerrainInfo,
+};
+use bevy_platform::collections::HashMap;
+use fastrand::u32 as rand_u32;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+
+// Reference: https://github.com/CleverRaven/Cataclysm-DDA/blob/master/doc/JSON/MAPGEN.md
+
+#[derive(Debug, Deserialize)]
+pub struct Mapgen {
+    /// Mapgens have no id of their own. This is the first overmap terrain, or nested mapgen id, with a random suffix when that id was already in use.
+    pub id: InfoId<Self>,
+
+    pub om_terrain: Option<OmTerrainIds>,
+    pub nested_mapgen_id: Option<InfoId<Self>>,
+    pub update_mapgen_id: Option<InfoId<Self>>,
+
+    /// Only 'json' is supported
+    pub method: Arc<str>,
+
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+
+    pub object: Option<MapgenObject>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+const fn default_weight() -> u32 {
+    1000
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum OmTerrainIds {
+    Single(InfoId<OvermapTerrainInfo>),
+    /// Each overmap terrain uses the full mapgen
+    List(Vec<InfoId<OvermapTerrainInfo>>),
+    /// Each overmap terrain uses its own 24x24 part of the mapgen
+    Grid(Vec<Vec<InfoId<OvermapTerrainInfo>>>),
+}
+
+impl OmTerrainIds {
+    /// The overmap terrains, with the column and row of their part of the mapgen
+    #[must_use]
+    pub fn positioned(&self) -> Vec<(&InfoId<OvermapTerrainInfo>, usize, usize)> {
+        match self {
+            Self::Single(id) => vec![(id, 0, 0)],
+            Self::List(ids) => ids.iter().map(|id| (id, 0, 0)).collect(),
+            Self::Grid(rows) => rows
+                .iter()
+                .enumerate()
+                .flat_map(|(row, ids)| ids.iter().enumerate().map(move |(col, id)| (id, col, row)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MapgenObject {
+    pub fill_ter: Option<MapgenValue<TerrainInfo>>,
+
+    /// Each character refers to the mappings of this object, or of its palettes
+    #[serde(default)]
+    pub rows: Vec<Arc<str>>,
+
+    /// Only for nested mapgens
+    pub mapgensize: Option<(u8, u8)>,
+
+    /// Clockwise quarter turns
+    pub rotation: Option<CountRange>,
+
+    #[serde(default)]
+    pub palettes: Vec<MapgenValue<MapgenPalette>>,
+
+    #[serde(default)]
+    pub place_terrain: Vec<Placed<PlacedTerrain>>,
+
+    #[serde(default)]
+    pub place_furniture: Vec<Placed<PlacedFurniture>>,
+
+    #[serde(default)]
+    pub place_items: Vec<Placed<MapgenItems>>,
+
+    #[serde(default)]
+    pub place_item: Vec<Placed<MapgenItem>>,
+
+    #[serde(default)]
+    pub place_monsters: Vec<Placed<MapgenMonsters>>,
+
+    #[serde(default)]
+    pub place_monster: Vec<Placed<MapgenMonster>>,
+
+    #[serde(default)]
+    pub place_nested: Vec<Placed<MapgenNested>>,
+
+    #[serde(flatten)]
+    pub mapping: MapgenMapping,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MapgenPalette {
+    pub id: InfoId<Self>,
+
+    #[serde(default)]
+    pub palettes: Vec<MapgenValue<Self>>,
+
+    #[serde(flatten)]
+    pub mapping: MapgenMapping,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+/// What the characters in the rows of a mapgen stand for
+#[derive(Debug, Default, Deserialize)]
+pub struct MapgenMapping {
+    #[serde(default)]
+    pub terrain: HashMap<char, MapgenValue<TerrainInfo>>,
+
+    #[serde(default)]
+    pub furniture: HashMap<char, MapgenValue<FurnitureInfo>>,
+
+    #[serde(default)]
+    pub items: HashMap<char, MaybeFlatVec<MapgenItems>>,
+
+    #[serde(default)]
+    pub item: HashMap<char, MaybeFlatVec<MapgenItem>>,
+
+    #[serde(default)]
+    pub monsters: HashMap<char, MaybeFlatVec<MapgenMonsters>>,
+
+    #[serde(default)]
+    pub monster: HashMap<char, MaybeFlatVec<MapgenMonster>>,
+
+    #[serde(default)]
+    pub nested: HashMap<char, MaybeFlatVec<MapgenNested>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MapgenValue<T> {
+    Single(InfoId<T>),
+    Options(Vec<MapgenOption<T>>),
+    Distribution {
+        distribution: Vec<MapgenOption<T>>,
+    },
+    /// The parameters themselves are not supported, so the fallback is used
+    Param {
+        param: Arc<str>,
+        fallback: Option<InfoId<T>>,
+    },
+    /// Like 'switch', not supported yet
+    Other(JsonValue),
+}
+
+impl<T> MapgenValue<T> {
+    #[must_use]
+    pub fn pick(&self) -> Option<&InfoId<T>> {
+        match self {
+            Self::Single(id) => Some(id),
+            Self::Options(options)
+            | Self::Distribution {
+                distribution: options,
+            } => pick_weighted(options.iter().map(MapgenOption::weighted)),
+            Self::Param { fallback, .. } => fallback.as_ref(),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MapgenOption<T> {
+    Single(InfoId<T>),
+    Weighted(InfoId<T>, u32),
+}
+
+impl<T> MapgenOption<T> {
+    #[must_use]
+    pub const fn weighted(&self) -> (&InfoId<T>, u32) {
+        match self {
+            Self::Single(id) => (id, 1),
+            Self::Weighted(id, weight) => (id, *weight),
+        }
+    }
+}
+
+/// Returns `None` when the total weight is zero
+pub fn pick_weighted<'a, T>(options: impl IntoIterator<Item = (&'a T, u32)>) -> Option<&'a T> {
+    let options = options.into_iter().collect::<Vec<_>>();
+    let total = options.iter().map(|(_, weight)| weight).sum::<u32>();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rand_u32(0..total);
+    for (option, weight) in options {
+        if roll < weight {
+            return Some(option);
+        }
+        roll -= weight;
+    }
+    None
+}
+
+/// Something placed at a fixed or random position, in a 'place_...' list
+#[derive(Debug, Deserialize)]
+pub struct Placed<T> {
+    pub x: CountRange,
+    pub y: CountRange,
+
+    #[serde(flatten)]
+    pub what: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlacedTerrain {
+    pub ter: InfoId<TerrainInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PlacedFurniture {
+    pub furn: InfoId<FurnitureInfo>,
+}
+
+/// An item group
+#[derive(Debug, Deserialize)]
+pub struct MapgenItems {
+    pub item: InfoId<ItemGroup>,
+
+    /// Percentage
+    #[serde(default = "certain")]
+    pub chance: u32,
+
+    pub repeat: Option<CountRange>,
+}
+
+/// A single item
+#[derive(Debug, Deserialize)]
+pub struct MapgenItem {
+    pub item: InfoId<CommonItemInfo>,
+    pub amount: Option<CountRange>,
+
+    /// Percentage
+    #[serde(default = "certain")]
+    pub chance: u32,
+
+    pub repeat: Option<CountRange>,
+}
+
+/// A monster group
+#[derive(Debug, Deserialize)]
+pub struct MapgenMonsters {
+    pub monster: InfoId<MonsterGroup>,
+
+    /// One in this many
+    #[serde(default = "one_in_one")]
+    pub chance: u32,
+
+    /// Defaults to the density of the region
+    pub density: Option<f32>,
+
+    /// Only a single monster, regardless of the density
+    #[serde(default)]
+    pub individual: bool,
+}
+
+/// A single monster, or a single pick from a monster group
+#[derive(Debug, Deserialize)]
+pub struct MapgenMonster {
+    pub monster: Option<InfoId<CharacterInfo>>,
+    pub group: Option<InfoId<MonsterGroup>>,
+
+    /// Percentage
+    #[serde(default = "certain")]
+    pub chance: u32,
+
+    pub repeat: Option<CountRange>,
+    pub pack_size: Option<CountRange>,
+}
+
+/// One of the chunks is placed. The chunk 'null' places nothing.
+#[derive(Debug, Deserialize)]
+pub struct MapgenNested {
+    #[serde(default)]
+    pub chunks: Vec<MapgenOption<Mapgen>>,
+}
+
+const fn certain() -> u32 {
+    100
+}
+
+const fn one_in_one() -> u32 {
+    1
+}
+
+/// Only used to categorize nested mapgens
+#[derive(Debug, Deserialize)]
+pub struct NestedCategory {
+    pub id: InfoId<Self>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[cfg(test)]
+mod mapgen_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn house_works() {
+        let json = include_str!("test_data/mapgen_house.json");
+        let result = from_json_str::<Mapgen>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let object = result
+            .as_ref()
+            .ok()
+            .and_then(|mapgen| mapgen.object.as_ref())
+            .expect("House mapgen should have an object");
+        assert_eq!(object.rows.len(), 24, "{object:?}");
+        assert!(
+            object.rows.iter().all(|row| row.chars().count() == 24),
+            "{object:?}"
+        );
+        assert_eq!(object.palettes.len(), 1, "{object:?}");
+        assert_eq!(object.mapping.terrain.len(), 3, "{object:?}");
+        assert_eq!(object.place_monsters.len(), 1, "{object:?}");
+        assert_eq!(object.place_nested.len(), 1, "{object:?}");
+    }
+
+    #[test]
+    fn grid_works() {
+        let json = include_str!("test_data/mapgen_grid.json");
+        let result = from_json_str::<Mapgen>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let positioned = result
+            .as_ref()
+            .ok()
+            .and_then(|mapgen| mapgen.om_terrain.as_ref())
+            .map(OmTerrainIds::positioned)
+            .expect("Grid mapgen should have overmap terrains");
+        assert_eq!(
+            positioned,
+            vec![
+                (&InfoId::new("farm_1"), 0, 0),
+                (&InfoId::new("farm_2"), 1, 0),
+                (&InfoId::new("farm_3"), 0, 1),
+                (&InfoId::new("farm_4"), 1, 1),
+            ],
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn palette_works() {
+        let json = include_str!("test_data/palette_domestic.json");
+        let result = from_json_str::<MapgenPalette>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let mapping = &result.as_ref().expect("Palette should be valid").mapping;
+        assert!(
+            matches!(mapping.terrain.get(&'.'), Some(MapgenValue::Options(_))),
+            "{mapping:?}"
+        );
+        assert!(
+            matches!(mapping.furniture.get(&'d'), Some(MapgenValue::Param { .. })),
+            "{mapping:?}"
+        );
+        assert_eq!(
+            mapping.items.get(&'d').map(|items| items.0.len()),
+            Some(2),
+            "{mapping:?}"
+        );
+    }
+
+    #[test]
+    fn pick_weighted_works() {
+        let options = [(&'a', 0), (&'b', 3), (&'c', 0)];
+        assert_eq!(
+            pick_weighted(options),
+            Some(&'b'),
+            "Only options with a weight should be picked"
+        );
+        assert_eq!(
+            pick_weighted([(&'a', 0)]),
+            None,
+            "Nothing should be picked without any weight"
+        );
+    }
+}
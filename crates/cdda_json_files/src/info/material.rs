@@ -0,0 +1,201 @@
+use crate::{CommonItemInfo, Ignored, InfoId, ItemName, OptionalLinkedLater, RequiredLinkedLater};
+use serde::Deserialize;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use units::Volume;
+
+/// What items are made of
+///
+/// Do not confuse with [`ItemMaterial`]
+// PartialEq, Eq, and Hash manually implemented below
+#[derive(Debug, Deserialize)]
+pub struct Material {
+    pub id: InfoId<Self>,
+    pub name: ItemName,
+
+    #[serde(default)]
+    pub resist: MaterialResist,
+
+    /// Resistance against damage from physical hits
+    #[serde(default)]
+    pub chip_resist: u16,
+
+    /// In g/ml
+    pub density: Option<f32>,
+
+    #[serde(default)]
+    pub soft: bool,
+
+    #[serde(default)]
+    pub conductive: bool,
+
+    /// One entry per fire intensity
+    #[serde(default)]
+    pub burn_data: Vec<BurnData>,
+
+    /// The item used to repair items of this material
+    pub repaired_with: OptionalLinkedLater<CommonItemInfo>,
+
+    /// The damage adjectives from lightly damaged to almost broken
+    #[serde(default)]
+    pub dmg_adj: Vec<Arc<str>>,
+
+    pub bash_dmg_verb: Option<Arc<str>>,
+    pub cut_dmg_verb: Option<Arc<str>>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl Material {
+    /// Without burn data, a material is assumed to be fireproof
+    #[must_use]
+    pub fn fireproof(&self) -> bool {
+        self.burn_data
+            .first()
+            .is_none_or(|burn_data| burn_data.immune || burn_data.burn <= 0.0)
+    }
+
+    /// How fast the material burns in the weakest fire, in damage per second
+    #[must_use]
+    pub fn burn_rate(&self) -> f32 {
+        if self.fireproof() {
+            0.0
+        } else {
+            self.burn_data
+                .first()
+                .map_or(0.0, |burn_data| burn_data.burn)
+        }
+    }
+}
+
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Material {}
+
+impl Hash for Material {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// Resistances per damage type, per mm of thickness
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct MaterialResist {
+    pub bash: f32,
+    pub cut: f32,
+    pub stab: Option<f32>,
+    pub bullet: Option<f32>,
+    pub acid: f32,
+    pub heat: f32,
+    pub cold: f32,
+    pub electric: f32,
+    pub biological: f32,
+}
+
+impl MaterialResist {
+    /// Falls back to 80% of the cut resistance
+    #[must_use]
+    pub fn stab_or_cut(&self) -> f32 {
+        self.stab.unwrap_or(0.8 * self.cut)
+    }
+
+    /// Falls back to the cut resistance
+    #[must_use]
+    pub fn bullet_or_cut(&self) -> f32 {
+        self.bullet.unwrap_or(self.cut)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BurnData {
+    #[serde(default)]
+    pub immune: bool,
+
+    /// Only this much gets burned per turn
+    pub volume_per_turn: Option<Volume>,
+
+    #[serde(default)]
+    pub fuel: f32,
+
+    #[serde(default)]
+    pub smoke: f32,
+
+    #[serde(default)]
+    pub burn: f32,
+}
+
+/// A material of an item, with its portion
+///
+/// Do not confuse with [`Material`]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(untagged)]
+pub enum ItemMaterial {
+    Simple(RequiredLinkedLater<Material>),
+    Complex {
+        #[serde(rename(deserialize = "type"))]
+        type_: RequiredLinkedLater<Material>,
+
+        /// assume 1 when missing
+        portion: Option<f32>,
+    },
+}
+
+impl ItemMaterial {
+    #[must_use]
+    pub const fn material(&self) -> &RequiredLinkedLater<Material> {
+        match self {
+            Self::Simple(material)
+            | Self::Complex {
+                type_: material, ..
+            } => material,
+        }
+    }
+
+    /// Relative to the other materials of the item
+    #[must_use]
+    pub fn portion(&self) -> f32 {
+        match self {
+            Self::Simple(_) => 1.0,
+            Self::Complex { portion, .. } => portion.unwrap_or(1.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod material_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn steel_works() {
+        let json = include_str!("test_data/steel.json");
+        let result = from_json_str::<Material>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let steel = result.expect("Checked above");
+        assert!(steel.fireproof(), "{steel:?}");
+        assert!((steel.resist.bash - 4.0).abs() < f32::EPSILON, "{steel:?}");
+        assert!(!steel.repaired_with.is_none(), "{steel:?}");
+    }
+
+    #[test]
+    fn cotton_works() {
+        let json = include_str!("test_data/cotton.json");
+        let result = from_json_str::<Material>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let cotton = result.expect("Checked above");
+        assert!(!cotton.fireproof(), "{cotton:?}");
+        assert!(
+            (cotton.burn_rate() - 1.0).abs() < f32::EPSILON,
+            "{cotton:?}"
+        );
+    }
+}
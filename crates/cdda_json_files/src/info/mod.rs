@@ -1,52 +1,97 @@
 mod character_info;
+mod construction;
+mod damage_unit;
+mod effect_info;
 mod examine_action;
 mod field_info;
 mod flags;
 mod furniture_info;
+mod harvest;
 mod info_id;
 mod item_action;
 mod item_group;
 mod item_info;
+mod mapgen;
+mod material;
 mod migration;
+mod mod_info;
+mod monster_group;
 mod overmap_info;
 mod practice;
+mod profession;
 mod quality;
 mod recipe;
+mod region_settings;
 mod requirement;
+mod scenario;
+mod skill_info;
+mod start_location;
 mod terrain_info;
+mod trap_info;
+mod uncraft;
 mod use_action;
 mod vehicle_part_info;
+mod weather_info;
 
 pub use self::character_info::CharacterInfo;
+pub use self::construction::{
+    Construction, ConstructionCategory, ConstructionGroup, ConstructionTarget,
+};
+pub use self::damage_unit::{DamageUnit, RangedDamage};
+pub use self::effect_info::{AttackEffect, EffectInfo, EffectRating};
 pub use self::examine_action::{ExamineAction, ExamineActionOption, SimpleExamineAction};
-pub use self::field_info::FieldInfo;
+pub use self::field_info::{FieldEffect, FieldInfo};
 pub use self::flags::Flags;
 pub use self::furniture_info::{
     Bash, BashItem, BashItems, CountRange, FurnitureInfo, MoveCostIncrease, MoveCostMod,
 };
+pub use self::harvest::{Harvest, HarvestDropType, HarvestEntry};
 pub use self::info_id::{InfoId, InfoIdDescription, UntypedInfoId};
 pub use self::item_action::ItemAction;
-pub use self::item_group::{ItemGroup, SpawnItem};
+pub use self::item_group::{
+    ItemCollectionEntry, ItemGroup, ItemGroupDetails, ItemOrGroup, Probability, SpawnItem,
+};
 pub use self::item_info::{
-    Ammo, BionicItem, Book, CddaItemName, CddaPhase, Clothing, Comestible, CommonItemInfo,
-    Description, Engine, GenericItem, Gun, Gunmod, ItemName, ItemTypeDetails, ItemWithCommonInfo,
-    Magazine, PetArmor, PocketInfo, PocketType, SealedData, Tool, ToolClothing, Toolmod, Wheel,
+    Ammo, ArmorMaterial, ArmorPortion, BionicItem, Book, CddaItemName, CddaPhase, Clothing,
+    ClothingLayer, Comestible, CommonItemInfo, Description, Engine, GenericItem, Gun, Gunmod,
+    ItemName, ItemTypeDetails, ItemWithCommonInfo, Magazine, PetArmor, PocketInfo, PocketType,
+    SealedData, Tool, ToolClothing, Toolmod, Wheel,
+};
+pub use self::mapgen::{
+    Mapgen, MapgenItem, MapgenItems, MapgenMapping, MapgenMonster, MapgenMonsters, MapgenNested,
+    MapgenObject, MapgenOption, MapgenPalette, MapgenValue, NestedCategory, OmTerrainIds, Placed,
+    PlacedFurniture, PlacedTerrain, pick_weighted,
 };
+pub use self::material::{BurnData, ItemMaterial, Material, MaterialResist};
 pub use self::migration::{ItemMigration, VehiclePartMigration};
+pub use self::mod_info::ModInfo;
+pub use self::monster_group::{MonsterGroup, MonsterGroupEntry, SpawnCondition};
 pub use self::overmap_info::OvermapTerrainInfo;
 pub use self::practice::Practice;
+pub use self::profession::{
+    GenderedText, Profession, ProfessionItemList, ProfessionItems, ProfessionSkill,
+};
 pub use self::quality::{ItemQuality, Quality};
 pub use self::recipe::{
     Alternative, AutoLearn, BookLearn, BookLearnItem, Recipe, RecipeResult, RequiredQualities,
-    RequiredQuality, Using, UsingKind,
+    RequiredQuality, Reversible, Using, UsingKind,
 };
+pub use self::region_settings::{RegionSettings, RegionWeather};
 pub use self::requirement::{CalculatedRequirement, Requirement};
+pub use self::scenario::Scenario;
+pub use self::skill_info::SkillInfo;
+pub use self::start_location::{StartLocation, StartTerrain};
 pub use self::terrain_info::{MoveCost, TerrainInfo};
+pub use self::trap_info::{TrapAction, TrapInfo};
+pub use self::uncraft::{DisassemblyRecipe, Uncraft};
 pub use self::use_action::{
     AmmobeltDetail, AttachMolleDetail, CastSpellDetail, ChangeScentDetail, ConsumeDrugDetail,
-    DelayedTransformDetail, DeployFurnDetail, DeployTentDetail, DetailedUseAction,
+    DelayedTransformDetail, DeployFurnDetail, DeployTentDetail, DetailedUseAction, DrugEffect,
     EffectOnConditionsDetail, ExplosionDetail, FirestarterDetail, HealDetail, HolsterDetail,
     InscribeDetail, ManualNoiseDetail, MusicDetail, PlaceMonsterDetail, PlaceTrapDetail,
     RepairItemDetail, RevealMapDetail, TransformDetail, UnpackDetail, UseAction, WeighSelfDetail,
 };
 pub use self::vehicle_part_info::VehiclePartInfo;
+pub use self::weather_info::{
+    Precipitation, WeatherCondition, WeatherInfo, WeatherOperand, WeatherReading,
+};
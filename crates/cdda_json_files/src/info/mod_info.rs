@@ -0,0 +1,63 @@
+use crate::InfoId;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+
+/// The description of a mod, from its `modinfo.json` file
+#[derive(Debug, Deserialize)]
+pub struct ModInfo {
+    pub id: InfoId<Self>,
+    pub name: Arc<str>,
+
+    /// These mods should be loaded first
+    #[serde(default)]
+    pub dependencies: Vec<InfoId<Self>>,
+
+    /// The json directory of the mod, relative to the directory of `modinfo.json`
+    pub path: Option<Arc<str>>,
+
+    /// Only the base game is a core mod
+    #[serde(default)]
+    pub core: bool,
+
+    #[serde(default)]
+    pub obsolete: bool,
+
+    pub authors: Option<Vec<Arc<str>>>,
+    pub category: Option<Arc<str>>,
+    pub conflicts: Option<Vec<InfoId<Self>>>,
+    pub description: Option<JsonValue>,
+    pub loading_images: Option<Vec<Arc<str>>>,
+    pub maintainers: Option<Vec<Arc<str>>>,
+    pub version: Option<Arc<str>>,
+}
+
+#[cfg(test)]
+mod mod_info_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn core_works() {
+        let json = include_str!("test_data/modinfo_dda.json");
+        let result = from_json_str::<Vec<ModInfo>>(json);
+        assert!(
+            result.as_ref().is_ok_and(|mod_infos| mod_infos
+                .iter()
+                .all(|mod_info| mod_info.core && mod_info.path.is_some())),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn dependencies_work() {
+        let json = include_str!("test_data/modinfo_aftershock.json");
+        let result = from_json_str::<Vec<ModInfo>>(json);
+        assert!(
+            result.as_ref().is_ok_and(|mod_infos| mod_infos
+                .iter()
+                .all(|mod_info| mod_info.dependencies == [InfoId::new("dda")])),
+            "{result:?}"
+        );
+    }
+}
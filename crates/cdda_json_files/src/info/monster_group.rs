@@ -0,0 +1,175 @@
+use crate::{CharacterInfo, CountRange, Ignored, InfoId};
+use fastrand::u32 as rand_u32;
+use serde::Deserialize;
+use std::sync::Arc;
+use units::{Duration, Season, Timestamp};
+
+/// The total weight of a group. When the entries weigh less, the remainder goes to the default monster.
+const GROUP_WEIGHT: u32 = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterGroup {
+    pub id: InfoId<Self>,
+
+    /// Used instead of 'id' in older versions of CDDA
+    pub name: Option<Arc<str>>,
+
+    pub default: Option<InfoId<CharacterInfo>>,
+
+    #[serde(default)]
+    pub monsters: Vec<MonsterGroupEntry>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl MonsterGroup {
+    /// Returns `None` when the default monster is picked
+    ///
+    /// Without a time, the conditions of the entries are ignored.
+    #[must_use]
+    pub fn pick(&self, time: Option<Timestamp>) -> Option<&MonsterGroupEntry> {
+        let entries = self
+            .monsters
+            .iter()
+            .filter(|entry| time.is_none_or(|time| entry.applies(time)))
+            .collect::<Vec<_>>();
+        let total = entries
+            .iter()
+            .map(|entry| entry.weight)
+            .sum::<u32>()
+            .max(GROUP_WEIGHT);
+
+        let mut roll = rand_u32(0..total);
+        for entry in entries {
+            if roll < entry.weight {
+                return Some(entry);
+            }
+            roll -= entry.weight;
+        }
+        None
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterGroupEntry {
+    pub monster: Option<InfoId<CharacterInfo>>,
+    pub group: Option<InfoId<MonsterGroup>>,
+
+    #[serde(alias = "freq", default = "default_weight")]
+    pub weight: u32,
+
+    pub pack_size: Option<CountRange>,
+
+    #[serde(default)]
+    pub conditions: Vec<SpawnCondition>,
+
+    /// Only after this much time since the start of the cataclysm
+    pub starts: Option<Duration>,
+
+    /// Only until this much time since the start of the cataclysm
+    pub ends: Option<Duration>,
+}
+
+impl MonsterGroupEntry {
+    /// Without conditions of a kind, any time of day or season is fine
+    #[must_use]
+    pub fn applies(&self, time: Timestamp) -> bool {
+        let any_applies = |daily: bool| {
+            let mut relevant = self
+                .conditions
+                .iter()
+                .filter(|condition| condition.daily() == daily)
+                .peekable();
+            relevant.peek().is_none() || relevant.any(|condition| condition.applies(time))
+        };
+        let since_start = time - Timestamp::ZERO;
+
+        any_applies(true)
+            && any_applies(false)
+            && self.starts.is_none_or(|starts| starts <= since_start)
+            && self.ends.is_none_or(|ends| since_start < ends)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SpawnCondition {
+    Day,
+    Night,
+    Dawn,
+    Dusk,
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl SpawnCondition {
+    const fn daily(self) -> bool {
+        matches!(self, Self::Day | Self::Night | Self::Dawn | Self::Dusk)
+    }
+
+    fn applies(self, time: Timestamp) -> bool {
+        let sunlight = time.sunlight_percentage();
+        match self {
+            Self::Day => 0.0 < sunlight,
+            Self::Night => sunlight <= 0.0,
+            Self::Dawn => 0.0 < sunlight && sunlight < 1.0 && time.before_solar_noon(),
+            Self::Dusk => 0.0 < sunlight && sunlight < 1.0 && !time.before_solar_noon(),
+            Self::Spring => time.season() == Season::Spring,
+            Self::Summer => time.season() == Season::Summer,
+            Self::Autumn => time.season() == Season::Autumn,
+            Self::Winter => time.season() == Season::Winter,
+        }
+    }
+}
+
+const fn default_weight() -> u32 {
+    1
+}
+
+#[cfg(test)]
+mod monster_group_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/monstergroup_zombie.json");
+        let result = from_json_str::<MonsterGroup>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let group = result.as_ref().expect("Monster group should be valid");
+        assert_eq!(group.monsters.len(), 3, "{group:?}");
+        assert!(
+            group.monsters.iter().all(|entry| 0 < entry.weight),
+            "{group:?}"
+        );
+        assert!(
+            group.monsters.iter().any(|entry| entry.pack_size.is_some()),
+            "{group:?}"
+        );
+    }
+
+    #[test]
+    fn conditions_work() {
+        let json = include_str!("test_data/monstergroup_night.json");
+        let result = from_json_str::<MonsterGroup>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let group = result.expect("Checked above");
+        let bat = &group.monsters[0];
+        let days_per_season = 91;
+        let summer_noon = Timestamp::new(
+            (days_per_season + 1) * 24 * 3600 + 13 * 3600,
+            days_per_season,
+        );
+        let summer_midnight = Timestamp::new((days_per_season + 1) * 24 * 3600, days_per_season);
+        let winter_midnight =
+            Timestamp::new((3 * days_per_season + 1) * 24 * 3600, days_per_season);
+        assert!(!bat.applies(summer_noon), "{bat:?}");
+        assert!(bat.applies(summer_midnight), "{bat:?}");
+        assert!(!bat.applies(winter_midnight), "{bat:?}");
+    }
+}
@@ -11,8 +11,6 @@ pub struct OvermapTerrainInfo {
 
     pub looks_like: Option<UntypedInfoId>,
     pub connect_group: Option<Arc<str>>,
-    pub delete: Option<JsonValue>,
-    pub extend: Option<JsonValue>,
     pub extras: Option<Arc<str>>,
     pub flags: Flags,
     pub land_use_code: Option<Arc<str>>,
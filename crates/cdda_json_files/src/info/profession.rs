@@ -0,0 +1,160 @@
+use crate::{CommonItemInfo, Flags, Ignored, InfoId};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+pub struct Profession {
+    pub id: InfoId<Self>,
+    pub name: GenderedText,
+    pub description: GenderedText,
+
+    /// Cost in character points
+    #[serde(default)]
+    pub points: i16,
+
+    /// Hobbies are professions with the subtype 'hobby'
+    pub subtype: Option<Arc<str>>,
+
+    #[serde(default)]
+    pub skills: Vec<ProfessionSkill>,
+
+    #[serde(default)]
+    pub items: ProfessionItems,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    pub addictions: Option<Vec<JsonValue>>,
+    pub age_lower: Option<u8>,
+    pub age_upper: Option<u8>,
+
+    #[serde(rename = "CBMs")]
+    pub cbms: Option<Vec<JsonValue>>,
+
+    pub martial_arts: Option<Vec<JsonValue>>,
+    pub missions: Option<Vec<JsonValue>>,
+    pub npc_background: Option<Arc<str>>,
+    pub pets: Option<Vec<JsonValue>>,
+    pub proficiencies: Option<Vec<JsonValue>>,
+    pub requirement: Option<Arc<str>>,
+    pub spells: Option<Vec<JsonValue>>,
+    pub sub_professions: Option<Vec<JsonValue>>,
+    pub traits: Option<Vec<JsonValue>>,
+    pub vehicle: Option<Arc<str>>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl Profession {
+    #[must_use]
+    pub fn is_hobby(&self) -> bool {
+        self.subtype.as_deref() == Some("hobby")
+    }
+}
+
+/// Text that may differ between male and female characters
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GenderedText {
+    Simple(Arc<str>),
+    Translatable { str: Arc<str> },
+    Gendered { male: Box<Self>, female: Box<Self> },
+}
+
+impl GenderedText {
+    #[must_use]
+    pub fn male(&self) -> &Arc<str> {
+        match self {
+            Self::Simple(text) | Self::Translatable { str: text } => text,
+            Self::Gendered { male, .. } => male.male(),
+        }
+    }
+
+    #[must_use]
+    pub fn female(&self) -> &Arc<str> {
+        match self {
+            Self::Simple(text) | Self::Translatable { str: text } => text,
+            Self::Gendered { female, .. } => female.female(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProfessionSkill {
+    pub name: Arc<str>,
+    pub level: u8,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ProfessionItems {
+    #[serde(default)]
+    pub both: ProfessionItemList,
+
+    #[serde(default)]
+    pub male: ProfessionItemList,
+
+    #[serde(default)]
+    pub female: ProfessionItemList,
+}
+
+/// The individual starting items. Item groups are not included.
+#[derive(Debug, Default, Deserialize)]
+#[serde(from = "CddaProfessionItemList")]
+pub struct ProfessionItemList(pub Vec<InfoId<CommonItemInfo>>);
+
+impl From<CddaProfessionItemList> for ProfessionItemList {
+    fn from(cdda: CddaProfessionItemList) -> Self {
+        let (items, entries) = match cdda {
+            CddaProfessionItemList::Simple(items) => (items, Vec::new()),
+            CddaProfessionItemList::Complex { items, entries } => (items, entries),
+        };
+        Self(
+            items
+                .iter()
+                .chain(entries.iter().filter_map(|entry| entry.get("item")))
+                .filter_map(JsonValue::as_str)
+                .map(InfoId::new)
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CddaProfessionItemList {
+    Simple(Vec<JsonValue>),
+    Complex {
+        #[serde(default)]
+        items: Vec<JsonValue>,
+
+        #[serde(default)]
+        entries: Vec<JsonValue>,
+    },
+}
+
+#[cfg(test)]
+mod profession_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/unemployed.json");
+        let result = from_json_str::<Profession>(json);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn items_work() {
+        let json = include_str!("test_data/unemployed.json");
+        let items = from_json_str::<Profession>(json).map(|profession| profession.items);
+        assert!(
+            items.as_ref().is_ok_and(|items| items.both.0.len() == 5
+                && items.male.0.len() == 1
+                && items.female.0.len() == 2),
+            "{items:?}"
+        );
+    }
+}
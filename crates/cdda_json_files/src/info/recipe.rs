@@ -60,12 +60,11 @@ pub struct Recipe {
     pub container: Option<Arc<str>>,
     pub decomp_learn: Option<u8>,
     pub delete_flags: Option<Vec<JsonValue>>,
-    pub extend: Option<JsonValue>,
     pub flags: Flags,
     pub never_learn: Option<bool>,
     pub proficiencies: Option<Vec<JsonValue>>,
     pub result_mult: Option<u8>,
-    pub reversible: Option<JsonValue>,
+    pub reversible: Option<Reversible>,
     pub skills_required: Option<Vec<JsonValue>>,
 
     #[serde(default)]
@@ -75,6 +74,18 @@ pub struct Recipe {
     _ignored: Ignored<Self>,
 }
 
+impl Recipe {
+    /// The time to disassemble the result, if the recipe is reversible
+    #[must_use]
+    pub fn reversed_time(&self) -> Option<Duration> {
+        match self.reversible.as_ref()? {
+            Reversible::Bool(false) => None,
+            Reversible::Bool(true) => Some(self.time.unwrap_or(Duration::MINUTE)),
+            Reversible::Timed { time } => Some(*time),
+        }
+    }
+}
+
 impl PartialEq for Recipe {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -128,6 +139,13 @@ impl RecipeResult {
     }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Reversible {
+    Bool(bool),
+    Timed { time: Duration },
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum BookLearn {
@@ -136,6 +154,34 @@ pub enum BookLearn {
     Other(JsonValue),
 }
 
+impl BookLearn {
+    /// The books that teach the recipe, with the skill level they require, if specified
+    ///
+    /// Returns `None` for an unknown format.
+    #[must_use]
+    pub fn books(&self) -> Option<Vec<(InfoId<CommonItemInfo>, Option<u8>)>> {
+        match self {
+            Self::List(list) => Some(
+                list.iter()
+                    .map(|item| (item.id(), item.skill_level()))
+                    .collect(),
+            ),
+            Self::Map(map) => Some(
+                map.iter()
+                    .map(|(id, details)| {
+                        let skill_level = details
+                            .get("skill_level")
+                            .and_then(JsonValue::as_u64)
+                            .map(|level| level.min(u64::from(u8::MAX)) as u8);
+                        (id.clone(), skill_level)
+                    })
+                    .collect(),
+            ),
+            Self::Other(_) => None,
+        }
+    }
+}
+
 impl Default for BookLearn {
     fn default() -> Self {
         Self::List(Vec::new())
@@ -157,6 +203,14 @@ impl BookLearnItem {
             Self::Simple(id) | Self::Wrapped((id,)) | Self::WithSkill(id, _) => id.clone(),
         }
     }
+
+    #[must_use]
+    pub const fn skill_level(&self) -> Option<u8> {
+        match self {
+            Self::Simple(_) | Self::Wrapped(_) => None,
+            Self::WithSkill(_, skill_level) => Some(*skill_level),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -354,6 +408,18 @@ mod recipe_tests {
         );
     }
 
+    #[test]
+    fn reversible_works() {
+        let json = include_str!("test_data/slingpack.json");
+        let recipe = from_json_str::<Recipe>(json);
+        let recipe = recipe.as_ref();
+        assert!(
+            recipe.is_ok_and(|recipe| recipe.reversed_time() == Some(Duration::MINUTE * 5)),
+            "{:?}",
+            recipe.map(|recipe| &recipe.reversible)
+        );
+    }
+
     #[test]
     fn building_works() {
         let json = include_str!("test_data/building.json");
@@ -0,0 +1,54 @@
+use crate::{Ignored, InfoId, RequiredLinkedLater, WeatherInfo};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct RegionSettings {
+    pub id: InfoId<Self>,
+    pub weather: Option<RegionWeather>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+/// The climate of a region
+#[derive(Debug, Deserialize)]
+pub struct RegionWeather {
+    /// Yearly average, in degrees Celsius
+    pub base_temperature: f32,
+
+    /// Yearly average, in percent
+    pub base_humidity: f32,
+
+    /// Yearly average, in millibar
+    pub base_pressure: f32,
+
+    /// Yearly average, in km/h
+    pub base_wind: f32,
+
+    /// The last weather type that applies is picked
+    pub weather_types: Vec<RequiredLinkedLater<WeatherInfo>>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[cfg(test)]
+mod region_settings_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/region_settings_default.json");
+        let result = from_json_str::<RegionSettings>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let region_settings = result.expect("Checked above");
+        let weather = region_settings.weather.as_ref();
+        assert_eq!(
+            weather.map(|weather| weather.weather_types.len()),
+            Some(13),
+            "{region_settings:?}"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    Alternative, Error, Ignored, InfoId, Recipe, RequiredComponent, RequiredPart,
-    RequiredQualities, RequiredTool, Using,
+    Alternative, Construction, DisassemblyRecipe, Error, Ignored, InfoId, Recipe,
+    RequiredComponent, RequiredPart, RequiredQualities, RequiredTool, Uncraft, Using,
 };
 use serde::Deserialize;
 use std::{iter::once, num::NonZeroU32};
@@ -32,6 +32,25 @@ pub struct CalculatedRequirement {
 }
 
 impl CalculatedRequirement {
+    fn including_using(
+        qualities: &RequiredQualities,
+        components: &[Vec<Alternative<RequiredComponent>>],
+        tools: &[Vec<Alternative<RequiredTool>>],
+        using: &[Using],
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            qualities: qualities.clone(),
+            components: clone(components, ONE),
+            tools: clone(tools, ONE),
+        }
+        .combine(
+            using
+                .iter()
+                .map(Self::try_from)
+                .collect::<Result<Vec<_>, Error>>()?,
+        ))
+    }
+
     fn combine(self, others: Vec<Self>) -> Self {
         let (other_qualities, other_components_and_tools): (Vec<_>, Vec<_>) = others
             .into_iter()
@@ -83,18 +102,47 @@ impl TryFrom<&Using> for CalculatedRequirement {
 impl TryFrom<&Recipe> for CalculatedRequirement {
     type Error = Error;
     fn try_from(recipe: &Recipe) -> Result<Self, Error> {
-        Ok(Self {
-            qualities: recipe.qualities.clone(),
-            components: clone(&recipe.components, ONE),
-            tools: clone(&recipe.tools, ONE),
+        Self::including_using(
+            &recipe.qualities,
+            &recipe.components,
+            &recipe.tools,
+            &recipe.using,
+        )
+    }
+}
+
+impl TryFrom<&Construction> for CalculatedRequirement {
+    type Error = Error;
+    fn try_from(construction: &Construction) -> Result<Self, Error> {
+        Self::including_using(
+            &construction.qualities,
+            &construction.components,
+            &construction.tools,
+            &construction.using,
+        )
+    }
+}
+
+impl TryFrom<&Uncraft> for CalculatedRequirement {
+    type Error = Error;
+    fn try_from(uncraft: &Uncraft) -> Result<Self, Error> {
+        Self::including_using(
+            &uncraft.qualities,
+            &uncraft.components,
+            &uncraft.tools,
+            &uncraft.using,
+        )
+    }
+}
+
+/// The components are returned instead of consumed
+impl TryFrom<&DisassemblyRecipe> for CalculatedRequirement {
+    type Error = Error;
+    fn try_from(disassembly_recipe: &DisassemblyRecipe) -> Result<Self, Error> {
+        match disassembly_recipe {
+            DisassemblyRecipe::Uncraft(uncraft) => Self::try_from(&**uncraft),
+            DisassemblyRecipe::Reversed(recipe) => Self::try_from(&**recipe),
         }
-        .combine(
-            recipe
-                .using
-                .iter()
-                .map(Self::try_from)
-                .collect::<Result<Vec<_>, Error>>()?,
-        ))
     }
 }
 
@@ -0,0 +1,79 @@
+use crate::{Flags, GenderedText, Ignored, InfoId, Profession, StartLocation};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+pub struct Scenario {
+    pub id: InfoId<Self>,
+    pub name: GenderedText,
+    pub description: GenderedText,
+
+    /// Cost in character points
+    #[serde(default)]
+    pub points: i16,
+
+    pub start_name: Option<Arc<str>>,
+
+    #[serde(default)]
+    pub allowed_locs: Vec<InfoId<StartLocation>>,
+
+    /// When empty, all professions are allowed
+    #[serde(default)]
+    pub professions: Vec<InfoId<Profession>>,
+
+    /// Inverts [`Self::professions`]
+    #[serde(default)]
+    pub blacklist_professions: bool,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    pub add_professions: Option<bool>,
+    pub allowed_hobbies: Option<Vec<JsonValue>>,
+    pub allowed_traits: Option<Vec<JsonValue>>,
+    pub custom_initial_date: Option<JsonValue>,
+    pub distance_initial_visibility: Option<u16>,
+    pub eoc: Option<Vec<JsonValue>>,
+    pub eocs: Option<Vec<JsonValue>>,
+    pub forbidden_traits: Option<Vec<JsonValue>>,
+    pub forced_traits: Option<Vec<JsonValue>>,
+    pub map_extra: Option<JsonValue>,
+    pub missions: Option<Vec<JsonValue>>,
+    pub requirement: Option<Arc<str>>,
+    pub reveal_locale: Option<bool>,
+    pub start_of_cataclysm: Option<JsonValue>,
+    pub start_of_game: Option<JsonValue>,
+    pub surround_groups: Option<Vec<JsonValue>>,
+    pub vehicle: Option<Arc<str>>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl Scenario {
+    #[must_use]
+    pub fn allows(&self, profession: &InfoId<Profession>) -> bool {
+        self.professions.is_empty()
+            || self.professions.contains(profession) != self.blacklist_professions
+    }
+}
+
+#[cfg(test)]
+mod scenario_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/evacuee.json");
+        let result = from_json_str::<Scenario>(json);
+        assert!(
+            result
+                .as_ref()
+                .is_ok_and(|scenario| scenario.allowed_locs.len() == 2
+                    && scenario.allows(&InfoId::new("unemployed"))),
+            "{result:?}"
+        );
+    }
+}
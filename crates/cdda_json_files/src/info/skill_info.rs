@@ -0,0 +1,45 @@
+use crate::{Ignored, InfoId, ItemName};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+pub struct SkillInfo {
+    pub id: InfoId<Self>,
+    pub name: ItemName,
+    pub description: Arc<str>,
+
+    /// The id of a `skill_display_type`
+    pub display_category: Option<Arc<str>>,
+
+    /// Lower ranks are shown first
+    #[serde(default)]
+    pub sort_rank: i32,
+
+    #[serde(default)]
+    pub obsolete: bool,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[cfg(test)]
+mod skill_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/skill_melee.json");
+        let result = from_json_str::<SkillInfo>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let skill_info = result.expect("Checked above");
+        assert_eq!(&*skill_info.name.single, "melee", "{skill_info:?}");
+        assert_eq!(
+            skill_info.display_category.as_deref(),
+            Some("display_melee"),
+            "{skill_info:?}"
+        );
+        assert!(!skill_info.obsolete, "{skill_info:?}");
+    }
+}
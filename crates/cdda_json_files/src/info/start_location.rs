@@ -0,0 +1,55 @@
+use crate::{Flags, GenderedText, Ignored, InfoId, OvermapTerrainInfo};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+#[derive(Debug, Deserialize)]
+pub struct StartLocation {
+    pub id: InfoId<Self>,
+    pub name: GenderedText,
+
+    /// The overmap terrain types that are suitable as a start
+    #[serde(default)]
+    pub terrain: Vec<StartTerrain>,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    pub allowed_z_levels: Option<JsonValue>,
+    pub city_distance: Option<JsonValue>,
+    pub city_sizes: Option<JsonValue>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum StartTerrain {
+    Simple(InfoId<OvermapTerrainInfo>),
+    /// Ignores `om_terrain_match_type`
+    Matched {
+        om_terrain: InfoId<OvermapTerrainInfo>,
+    },
+}
+
+impl StartTerrain {
+    #[must_use]
+    pub const fn om_terrain(&self) -> &InfoId<OvermapTerrainInfo> {
+        match self {
+            Self::Simple(om_terrain) | Self::Matched { om_terrain } => om_terrain,
+        }
+    }
+}
+
+#[cfg(test)]
+mod start_location_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/sloc_shelter.json");
+        let result = from_json_str::<StartLocation>(json);
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     Bash, ExamineActionOption, Flags, Ignored, InfoId, ItemName, MoveCostIncrease,
-    OptionalLinkedLater, UntypedInfoId,
+    OptionalLinkedLater, TrapInfo, UntypedInfoId,
 };
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
@@ -46,7 +46,7 @@ pub struct TerrainInfo {
     pub rotates_to: Option<Arc<str>>,
     pub shoot: Option<JsonValue>,
     pub transforms_into: Option<Arc<str>>,
-    pub trap: Option<Arc<str>>,
+    pub trap: OptionalLinkedLater<TrapInfo>,
 
     #[serde(flatten)]
     _ignored: Ignored<Self>,
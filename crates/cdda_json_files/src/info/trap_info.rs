@@ -0,0 +1,150 @@
+use crate::{CommonItemInfo, Flags, Ignored, InfoId, ItemName, RequiredLinkedLater, UntypedInfoId};
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize)]
+pub struct TrapInfo {
+    pub id: InfoId<Self>,
+    pub name: ItemName,
+    pub color: JsonValue,
+    pub symbol: Arc<str>,
+
+    /// Higher is harder to detect, negative is always visible
+    pub visibility: i8,
+
+    /// Higher is easier to avoid
+    pub avoidance: u8,
+
+    /// Higher is harder to disarm
+    pub difficulty: u8,
+
+    #[serde(default)]
+    pub action: TrapAction,
+
+    /// Not a danger, like a funnel or a bed roll
+    #[serde(default)]
+    pub benign: bool,
+
+    #[serde(default)]
+    pub always_invisible: bool,
+
+    #[serde(default)]
+    pub remove_on_trigger: bool,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    /// The items that remain after disarming
+    #[serde(default)]
+    pub drops: Vec<RequiredLinkedLater<CommonItemInfo>>,
+
+    pub looks_like: Option<UntypedInfoId>,
+
+    pub comfort: Option<i8>,
+    pub eocs: Option<Vec<JsonValue>>,
+    pub floor_bedding_warmth: Option<i16>,
+    pub funnel_radius: Option<u16>,
+    pub map_regen: Option<Arc<str>>,
+    pub memory_variant: Option<Arc<str>>,
+    pub sound: Option<Arc<str>>,
+    pub sound_type: Option<Arc<str>>,
+    pub sound_variant: Option<Arc<str>>,
+    pub sound_volume: Option<u8>,
+    pub spell_data: Option<JsonValue>,
+    pub trap_radius: Option<u8>,
+    pub trigger_items: Option<Vec<JsonValue>>,
+    pub trigger_message_npc: Option<Arc<str>>,
+    pub trigger_message_u: Option<Arc<str>>,
+    pub trigger_weight: Option<Arc<str>>,
+    pub vehicle_data: Option<JsonValue>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl TrapInfo {
+    /// CDDA uses a difficulty of 99 for traps that can not be disarmed, like a pit
+    #[must_use]
+    pub const fn disarmable(&self) -> bool {
+        self.difficulty < 99
+    }
+
+    /// Does not have to be detected first
+    #[must_use]
+    pub const fn obvious(&self) -> bool {
+        self.visibility < 0 && !self.always_invisible
+    }
+
+    /// Removed after triggering once, like a land mine
+    #[must_use]
+    pub const fn single_use(&self) -> bool {
+        self.remove_on_trigger
+            || matches!(self.action, TrapAction::Landmine | TrapAction::Boobytrap)
+    }
+}
+
+/// What happens when a trap is triggered
+///
+/// Reference: `trap_function_from_string` in CDDA's trapfunc.cpp
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrapAction {
+    #[default]
+    None,
+    Bubble,
+    Glass,
+    Cot,
+    Beartrap,
+    Board,
+    Caltrops,
+    CaltropsGlass,
+    Tripwire,
+    Crossbow,
+    Shotgun,
+    Blade,
+    SnareLight,
+    SnareHeavy,
+    Landmine,
+    Boobytrap,
+    Sinkhole,
+    Pit,
+    PitSpikes,
+    PitGlass,
+    Ledge,
+
+    /// Actions that are not supported yet, like `telepad` and `cast_spell`
+    #[serde(other)]
+    Other,
+}
+
+#[cfg(test)]
+mod trap_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/beartrap.json");
+        let result = from_json_str::<TrapInfo>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let trap_info = result.expect("Checked above");
+        assert_eq!(trap_info.action, TrapAction::Beartrap, "{trap_info:?}");
+        assert!(trap_info.disarmable(), "{trap_info:?}");
+        assert!(!trap_info.obvious(), "{trap_info:?}");
+        assert_eq!(trap_info.drops.len(), 1, "{trap_info:?}");
+    }
+
+    #[test]
+    fn unknown_action_works() {
+        let json = include_str!("test_data/telepad.json");
+        let result = from_json_str::<TrapInfo>(json);
+        assert!(
+            result
+                .as_ref()
+                .is_ok_and(|trap_info| trap_info.action == TrapAction::Other),
+            "{result:?}"
+        );
+    }
+}
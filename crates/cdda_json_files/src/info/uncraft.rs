@@ -0,0 +1,101 @@
+use crate::info::practice::ActivityLevel;
+use crate::{
+    Alternative, CommonItemInfo, Flags, Ignored, InfoId, Recipe, RequiredComponent,
+    RequiredLinkedLater, RequiredQualities, RequiredTool, Using,
+};
+use serde::Deserialize;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use units::Duration;
+
+/// Disassembly of an item without a reversible recipe
+// PartialEq, Eq, and Hash manually implemented below
+#[derive(Debug, Deserialize)]
+pub struct Uncraft {
+    pub id: InfoId<Self>,
+
+    /// The item that gets disassembled
+    pub result: RequiredLinkedLater<CommonItemInfo>,
+
+    pub skill_used: Option<Arc<str>>,
+
+    #[serde(default)]
+    pub difficulty: u8,
+
+    pub time: Option<Duration>,
+    pub activity_level: Option<ActivityLevel>,
+
+    #[serde(default)]
+    pub qualities: RequiredQualities,
+
+    /// The components that are returned
+    #[serde(default)]
+    pub components: Vec<Vec<Alternative<RequiredComponent>>>,
+
+    #[serde(default)]
+    pub tools: Vec<Vec<Alternative<RequiredTool>>>,
+
+    #[serde(default)]
+    pub using: Vec<Using>,
+
+    #[serde(default)]
+    pub flags: Flags,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl PartialEq for Uncraft {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Uncraft {}
+
+impl Hash for Uncraft {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// How an item gets taken apart
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DisassemblyRecipe {
+    Uncraft(Arc<Uncraft>),
+
+    /// Assumes the recipe to be reversible
+    Reversed(Arc<Recipe>),
+}
+
+impl DisassemblyRecipe {
+    /// Without a time, a minute is assumed
+    pub fn time(&self) -> Duration {
+        match self {
+            Self::Uncraft(uncraft) => uncraft.time,
+            Self::Reversed(recipe) => recipe.reversed_time(),
+        }
+        .unwrap_or(Duration::MINUTE)
+    }
+}
+
+#[cfg(test)]
+mod uncraft_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/uncraft_mp3.json");
+        let result = from_json_str::<Uncraft>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let uncraft = result.expect("Checked above");
+        assert_eq!(uncraft.components.len(), 3, "{uncraft:?}");
+        assert_eq!(
+            DisassemblyRecipe::Uncraft(Arc::new(uncraft)).time(),
+            Duration::MINUTE * 6,
+            "Time from json"
+        );
+    }
+}
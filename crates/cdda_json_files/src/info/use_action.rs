@@ -1,6 +1,7 @@
 use crate::{
-    CharacterInfo, CommonItemInfo, FieldInfo, FurnitureInfo, Ignored, InfoId, ItemAction,
-    ItemGroup, OptionalLinkedLater, Range, RequiredLinkedLater, UntypedInfoId,
+    CharacterInfo, CommonItemInfo, EffectInfo, FieldInfo, FurnitureInfo, Ignored, InfoId,
+    ItemAction, ItemGroup, Material, OptionalLinkedLater, Range, RequiredLinkedLater,
+    UntypedInfoId,
 };
 use bevy_platform::collections::HashMap;
 use serde::{Deserialize, de::Error as _};
@@ -221,7 +222,7 @@ pub struct ConsumeDrugDetail {
 
 #[derive(Debug, Deserialize)]
 pub struct DrugEffect {
-    pub id: UntypedInfoId, // TODO link
+    pub id: RequiredLinkedLater<EffectInfo>,
 
     #[serde(default)]
     pub duration: Duration,
@@ -425,7 +426,7 @@ pub struct PlaceTrapDetail {
 #[derive(Debug, Deserialize)]
 pub struct RepairItemDetail {
     pub cost_scaling: f32,
-    pub materials: Vec<UntypedInfoId>, // TODO link
+    pub materials: Vec<RequiredLinkedLater<Material>>,
     pub move_cost: u16,
     pub skill: UntypedInfoId, // TODO link
 
@@ -27,14 +27,12 @@ pub struct VehiclePartInfo {
     pub damage_modifier: Option<u16>,
     pub damage_reduction: Option<JsonValue>,
     pub damaged_power_factor: Option<f32>,
-    pub delete: Option<JsonValue>,
     pub description: Option<Arc<str>>,
     pub emissions: Option<Vec<JsonValue>>,
     pub energy_consumption: Option<Arc<str>>,
     pub epower: Option<i32>,
     pub exclusions: Option<Vec<JsonValue>>,
     pub exhaust: Option<Vec<JsonValue>>,
-    pub extend: Option<JsonValue>,
     pub floor_bedding_warmth: Option<u16>,
     pub folded_volume: Option<Arc<str>>,
     pub folding_time: Option<Arc<str>>,
@@ -45,7 +43,6 @@ pub struct VehiclePartInfo {
     pub muscle_power_factor: Option<u8>,
     pub noise_factor: Option<u8>,
     pub power: Option<i32>,
-    pub proportional: Option<JsonValue>,
     pub pseudo_tools: Option<Vec<JsonValue>>,
     pub qualities: Option<Vec<JsonValue>>,
     pub requirements: Option<JsonValue>,
@@ -0,0 +1,244 @@
+use crate::{Ignored, InfoId, ItemName};
+use serde::Deserialize;
+use std::sync::Arc;
+use units::Duration;
+
+/// A `weather_type`, like 'drizzle' or 'fog'
+#[derive(Debug, Deserialize)]
+pub struct WeatherInfo {
+    pub id: InfoId<Self>,
+    pub name: ItemName,
+
+    /// Divides how far characters can see
+    #[serde(default = "one")]
+    pub sight_penalty: f32,
+
+    /// Added to the light level, in percentage points
+    #[serde(default)]
+    pub light_modifier: i16,
+
+    /// Subtracted from the volume of sounds
+    #[serde(default)]
+    pub sound_attn: u8,
+
+    #[serde(default)]
+    pub precip: Precipitation,
+
+    /// Rain when true, snow otherwise, if there is precipitation
+    #[serde(default)]
+    pub rains: bool,
+
+    #[serde(default)]
+    pub acidic: bool,
+
+    #[serde(default)]
+    pub dangerous: bool,
+
+    #[serde(default)]
+    pub duration_min: Duration,
+
+    #[serde(default)]
+    pub duration_max: Duration,
+
+    /// Without a condition, the weather type always applies
+    pub condition: Option<WeatherCondition>,
+
+    #[serde(flatten)]
+    _ignored: Ignored<Self>,
+}
+
+impl WeatherInfo {
+    #[must_use]
+    pub fn applies(&self, reading: &WeatherReading, current: &InfoId<Self>) -> bool {
+        self.condition
+            .as_ref()
+            .is_none_or(|condition| condition.applies(reading, current))
+    }
+
+    #[must_use]
+    pub fn rain(&self) -> bool {
+        self.precip != Precipitation::None && self.rains
+    }
+
+    #[must_use]
+    pub fn snow(&self) -> bool {
+        self.precip != Precipitation::None && !self.rains
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Precipitation {
+    #[default]
+    None,
+    VeryLight,
+    Light,
+    Heavy,
+}
+
+/// The measurable state of the weather, used to pick the weather type
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeatherReading {
+    /// In degrees Celsius
+    pub temperature: f32,
+
+    /// Relative humidity, in percent
+    pub humidity: f32,
+
+    /// In millibar
+    pub pressure: f32,
+
+    /// In km/h
+    pub windpower: f32,
+
+    pub daylight: bool,
+}
+
+impl WeatherReading {
+    fn get(&self, aspect: &str) -> Option<f32> {
+        match aspect {
+            "temperature" => Some(self.temperature),
+            "humidity" => Some(self.humidity),
+            "pressure" => Some(self.pressure),
+            "windpower" => Some(self.windpower),
+            _ => None,
+        }
+    }
+}
+
+/// The subset of dialogue conditions that weather types use
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum WeatherCondition {
+    And {
+        and: Vec<Self>,
+    },
+    Or {
+        or: Vec<Self>,
+    },
+    Not {
+        not: Box<Self>,
+    },
+    IsWeather {
+        is_weather: InfoId<WeatherInfo>,
+    },
+    Compare {
+        #[serde(alias = "compare_int")]
+        compare_num: (WeatherOperand, Arc<str>, WeatherOperand),
+    },
+
+    /// Like `["weather('humidity')", ">", "96"]`
+    Math {
+        math: (Arc<str>, Arc<str>, Arc<str>),
+    },
+
+    /// Like `is_day`
+    Simple(Arc<str>),
+}
+
+impl WeatherCondition {
+    /// Unknown conditions do not apply
+    #[must_use]
+    pub fn applies(&self, reading: &WeatherReading, current: &InfoId<WeatherInfo>) -> bool {
+        match self {
+            Self::And { and } => and.iter().all(|part| part.applies(reading, current)),
+            Self::Or { or } => or.iter().any(|part| part.applies(reading, current)),
+            Self::Not { not } => !not.applies(reading, current),
+            Self::IsWeather { is_weather } => is_weather == current,
+            Self::Compare {
+                compare_num: (left, operator, right),
+            } => compare(left.value(reading), operator, right.value(reading)),
+            Self::Math {
+                math: (left, operator, right),
+            } => compare(
+                math_value(left, reading),
+                operator,
+                math_value(right, reading),
+            ),
+            Self::Simple(simple) => match &**simple {
+                "is_day" => reading.daylight,
+                _ => false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum WeatherOperand {
+    Weather {
+        weather: Arc<str>,
+    },
+    Const {
+        #[serde(rename = "const")]
+        value: f32,
+    },
+}
+
+impl WeatherOperand {
+    fn value(&self, reading: &WeatherReading) -> Option<f32> {
+        match self {
+            Self::Weather { weather } => reading.get(weather),
+            Self::Const { value } => Some(*value),
+        }
+    }
+}
+
+fn math_value(operand: &str, reading: &WeatherReading) -> Option<f32> {
+    operand
+        .strip_prefix("weather('")
+        .and_then(|rest| rest.strip_suffix("')"))
+        .map_or_else(|| operand.trim().parse().ok(), |aspect| reading.get(aspect))
+}
+
+fn compare(left: Option<f32>, operator: &str, right: Option<f32>) -> bool {
+    let (Some(left), Some(right)) = (left, right) else {
+        return false;
+    };
+    match operator {
+        "<" => left < right,
+        "<=" => left <= right,
+        ">" => left > right,
+        ">=" => left >= right,
+        "==" => (left - right).abs() < f32::EPSILON,
+        "!=" => f32::EPSILON <= (left - right).abs(),
+        _ => false,
+    }
+}
+
+const fn one() -> f32 {
+    1.0
+}
+
+#[cfg(test)]
+mod weather_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+
+    #[test]
+    fn it_works() {
+        let json = include_str!("test_data/weather_drizzle.json");
+        let result = from_json_str::<WeatherInfo>(json);
+        assert!(result.is_ok(), "{result:?}");
+
+        let weather_info = result.expect("Checked above");
+        assert!(weather_info.rain(), "{weather_info:?}");
+        assert_eq!(weather_info.light_modifier, -20, "{weather_info:?}");
+
+        let mut reading = WeatherReading {
+            temperature: 12.0,
+            humidity: 98.0,
+            pressure: 1000.0,
+            windpower: 5.0,
+            daylight: true,
+        };
+        let clear = InfoId::new("clear");
+        assert!(weather_info.applies(&reading, &clear), "{reading:?}");
+
+        reading.pressure = 1010.0;
+        assert!(!weather_info.applies(&reading, &clear), "{reading:?}");
+
+        let drizzle = InfoId::new("drizzle");
+        assert!(weather_info.applies(&reading, &drizzle), "{reading:?}");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::structure::FlatVec;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 
 #[derive(Debug, Deserialize)]
 pub struct At<T> {
@@ -8,4 +8,11 @@ pub struct At<T> {
     pub obj: T,
 }
 
+/// Serialized as `[x, y, obj]`, like CDDA does
+impl<T: Serialize> Serialize for At<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x, self.y, &self.obj).serialize(serializer)
+    }
+}
+
 pub type AtVec<T> = FlatVec<At<T>, 3>;
@@ -0,0 +1,168 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+static COLLECTING: AtomicBool = AtomicBool::new(false);
+static COLLECTED: Mutex<Vec<Diagnostic>> = Mutex::new(Vec::new());
+
+/// Held by the active [`DiagnosticCollector`], so only one collects at a time
+static COLLECTOR: Mutex<()> = Mutex::new(());
+
+/// A problem in the CDDA data, found while loading it
+///
+/// Diagnostics are only collected while a [`DiagnosticCollector`] exists. The game itself only logs these problems.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Diagnostic {
+    /// A json file that could not be parsed
+    InvalidFile { path: Arc<str>, message: Arc<str> },
+
+    /// A 'copy-from' that could not be resolved
+    BrokenCopyFrom {
+        info_type: Arc<str>,
+        id: Arc<str>,
+        copy_from: Arc<str>,
+        problem: Arc<str>,
+    },
+
+    /// An info that could not be deserialized, like for a field that is not allowed by `deny_unknown_fields`
+    InvalidInfo {
+        info_type: Arc<str>,
+        id: Arc<str>,
+        message: Arc<str>,
+        likely_cause: Option<Arc<str>>,
+    },
+
+    /// A field that is caught by [`Ignored`](`crate::Ignored`), or that is dropped from a save of another version
+    UnknownField {
+        info_type: Arc<str>,
+        field: Arc<str>,
+        value_kind: &'static str,
+    },
+
+    /// A link to another info that could not be established
+    BrokenLink {
+        description: Arc<str>,
+        message: Arc<str>,
+    },
+}
+
+impl Diagnostic {
+    /// Waits for other collectors to finish
+    pub fn start_collecting() -> DiagnosticCollector {
+        let exclusive = COLLECTOR.lock().unwrap_or_else(PoisonError::into_inner);
+        collected().clear();
+        COLLECTING.store(true, Ordering::Relaxed);
+        DiagnosticCollector {
+            _exclusive: exclusive,
+        }
+    }
+
+    #[must_use]
+    pub fn is_collecting() -> bool {
+        COLLECTING.load(Ordering::Relaxed)
+    }
+
+    /// Does nothing, unless collecting
+    pub fn report(self) {
+        if Self::is_collecting() {
+            collected().push(self);
+        }
+    }
+}
+
+/// Collects the reported diagnostics, until it is dropped
+#[must_use]
+pub struct DiagnosticCollector {
+    _exclusive: MutexGuard<'static, ()>,
+}
+
+impl DiagnosticCollector {
+    /// All diagnostics reported so far, sorted and without duplicates
+    ///
+    /// This takes `self`, because only an active collector has collected anything.
+    #[must_use]
+    #[expect(clippy::unused_self)]
+    pub fn collected(&self) -> Vec<Diagnostic> {
+        let mut collected = collected().clone();
+        collected.sort();
+        collected.dedup();
+        collected
+    }
+}
+
+impl Drop for DiagnosticCollector {
+    fn drop(&mut self) {
+        COLLECTING.store(false, Ordering::Relaxed);
+        collected().clear();
+    }
+}
+
+fn collected() -> MutexGuard<'static, Vec<Diagnostic>> {
+    COLLECTED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod diagnostic_tests {
+    use super::*;
+
+    fn broken_link(description: &str) -> Diagnostic {
+        Diagnostic::BrokenLink {
+            description: Arc::from(description),
+            message: Arc::from("Unknown"),
+        }
+    }
+
+    /// Other tests may report other kinds of diagnostics at the same time
+    fn broken_links(collector: &DiagnosticCollector) -> Vec<Diagnostic> {
+        collector
+            .collected()
+            .into_iter()
+            .filter(|diagnostic| matches!(diagnostic, Diagnostic::BrokenLink { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn collecting_is_scoped() {
+        broken_link("before").report();
+
+        let collector = Diagnostic::start_collecting();
+        assert!(Diagnostic::is_collecting(), "Collecting should start");
+        broken_link("b").report();
+        broken_link("a").report();
+        broken_link("b").report();
+        assert_eq!(
+            broken_links(&collector),
+            vec![broken_link("a"), broken_link("b")],
+            "Only later diagnostics should be collected, sorted and deduplicated"
+        );
+        drop(collector);
+
+        let collector = Diagnostic::start_collecting();
+        assert_eq!(
+            broken_links(&collector),
+            Vec::new(),
+            "A new collector should start empty"
+        );
+    }
+
+    #[test]
+    fn serialization_is_tagged() {
+        let json = serde_json::to_value(Diagnostic::UnknownField {
+            info_type: Arc::from("Submap"),
+            field: Arc::from("fork_only"),
+            value_kind: "array",
+        })
+        .expect("Diagnostics should be serializable");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "unknown_field",
+                "info_type": "Submap",
+                "field": "fork_only",
+                "value_kind": "array",
+            }),
+            "The kind should be included in snake case"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use serde::de::{Deserialize, DeserializeOwned, Deserializer, Error as _, SeqAccess, Visitor};
-use serde_json::{Value as JsonValue, from_value as from_json_value};
+use serde::ser::{Error as _, Serialize, SerializeSeq as _, Serializer};
+use serde_json::{Value as JsonValue, from_value as from_json_value, to_value as to_json_value};
 use std::{fmt, marker::PhantomData};
 
 #[derive(Debug, Default)]
@@ -55,3 +56,34 @@ where
         Ok(result)
     }
 }
+
+impl<T, const N: usize> Serialize for FlatVec<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(N * self.0.len()))?;
+        for element in &self.0 {
+            let fields = match to_json_value(element) {
+                Ok(JsonValue::Array(fields)) if fields.len() == N => fields,
+                Ok(unexpected) => {
+                    return Err(S::Error::custom(format!(
+                        "FlatVec(N={N}) - Expected {N} fields, but got {unexpected:?}"
+                    )));
+                }
+                Err(e) => {
+                    return Err(S::Error::custom(format!(
+                        "FlatVec(N={N}) - Serialize error: {e}"
+                    )));
+                }
+            };
+            for field in &fields {
+                seq.serialize_element(field)?;
+            }
+        }
+        seq.end()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Recipe;
+use crate::{Diagnostic, Recipe};
 use bevy_log::error;
 use bevy_platform::collections::HashMap;
 use serde::{Deserialize, Deserializer};
@@ -30,8 +30,8 @@ impl<'de, T: 'static> Deserialize<'de> for Ignored<T> {
                     && (TypeId::of::<T>() != TypeId::of::<Recipe>()
                         || !["result", "category", "description"].contains(&&***key))
             })
-            .map(|(key, value)| format!("{key} ({})", variant_name(value)))
-            .filter(|field| {
+            .map(|(key, value)| (key, format!("{key} ({})", variant_name(value)), value))
+            .filter(|(_, field, _)| {
                 !all_unused_fields
                     .lock()
                     .expect("The mutex should not be poisoned")
@@ -39,6 +39,15 @@ impl<'de, T: 'static> Deserialize<'de> for Ignored<T> {
                     .or_default()
                     .contains(field)
             })
+            .inspect(|(key, _, value)| {
+                Diagnostic::UnknownField {
+                    info_type: type_name::<T>().into(),
+                    field: Arc::clone(key),
+                    value_kind: variant_name(value),
+                }
+                .report();
+            })
+            .map(|(_, field, _)| field)
             .collect::<Vec<_>>();
         if !new_ignored_fields.is_empty() {
             let mut all_unused_fields = all_unused_fields
@@ -70,7 +79,7 @@ impl<T> Default for Ignored<T> {
     }
 }
 
-const fn variant_name(value: &JsonValue) -> &'static str {
+pub(crate) const fn variant_name(value: &JsonValue) -> &'static str {
     match value {
         JsonValue::Null => "null",
         JsonValue::Bool(_) => "bool",
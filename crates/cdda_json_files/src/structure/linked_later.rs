@@ -1,6 +1,6 @@
-use crate::{Error, InfoId, InfoIdDescription, TerrainInfo};
+use crate::{Diagnostic, Error, InfoId, InfoIdDescription, TerrainInfo};
 use bevy_log::{error, warn};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, OnceLock, Weak};
 use std::{fmt, panic::Location};
@@ -15,11 +15,16 @@ pub trait Link<T> {
     /// When the link could not be established
     fn connect(&self, provider: &impl LinkProvider<T>) -> Result<(), Error>;
 
-    /// Calls [`Self::connect`], and logs the error
+    /// Calls [`Self::connect`], and logs and reports the error
     fn finalize(&self, provider: &impl LinkProvider<T>, err_description: impl AsRef<str>) {
         if let Err(error) = self.connect(provider) {
             let err_description = err_description.as_ref();
             error!("Linking {err_description} failed: {error:?}");
+            Diagnostic::BrokenLink {
+                description: err_description.into(),
+                message: format!("{error:?}").into(),
+            }
+            .report();
         }
     }
 }
@@ -46,6 +51,10 @@ impl<T: fmt::Debug> OptionalLinkedLater<T> {
     pub fn get(&self) -> Option<Arc<T>> {
         self.option.as_ref().and_then(LinkedLater::get)
     }
+
+    pub(crate) const fn is_none(&self) -> bool {
+        self.option.is_none()
+    }
 }
 
 impl<T: fmt::Debug> From<Option<InfoId<T>>> for OptionalLinkedLater<T> {
@@ -60,6 +69,15 @@ impl<T: fmt::Debug> From<Option<InfoId<T>>> for OptionalLinkedLater<T> {
     }
 }
 
+impl<T: fmt::Debug> Serialize for OptionalLinkedLater<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.option
+            .as_ref()
+            .map(|linked_later| &linked_later.info_id)
+            .serialize(serializer)
+    }
+}
+
 impl<T: fmt::Debug> Link<T> for OptionalLinkedLater<T> {
     fn connect(&self, provider: &impl LinkProvider<T>) -> Result<(), Error> {
         self.option.as_ref().map_or(Ok(()), |linked_later| {
@@ -154,6 +172,12 @@ impl<T: fmt::Debug> Hash for RequiredLinkedLater<T> {
     }
 }
 
+impl<T: fmt::Debug> Serialize for RequiredLinkedLater<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.required.info_id.serialize(serializer)
+    }
+}
+
 impl<T: fmt::Debug> Link<T> for RequiredLinkedLater<T> {
     fn connect(&self, provider: &impl LinkProvider<T>) -> Result<(), Error> {
         self.required.finalize(
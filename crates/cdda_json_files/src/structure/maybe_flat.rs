@@ -1,7 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Used when both a single T, and a list of T can be expected.
-#[derive(Clone, Debug, Deserialize)]
+///
+/// This is always written as a list, because the original form is not kept.
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(from = "MaybeFlat<T>")]
 pub struct MaybeFlatVec<T>(pub Vec<T>);
 
@@ -27,3 +29,26 @@ enum MaybeFlat<T> {
     Single(T),
     Multi(Vec<T>),
 }
+
+#[cfg(test)]
+mod maybe_flat_tests {
+    use super::*;
+    use serde_json::{Error as JsonError, from_str as from_json_str, to_string as to_json_string};
+
+    #[test]
+    fn round_trip() -> Result<(), JsonError> {
+        for (json, expected) in [
+            ("7", "[7]"),
+            ("[7]", "[7]"),
+            ("[]", "[]"),
+            ("[7,8]", "[7,8]"),
+        ] {
+            let serialized = to_json_string(&from_json_str::<MaybeFlatVec<u8>>(json)?)?;
+            assert_eq!(
+                serialized, expected,
+                "{json} should be written as {expected}"
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod at;
+mod diagnostic;
 mod flat_vec;
 mod ignored;
 mod linked_later;
@@ -8,8 +9,10 @@ mod range;
 mod repitition;
 
 pub use self::at::{At, AtVec};
+pub use self::diagnostic::{Diagnostic, DiagnosticCollector};
 pub use self::flat_vec::FlatVec;
 pub use self::ignored::Ignored;
+pub(crate) use self::ignored::variant_name;
 pub use self::linked_later::{Link, LinkProvider, OptionalLinkedLater, RequiredLinkedLater};
 pub use self::maybe_flat::MaybeFlatVec;
 pub use self::presence::{RequiredComponent, RequiredPart, RequiredTool};
@@ -1,7 +1,7 @@
 use crate::{RequiredLinkedLater, TerrainInfo};
 use bevy_log::error;
-use serde::Deserialize;
 use serde::de::{Deserializer, Error as _};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value as JsonValue;
 
 #[derive(Debug, Deserialize)]
@@ -12,6 +12,13 @@ pub struct CddaAmount<T> {
     pub amount: u32,
 }
 
+/// Serialized as `[obj, amount]`, like CDDA does
+impl<T: Serialize> Serialize for CddaAmount<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.obj, self.amount).serialize(serializer)
+    }
+}
+
 #[derive(Debug)]
 pub struct Single<T>(CddaAmount<T>);
 
@@ -73,7 +80,16 @@ where
     }
 }
 
-#[derive(Debug, Deserialize)]
+impl<T: Serialize> Serialize for Repetition<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Single(single) => single.0.obj.serialize(serializer),
+            Self::Multiple(amount) => amount.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct RepetitionBlock<T>(pub Vec<Repetition<T>>);
 
 impl<T> RepetitionBlock<T> {
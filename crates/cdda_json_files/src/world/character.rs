@@ -1,28 +1,37 @@
-use crate::{CharacterInfo, RequiredLinkedLater};
-use serde::Deserialize;
+use crate::{CharacterInfo, InfoId, RequiredLinkedLater};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Character {
     #[serde(rename = "id")]
     pub info: RequiredLinkedLater<CharacterInfo>,
 
-    #[expect(unused)]
     count: i32,
 
     pub x: i32,
     pub z: i32,
 
-    #[expect(unused)]
     faction_id: i32,
-
-    #[expect(unused)]
     mission_id: i32,
-
-    #[expect(unused)]
     friendly: bool,
-
-    #[expect(unused)]
     name: Option<Arc<str>>,
 }
+
+impl Character {
+    /// A single hostile monster, linked later
+    #[must_use]
+    pub fn new(info_id: InfoId<CharacterInfo>, x: i32, z: i32) -> Self {
+        Self {
+            info: RequiredLinkedLater::new(info_id),
+            count: 1,
+            x,
+            z,
+            faction_id: -1,
+            mission_id: -1,
+            friendly: false,
+            name: None,
+        }
+    }
+}
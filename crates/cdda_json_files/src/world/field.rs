@@ -1,15 +1,20 @@
 use crate::{FieldInfo, FlatVec, RequiredLinkedLater};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 
 #[derive(Debug, Deserialize)]
 pub struct Field {
     #[serde(rename = "id")]
     pub field_info: RequiredLinkedLater<FieldInfo>,
 
-    #[expect(unused)]
     intensity: i32,
-    #[expect(unused)]
     age: i64,
 }
 
+/// Serialized as `[id, intensity, age]`, like CDDA does
+impl Serialize for Field {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.field_info, self.intensity, self.age).serialize(serializer)
+    }
+}
+
 pub type FieldVec = FlatVec<Field, 3>;
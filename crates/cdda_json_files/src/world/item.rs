@@ -1,71 +1,113 @@
 use crate::{
-    CharacterInfo, CommonItemInfo, OptionalLinkedLater, PocketType, RequiredLinkedLater,
+    CharacterInfo, CommonItemInfo, InfoId, OptionalLinkedLater, PocketType, RequiredLinkedLater,
     UntypedInfoId,
 };
 use bevy_platform::collections::HashMap;
 use serde::de::Error;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value as JsonValue;
 use std::sync::{Arc, Mutex};
 use strum::VariantArray as _;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct CddaItem {
     #[serde(rename = "typeid")]
     pub item_info: RequiredLinkedLater<CommonItemInfo>,
 
     /// Can change after a migration
+    #[serde(default, skip_serializing_if = "has_no_variant")]
     pub variant: Mutex<Option<Arc<str>>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snip_id: Option<Arc<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub charges: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
 
+    #[serde(skip_serializing_if = "OptionalLinkedLater::is_none")]
     pub corpse: OptionalLinkedLater<CharacterInfo>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<Arc<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<Arc<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bday: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_temp_check: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub specific_energy: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_vars: Option<HashMap<Arc<str>, Arc<str>>>,
 
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub item_tags: Vec<Arc<str>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub contents: Option<CddaContainer>,
 
     // Sometilmes Vec<CddaItem>, sometimes HashMap<String, CddaItem>
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub components: Option<JsonValue>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_favorite: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub relic_data: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub damaged: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub current_phase: Option<u8>,
 
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub faults: Vec<Arc<str>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rot: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub curammo: Option<Arc<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_counter: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recipe_charges: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub poison: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub burnt: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub craft_data: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dropped_from: Option<Arc<str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub degradation: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub link_data: Option<HashMap<Arc<str>, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invlet: Option<u8>,
 }
 
 impl CddaItem {
     #[must_use]
     pub fn new(item_info: &Arc<CommonItemInfo>) -> Self {
+        Self::with_info(RequiredLinkedLater::new_final(
+            item_info.id.clone(),
+            item_info,
+        ))
+    }
+
+    /// Like an item from a map file, this still has to be linked
+    #[must_use]
+    pub fn unlinked(item_id: InfoId<CommonItemInfo>) -> Self {
+        Self::with_info(RequiredLinkedLater::new(item_id))
+    }
+
+    fn with_info(item_info: RequiredLinkedLater<CommonItemInfo>) -> Self {
         Self {
-            item_info: RequiredLinkedLater::new_final(item_info.id.clone(), item_info),
+            item_info,
             snip_id: None,
             charges: None,
             active: None,
@@ -99,22 +141,33 @@ impl CddaItem {
             invlet: None,
         }
     }
+
+    /// In degrees Celsius
+    #[must_use]
+    pub fn celsius(&self) -> Option<f32> {
+        self.temperature
+            .as_ref()
+            .and_then(Number::as_f32)
+            .map(|temperature| temperature / Number::KELVIN_FACTOR - Number::ZERO_CELSIUS)
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct CddaContainer {
     pub contents: Vec<CddaPocket>,
 
-    #[expect(unused)]
     #[serde(default)]
     additional_pockets: Vec<AdditionalPocket>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct CddaPocket {
-    #[serde(deserialize_with = "pocket_type_from_index")]
+    #[serde(
+        deserialize_with = "pocket_type_from_index",
+        serialize_with = "pocket_type_to_index"
+    )]
     pub pocket_type: PocketType,
 
     pub contents: Vec<CddaItem>,
@@ -126,10 +179,10 @@ pub struct CddaPocket {
     #[serde(default = "always_true")]
     pub allowed: bool,
 
-    #[expect(unused)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     favorite_settings: Option<JsonValue>,
 
-    #[expect(unused)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     no_rigid: Option<JsonValue>,
 }
 
@@ -141,26 +194,66 @@ fn pocket_type_from_index<'de, D: Deserializer<'de>>(
         .ok_or_else(|| Error::custom("Pocket type index out of range"))?)
 }
 
+fn has_no_variant(variant: &Mutex<Option<Arc<str>>>) -> bool {
+    variant
+        .lock()
+        .expect("The mutex should not be poisoned")
+        .is_none()
+}
+
+#[expect(clippy::trivially_copy_pass_by_ref)]
+fn pocket_type_to_index<S: Serializer>(
+    pocket_type: &PocketType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    PocketType::VARIANTS
+        .iter()
+        .position(|variant| variant == pocket_type)
+        .ok_or_else(|| serde::ser::Error::custom("Unknown pocket type"))?
+        .serialize(serializer)
+}
+
 const fn always_true() -> bool {
     true
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct AdditionalPocket {
     pub typeid: UntypedInfoId,
 
-    #[expect(unused)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     last_temp_check: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum Number {
     Int(i64),
     Text(Arc<str>),
 }
 
+impl Number {
+    /// CDDA stores temperatures in hundred thousandths of kelvins
+    const KELVIN_FACTOR: f32 = 100_000.0;
+
+    /// In kelvins
+    const ZERO_CELSIUS: f32 = 273.15;
+
+    /// Converts from degrees Celsius, to save an item temperature
+    #[must_use]
+    pub fn from_celsius(celsius: f32) -> Self {
+        Self::Int(((celsius + Self::ZERO_CELSIUS) * Self::KELVIN_FACTOR).round() as i64)
+    }
+
+    fn as_f32(&self) -> Option<f32> {
+        match self {
+            Self::Int(int) => Some(*int as f32),
+            Self::Text(text) => text.parse().ok(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod container_tests {
     use super::*;
@@ -1,19 +1,19 @@
 use crate::{
-    At, AtVec, CddaItem, CddaVehicle, Character, FieldVec, FurnitureInfo, Repetition,
-    RepetitionBlock, RequiredLinkedLater, TerrainInfo, UntypedInfoId,
+    At, AtVec, CddaItem, CddaVehicle, Character, FieldVec, FurnitureInfo, PartialConstructionVec,
+    Repetition, RepetitionBlock, RequiredLinkedLater, TerrainInfo, TrapInfo,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::sync::{Arc, OnceLock};
 
 // Reference: https://github.com/CleverRaven/Cataclysm-DDA/blob/master/src/savegame_json.cpp
 
 /// Corresponds to a 'map' in CDDA. It defines the layout of a `ZoneLevel`.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Map(pub [Submap; 4]);
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Submap {
     pub version: u64,
@@ -24,14 +24,14 @@ pub struct Submap {
     pub terrain: RepetitionBlock<RequiredLinkedLater<TerrainInfo>>,
     pub furniture: Vec<At<RequiredLinkedLater<FurnitureInfo>>>,
     pub items: AtVec<Vec<Repetition<CddaItem>>>,
-    pub traps: Vec<At<UntypedInfoId>>,
+    pub traps: Vec<At<RequiredLinkedLater<TrapInfo>>>,
     pub fields: AtVec<FieldVec>,
     pub cosmetics: Vec<(u8, u8, Arc<str>, Arc<str>)>,
     pub spawns: Vec<Character>,
     pub vehicles: Vec<CddaVehicle>,
-    pub partial_constructions: Vec<JsonValue>,
+    pub partial_constructions: PartialConstructionVec,
 
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub computers: Vec<JsonValue>,
 
     /// Marker to remember the state of the links
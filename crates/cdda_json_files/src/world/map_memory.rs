@@ -1,10 +1,11 @@
 use bevy_log::error;
 use serde::de::{Deserialize, Deserializer, Error as _, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq as _, Serializer};
 use serde_json::Value as JsonValue;
 use std::{fmt, sync::Arc};
 
 /// A player's memory of terrain on 8x8 suzones or 4x4 zones. Corresponds to a map memory ('.mmr') file in CDDA.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
 pub enum MapMemory {
@@ -59,6 +60,12 @@ impl Default for SubmapMemory {
     }
 }
 
+impl Serialize for SubmapMemory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
 impl From<Option<Vec<TileMemory>>> for SubmapMemory {
     fn from(value: Option<Vec<TileMemory>>) -> Self {
         if let Some(vec) = value {
@@ -120,6 +127,54 @@ impl<'de> Deserialize<'de> for TileMemory {
     }
 }
 
+impl Serialize for TileMemory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+        match self {
+            Self::Old {
+                type_id,
+                subtile,
+                rotation,
+                symbol,
+                amount,
+            } => {
+                seq.serialize_element(type_id.as_deref().unwrap_or(""))?;
+                seq.serialize_element(subtile)?;
+                seq.serialize_element(rotation)?;
+                seq.serialize_element(symbol)?;
+                seq.serialize_element(amount)?;
+            }
+            Self::New {
+                amount,
+                unknown_a,
+                type_id,
+                unknown_b,
+                unknown_c,
+                furniture_id,
+                unknown_d,
+                unknown_e,
+            } => {
+                seq.serialize_element(amount)?;
+                seq.serialize_element(unknown_a)?;
+                seq.serialize_element(type_id.as_deref().unwrap_or(""))?;
+                seq.serialize_element(unknown_b)?;
+                seq.serialize_element(unknown_c)?;
+                // Optional trailing values
+                if let Some(furniture_id) = furniture_id {
+                    seq.serialize_element(furniture_id)?;
+                    if let Some(unknown_d) = unknown_d {
+                        seq.serialize_element(unknown_d)?;
+                        if let Some(unknown_e) = unknown_e {
+                            seq.serialize_element(unknown_e)?;
+                        }
+                    }
+                }
+            }
+        }
+        seq.end()
+    }
+}
+
 struct TileMemoryVisitor;
 
 impl<'de> Visitor<'de> for TileMemoryVisitor {
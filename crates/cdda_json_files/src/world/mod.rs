@@ -3,19 +3,28 @@ mod field;
 mod item;
 mod map;
 mod map_memory;
+mod mods;
 mod overmap;
 mod overmap_buffer;
+mod partial_construction;
 mod player;
 mod sav;
+mod save_version;
 mod vehicle;
 
+#[cfg(test)]
+mod round_trip_tests;
+
 pub use self::character::Character;
 pub use self::field::{Field, FieldVec};
-pub use self::item::{AdditionalPocket, CddaItem, CddaPocket};
+pub use self::item::{AdditionalPocket, CddaItem, CddaPocket, Number};
 pub use self::map::{Map, Submap};
 pub use self::map_memory::{MapMemory, SubmapMemory};
-pub use self::overmap::{Overmap, OvermapLevel, SubzoneOffset};
+pub use self::mods::WorldMods;
+pub use self::overmap::{Overmap, OvermapLevel, OvermapMonsterGroup, SubzoneOffset};
 pub use self::overmap_buffer::OvermapBuffer;
-pub use self::player::{CddaPlayer, Skill};
+pub use self::partial_construction::{PartialConstruction, PartialConstructionVec};
+pub use self::player::{CddaPlayer, SavedEffect, Skill};
 pub use self::sav::Sav;
+pub use self::save_version::{SaveVersion, VersionAdapter};
 pub use self::vehicle::{CddaVehicle, CddaVehiclePart};
@@ -0,0 +1,7 @@
+use crate::{InfoId, ModInfo};
+use serde::{Deserialize, Serialize};
+
+/// The `mods.json` file of a world, with the ids of the active mods
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct WorldMods(pub Vec<InfoId<ModInfo>>);
@@ -1,19 +1,22 @@
 use crate::{
-    CddaAmount, CharacterInfo, FlatVec, InfoId, OvermapTerrainInfo, RepetitionBlock,
-    RequiredLinkedLater,
+    CddaAmount, CharacterInfo, FlatVec, InfoId, MonsterGroup, OvermapTerrainInfo, Repetition,
+    RepetitionBlock, RequiredLinkedLater,
 };
 use bevy_platform::collections::HashMap;
-use serde::Deserialize;
-use serde_json::Value as JsonValue;
-use std::sync::{Arc, OnceLock};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value as JsonValue, json};
+use std::{
+    array::from_fn,
+    sync::{Arc, OnceLock},
+};
 
 /// Corresponds to an 'overmap' in CDDA. It defines the layout of 180x180 `Zone`s.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Overmap {
     pub layers: [OvermapLevel; Self::LEVEL_AMOUNT],
     pub region_id: JsonValue,
-    pub monster_groups: JsonValue,
+    pub monster_groups: Vec<(OvermapMonsterGroup, Vec<SubzoneOffset>)>,
     pub cities: JsonValue,
     pub connections_out: JsonValue,
     pub radios: JsonValue,
@@ -35,9 +38,44 @@ pub struct Overmap {
 
 impl Overmap {
     pub const LEVEL_AMOUNT: usize = 21;
+
+    /// The index of the surface level in [`Self::layers`]
+    pub const GROUND_LAYER: usize = 10;
+
+    /// Rock and earth below the surface, fields on it, and air above it, in the default region
+    #[must_use]
+    pub fn plain() -> Self {
+        Self {
+            layers: from_fn(|index| {
+                OvermapLevel::all(InfoId::new(match index {
+                    ..7 => "deep_rock",
+                    7..9 => "empty_rock",
+                    9 => "solid_earth",
+                    Self::GROUND_LAYER => "field",
+                    _ => "open_air",
+                }))
+            }),
+            region_id: json!("default"),
+            monster_groups: Vec::new(),
+            cities: json!([]),
+            connections_out: json!({}),
+            radios: json!([]),
+            monster_map: FlatVec(Vec::new()),
+            tracked_vehicles: json!([]),
+            scent_traces: json!([]),
+            npcs: json!([]),
+            camps: json!([]),
+            overmap_special_placements: json!([]),
+            mapgen_arg_storage: None,
+            mapgen_arg_index: None,
+            joins_used: None,
+            predecessors: None,
+            linked: OnceLock::new(),
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct OvermapLevel(pub RepetitionBlock<InfoId<OvermapTerrainInfo>>);
 
@@ -49,16 +87,63 @@ impl OvermapLevel {
             amount: 180 * 180,
         }))
     }
+
+    /// Like [`Self::all`], but with `other` at the zone `x`, `z`
+    #[must_use]
+    pub fn all_except(
+        id: &InfoId<OvermapTerrainInfo>,
+        x: u32,
+        z: u32,
+        other: InfoId<OvermapTerrainInfo>,
+    ) -> Self {
+        // Zones are stored row by row
+        let before = z * 180 + x;
+        Self(RepetitionBlock(
+            [
+                (id.clone(), before),
+                (other, 1),
+                (id.clone(), 180 * 180 - before - 1),
+            ]
+            .into_iter()
+            .filter(|&(_, amount)| 0 < amount)
+            .map(|(obj, amount)| Repetition::Multiple(CddaAmount { obj, amount }))
+            .collect(),
+        ))
+    }
+}
+
+/// A monster group on the overmap, that is not spawned yet
+///
+/// The subzones of the group are stored next to it, in [`Overmap::monster_groups`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OvermapMonsterGroup {
+    #[serde(rename = "type")]
+    pub group: InfoId<MonsterGroup>,
+
+    /// The amount of monsters in the group
+    #[serde(default)]
+    pub population: u32,
+
+    /// Hordes move over the overmap
+    #[serde(default)]
+    pub horde: bool,
+
+    /// From 0 to 100, how eager a horde is to reach its target
+    #[serde(default)]
+    pub interest: u8,
+
+    /// Preserved as is
+    #[serde(flatten)]
+    extra: HashMap<Arc<str>, JsonValue>,
 }
 
 /// Offset of the subzone from the overmap
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(deny_unknown_fields)]
 pub struct SubzoneOffset(pub u16, pub u16, pub i8);
 
-#[expect(unused)]
 #[expect(clippy::struct_excessive_bools)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Monster {
     location: (i32, i32, i8),
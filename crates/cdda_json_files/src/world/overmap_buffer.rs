@@ -1,9 +1,9 @@
 use crate::{Overmap, RepetitionBlock};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 /// Corresponds to an 'overmapbuffer' in CDDA. It defines the save-specific information of an `OverZone`.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct OvermapBuffer {
     /// Visible on the overmap
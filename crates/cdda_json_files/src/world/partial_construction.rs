@@ -0,0 +1,39 @@
+use crate::{CddaItem, Construction, FlatVec, RequiredLinkedLater};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// An unfinished construction on a submap
+#[derive(Debug, Deserialize)]
+pub struct PartialConstruction {
+    pub x: u8,
+    pub y: u8,
+    pub z: i8,
+
+    /// From 0 to [`Self::COUNTER_FINISHED`]
+    pub counter: u32,
+
+    pub construction: RequiredLinkedLater<Construction>,
+
+    /// The consumed components, to return when the construction gets cancelled
+    pub components: Vec<CddaItem>,
+}
+
+impl PartialConstruction {
+    pub const COUNTER_FINISHED: u32 = 10_000_000;
+}
+
+/// Serialized as `[x, y, z, counter, id, components]`, like CDDA does
+impl Serialize for PartialConstruction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            self.x,
+            self.y,
+            self.z,
+            self.counter,
+            &self.construction,
+            &self.components,
+        )
+            .serialize(serializer)
+    }
+}
+
+pub type PartialConstructionVec = FlatVec<PartialConstruction, 6>;
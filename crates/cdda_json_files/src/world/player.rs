@@ -1,9 +1,9 @@
 use bevy_platform::collections::HashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct CddaPlayer {
     pub str_max: i16,
     pub str_bonus: i16,
@@ -59,7 +59,9 @@ pub struct CddaPlayer {
     //pub destination_point: JsonValue,
     //pub dodge_bonus: JsonValue,
     //pub dodges_left: JsonValue,
-    //pub effects: JsonValue,
+    /// Per effect and body part
+    #[serde(default)]
+    pub effects: HashMap<Arc<str>, HashMap<Arc<str>, SavedEffect>>,
     //pub faction_warnings: JsonValue,
     //pub failed_missions: JsonValue,
     //pub fatigue: JsonValue,
@@ -72,7 +74,7 @@ pub struct CddaPlayer {
     //pub healthy: JsonValue,
     //pub healthy_mod: JsonValue,
     //pub hit_bonus: JsonValue,
-    //pub hunger: JsonValue,
+    pub hunger: JsonValue,
     pub id: JsonValue,
     //pub in_vehicle: JsonValue,
     //pub inactive_eocs: JsonValue,
@@ -86,7 +88,9 @@ pub struct CddaPlayer {
     //pub last_target_pos: JsonValue,
     //pub last_updated: JsonValue,
     //pub learned_recipes: JsonValue,
-    //pub location: JsonValue,
+    /// Absolute position, with the CDDA y-axis as our z-axis
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<(i32, i32, i8)>,
     //pub magic: JsonValue,
     //pub male: JsonValue,
     //pub martial_arts_data: JsonValue,
@@ -128,9 +132,9 @@ pub struct CddaPlayer {
     //pub stashed_outbounds_activity: JsonValue,
     //pub stashed_outbounds_backlog: JsonValue,
     //pub stim: JsonValue,
-    //pub stomach: JsonValue,
-    //pub stored_calories: JsonValue,
-    //pub thirst: JsonValue,
+    pub stomach: JsonValue,
+    pub stored_calories: JsonValue,
+    pub thirst: JsonValue,
     //pub throw_resist: JsonValue,
     pub traits: JsonValue,
     //pub translocators: JsonValue,
@@ -145,11 +149,48 @@ pub struct CddaPlayer {
     pub extra: HashMap<Arc<str>, JsonValue>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SavedEffect {
+    /// In seconds
+    pub duration: u64,
+
+    pub intensity: u8,
+
+    #[serde(flatten)]
+    extra: HashMap<Arc<str>, JsonValue>,
+}
+
+impl SavedEffect {
+    #[must_use]
+    pub fn new(duration: u64, intensity: u8) -> Self {
+        Self {
+            duration,
+            intensity,
+            extra: HashMap::default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Skill {
     pub level: u8,
 
-    #[expect(unused)]
+    /// Progress towards the next level
+    #[serde(default)]
+    pub exercise: u32,
+
     #[serde(flatten)]
     extra: HashMap<Arc<str>, JsonValue>,
 }
+
+impl Skill {
+    /// A skill without any practice towards the next level
+    #[must_use]
+    pub fn new(level: u8) -> Self {
+        Self {
+            level,
+            exercise: 0,
+            extra: HashMap::default(),
+        }
+    }
+}
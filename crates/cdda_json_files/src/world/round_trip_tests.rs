@@ -0,0 +1,136 @@
+use super::{item::CddaContainer, map_memory::TileMemory};
+use crate::{
+    CddaPlayer, CddaVehicle, MapMemory, Overmap, OvermapBuffer, OvermapMonsterGroup, Sav, Submap,
+    SubzoneOffset,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{
+    Error as JsonError, Value as JsonValue, from_str as from_json_str,
+    from_value as from_json_value, to_value as to_json_value,
+};
+use std::any::type_name;
+
+/// Parse, serialize, re-parse, and compare both serialized versions
+fn round_trip<T: DeserializeOwned + Serialize>(json: &str) -> Result<JsonValue, JsonError> {
+    let serialized = to_json_value(from_json_str::<T>(json)?)?;
+    let reserialized = to_json_value(from_json_value::<T>(serialized.clone())?)?;
+    assert_eq!(
+        serialized,
+        reserialized,
+        "{} should survive a round trip",
+        type_name::<T>()
+    );
+    Ok(serialized)
+}
+
+#[test]
+fn container_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/container.json");
+    let serialized = round_trip::<CddaContainer>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The container should be written as read"
+    );
+    Ok(())
+}
+
+#[test]
+fn vehicle_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/vehicle.json");
+    let serialized = round_trip::<CddaVehicle>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The vehicle should be written as read"
+    );
+    Ok(())
+}
+
+#[test]
+fn submap_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/submap.json");
+    let serialized = round_trip::<Submap>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The submap should be written as read"
+    );
+    Ok(())
+}
+
+#[test]
+fn tile_memory_round_trip() -> Result<(), JsonError> {
+    round_trip::<[TileMemory; 3]>(include_str!("test_data/tile_memory.json"))?;
+    Ok(())
+}
+
+#[test]
+fn map_memory_round_trip() -> Result<(), JsonError> {
+    let tile_memory = include_str!("test_data/tile_memory.json");
+    round_trip::<MapMemory>(&format!("[null, {tile_memory}]"))?;
+    round_trip::<MapMemory>(&format!(
+        r#"{{"version": 1, "data": [{tile_memory}, null]}}"#
+    ))?;
+    Ok(())
+}
+
+#[test]
+fn monster_groups_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/monster_groups.json");
+    let serialized = round_trip::<Vec<(OvermapMonsterGroup, Vec<SubzoneOffset>)>>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The monster groups should be written as read"
+    );
+    Ok(())
+}
+
+#[test]
+fn player_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/player.json");
+    let serialized = round_trip::<CddaPlayer>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The player should be written as read"
+    );
+    Ok(())
+}
+
+#[test]
+fn sav_round_trip() -> Result<(), JsonError> {
+    let mut json = from_json_str::<JsonValue>(include_str!("test_data/sav.json"))?;
+    let player = from_json_str::<JsonValue>(include_str!("test_data/player.json"))?;
+    if let JsonValue::Object(sav) = &mut json {
+        sav.insert(String::from("player"), player);
+    }
+    let serialized = round_trip::<Sav>(&json.to_string())?;
+    assert_eq!(serialized, json, "The sav should be written as read");
+    Ok(())
+}
+
+#[test]
+fn overmap_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/overmap.json");
+    let serialized = round_trip::<Overmap>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The overmap should be written as read"
+    );
+    Ok(())
+}
+
+#[test]
+fn overmap_buffer_round_trip() -> Result<(), JsonError> {
+    let json = include_str!("test_data/overmap_buffer.json");
+    let serialized = round_trip::<OvermapBuffer>(json)?;
+    assert_eq!(
+        serialized,
+        from_json_str::<JsonValue>(json)?,
+        "The overmap buffer should be written as read"
+    );
+    Ok(())
+}
@@ -1,9 +1,9 @@
 use crate::CddaPlayer;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 /// This represents a .sav file
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Sav {
     pub achievements_tracker: JsonValue,
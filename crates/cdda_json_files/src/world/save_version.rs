@@ -0,0 +1,962 @@
+use crate::structure::variant_name;
+use crate::{
+    CddaItem, CddaPlayer, CddaVehicle, CddaVehiclePart, Character, Diagnostic, Overmap,
+    OvermapBuffer, Sav, Submap,
+};
+use bevy_log::{debug, warn};
+use serde::de::DeserializeOwned;
+use serde::de::{Deserializer, Error as _, Visitor, value::Error as ValueError};
+use serde::{Deserialize, Serialize, forward_to_deserialize_any};
+use serde_json::{Map as JsonMap, Value as JsonValue, json};
+use std::any::type_name;
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+
+/// The `savegame_version` of CDDA, from the header of .sav and overmap files, and from each submap
+///
+/// The world types, like [`Sav`] and [`Submap`], follow the layout of [`Self::CURRENT`]. The json of other versions, including forks like Cataclysm: Bright Nights, is first converted to that layout by the [`VersionAdapter`]s that apply to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct SaveVersion(pub u32);
+
+impl SaveVersion {
+    /// The version of 0.G
+    pub const CURRENT: Self = Self(33);
+
+    /// Parses the first line of a .sav or overmap file, like `# version 33`
+    #[must_use]
+    pub fn from_sav_header(line: &str) -> Option<Self> {
+        line.trim()
+            .strip_prefix('#')?
+            .trim_start()
+            .strip_prefix("version")?
+            .trim()
+            .parse()
+            .ok()
+            .map(Self)
+    }
+
+    /// From the 'version' field of a json object, like a submap
+    #[must_use]
+    pub fn from_json(json: &JsonValue) -> Option<Self> {
+        json.get("version")?
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .map(Self)
+    }
+
+    /// Converts the json of a .sav file of this version, including the player, to the layout of [`Self::CURRENT`], for loading
+    ///
+    /// The fields that no adapter converts are dropped, see [`Self::upgrade_sav`] to keep them.
+    pub fn adapt_sav(self, sav: &mut JsonValue) {
+        self.convert_sav(sav, UnknownFields::Drop);
+    }
+
+    /// Converts the json of a .sav file of this version to the layout of [`Self::CURRENT`], for writing
+    ///
+    /// Unlike [`Self::adapt_sav`], this keeps the fields that no adapter converts.
+    ///
+    /// # Errors
+    /// Returns this version when it is newer than [`Self::CURRENT`], because downgrading would lose data
+    pub fn upgrade_sav(self, sav: &mut JsonValue) -> Result<(), Self> {
+        self.check_upgradable()?;
+        self.convert_sav(sav, UnknownFields::Keep);
+        Ok(())
+    }
+
+    /// Converts the json of a .map file to the layout of [`Self::CURRENT`], for loading
+    ///
+    /// Each submap is converted according to its own version. This version is only used for submaps without one. The fields that no adapter converts are dropped, see [`Self::upgrade_map`] to keep them.
+    pub fn adapt_map(self, map: &mut JsonValue) {
+        self.convert_map(map, UnknownFields::Drop);
+    }
+
+    /// Converts the json of a .map file to the layout of [`Self::CURRENT`], for writing
+    ///
+    /// Unlike [`Self::adapt_map`], this keeps the fields that no adapter converts, and it sets the version of each submap to [`Self::CURRENT`].
+    ///
+    /// # Errors
+    /// Returns the version of a submap that is newer than [`Self::CURRENT`], because downgrading would lose data
+    pub fn upgrade_map(self, map: &mut JsonValue) -> Result<(), Self> {
+        for submap in map.as_array().into_iter().flatten() {
+            Self::from_json(submap).unwrap_or(self).check_upgradable()?;
+        }
+
+        self.convert_map(map, UnknownFields::Keep);
+        for submap in map
+            .as_array_mut()
+            .into_iter()
+            .flatten()
+            .filter_map(JsonValue::as_object_mut)
+        {
+            submap.insert(String::from("version"), json!(Self::CURRENT));
+        }
+        Ok(())
+    }
+
+    /// Converts the json of an overmap file of this version to the layout of [`Self::CURRENT`], for loading
+    ///
+    /// The fields that no adapter converts are dropped, see [`Self::upgrade_overmap`] to keep them.
+    pub fn adapt_overmap(self, overmap: &mut JsonValue) {
+        self.convert_overmap(overmap, UnknownFields::Drop);
+    }
+
+    /// Converts the json of an overmap file of this version to the layout of [`Self::CURRENT`], for writing
+    ///
+    /// Unlike [`Self::adapt_overmap`], this keeps the fields that no adapter converts.
+    ///
+    /// # Errors
+    /// Returns this version when it is newer than [`Self::CURRENT`], because downgrading would lose data
+    pub fn upgrade_overmap(self, overmap: &mut JsonValue) -> Result<(), Self> {
+        self.check_upgradable()?;
+        self.convert_overmap(overmap, UnknownFields::Keep);
+        Ok(())
+    }
+
+    /// Converts the json of an overmap buffer ('seen') file of this version to the layout of [`Self::CURRENT`], for loading
+    pub fn adapt_overmap_buffer(self, buffer: &mut JsonValue) {
+        if let Some(buffer) = buffer.as_object_mut() {
+            self.adapt::<OvermapBuffer>(buffer, UnknownFields::Drop, |adapter, buffer| {
+                adapter.adapt_overmap_buffer(buffer);
+            });
+        }
+    }
+
+    /// Converts the json of a map memory ('.mmr') file of this version to the layout of [`Self::CURRENT`]
+    ///
+    /// Map memory files have no version of their own, so this should be the version of the save.
+    pub fn adapt_map_memory(self, map_memory: &mut JsonValue) {
+        for adapter in self.adapters() {
+            adapter.adapt_map_memory(map_memory);
+        }
+    }
+
+    /// Like [`Self::adapt_map_memory`], for writing
+    ///
+    /// # Errors
+    /// Returns this version when it is newer than [`Self::CURRENT`], because downgrading would lose data
+    pub fn upgrade_map_memory(self, map_memory: &mut JsonValue) -> Result<(), Self> {
+        self.check_upgradable()?;
+        self.adapt_map_memory(map_memory);
+        Ok(())
+    }
+
+    /// Saves of newer versions, including forks, may contain data that the downgrading adapters remove
+    const fn check_upgradable(self) -> Result<(), Self> {
+        if self.0 <= Self::CURRENT.0 {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    fn convert_sav(self, sav: &mut JsonValue, unknown: UnknownFields) {
+        let Some(sav) = sav.as_object_mut() else {
+            return;
+        };
+        self.adapt::<Sav>(sav, unknown, |adapter, sav| adapter.adapt_sav(sav));
+
+        if let Some(player) = sav.get_mut("player").and_then(JsonValue::as_object_mut) {
+            self.adapt::<CddaPlayer>(player, unknown, |adapter, player| {
+                adapter.adapt_player(player);
+            });
+        }
+    }
+
+    fn convert_map(self, map: &mut JsonValue, unknown: UnknownFields) {
+        for submap in map.as_array_mut().into_iter().flatten() {
+            let version = Self::from_json(submap).unwrap_or(self);
+            let Some(submap) = submap.as_object_mut() else {
+                continue;
+            };
+            version.adapt::<Submap>(submap, unknown, |adapter, submap| {
+                adapter.adapt_submap(submap);
+            });
+
+            // Flat list of x, y, and the items on that position
+            let items = submap.get_mut("items").and_then(JsonValue::as_array_mut);
+            for items in items.into_iter().flatten().skip(2).step_by(3) {
+                version.adapt_items(items, unknown);
+            }
+
+            let vehicles = submap.get_mut("vehicles").and_then(JsonValue::as_array_mut);
+            for vehicle in vehicles
+                .into_iter()
+                .flatten()
+                .filter_map(JsonValue::as_object_mut)
+            {
+                version.adapt::<CddaVehicle>(vehicle, unknown, |adapter, vehicle| {
+                    adapter.adapt_vehicle(vehicle);
+                });
+
+                let parts = vehicle.get_mut("parts").and_then(JsonValue::as_array_mut);
+                for part in parts
+                    .into_iter()
+                    .flatten()
+                    .filter_map(JsonValue::as_object_mut)
+                {
+                    version.adapt::<CddaVehiclePart>(part, unknown, |adapter, part| {
+                        adapter.adapt_vehicle_part(part);
+                    });
+                    if let Some(items) = part.get_mut("items") {
+                        version.adapt_items(items, unknown);
+                    }
+                }
+            }
+
+            let spawns = submap.get_mut("spawns").and_then(JsonValue::as_array_mut);
+            for spawn in spawns
+                .into_iter()
+                .flatten()
+                .filter_map(JsonValue::as_object_mut)
+            {
+                version.adapt::<Character>(spawn, unknown, |adapter, spawn| {
+                    adapter.adapt_spawn(spawn);
+                });
+            }
+        }
+    }
+
+    fn convert_overmap(self, overmap: &mut JsonValue, unknown: UnknownFields) {
+        if let Some(overmap) = overmap.as_object_mut() {
+            self.adapt::<Overmap>(overmap, unknown, |adapter, overmap| {
+                adapter.adapt_overmap(overmap);
+            });
+        }
+    }
+
+    /// Items are stored as a list, where each entry is either an item, or an item with an amount
+    fn adapt_items(self, items: &mut JsonValue, unknown: UnknownFields) {
+        for item in items.as_array_mut().into_iter().flatten() {
+            let item = match item {
+                JsonValue::Array(repeated) => repeated.first_mut(),
+                item => Some(item),
+            };
+            let Some(item) = item.and_then(JsonValue::as_object_mut) else {
+                continue;
+            };
+            self.adapt::<CddaItem>(item, unknown, |adapter, item| adapter.adapt_item(item));
+
+            let pockets = item
+                .get_mut("contents")
+                .and_then(|contents| contents.get_mut("contents"))
+                .and_then(JsonValue::as_array_mut);
+            for pocket in pockets.into_iter().flatten() {
+                if let Some(contents) = pocket.get_mut("contents") {
+                    self.adapt_items(contents, unknown);
+                }
+            }
+        }
+    }
+
+    /// Applies all adapters for this version, and then drops the fields that remain unknown, if requested
+    fn adapt<T: DeserializeOwned>(
+        self,
+        object: &mut JsonMap<String, JsonValue>,
+        unknown: UnknownFields,
+        adapt: impl Fn(&dyn VersionAdapter, &mut JsonMap<String, JsonValue>),
+    ) {
+        for adapter in self.adapters() {
+            adapt(adapter, object);
+        }
+        if self != Self::CURRENT && unknown == UnknownFields::Drop {
+            drop_unknown::<T>(self, object);
+        }
+    }
+
+    fn adapters(self) -> impl Iterator<Item = &'static dyn VersionAdapter> {
+        ADAPTERS
+            .iter()
+            .copied()
+            .filter(move |adapter| adapter.applies_to(self))
+    }
+}
+
+/// What to do with the fields of other versions that no adapter converts
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum UnknownFields {
+    /// The world types deny unknown fields, so loading requires dropping them
+    Drop,
+    /// Writing keeps them, so upgrading a save does not lose data that the game may still use
+    Keep,
+}
+
+/// Used as the settings of loaders for files without a version of their own
+impl Default for SaveVersion {
+    fn default() -> Self {
+        Self::CURRENT
+    }
+}
+
+/// A single change in the save layout of CDDA, and how to convert json from before that change
+///
+/// Adapters with a [`Self::source_version`] before their [`Self::target_version`] upgrade older saves. The others downgrade saves from newer versions, and from forks. New schema changes get their own adapter in [`ADAPTERS`].
+pub trait VersionAdapter: Sync {
+    /// The last version with the layout this converts from
+    fn source_version(&self) -> SaveVersion;
+
+    /// The first version with the layout this converts to
+    fn target_version(&self) -> SaveVersion;
+
+    fn applies_to(&self, version: SaveVersion) -> bool {
+        if self.source_version() < self.target_version() {
+            version <= self.source_version()
+        } else {
+            self.source_version() <= version
+        }
+    }
+
+    fn adapt_sav(&self, _sav: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_player(&self, _player: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_submap(&self, _submap: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_item(&self, _item: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_vehicle(&self, _vehicle: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_vehicle_part(&self, _part: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_spawn(&self, _spawn: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_overmap(&self, _overmap: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_overmap_buffer(&self, _buffer: &mut JsonMap<String, JsonValue>) {}
+    fn adapt_map_memory(&self, _map_memory: &mut JsonValue) {}
+}
+
+/// In the order they are applied: first the upgrades from old to new, then the downgrades from new to old
+const ADAPTERS: &[&dyn VersionAdapter] = &[
+    &ItemDamageLevels,
+    &SubmapCamps,
+    &SubmapLists,
+    &VehicleState,
+    &SpawnFactions,
+    &SavTrackers,
+    &PlayerVitals,
+    &OvermapSpecials,
+    &OvermapBufferExtras,
+    &MapMemoryVersion,
+    &SubmapEphemeralData,
+];
+
+/// Adds the fields of `defaults` that `object` lacks
+fn insert_missing(object: &mut JsonMap<String, JsonValue>, defaults: &JsonValue) {
+    for (key, value) in defaults.as_object().into_iter().flatten() {
+        if !object.contains_key(key) {
+            object.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+/// Removes a field that is known to be obsolete
+fn remove_obsolete<T>(object: &mut JsonMap<String, JsonValue>, field: &str) {
+    if object.remove(field).is_some() {
+        debug!("Removed obsolete field {field:?} of {}", type_name::<T>());
+    }
+}
+
+/// Items used to have a damage level from -1 to 4, instead of a damage from -1000 to 4000
+struct ItemDamageLevels;
+
+impl VersionAdapter for ItemDamageLevels {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(27)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(28)
+    }
+
+    fn adapt_item(&self, item: &mut JsonMap<String, JsonValue>) {
+        if let Some(level) = item.remove("damage") {
+            let damaged = level.as_i64().unwrap_or_default() * 1000;
+            item.insert(String::from("damaged"), json!(damaged));
+        }
+    }
+}
+
+/// Submaps used to store the basecamp on them. Camps are on the overmap now.
+struct SubmapCamps;
+
+impl VersionAdapter for SubmapCamps {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(29)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(30)
+    }
+
+    fn adapt_submap(&self, submap: &mut JsonMap<String, JsonValue>) {
+        remove_obsolete::<Submap>(submap, "camp");
+    }
+}
+
+/// Submaps used to leave out empty lists, including cosmetics and partial constructions
+struct SubmapLists;
+
+impl VersionAdapter for SubmapLists {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(30)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(31)
+    }
+
+    fn adapt_submap(&self, submap: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            submap,
+            &json!({
+                "turn_last_touched": 0,
+                "temperature": 0,
+                "radiation": [0, 144],
+                "furniture": [],
+                "items": [],
+                "traps": [],
+                "fields": [],
+                "cosmetics": [],
+                "spawns": [],
+                "vehicles": [],
+                "partial_constructions": [],
+            }),
+        );
+    }
+}
+
+/// Vehicles and their parts used to store less of their state
+struct VehicleState;
+
+impl VersionAdapter for VehicleState {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(31)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn adapt_vehicle(&self, vehicle: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            vehicle,
+            &json!({
+                "velocity": 0,
+                "falling": false,
+                "cruise_velocity": 0,
+                "vertical_velocity": 0,
+                "engine_on": false,
+                "tracking_on": false,
+                "skidding": false,
+                "of_turn_carry": 0.0,
+                "owner": "",
+                "old_owner": "",
+                "tags": [],
+                "labels": [],
+                "zones": [],
+                "is_locked": false,
+                "is_alarm_on": false,
+                "camera_on": false,
+                "last_update_turn": 0,
+                "pivot": [0, 0],
+                "is_following": false,
+                "is_patrolling": false,
+                "airworthy": false,
+                "magic": false,
+            }),
+        );
+    }
+
+    fn adapt_vehicle_part(&self, part: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            part,
+            &json!({
+                "open": false,
+                "direction": 0,
+                "blood": 0,
+                "enabled": true,
+                "flags": 0,
+                "passenger_id": -1,
+                "crew_id": -1,
+                "items": [],
+                "ammo_pref": "",
+            }),
+        );
+    }
+}
+
+/// Monster spawns used to have no amount, faction, mission, or friendliness
+struct SpawnFactions;
+
+impl VersionAdapter for SpawnFactions {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(31)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn adapt_spawn(&self, spawn: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            spawn,
+            &json!({
+                "count": 1,
+                "faction_id": -1,
+                "mission_id": -1,
+                "friendly": false,
+            }),
+        );
+    }
+}
+
+/// .sav files used to lack the trackers, the view offsets, and the global effects on conditions
+struct SavTrackers;
+
+impl VersionAdapter for SavTrackers {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(33)
+    }
+
+    fn adapt_sav(&self, sav: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            sav,
+            &json!({
+                "achievements_tracker": null,
+                "active_monsters": null,
+                "auto_travel_mode": null,
+                "bVMonsterLookFire": null,
+                "driving_view_offset": null,
+                "global_vals": null,
+                "grscent": null,
+                "inactive_global_effect_on_condition_vector": null,
+                "initial_season": null,
+                "kill_tracker": null,
+                "mostseen": null,
+                "player_messages": null,
+                "queued_global_effect_on_conditions": null,
+                "run_mode": null,
+                "stats_tracker": null,
+                "turnssincelastmon": null,
+                "typescent": null,
+                "view_offset_x": null,
+                "view_offset_y": null,
+                "view_offset_z": null,
+            }),
+        );
+    }
+}
+
+/// The player used to lack focus, oxygen, sleep deprivation, and a separate stomach
+///
+/// Missing values get the defaults of the game, like for a new character.
+struct PlayerVitals;
+
+impl VersionAdapter for PlayerVitals {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(33)
+    }
+
+    fn adapt_player(&self, player: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            player,
+            &json!({
+                "focus_pool": null,
+                "items_identified": [],
+                "omt_path": [],
+                "oxygen": null,
+                "pkill": 0,
+                "sleep_deprivation": null,
+                "stomach": null,
+                "stored_calories": null,
+            }),
+        );
+    }
+}
+
+/// Overmaps used to lack the camps, the scent traces, the tracked vehicles, and the placements of specials
+struct OvermapSpecials;
+
+impl VersionAdapter for OvermapSpecials {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(33)
+    }
+
+    fn adapt_overmap(&self, overmap: &mut JsonMap<String, JsonValue>) {
+        insert_missing(
+            overmap,
+            &json!({
+                "camps": [],
+                "overmap_special_placements": [],
+                "scent_traces": [],
+                "tracked_vehicles": [],
+            }),
+        );
+    }
+}
+
+/// Overmap buffers used to lack the extras
+struct OvermapBufferExtras;
+
+impl VersionAdapter for OvermapBufferExtras {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(33)
+    }
+
+    fn adapt_overmap_buffer(&self, buffer: &mut JsonMap<String, JsonValue>) {
+        insert_missing(buffer, &json!({"extras": []}));
+    }
+}
+
+/// Map memory files used to be a bare list of submaps, without a version
+struct MapMemoryVersion;
+
+impl VersionAdapter for MapMemoryVersion {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(32)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(33)
+    }
+
+    fn adapt_map_memory(&self, map_memory: &mut JsonValue) {
+        if map_memory.is_array() {
+            *map_memory = json!({"version": 1, "data": map_memory.take()});
+        }
+    }
+}
+
+/// Newer versions store ephemeral data, like temporary item flags, on submaps
+struct SubmapEphemeralData;
+
+impl VersionAdapter for SubmapEphemeralData {
+    fn source_version(&self) -> SaveVersion {
+        SaveVersion(34)
+    }
+
+    fn target_version(&self) -> SaveVersion {
+        SaveVersion(33)
+    }
+
+    fn adapt_submap(&self, submap: &mut JsonMap<String, JsonValue>) {
+        remove_obsolete::<Submap>(submap, "ephemeral_data");
+    }
+}
+
+/// Drops the fields that no adapter converted, because the world types deny unknown fields
+///
+/// This only affects the loaded json, not the files. Each unknown field is logged once per type, and reported as a [`Diagnostic`].
+fn drop_unknown<T: DeserializeOwned>(
+    version: SaveVersion,
+    object: &mut JsonMap<String, JsonValue>,
+) {
+    static REPORTED: Mutex<BTreeSet<(&'static str, String)>> = Mutex::new(BTreeSet::new());
+
+    let Some(known) = known_fields::<T>() else {
+        return;
+    };
+    object.retain(|key, value| {
+        let is_known = known.contains(&key.as_str());
+        if !is_known {
+            let first = REPORTED
+                .lock()
+                .expect("The mutex should not be poisoned")
+                .insert((type_name::<T>(), key.clone()));
+            if first {
+                warn!(
+                    "Dropping field {key:?} of {} from save version {}, because no adapter converts it",
+                    type_name::<T>(),
+                    version.0
+                );
+                Diagnostic::UnknownField {
+                    info_type: type_name::<T>().into(),
+                    field: Arc::from(key.as_str()),
+                    value_kind: variant_name(value),
+                }
+                .report();
+            }
+        }
+        is_known
+    });
+}
+
+/// The serialized field names of a struct, as used by `deny_unknown_fields`
+///
+/// Returns `None` for types that are not deserialized as a struct.
+fn known_fields<T: DeserializeOwned>() -> Option<&'static [&'static str]> {
+    let mut collector = FieldCollector(None);
+    // This always fails, after collecting the fields
+    drop(T::deserialize(&mut collector));
+    collector.0
+}
+
+/// A deserializer that only remembers the fields of the struct it is asked to deserialize
+struct FieldCollector(Option<&'static [&'static str]>);
+
+impl<'de> Deserializer<'de> for &mut FieldCollector {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(ValueError::custom("Only structs are supported"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0 = Some(fields);
+        Err(ValueError::custom("The fields are collected"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+#[cfg(test)]
+mod save_version_tests {
+    use super::*;
+    use crate::{Map, MapMemory};
+    use serde_json::{from_str as from_json_str, from_value as from_json_value};
+
+    /// Splits the version header from the json, like in .sav and overmap files
+    fn versioned(contents: &str) -> (SaveVersion, JsonValue) {
+        let (header, json) = contents
+            .split_once('\n')
+            .expect("Test data should have a header");
+        let version = SaveVersion::from_sav_header(header).expect("Header should be valid");
+        let json = from_json_str(json).expect("Test data should be valid");
+        (version, json)
+    }
+
+    #[test]
+    fn header_works() {
+        assert_eq!(
+            SaveVersion::from_sav_header("# version 33"),
+            Some(SaveVersion(33)),
+            "A regular header should be recognized"
+        );
+        assert_eq!(
+            SaveVersion::from_sav_header("{\"turn\": 1}"),
+            None,
+            "Json is not a header"
+        );
+    }
+
+    #[test]
+    fn other_versions_work() {
+        let current = from_json_str::<JsonValue>(include_str!("test_data/submap.json"))
+            .expect("Test data should be valid");
+
+        let mut older = current.clone();
+        let older_submap = older.as_object_mut().expect("Submap should be an object");
+        older_submap.insert(String::from("version"), json!(29));
+        older_submap.remove("cosmetics");
+        older_submap.remove("partial_constructions");
+        older_submap.insert(String::from("camp"), json!(null));
+
+        let mut newer = current.clone();
+        let newer_submap = newer.as_object_mut().expect("Submap should be an object");
+        newer_submap.insert(String::from("version"), json!(36));
+        newer_submap.insert(String::from("ephemeral_data"), json!({}));
+
+        let mut map = json!([current, older, newer, current]);
+        let result = from_json_value::<Map>(map.clone());
+        assert!(result.is_err(), "Unadapted other versions should fail");
+
+        SaveVersion::CURRENT.adapt_map(&mut map);
+        let result = from_json_value::<Map>(map);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn known_fields_works() {
+        let fields = known_fields::<Submap>().expect("Submap should be a struct");
+        assert!(fields.contains(&"version"), "{fields:?}");
+        assert!(fields.contains(&"computers"), "{fields:?}");
+        assert!(!fields.contains(&"linked"), "{fields:?}");
+    }
+
+    #[test]
+    fn adapters_are_scoped() {
+        assert!(SubmapCamps.applies_to(SaveVersion(29)), "Camps are from 29");
+        assert!(SubmapCamps.applies_to(SaveVersion(12)), "Camps are older");
+        assert!(
+            !SubmapCamps.applies_to(SaveVersion(30)),
+            "Camps are gone in 30"
+        );
+        assert!(
+            !SubmapEphemeralData.applies_to(SaveVersion::CURRENT),
+            "Ephemeral data is newer"
+        );
+        assert!(
+            SubmapEphemeralData.applies_to(SaveVersion(36)),
+            "Ephemeral data is from 34"
+        );
+        assert_eq!(
+            SaveVersion::CURRENT.adapters().count(),
+            0,
+            "The current version needs no adapters"
+        );
+    }
+
+    #[test]
+    fn older_map_works() {
+        let mut map = from_json_str::<JsonValue>(include_str!("test_data/map_v27.map"))
+            .expect("Test data should be valid");
+        let result = from_json_value::<Map>(map.clone());
+        assert!(result.is_err(), "An unadapted older map should fail");
+
+        SaveVersion::CURRENT.adapt_map(&mut map);
+        assert_eq!(
+            map.pointer("/0/items/2/0/damaged"),
+            Some(&json!(1000)),
+            "The damage level should be converted"
+        );
+        assert_eq!(map.pointer("/0/camp"), None, "The camp should be removed");
+        let result = from_json_value::<Map>(map);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn older_sav_works() {
+        let (version, mut sav) = versioned(include_str!("test_data/sav_v32.sav"));
+        assert_eq!(version, SaveVersion(32), "The header should be read");
+        let result = from_json_value::<Sav>(sav.clone());
+        assert!(result.is_err(), "An unadapted older sav should fail");
+
+        version.adapt_sav(&mut sav);
+        let result = from_json_value::<Sav>(sav);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn older_overmap_works() {
+        let (version, mut overmap) = versioned(include_str!("test_data/overmap_v32"));
+        let result = from_json_value::<Overmap>(overmap.clone());
+        assert!(result.is_err(), "An unadapted older overmap should fail");
+
+        version.adapt_overmap(&mut overmap);
+        let result = from_json_value::<Overmap>(overmap);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn older_overmap_buffer_works() {
+        let (version, mut buffer) = versioned(include_str!("test_data/overmap_buffer_v32"));
+        let result = from_json_value::<OvermapBuffer>(buffer.clone());
+        assert!(
+            result.is_err(),
+            "An unadapted older overmap buffer should fail"
+        );
+
+        version.adapt_overmap_buffer(&mut buffer);
+        let result = from_json_value::<OvermapBuffer>(buffer);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn older_map_memory_works() {
+        let mut map_memory =
+            from_json_str::<JsonValue>(include_str!("test_data/map_memory_v32.mmr"))
+                .expect("Test data should be valid");
+
+        SaveVersion(32).adapt_map_memory(&mut map_memory);
+        let result = from_json_value::<MapMemory>(map_memory);
+        assert!(
+            matches!(result, Ok(MapMemory::Map { version: 1, .. })),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn unknown_fields_are_reported() {
+        let collector = Diagnostic::start_collecting();
+        let mut submap = from_json_str::<JsonValue>(include_str!("test_data/submap.json"))
+            .expect("Test data should be valid");
+        let object = submap.as_object_mut().expect("Submap should be an object");
+        object.insert(String::from("version"), json!(36));
+        object.insert(String::from("fork_only"), json!([]));
+
+        let mut map = json!([submap]);
+        SaveVersion::CURRENT.adapt_map(&mut map);
+        assert_eq!(
+            map.pointer("/0/fork_only"),
+            None,
+            "The field should be dropped"
+        );
+        let reported = collector.collected();
+        assert!(
+            reported.contains(&Diagnostic::UnknownField {
+                info_type: type_name::<Submap>().into(),
+                field: Arc::from("fork_only"),
+                value_kind: "array",
+            }),
+            "{reported:?}"
+        );
+    }
+
+    #[test]
+    fn upgrade_keeps_unknown_fields() {
+        let mut map = from_json_str::<JsonValue>(include_str!("test_data/map_v27.map"))
+            .expect("Test data should be valid");
+        map.pointer_mut("/0")
+            .and_then(JsonValue::as_object_mut)
+            .expect("The submap should be an object")
+            .insert(String::from("mod_data"), json!({"seen": true}));
+
+        SaveVersion::CURRENT
+            .upgrade_map(&mut map)
+            .expect("Older maps should be upgradable");
+        assert_eq!(
+            map.pointer("/0/mod_data"),
+            Some(&json!({"seen": true})),
+            "Unknown fields should be kept"
+        );
+        assert_eq!(
+            map.pointer("/0/items/2/0/damaged"),
+            Some(&json!(1000)),
+            "The damage level should be converted"
+        );
+        assert_eq!(
+            SaveVersion::from_json(&map[0]),
+            Some(SaveVersion::CURRENT),
+            "The submap should be marked as current"
+        );
+    }
+
+    #[test]
+    fn newer_versions_are_not_upgraded() {
+        let mut submap = from_json_str::<JsonValue>(include_str!("test_data/submap.json"))
+            .expect("Test data should be valid");
+        submap
+            .as_object_mut()
+            .expect("Submap should be an object")
+            .insert(String::from("version"), json!(36));
+        let mut map = json!([submap]);
+        let original = map.clone();
+
+        assert_eq!(
+            SaveVersion::CURRENT.upgrade_map(&mut map),
+            Err(SaveVersion(36))
+        );
+        assert_eq!(map, original, "A refused map should be unchanged");
+        assert_eq!(
+            SaveVersion(36).upgrade_sav(&mut json!({})),
+            Err(SaveVersion(36))
+        );
+    }
+}
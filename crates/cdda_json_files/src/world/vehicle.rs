@@ -1,11 +1,11 @@
 use crate::{CddaItem, Repetition, RequiredLinkedLater, UntypedInfoId, VehiclePartInfo};
 use bevy_platform::collections::HashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
 #[expect(clippy::struct_excessive_bools)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct CddaVehicle {
     #[serde(rename = "type")]
@@ -93,16 +93,19 @@ pub struct CddaVehicle {
     #[serde(default)]
     pub vehicle_noise: Option<u8>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub in_deep_water: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub precollision_on: Option<JsonValue>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct CddaVehiclePart {
     #[serde(rename = "id")]
     pub info: RequiredLinkedLater<VehiclePartInfo>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub variant: Option<Arc<str>>,
     pub base: Arc<CddaItem>,
 
@@ -119,22 +122,32 @@ pub struct CddaVehiclePart {
     pub blood: i16,
     pub enabled: bool,
     pub flags: u8,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub carry: Vec<String>,
     pub passenger_id: i8,
     pub crew_id: i8,
     pub items: Vec<Repetition<CddaItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_first_x: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_first_y: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_first_z: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_second_x: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_second_y: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub target_second_z: Option<i16>,
     pub ammo_pref: Arc<str>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub salvageable: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locked: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_disconnected: Option<JsonValue>,
 }
 
@@ -0,0 +1,125 @@
+//! Loads the data of Cataclysm: Dark Days Ahead like the game does, but without a window, and reports all problems as JSON.
+//!
+//! Run this from the root of the repository, so the `assets` directory is found.
+//! The exit code is 1 when any problem is found.
+
+use bevy::tasks::{IoTaskPool, TaskPool};
+use cdda_json_files::Diagnostic;
+use gameplay_cdda::{Infos, ModList};
+use serde::Serialize;
+use serde_json::to_writer_pretty as to_json_writer_pretty;
+use std::io::{Write as _, stderr, stdout};
+use std::path::PathBuf;
+use std::{env, process::ExitCode, sync::Arc};
+
+const USAGE: &str = "Usage: cdda-lint [--world <path to a world directory>]";
+
+#[derive(Debug, Serialize)]
+struct Report {
+    /// In load order
+    mods: Vec<Arc<str>>,
+    problems: Vec<Diagnostic>,
+}
+
+fn main() -> ExitCode {
+    let mods = match mods_from_args(env::args().skip(1)) {
+        Ok(mods) => mods,
+        Err(message) => {
+            writeln!(stderr(), "{message}\n{USAGE}").expect("Writing to stderr should succeed");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    IoTaskPool::get_or_init(TaskPool::new);
+    let collector = Diagnostic::start_collecting();
+
+    let mod_names = mods
+        .iter()
+        .map(|loaded_mod| loaded_mod.id.fallback_name())
+        .collect();
+    drop(Infos::load(mods));
+    let report = Report {
+        mods: mod_names,
+        problems: collector.collected(),
+    };
+
+    let mut stdout = stdout().lock();
+    to_json_writer_pretty(&mut stdout, &report).expect("Writing the report should succeed");
+    writeln!(stdout).expect("Writing to stdout should succeed");
+
+    if report.problems.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn mods_from_args(mut args: impl Iterator<Item = String>) -> Result<ModList, String> {
+    match (args.next().as_deref(), args.next(), args.next()) {
+        (None, _, _) => Ok(ModList::core()),
+        (Some("--world"), Some(world_path), None) => {
+            let world_path = PathBuf::from(world_path);
+            if world_path.is_dir() {
+                Ok(ModList::for_world(&world_path))
+            } else {
+                Err(format!(
+                    "World directory {} not found",
+                    world_path.display()
+                ))
+            }
+        }
+        _ => Err(String::from("Unexpected arguments")),
+    }
+}
+
+#[cfg(test)]
+mod cdda_lint_tests {
+    use super::*;
+    use std::env::temp_dir;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter().map(|&arg| String::from(arg))
+    }
+
+    #[test]
+    fn without_arguments_uses_core() {
+        assert_eq!(mods_from_args(args(&[])), Ok(ModList::core()));
+    }
+
+    #[test]
+    fn world_without_mod_list_uses_core() {
+        let world_path = temp_dir().join("cdda-lint-test-world");
+        std::fs::create_dir_all(&world_path).expect("The world directory should be created");
+        let world_path = world_path.to_str().expect("The path should be valid UTF-8");
+        assert_eq!(
+            mods_from_args(args(&["--world", world_path])),
+            Ok(ModList::core())
+        );
+    }
+
+    #[test]
+    fn missing_world_fails() {
+        let result = mods_from_args(args(&["--world", "/nonexistent/cdda-lint/world"]));
+        assert!(
+            result
+                .as_ref()
+                .is_err_and(|error| error.contains("not found")),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn unexpected_arguments_fail() {
+        for unexpected in [
+            &["--world"][..],
+            &["--world", ".", "extra"],
+            &["--mods", "dda"],
+        ] {
+            assert_eq!(
+                mods_from_args(args(unexpected)),
+                Err(String::from("Unexpected arguments")),
+                "{unexpected:?}"
+            );
+        }
+    }
+}
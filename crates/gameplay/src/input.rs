@@ -1,7 +1,8 @@
 use application_state::ApplicationState;
-use bevy::prelude::{DespawnOnExit, KeyCode, Local, Single, With, World};
+use bevy::prelude::{DespawnOnExit, KeyCode, Local, Single, With, World, error};
 use gameplay_location::Pos;
 use gameplay_player::Player;
+use gameplay_save::save_game;
 use gameplay_screen_death::to_main_menu;
 use gameplay_spawn::TileSpawner;
 use keyboard::KeyBindings;
@@ -18,12 +19,12 @@ pub(crate) fn create_gameplay_key_bindings(
 
     bindings.spawn(world, ApplicationState::Gameplay, |bindings| {
         bindings.add('!', spawn_zombies);
-        bindings.add(KeyCode::F12, to_main_menu);
+        bindings.add(KeyCode::F12, save_and_quit);
     });
 
     world.spawn((
         ManualSection::new(
-            &[("add debug zeds", "!"), ("to main menu", "F12")],
+            &[("add debug zeds", "!"), ("save and quit", "F12")],
             u8::MAX - 2,
         ),
         DespawnOnExit(ApplicationState::Gameplay),
@@ -37,3 +38,12 @@ fn spawn_zombies(mut tile_spawner: TileSpawner, player: Option<Single<&Pos, With
         tile_spawner.spawn_zombies(**player_pos);
     }
 }
+
+fn save_and_quit(world: &mut World) {
+    if let Err(error) = world.run_system_cached(save_game) {
+        error!("Could not save: {error:?}");
+    }
+    if let Err(error) = world.run_system_cached(to_main_menu) {
+        error!("Could not go to the main menu: {error:?}");
+    }
+}
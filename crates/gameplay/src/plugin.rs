@@ -19,21 +19,25 @@ use gameplay_model::ModelPlugin;
 use gameplay_perception::{GameplayPerceptionPlugin, RelativeSegments};
 use gameplay_player::PlayerPlugin;
 use gameplay_resource::GampelayResourceSet;
+use gameplay_save::GameplaySavePlugin;
 use gameplay_screen_base::BaseScreenPlugin;
+use gameplay_screen_construction::ConstructionScreenPlugin;
 use gameplay_screen_crafting::CraftingScreenPlugin;
 use gameplay_screen_death::DeathScreenPlugin;
 use gameplay_screen_inventory::InventoryScreenPlugin;
 use gameplay_screen_menu::MenuScreenPlugin;
 use gameplay_screen_quality::QualityScreenPlugin;
+use gameplay_screen_skills::SkillsScreenPlugin;
 use gameplay_screen_state::GameplayScreenState;
 use gameplay_screen_tool::ToolScreenPlugin;
 use gameplay_screen_transitioning::TransitioningScreenPlugin;
 use gameplay_screen_waiting::WaitingModalPlugin;
 use gameplay_sidebar::SidebarPlugin;
 use gameplay_spawn::{
-    SpawnPlugin, despawn_systems, handle_region_asset_events, handle_zone_levels,
+    SpawnPlugin, despawn_systems, handle_region_asset_events, handle_zone_levels, move_hordes,
     spawn_initial_entities, spawn_subzone_levels, spawn_subzones_for_camera, update_explored,
 };
+use gameplay_temperature::TemperaturePlugin;
 use gameplay_terrain::TerrainPlugin;
 use gameplay_time::TimePlugin;
 use gameplay_transition::TransitionPlugin;
@@ -41,6 +45,7 @@ use gameplay_visualization::{
     GameplayVisualizationPlugin, VisualizationUpdate, update_visibility,
     update_visualization_on_item_move,
 };
+use gameplay_weather::WeatherPlugin;
 use gameplay_world::GameplayWorldPlugin;
 use util::log_transition_plugin;
 
@@ -67,21 +72,26 @@ impl Plugin for GameplayPlugin {
                 PlayerPlugin,
                 (
                     BaseScreenPlugin,
+                    ConstructionScreenPlugin,
                     CraftingScreenPlugin,
                     DeathScreenPlugin,
                     InventoryScreenPlugin,
                     MenuScreenPlugin,
                     QualityScreenPlugin,
+                    SkillsScreenPlugin,
                     ToolScreenPlugin,
                     TransitioningScreenPlugin,
                     WaitingModalPlugin,
                 ),
                 (
+                    GameplaySavePlugin,
                     SpawnPlugin,
                     SidebarPlugin,
+                    TemperaturePlugin,
                     TerrainPlugin,
                     TimePlugin,
                     TransitionPlugin,
+                    WeatherPlugin,
                 ),
             ),
             log_transition_plugin::<GameplayScreenState>,
@@ -110,6 +120,7 @@ fn update_systems() -> ScheduleConfigs<ScheduleSystem> {
             spawn_subzones_for_camera.after(UpdateCameraOffset),
             (
                 spawn_subzone_levels,
+                move_hordes,
                 update_visualization_on_item_move.run_if(resource_exists::<RelativeSegments>),
             )
                 .chain(),
@@ -41,13 +41,28 @@ pub trait FactionPlanner: BaseFaction {
         }
     }
 
+    /// Characters with a loaded gun shoot at the nearest visible enemy in range, unless it is adjacent
     fn attack(
         &self,
         envir: &Envir,
         factions: &[(Pos, &Self)],
         enemies: &[Pos],
         actor: &ActorItem,
+        fire_range: Option<usize>,
     ) -> Option<(PlannedAction, LastEnemy)> {
+        if let Some(fire_range) = fire_range
+            && let Some(target) = enemies
+                .iter()
+                .copied()
+                .filter(|enemy_pos| {
+                    let distance = actor.pos.vision_distance(*enemy_pos);
+                    distance.in_range(fire_range) && !distance.in_range(1)
+                })
+                .min_by_key(|enemy_pos| actor.pos.vision_distance(*enemy_pos).as_tiles())
+        {
+            return Some((PlannedAction::fire(target), LastEnemy(target)));
+        }
+
         enemies
             .iter()
             .map(|pos| (false, *pos))
@@ -180,10 +195,11 @@ pub trait FactionPlanner: BaseFaction {
         factions: &[(Pos, &Self)],
         enemies: &[Pos],
         actor: &ActorItem,
+        fire_range: Option<usize>,
     ) -> Option<Strategy> {
         match intent {
             Intent::Attack => self
-                .attack(envir, factions, enemies, actor)
+                .attack(envir, factions, enemies, actor, fire_range)
                 .map(|(action, last_enemy)| (action, Some(last_enemy))),
             Intent::Flee => self
                 .flee(envir, enemies, actor)
@@ -210,18 +226,20 @@ pub trait FactionPlanner: BaseFaction {
         })
     }
 
+    /// The fire range is `None` without a loaded gun
     fn strategize(
         &self,
         envir: &Envir,
         factions: &[(Pos, &Self)],
         enemies: &[Pos],
         actor: &ActorItem,
+        fire_range: Option<usize>,
     ) -> Strategy {
         //trace!("{self:?} can see {:?} enemies", enemies.len());
         Intent::ALL
             .into_iter()
             .filter(|intent| self.consider(*intent, actor.health))
-            .find_map(|intent| self.attempt(intent, envir, factions, enemies, actor))
+            .find_map(|intent| self.attempt(intent, envir, factions, enemies, actor, fire_range))
             .expect("Fallback intent")
     }
 
@@ -1,6 +1,9 @@
 use bevy::prelude::{Resource, warn};
-use gameplay_character::{ChangePace, ExamineItem, MoveItem, Pickup, Unwield, Wield};
-use gameplay_crafting::RecipeSituation;
+use gameplay_character::{
+    ChangePace, Drink, Eat, ExamineItem, Fire, MoveItem, Pickup, Reload, TakeOff, Unload, Unwield,
+    Wear, Wield,
+};
+use gameplay_crafting::{ConstructionSituation, DisassemblySituation, RecipeSituation};
 use gameplay_location::{HorizontalDirection, Nbor};
 use strum::VariantArray;
 use text::Fragment;
@@ -54,14 +57,25 @@ pub enum QueuedInstruction {
     Offset(PlayerDirection),
     Wield(Wield),
     Unwield(Unwield),
+    Wear(Wear),
+    TakeOff(TakeOff),
     Pickup(Pickup),
     MoveItem(MoveItem),
+    Eat(Eat),
+    Drink(Drink),
+    Reload(Reload),
+    Unload(Unload),
     StartCraft(RecipeSituation),
+    StartConstruction(ConstructionSituation, HorizontalDirection),
+    StartDisassembly(DisassemblySituation),
     Attack,
+    Fire(Fire),
     Smash,
     Pulp,
+    Butcher,
     Peek,
     Close,
+    Disarm,
     Drag,
     Sleep,
     ToggleAutoTravel,
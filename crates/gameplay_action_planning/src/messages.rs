@@ -37,6 +37,17 @@ impl ProtoLogMessage for NoTargetsNearby {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct NothingToButcherNearby;
+
+impl ProtoLogMessage for NothingToButcherNearby {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        Phrase::new("nothing to butcher nearby")
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct NothingToCloseNearby;
 
@@ -48,6 +59,17 @@ impl ProtoLogMessage for NothingToCloseNearby {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct NothingToDisarmNearby;
+
+impl ProtoLogMessage for NothingToDisarmNearby {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        Phrase::new("nothing to disarm nearby")
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct YouAreAlmostOutOfBreathAndStop {
     pub(crate) verb: String,
@@ -143,10 +165,10 @@ impl<const SUCCESS: bool> ProtoLogMessage for YouFinish<SUCCESS> {
     const SEVERITY: Severity = Self::severity();
 
     fn phrase(self) -> Phrase {
-        Self::you("finish").hard(if let PlayerActionState::Crafting { .. } = self.action {
-            String::from("your craft")
-        } else {
-            self.action.to_string().to_lowercase()
+        Self::you("finish").hard(match self.action {
+            PlayerActionState::Crafting { .. } => String::from("your craft"),
+            PlayerActionState::Constructing { .. } => String::from("your construction"),
+            _ => self.action.to_string().to_lowercase(),
         })
     }
 }
@@ -1,8 +1,10 @@
 use gameplay_character::{
-    Attack, ChangePace, Close, ContinueCraft, ExamineItem, MoveItem, Peek, Pickup, Pulp, Smash,
-    StartCraft, Step, Unwield, Wield,
+    Attack, Butcher, ChangePace, Close, ContinueConstruction, ContinueCraft, ContinueDisassembly,
+    Disarm, Drink, Eat, ExamineItem, Fire, MoveItem, Peek, Pickup, Pulp, Reload, Smash,
+    StartConstruction, StartCraft, StartDisassembly, Step, TakeOff, Train, Unload, Unwield, Wear,
+    Wield,
 };
-use gameplay_location::{CardinalDirection, HorizontalDirection, Nbor};
+use gameplay_location::{CardinalDirection, HorizontalDirection, Nbor, Pos};
 
 #[derive(Debug)]
 pub enum PlannedAction {
@@ -10,17 +12,31 @@ pub enum PlannedAction {
     Sleep,
     Step(Step),
     Attack(Attack),
+    Fire(Fire),
     Smash(Smash),
     Pulp(Pulp),
+    Butcher(Butcher),
     Peek(Peek),
     Close(Close),
+    Disarm(Disarm),
     Wield(Wield),
     Unwield(Unwield),
+    Wear(Wear),
+    TakeOff(TakeOff),
     Pickup(Pickup),
     /// Redundantly named to avoid confusion
     MoveItem(MoveItem),
+    Eat(Eat),
+    Drink(Drink),
+    Reload(Reload),
+    Unload(Unload),
     StartCraft(StartCraft),
     ContinueCraft(ContinueCraft),
+    StartConstruction(StartConstruction),
+    ContinueConstruction(ContinueConstruction),
+    StartDisassembly(StartDisassembly),
+    ContinueDisassembly(ContinueDisassembly),
+    Train(Train),
     /// Redundantly named to avoid confusion
     ExamineItem(ExamineItem),
     ChangePace(ChangePace),
@@ -35,6 +51,10 @@ impl PlannedAction {
         Self::Attack(Attack { target })
     }
 
+    pub(crate) const fn fire(target: Pos) -> Self {
+        Self::Fire(Fire { target })
+    }
+
     pub(crate) const fn smash(target: Nbor) -> Self {
         Self::Smash(Smash { target })
     }
@@ -43,6 +63,10 @@ impl PlannedAction {
         Self::Pulp(Pulp { target })
     }
 
+    pub(crate) const fn butcher(target: HorizontalDirection) -> Self {
+        Self::Butcher(Butcher { target })
+    }
+
     pub(crate) const fn peek(target: CardinalDirection) -> Self {
         Self::Peek(Peek { target })
     }
@@ -50,4 +74,8 @@ impl PlannedAction {
     pub(crate) const fn close(target: HorizontalDirection) -> Self {
         Self::Close(Close { target })
     }
+
+    pub(crate) const fn disarm(target: HorizontalDirection) -> Self {
+        Self::Disarm(Disarm { target })
+    }
 }
@@ -1,8 +1,8 @@
 use crate::messages::{
-    FirstExamineYourDestination, NoPlaceToCraftNearby, NoTargetsNearby, NothingToCloseNearby,
-    YouAreAlmostOutOfBreathAndStop, YouAreStillAsleep, YouAreStillDraggingItems, YouCant,
-    YouCantAttackYourself, YouFallAsleep, YouFinish, YouSpotAndStop, YouStartDefending,
-    YouWakeUpAfterSleeping,
+    FirstExamineYourDestination, NoPlaceToCraftNearby, NoTargetsNearby, NothingToButcherNearby,
+    NothingToCloseNearby, NothingToDisarmNearby, YouAreAlmostOutOfBreathAndStop, YouAreStillAsleep,
+    YouAreStillDraggingItems, YouCant, YouCantAttackYourself, YouFallAsleep, YouFinish,
+    YouSpotAndStop, YouStartDefending, YouWakeUpAfterSleeping,
 };
 use crate::{
     FactionPlanner as _, Interruption, Pathfinder, PlannedAction, PlayerDirection,
@@ -10,7 +10,8 @@ use crate::{
 };
 use bevy::prelude::{DetectChanges as _, Entity, NextState, ResMut};
 use gameplay_character::{
-    ActorItem, Breath, ContinueCraft, Faction, Intelligence, MoveItem, Pulp, StartCraft,
+    ActorItem, Breath, Butcher, ContinueConstruction, ContinueCraft, ContinueDisassembly, Faction,
+    Intelligence, MoveItem, Pulp, StartConstruction, StartCraft, StartDisassembly, Train,
 };
 use gameplay_crafting::RecipeSituation;
 use gameplay_location::{CardinalDirection, HorizontalDirection, Nbor, Pos, VisionDistance};
@@ -18,6 +19,7 @@ use gameplay_log::{LogMessageWriter, Severity};
 use gameplay_perception::{CurrentlyVisibleBuilder, Explored};
 use gameplay_player::{PickingNbor, PlayerActionState};
 use gameplay_world::Envir;
+use std::sync::Arc;
 use text::Fragment;
 use units::{Duration, Timestamp};
 
@@ -70,6 +72,12 @@ pub fn plan_automatic_action(
         PlayerActionState::Crafting { item } => {
             plan_auto_continue_craft(player_instructions, *item, enemy_name)
         }
+        PlayerActionState::Constructing { site } => {
+            plan_auto_continue_construction(player_instructions, *site, enemy_name)
+        }
+        PlayerActionState::Disassembling { item } => {
+            plan_auto_continue_disassembly(player_instructions, *item, enemy_name)
+        }
         PlayerActionState::AutoDefend => {
             let enemies = player
                 .faction
@@ -87,6 +95,12 @@ pub fn plan_automatic_action(
         PlayerActionState::Pulping { direction } => {
             plan_auto_pulp(envir, player_instructions, player, *direction, enemy_name)
         }
+        PlayerActionState::Butchering { direction } => {
+            plan_auto_butcher(envir, player_instructions, player, *direction, enemy_name)
+        }
+        PlayerActionState::Training { skill, cap, until } => {
+            plan_auto_train(player_instructions, now, skill, *cap, until, enemy_name)
+        }
         PlayerActionState::Waiting { until } => {
             plan_auto_wait(player_instructions, now, until, enemy_name)
         }
@@ -162,8 +176,9 @@ fn plan(
             QueuedInstruction::Drag | QueuedInstruction::CancelAction,
         )
         | (
-            PlayerActionState::PickingNbor(PickingNbor::Pulping)
-            | PlayerActionState::Pulping { .. },
+            PlayerActionState::PickingNbor(PickingNbor::Pulping | PickingNbor::Butchering)
+            | PlayerActionState::Pulping { .. }
+            | PlayerActionState::Butchering { .. },
             QueuedInstruction::Interrupt(Interruption::Finished),
         ) => {
             next_state.set(PlayerActionState::Normal);
@@ -224,8 +239,14 @@ fn generic_plan(
         ),
         QueuedInstruction::Wield(wield) => Some(PlannedAction::Wield(wield)),
         QueuedInstruction::Unwield(unwield) => Some(PlannedAction::Unwield(unwield)),
+        QueuedInstruction::Wear(wear) => Some(PlannedAction::Wear(wear)),
+        QueuedInstruction::TakeOff(take_off) => Some(PlannedAction::TakeOff(take_off)),
         QueuedInstruction::Pickup(pickup) => Some(PlannedAction::Pickup(pickup)),
         QueuedInstruction::MoveItem(move_item) => Some(PlannedAction::MoveItem(move_item)),
+        QueuedInstruction::Eat(eat) => Some(PlannedAction::Eat(eat)),
+        QueuedInstruction::Drink(drink) => Some(PlannedAction::Drink(drink)),
+        QueuedInstruction::Reload(reload) => Some(PlannedAction::Reload(reload)),
+        QueuedInstruction::Unload(unload) => Some(PlannedAction::Unload(unload)),
         QueuedInstruction::StartCraft(recipe_situation) => plan_start_craft(
             next_state,
             message_writer,
@@ -234,14 +255,30 @@ fn generic_plan(
             recipe_situation,
         ),
         // TODO instruction to continue crafting
+        QueuedInstruction::StartConstruction(construction_situation, target) => {
+            // Constructing state is set when performing the action
+            Some(PlannedAction::StartConstruction(StartConstruction {
+                construction_situation,
+                target,
+            }))
+        }
+        QueuedInstruction::StartDisassembly(disassembly_situation) => {
+            // Disassembling state is set when performing the action
+            Some(PlannedAction::StartDisassembly(StartDisassembly {
+                disassembly_situation,
+            }))
+        }
         QueuedInstruction::Attack => plan_attack(next_state, message_writer, envir, player_pos),
+        QueuedInstruction::Fire(fire) => Some(PlannedAction::Fire(fire)),
         QueuedInstruction::Smash => plan_smash(next_state, message_writer, envir, player_pos),
         QueuedInstruction::Pulp => plan_pulp(next_state, message_writer, envir, player_pos),
+        QueuedInstruction::Butcher => plan_butcher(next_state, message_writer, envir, player_pos),
         QueuedInstruction::Peek => {
             next_state.set(PlayerActionState::PickingNbor(PickingNbor::Peeking));
             None
         }
         QueuedInstruction::Close => plan_close(next_state, message_writer, envir, player_pos),
+        QueuedInstruction::Disarm => plan_disarm(next_state, message_writer, envir, player_pos),
         QueuedInstruction::Drag => {
             next_state.set(PlayerActionState::PickingNbor(PickingNbor::Dragging));
             None
@@ -303,7 +340,11 @@ fn handle_offset(
         | PlayerActionState::Pulping { .. } => {
             panic!("{current_state:?} {player_pos:?} {raw_nbor:?}");
         }
-        PlayerActionState::Crafting { .. }
+        PlayerActionState::Butchering { .. }
+        | PlayerActionState::Crafting { .. }
+        | PlayerActionState::Constructing { .. }
+        | PlayerActionState::Disassembling { .. }
+        | PlayerActionState::Training { .. }
         | PlayerActionState::Waiting { .. }
         | PlayerActionState::AutoTravel { .. }
         | PlayerActionState::AutoDefend => {
@@ -311,61 +352,99 @@ fn handle_offset(
             None
         }
         PlayerActionState::Normal => Some(PlannedAction::step(raw_nbor)),
-        PlayerActionState::PickingNbor(picking_nbor) => {
-            match picking_nbor {
-                PickingNbor::Attacking => {
-                    next_state.set(PlayerActionState::Normal);
-                    Some(PlannedAction::attack(raw_nbor))
-                }
-                PickingNbor::Smashing => {
-                    next_state.set(PlayerActionState::Normal);
-                    Some(PlannedAction::smash(raw_nbor))
-                }
-                PickingNbor::Pulping => {
-                    //trace!("Inactive pulping");
-                    if let Nbor::Horizontal(target) = raw_nbor {
-                        //trace!("Activating pulping");
-                        Some(PlannedAction::pulp(target))
-                    } else {
-                        message_writer.send(YouCant {
-                            verb: "pulp",
-                            direction: "vertically",
-                        });
-                        None
-                    }
-                }
-                PickingNbor::Peeking => handle_peeking_offset(next_state, message_writer, raw_nbor),
-                PickingNbor::Closing => {
-                    next_state.set(PlayerActionState::Normal);
-                    if let Nbor::Horizontal(target) = raw_nbor {
-                        Some(PlannedAction::close(target))
-                    } else {
-                        message_writer.send(YouCant {
-                            verb: "close",
-                            direction: "vertically",
-                        });
-                        None
-                    }
-                }
-                PickingNbor::Dragging => {
-                    next_state.set(PlayerActionState::Dragging { from: player_pos });
-                    Some(PlannedAction::step(raw_nbor))
-                }
-                PickingNbor::Crafting(recipe_situation) => {
-                    if let Nbor::Horizontal(target) = raw_nbor {
-                        // next_state is set when performing the action
-                        Some(PlannedAction::StartCraft(StartCraft {
-                            recipe_situation: recipe_situation.clone(),
-                            target,
-                        }))
-                    } else {
-                        message_writer.send(YouCant {
-                            verb: "craft",
-                            direction: "vertically",
-                        });
-                        None
-                    }
-                }
+        PlayerActionState::PickingNbor(picking_nbor) => handle_picking_nbor_offset(
+            picking_nbor,
+            next_state,
+            message_writer,
+            player_pos,
+            raw_nbor,
+        ),
+    }
+}
+
+fn handle_picking_nbor_offset(
+    picking_nbor: &PickingNbor,
+    next_state: &mut ResMut<NextState<PlayerActionState>>,
+    message_writer: &mut LogMessageWriter,
+    player_pos: Pos,
+    raw_nbor: Nbor,
+) -> Option<PlannedAction> {
+    match picking_nbor {
+        PickingNbor::Attacking => {
+            next_state.set(PlayerActionState::Normal);
+            Some(PlannedAction::attack(raw_nbor))
+        }
+        PickingNbor::Smashing => {
+            next_state.set(PlayerActionState::Normal);
+            Some(PlannedAction::smash(raw_nbor))
+        }
+        PickingNbor::Pulping => {
+            //trace!("Inactive pulping");
+            if let Nbor::Horizontal(target) = raw_nbor {
+                //trace!("Activating pulping");
+                Some(PlannedAction::pulp(target))
+            } else {
+                message_writer.send(YouCant {
+                    verb: "pulp",
+                    direction: "vertically",
+                });
+                None
+            }
+        }
+        PickingNbor::Butchering => {
+            if let Nbor::Horizontal(target) = raw_nbor {
+                next_state.set(PlayerActionState::Butchering { direction: target });
+                Some(PlannedAction::butcher(target))
+            } else {
+                message_writer.send(YouCant {
+                    verb: "butcher",
+                    direction: "vertically",
+                });
+                None
+            }
+        }
+        PickingNbor::Peeking => handle_peeking_offset(next_state, message_writer, raw_nbor),
+        PickingNbor::Closing => {
+            next_state.set(PlayerActionState::Normal);
+            if let Nbor::Horizontal(target) = raw_nbor {
+                Some(PlannedAction::close(target))
+            } else {
+                message_writer.send(YouCant {
+                    verb: "close",
+                    direction: "vertically",
+                });
+                None
+            }
+        }
+        PickingNbor::Disarming => {
+            next_state.set(PlayerActionState::Normal);
+            if let Nbor::Horizontal(target) = raw_nbor {
+                Some(PlannedAction::disarm(target))
+            } else {
+                message_writer.send(YouCant {
+                    verb: "disarm",
+                    direction: "vertically",
+                });
+                None
+            }
+        }
+        PickingNbor::Dragging => {
+            next_state.set(PlayerActionState::Dragging { from: player_pos });
+            Some(PlannedAction::step(raw_nbor))
+        }
+        PickingNbor::Crafting(recipe_situation) => {
+            if let Nbor::Horizontal(target) = raw_nbor {
+                // next_state is set when performing the action
+                Some(PlannedAction::StartCraft(StartCraft {
+                    recipe_situation: recipe_situation.clone(),
+                    target,
+                }))
+            } else {
+                message_writer.send(YouCant {
+                    verb: "craft",
+                    direction: "vertically",
+                });
+                None
             }
         }
     }
@@ -468,6 +547,31 @@ fn plan_pulp(
     }
 }
 
+fn plan_butcher(
+    next_state: &mut ResMut<NextState<PlayerActionState>>,
+    message_writer: &mut LogMessageWriter,
+    envir: &Envir,
+    pos: Pos,
+) -> Option<PlannedAction> {
+    let butcherable_directions = envir.directions_to_butcher(pos).collect::<Vec<_>>();
+    match butcherable_directions.as_slice() {
+        [] => {
+            message_writer.send(NothingToButcherNearby);
+            None
+        }
+        [direction] => {
+            next_state.set(PlayerActionState::Butchering {
+                direction: *direction,
+            });
+            Some(PlannedAction::butcher(*direction))
+        }
+        _ => {
+            next_state.set(PlayerActionState::PickingNbor(PickingNbor::Butchering));
+            None
+        }
+    }
+}
+
 fn plan_close(
     next_state: &mut ResMut<NextState<PlayerActionState>>,
     message_writer: &mut LogMessageWriter,
@@ -488,6 +592,26 @@ fn plan_close(
     }
 }
 
+fn plan_disarm(
+    next_state: &mut ResMut<NextState<PlayerActionState>>,
+    message_writer: &mut LogMessageWriter,
+    envir: &Envir,
+    pos: Pos,
+) -> Option<PlannedAction> {
+    let disarmable_nbors = envir.directions_to_disarm(pos).collect::<Vec<_>>();
+    match disarmable_nbors.as_slice() {
+        [] => {
+            message_writer.send(NothingToDisarmNearby);
+            None
+        }
+        [direction] => Some(PlannedAction::disarm(*direction)),
+        _ => {
+            next_state.set(PlayerActionState::PickingNbor(PickingNbor::Disarming));
+            None
+        }
+    }
+}
+
 fn stop_peeking(
     next_state: &mut ResMut<NextState<PlayerActionState>>,
     message_writer: &mut LogMessageWriter,
@@ -599,6 +723,32 @@ fn plan_auto_continue_craft(
     )
 }
 
+fn plan_auto_continue_construction(
+    player_instructions: &mut PlayerInstructions,
+    site_entity: Entity,
+    enemy_name: Option<Fragment>,
+) -> Option<PlannedAction> {
+    // Finishing a construction is checked when performing the action
+    interrupt_on_danger(
+        player_instructions,
+        enemy_name,
+        PlannedAction::ContinueConstruction(ContinueConstruction { site_entity }),
+    )
+}
+
+fn plan_auto_continue_disassembly(
+    player_instructions: &mut PlayerInstructions,
+    item_entity: Entity,
+    enemy_name: Option<Fragment>,
+) -> Option<PlannedAction> {
+    // Finishing a disassembly is checked when performing the action
+    interrupt_on_danger(
+        player_instructions,
+        enemy_name,
+        PlannedAction::ContinueDisassembly(ContinueDisassembly { item_entity }),
+    )
+}
+
 fn plan_auto_travel(
     envir: &Envir<'_, '_>,
     player_instructions: &mut PlayerInstructions,
@@ -695,6 +845,52 @@ fn plan_auto_pulp(
     }
 }
 
+fn plan_auto_butcher(
+    envir: &Envir<'_, '_>,
+    player_instructions: &mut PlayerInstructions,
+    player: &ActorItem<'_, '_>,
+    target: HorizontalDirection,
+    enemy_name: Option<Fragment>,
+) -> Option<PlannedAction> {
+    // Finishing the butchery is checked when performing the action
+    if envir
+        .find_butcherable(player.pos.horizontal_nbor(target))
+        .is_some()
+    {
+        interrupt_on_danger(
+            player_instructions,
+            enemy_name,
+            PlannedAction::Butcher(Butcher { target }),
+        )
+    } else {
+        player_instructions.interrupt(Interruption::Finished);
+        None
+    }
+}
+
+fn plan_auto_train(
+    player_instructions: &mut PlayerInstructions,
+    now: Timestamp,
+    skill: &Arc<str>,
+    cap: Option<u8>,
+    until: &Timestamp,
+    enemy_name: Option<Fragment>,
+) -> Option<PlannedAction> {
+    if *until <= now {
+        player_instructions.interrupt(Interruption::Finished);
+        None
+    } else {
+        interrupt_on_danger(
+            player_instructions,
+            enemy_name,
+            PlannedAction::Train(Train {
+                skill: skill.clone(),
+                cap,
+            }),
+        )
+    }
+}
+
 fn plan_auto_wait(
     player_instructions: &mut PlayerInstructions,
     now: Timestamp,
@@ -2,20 +2,23 @@ use crate::messages::NpcActionFailed;
 use bevy::ecs::schedule::{IntoScheduleConfigs as _, ScheduleConfigs};
 use bevy::ecs::system::{ScheduleSystem, SystemId};
 use bevy::prelude::{
-    Commands, Entity, In, IntoSystem as _, Local, MessageWriter, NextState, Query, Res, ResMut,
-    Single, State, StateTransition, SystemInput, With, World, debug,
+    AnyOf, Commands, Entity, In, IntoSystem as _, Local, MessageWriter, NextState, Query, Res,
+    ResMut, Single, State, StateTransition, SystemInput, With, World, debug,
 };
+use cdda_json_files::{FurnitureInfo, TerrainInfo};
 use gameplay_action_planning::{
     FactionPlanner as _, PlannedAction, PlayerInstructions, plan_automatic_action,
     plan_manual_action,
 };
 use gameplay_character::{
-    Action, ActionIn, Actor, ActorImpact, Attack, ChangePace, CharacterEvent, Close, ContinueCraft,
-    CorpseEvent, ExamineItem, Faction, HealingDuration, ItemAction as _, MoveItem, Peek, Pickup,
-    Pulp, Sleep, Smash, Stamina, StartCraft, Stay, Step, Unwield, Wield,
+    Action, ActionIn, Actor, ActorImpact, ApplyEffect, Attack, Butcher, ChangePace, CharacterEvent,
+    Close, ContinueConstruction, ContinueCraft, ContinueDisassembly, CorpseEvent, Disarm, Drink,
+    Eat, ExamineItem, Exercise, Faction, Fire, HealingDuration, ItemAction as _, ItemEvent,
+    MoveItem, Nourishment, Peek, Pickup, Pulp, Reload, Sleep, Smash, Stamina, StartConstruction,
+    StartCraft, StartDisassembly, Stay, Step, TakeOff, Train, Unload, Unwield, Wear, Wield,
 };
-use gameplay_common::Tile;
-use gameplay_crafting::Craft;
+use gameplay_common::{LastSeen, Shared, Tile};
+use gameplay_crafting::{Butchery, ConstructionSite, Craft, Disassembly, nearby_cutting_quality};
 use gameplay_item::{Amount, Item, ItemHierarchy};
 use gameplay_location::{LocationCache, Pos};
 use gameplay_log::LogMessageWriter;
@@ -23,9 +26,9 @@ use gameplay_object::{Damage, Healing, Life};
 use gameplay_perception::{CurrentlyVisibleBuilder, Explored};
 use gameplay_player::{Player, PlayerActionState};
 use gameplay_spawn::TileSpawner;
-use gameplay_terrain::{TerrainEvent, Toggle};
+use gameplay_terrain::{Construct, TerrainEvent, Toggle, TrapTrigger};
 use gameplay_time::{Clock, Timeouts};
-use gameplay_world::Envir;
+use gameplay_world::{Envir, Noise};
 use std::{cell::OnceCell, time::Instant};
 use units::Duration;
 use util::log_if_slow;
@@ -170,6 +173,7 @@ fn plan_npc_action(
     In(active_actor): In<Entity>,
     mut commands: Commands,
     currently_visible_builder: CurrentlyVisibleBuilder,
+    hierarchy: ItemHierarchy,
     actors: Query<Actor>,
     factions: Query<(&Pos, &Faction), With<Life>>,
 ) -> PlannedAction {
@@ -182,10 +186,13 @@ fn plan_npc_action(
     let enemies = actor
         .faction
         .enemies(&currently_visible_builder, factions, &actor);
-    let strategy =
-        actor
-            .faction
-            .strategize(&currently_visible_builder.envir, factions, &enemies, &actor);
+    let strategy = actor.faction.strategize(
+        &currently_visible_builder.envir,
+        factions,
+        &enemies,
+        &actor,
+        actor.fire_range(&hierarchy),
+    );
     if let Some(last_enemy) = strategy.last_enemy {
         commands.entity(actor.entity).insert(last_enemy);
     }
@@ -207,16 +214,30 @@ struct PerformSystems {
     sleep: SystemId<In<ActionIn<Sleep>>, ActorImpact>,
     step: SystemId<In<ActionIn<Step>>, ActorImpact>,
     attack: SystemId<In<ActionIn<Attack>>, ActorImpact>,
+    fire: SystemId<In<ActionIn<Fire>>, ActorImpact>,
     smash: SystemId<In<ActionIn<Smash>>, ActorImpact>,
     pulp: SystemId<In<ActionIn<Pulp>>, ActorImpact>,
+    butcher: SystemId<In<ActionIn<Butcher>>, ActorImpact>,
     peek: SystemId<In<ActionIn<Peek>>, ActorImpact>,
     close: SystemId<In<ActionIn<Close>>, ActorImpact>,
+    disarm: SystemId<In<ActionIn<Disarm>>, ActorImpact>,
     wield: SystemId<In<ActionIn<Wield>>, ActorImpact>,
     unwield: SystemId<In<ActionIn<Unwield>>, ActorImpact>,
+    wear: SystemId<In<ActionIn<Wear>>, ActorImpact>,
+    take_off: SystemId<In<ActionIn<TakeOff>>, ActorImpact>,
     pickup: SystemId<In<ActionIn<Pickup>>, ActorImpact>,
     move_item: SystemId<In<ActionIn<MoveItem>>, ActorImpact>,
+    eat: SystemId<In<ActionIn<Eat>>, ActorImpact>,
+    drink: SystemId<In<ActionIn<Drink>>, ActorImpact>,
+    reload: SystemId<In<ActionIn<Reload>>, ActorImpact>,
+    unload: SystemId<In<ActionIn<Unload>>, ActorImpact>,
     start_craft: SystemId<In<ActionIn<StartCraft>>, ActorImpact>,
     continue_craft: SystemId<In<ActionIn<ContinueCraft>>, ActorImpact>,
+    start_construction: SystemId<In<ActionIn<StartConstruction>>, ActorImpact>,
+    continue_construction: SystemId<In<ActionIn<ContinueConstruction>>, ActorImpact>,
+    start_disassembly: SystemId<In<ActionIn<StartDisassembly>>, ActorImpact>,
+    continue_disassembly: SystemId<In<ActionIn<ContinueDisassembly>>, ActorImpact>,
+    train: SystemId<In<ActionIn<Train>>, ActorImpact>,
     examine_item: SystemId<In<ActionIn<ExamineItem>>, ActorImpact>,
     change_pace: SystemId<In<ActionIn<ChangePace>>, ActorImpact>,
 }
@@ -228,16 +249,30 @@ impl PerformSystems {
             sleep: world.register_system_cached(perform_sleep),
             step: world.register_system_cached(perform_step),
             attack: world.register_system_cached(perform_attack),
+            fire: world.register_system_cached(perform_fire),
             smash: world.register_system_cached(perform_smash),
             pulp: world.register_system_cached(perform_pulp),
+            butcher: world.register_system_cached(perform_butcher),
             peek: world.register_system_cached(perform_peek),
             close: world.register_system_cached(perform_close),
+            disarm: world.register_system_cached(perform_disarm),
             wield: world.register_system_cached(perform_wield),
             unwield: world.register_system_cached(perform_unwield),
+            wear: world.register_system_cached(perform_wear),
+            take_off: world.register_system_cached(perform_take_off),
             pickup: world.register_system_cached(perform_pickup),
             move_item: world.register_system_cached(perform_move_item),
+            eat: world.register_system_cached(perform_eat),
+            drink: world.register_system_cached(perform_drink),
+            reload: world.register_system_cached(perform_reload),
+            unload: world.register_system_cached(perform_unload),
             start_craft: world.register_system_cached(perform_start_craft),
             continue_craft: world.register_system_cached(perform_continue_craft),
+            start_construction: world.register_system_cached(perform_start_construction),
+            continue_construction: world.register_system_cached(perform_continue_construction),
+            start_disassembly: world.register_system_cached(perform_start_disassembly),
+            continue_disassembly: world.register_system_cached(perform_continue_disassembly),
+            train: world.register_system_cached(perform_train),
             examine_item: world.register_system_cached(perform_examine_item),
             change_pace: world.register_system_cached(perform_change_pace),
         }
@@ -254,18 +289,40 @@ impl PerformSystems {
             PlannedAction::Sleep => act_fn(self.sleep, Sleep),
             PlannedAction::Step(step) => act_fn(self.step, step),
             PlannedAction::Attack(attack) => act_fn(self.attack, attack),
+            PlannedAction::Fire(fire) => act_fn(self.fire, fire),
             PlannedAction::Smash(smash) => act_fn(self.smash, smash),
             PlannedAction::Pulp(pulp) => act_fn(self.pulp, pulp),
+            PlannedAction::Butcher(butcher) => act_fn(self.butcher, butcher),
             PlannedAction::Peek(peek) => act_fn(self.peek, peek),
             PlannedAction::Close(close) => act_fn(self.close, close),
+            PlannedAction::Disarm(disarm) => act_fn(self.disarm, disarm),
             PlannedAction::Wield(wield) => act_fn(self.wield, wield),
             PlannedAction::Unwield(unwield) => act_fn(self.unwield, unwield),
+            PlannedAction::Wear(wear) => act_fn(self.wear, wear),
+            PlannedAction::TakeOff(take_off) => act_fn(self.take_off, take_off),
             PlannedAction::Pickup(pickup) => act_fn(self.pickup, pickup),
             PlannedAction::MoveItem(move_item) => act_fn(self.move_item, move_item),
+            PlannedAction::Eat(eat) => act_fn(self.eat, eat),
+            PlannedAction::Drink(drink) => act_fn(self.drink, drink),
+            PlannedAction::Reload(reload) => act_fn(self.reload, reload),
+            PlannedAction::Unload(unload) => act_fn(self.unload, unload),
             PlannedAction::StartCraft(start_craft) => act_fn(self.start_craft, start_craft),
             PlannedAction::ContinueCraft(continue_craft) => {
                 act_fn(self.continue_craft, continue_craft)
             }
+            PlannedAction::StartConstruction(start_construction) => {
+                act_fn(self.start_construction, start_construction)
+            }
+            PlannedAction::ContinueConstruction(continue_construction) => {
+                act_fn(self.continue_construction, continue_construction)
+            }
+            PlannedAction::StartDisassembly(start_disassembly) => {
+                act_fn(self.start_disassembly, start_disassembly)
+            }
+            PlannedAction::ContinueDisassembly(continue_disassembly) => {
+                act_fn(self.continue_disassembly, continue_disassembly)
+            }
+            PlannedAction::Train(train) => act_fn(self.train, train),
             PlannedAction::ExamineItem(examine_item) => act_fn(self.examine_item, examine_item),
             PlannedAction::ChangePace(change_pace) => act_fn(self.change_pace, change_pace),
         };
@@ -343,6 +400,7 @@ fn perform_step(
     mut commands: Commands,
     mut message_writer: LogMessageWriter,
     mut toggle_writer: MessageWriter<TerrainEvent<Toggle>>,
+    mut trap_writer: MessageWriter<CharacterEvent<TrapTrigger>>,
     mut envir: Envir,
     actors: Query<Actor>,
 ) -> ActorImpact {
@@ -350,6 +408,7 @@ fn perform_step(
         &mut commands,
         &mut message_writer,
         &mut toggle_writer,
+        &mut trap_writer,
         &mut envir,
         &step.action,
     )
@@ -360,6 +419,8 @@ fn perform_attack(
     In(attack): In<ActionIn<Attack>>,
     mut message_writer: LogMessageWriter,
     mut damage_writer: MessageWriter<CharacterEvent<Damage>>,
+    mut effect_writer: MessageWriter<CharacterEvent<ApplyEffect>>,
+    mut exercise_writer: MessageWriter<CharacterEvent<Exercise>>,
     envir: Envir,
     hierarchy: ItemHierarchy,
     actors: Query<Actor>,
@@ -367,17 +428,50 @@ fn perform_attack(
     attack.actor(&actors).attack(
         &mut message_writer,
         &mut damage_writer,
+        &mut effect_writer,
+        &mut exercise_writer,
         &envir,
         &hierarchy,
         &attack.action,
     )
 }
 
+#[expect(clippy::needless_pass_by_value)]
+fn perform_fire(
+    In(fire): In<ActionIn<Fire>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut damage_writer: MessageWriter<CharacterEvent<Damage>>,
+    mut terrain_damage_writer: MessageWriter<TerrainEvent<Damage>>,
+    mut exercise_writer: MessageWriter<CharacterEvent<Exercise>>,
+    mut noise_writer: MessageWriter<Noise>,
+    currently_visible_builder: CurrentlyVisibleBuilder,
+    hierarchy: ItemHierarchy,
+    actors: Query<Actor>,
+) -> ActorImpact {
+    let actor = fire.actor(&actors);
+    let line_of_fire = currently_visible_builder
+        .for_npc(*actor.pos)
+        .line_of_fire(fire.action.target);
+    actor.fire(
+        &mut commands,
+        &mut message_writer,
+        &mut damage_writer,
+        &mut terrain_damage_writer,
+        &mut exercise_writer,
+        &mut noise_writer,
+        &currently_visible_builder.envir,
+        &hierarchy,
+        line_of_fire,
+    )
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn perform_smash(
     In(smash): In<ActionIn<Smash>>,
     mut message_writer: LogMessageWriter,
     mut damage_writer: MessageWriter<TerrainEvent<Damage>>,
+    mut item_damage_writer: MessageWriter<ItemEvent<Damage>>,
     envir: Envir,
     hierarchy: ItemHierarchy,
     actors: Query<Actor>,
@@ -385,6 +479,7 @@ fn perform_smash(
     smash.actor(&actors).smash(
         &mut message_writer,
         &mut damage_writer,
+        &mut item_damage_writer,
         &envir,
         &hierarchy,
         &smash.action,
@@ -409,6 +504,49 @@ fn perform_pulp(
     )
 }
 
+#[expect(clippy::needless_pass_by_value)]
+fn perform_butcher(
+    In(butcher): In<ActionIn<Butcher>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut transient_message_writer: LogMessageWriter<PlayerActionState>,
+    player_action_state: Res<State<PlayerActionState>>,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    mut spawner: TileSpawner,
+    envir: Envir,
+    location: Res<LocationCache>,
+    actors: Query<Actor>,
+    items: Query<(Item, &LastSeen)>,
+    infrastructure: Query<(
+        AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
+        &LastSeen,
+    )>,
+    butcheries: Query<&Butchery>,
+) -> ActorImpact {
+    let actor = butcher.actor(&actors);
+    let cutting_quality = actor.body_containers.and_then(|body_containers| {
+        nearby_cutting_quality(
+            &location,
+            &items,
+            &infrastructure,
+            *actor.pos,
+            body_containers,
+        )
+    });
+    actor.butcher(
+        &mut commands,
+        &mut message_writer,
+        &mut transient_message_writer,
+        &player_action_state,
+        &mut next_player_action_state,
+        &mut spawner,
+        &envir,
+        &butcheries,
+        cutting_quality,
+        &butcher.action,
+    )
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn perform_peek(
     In(peek): In<ActionIn<Peek>>,
@@ -441,6 +579,26 @@ fn perform_close(
     )
 }
 
+#[expect(clippy::needless_pass_by_value)]
+fn perform_disarm(
+    In(disarm): In<ActionIn<Disarm>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut trap_writer: MessageWriter<CharacterEvent<TrapTrigger>>,
+    mut spawner: TileSpawner,
+    envir: Envir,
+    actors: Query<Actor>,
+) -> ActorImpact {
+    disarm.actor(&actors).disarm(
+        &mut commands,
+        &mut message_writer,
+        &mut trap_writer,
+        &mut spawner,
+        &envir,
+        &disarm.action,
+    )
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn perform_wield(
     In(wield): In<ActionIn<Wield>>,
@@ -475,6 +633,40 @@ fn perform_unwield(
     )
 }
 
+#[expect(clippy::needless_pass_by_value)]
+fn perform_wear(
+    In(wear): In<ActionIn<Wear>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    hierarchy: ItemHierarchy,
+    actors: Query<Actor>,
+    items: Query<Item>,
+) -> ActorImpact {
+    wear.actor(&actors).wear(
+        &mut commands,
+        &mut message_writer,
+        &hierarchy,
+        &wear.action.item(&items),
+    )
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn perform_take_off(
+    In(take_off): In<ActionIn<TakeOff>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    hierarchy: ItemHierarchy,
+    actors: Query<Actor>,
+    items: Query<Item>,
+) -> ActorImpact {
+    take_off.actor(&actors).take_off(
+        &mut commands,
+        &mut message_writer,
+        &hierarchy,
+        &take_off.action.item(&items),
+    )
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn perform_pickup(
     In(pickup): In<ActionIn<Pickup>>,
@@ -512,6 +704,72 @@ fn perform_move_item(
     )
 }
 
+fn perform_eat(
+    In(eat): In<ActionIn<Eat>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut nourishment_writer: MessageWriter<CharacterEvent<Nourishment>>,
+    actors: Query<Actor>,
+    items: Query<Item>,
+) -> ActorImpact {
+    eat.actor(&actors).eat(
+        &mut commands,
+        &mut message_writer,
+        &mut nourishment_writer,
+        &eat.action.item(&items),
+    )
+}
+
+fn perform_drink(
+    In(drink): In<ActionIn<Drink>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut nourishment_writer: MessageWriter<CharacterEvent<Nourishment>>,
+    actors: Query<Actor>,
+    items: Query<Item>,
+) -> ActorImpact {
+    drink.actor(&actors).drink(
+        &mut commands,
+        &mut message_writer,
+        &mut nourishment_writer,
+        &drink.action.item(&items),
+    )
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn perform_reload(
+    In(reload): In<ActionIn<Reload>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    hierarchy: ItemHierarchy,
+    actors: Query<Actor>,
+    items: Query<Item>,
+) -> ActorImpact {
+    reload.actor(&actors).reload(
+        &mut commands,
+        &mut message_writer,
+        &hierarchy,
+        &reload.action.item(&items),
+    )
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn perform_unload(
+    In(unload): In<ActionIn<Unload>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    hierarchy: ItemHierarchy,
+    actors: Query<Actor>,
+    items: Query<Item>,
+) -> ActorImpact {
+    unload.actor(&actors).unload(
+        &mut commands,
+        &mut message_writer,
+        &hierarchy,
+        &unload.action.item(&items),
+    )
+}
+
 fn perform_start_craft(
     In(start_craft): In<ActionIn<StartCraft>>,
     mut commands: Commands,
@@ -535,6 +793,7 @@ fn perform_continue_craft(
     mut commands: Commands,
     mut message_writer: LogMessageWriter,
     mut transient_message_writer: LogMessageWriter<PlayerActionState>,
+    mut exercise_writer: MessageWriter<CharacterEvent<Exercise>>,
     player_action_state: Res<State<PlayerActionState>>,
     mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
     mut spawner: TileSpawner,
@@ -545,6 +804,7 @@ fn perform_continue_craft(
         &mut commands,
         &mut message_writer,
         &mut transient_message_writer,
+        &mut exercise_writer,
         &player_action_state,
         &mut next_player_action_state,
         &mut spawner,
@@ -553,6 +813,94 @@ fn perform_continue_craft(
     )
 }
 
+fn perform_start_construction(
+    In(start_construction): In<ActionIn<StartConstruction>>,
+    mut commands: Commands,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    mut spawner: TileSpawner,
+    actors: Query<Actor>,
+    mut amounts: Query<&mut Amount>,
+) -> ActorImpact {
+    start_construction.actor(&actors).start_construction(
+        &mut commands,
+        &mut next_player_action_state,
+        &mut spawner,
+        &mut amounts,
+        &start_construction.action,
+    )
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn perform_continue_construction(
+    In(continue_construction): In<ActionIn<ContinueConstruction>>,
+    mut message_writer: LogMessageWriter,
+    mut transient_message_writer: LogMessageWriter<PlayerActionState>,
+    mut construct_writer: MessageWriter<TerrainEvent<Construct>>,
+    player_action_state: Res<State<PlayerActionState>>,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    actors: Query<Actor>,
+    mut sites: Query<&mut ConstructionSite>,
+) -> ActorImpact {
+    continue_construction.actor(&actors).continue_construction(
+        &mut message_writer,
+        &mut transient_message_writer,
+        &mut construct_writer,
+        &player_action_state,
+        &mut next_player_action_state,
+        &mut sites,
+        continue_construction.action.site_entity,
+    )
+}
+
+fn perform_start_disassembly(
+    In(start_disassembly): In<ActionIn<StartDisassembly>>,
+    mut commands: Commands,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    actors: Query<Actor>,
+    disassemblies: Query<&Disassembly>,
+) -> ActorImpact {
+    start_disassembly.actor(&actors).start_disassembly(
+        &mut commands,
+        &mut next_player_action_state,
+        &disassemblies,
+        &start_disassembly.action,
+    )
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn perform_continue_disassembly(
+    In(continue_disassembly): In<ActionIn<ContinueDisassembly>>,
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut transient_message_writer: LogMessageWriter<PlayerActionState>,
+    player_action_state: Res<State<PlayerActionState>>,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    mut spawner: TileSpawner,
+    actors: Query<Actor>,
+    mut items: Query<(Item, &mut Disassembly)>,
+) -> ActorImpact {
+    continue_disassembly.actor(&actors).continue_disassembly(
+        &mut commands,
+        &mut message_writer,
+        &mut transient_message_writer,
+        &player_action_state,
+        &mut next_player_action_state,
+        &mut spawner,
+        &mut items,
+        continue_disassembly.action.item_entity,
+    )
+}
+
+fn perform_train(
+    In(train): In<ActionIn<Train>>,
+    mut exercise_writer: MessageWriter<CharacterEvent<Exercise>>,
+    actors: Query<Actor>,
+) -> ActorImpact {
+    train
+        .actor(&actors)
+        .train(&mut exercise_writer, &train.action)
+}
+
 fn perform_examine_item(
     In(examine_item): In<ActionIn<ExamineItem>>,
     mut message_writer: LogMessageWriter,
@@ -1,43 +1,83 @@
-use crate::messages::{Break, Heal, Hit, IsThoroughlyPulped, Kill, Pulp};
+use crate::messages::{
+    Alarm, Break, DamageItem, Detect, EffectNotice, EffectWarning, Heal, Hit, IsThoroughlyPulped,
+    Kill, NutritionWarning, Pulp, SkillImproved, TriggerTrap,
+};
 use bevy::ecs::schedule::{IntoScheduleConfigs as _, ScheduleConfigs};
 use bevy::ecs::system::ScheduleSystem;
 use bevy::prelude::{
-    Changed, ChildOf, Commands, Entity, MessageReader, NextState, ParamSet, Quat, Query, Res,
-    ResMut, Transform, With, Without, on_message,
+    Changed, ChildOf, Commands, DetectChangesMut as _, Entity, Local, MessageReader, MessageWriter,
+    NextState, ParamSet, Quat, Query, Res, ResMut, Transform, With, Without, error, on_message,
+    warn,
+};
+use cdda_json_files::{
+    CharacterInfo, ConstructionTarget, EffectInfo, EffectRating, FieldInfo, FurnitureInfo, InfoId,
+    TerrainInfo, TrapInfo,
 };
-use cdda_json_files::{FurnitureInfo, InfoId, TerrainInfo};
 use either::Either;
+use fastrand::{u8 as rand_u8, u16 as rand_u16, u64 as rand_u64};
 use gameplay_cdda::Infos;
 use gameplay_character::{
-    Actor, BaseFaction as _, CharacterEvent, CorpseEvent, Faction, Health, Stamina, WalkingMode,
+    Actor, ApplyEffect, BaseFaction as _, CharacterEvent, CorpseEvent, Effects, Encumbrance,
+    Exercise, Faction, Health, ItemEvent, Nourishment, Nutrition, Perception, Skills, Snared,
+    Stamina, WalkingMode,
 };
 use gameplay_common::{Limited, Shared};
-use gameplay_item::{Amount, ContainerLimits, Item, ItemHierarchy};
-use gameplay_location::Pos;
+use gameplay_crafting::ConstructionSite;
+use gameplay_item::{
+    Amount, BodyContainers, ContainerLimits, Item, ItemHierarchy, ItemTemperature,
+};
+use gameplay_location::{LevelOffset, LocationCache, Pos};
 use gameplay_log::LogMessageWriter;
 use gameplay_object::{
-    Corpse, CorpseRaise, Damage, Healing, Life, ObjectName, Obstacle, StandardIntegrity,
+    BodyPart, Corpse, CorpseRaise, Damage, DamageType, Healing, Life, ObjectName, Obstacle,
+    StandardIntegrity,
 };
+use gameplay_perception::{CurrentlyVisibleBuilder, Visible};
 use gameplay_player::Player;
 use gameplay_relations::ObjectOn;
 use gameplay_screen_state::GameplayScreenState;
 use gameplay_spawn::TileSpawner;
-use gameplay_terrain::{LocalTerrain, TerrainEvent, Toggle};
+use gameplay_terrain::{
+    Concealed, Construct, LocalTerrain, TerrainEvent, Toggle, TrapEffect, TrapTrigger,
+};
 use gameplay_time::Clock;
 use gameplay_visualization::VisualizationUpdate;
-use std::{f32::consts::FRAC_PI_2, time::Instant};
-use text::Fragment;
-use units::Duration;
+use gameplay_world::Noise;
+use std::{f32::consts::FRAC_PI_2, sync::Arc, time::Instant};
+use text::{Fragment, Phrase, Subject};
+use units::{Duration, Timestamp};
 use util::log_if_slow;
 
+/// Volume of traps without a sound volume
+const ALARM_VOLUME: u16 = 20;
+
+/// Damage absorbed by worn armor per point of wear
+const ARMOR_WEAR_PER_POINT: u16 = 4;
+
+/// Volume of terrain and furniture that breaks
+const BREAK_VOLUME: u16 = 16;
+
+/// Volume of terrain and furniture that gets hit
+const HIT_VOLUME: u16 = 8;
+
 pub(crate) fn handle_action_effects() -> ScheduleConfigs<ScheduleSystem> {
     (
         (
             // actor events
+            detect_traps,
+            // Traps may cause damage
+            trigger_traps.run_if(on_message::<CharacterEvent<TrapTrigger>>),
+            apply_field_effects,
+            apply_effects.run_if(on_message::<CharacterEvent<ApplyEffect>>),
+            // Effects may cause damage
+            update_effects,
             // Make sure killed actors are handled early
             update_damaged_characters.run_if(on_message::<CharacterEvent<Damage>>),
             (
                 update_healed_characters.run_if(on_message::<CharacterEvent<Healing>>),
+                update_skills,
+                update_nutrition,
+                update_encumbrance,
                 update_corpses,
             ),
         )
@@ -45,6 +85,8 @@ pub(crate) fn handle_action_effects() -> ScheduleConfigs<ScheduleSystem> {
         (
             // item events
             update_damaged_corpses.run_if(on_message::<CorpseEvent<Damage>>),
+            update_damaged_items.run_if(on_message::<ItemEvent<Damage>>),
+            burn_items,
             combine_items,
         )
             .chain(),
@@ -53,6 +95,7 @@ pub(crate) fn handle_action_effects() -> ScheduleConfigs<ScheduleSystem> {
             // Make sure destoyed items are handled early
             update_damaged_terrain.run_if(on_message::<TerrainEvent<Damage>>),
             toggle_doors.run_if(on_message::<TerrainEvent<Toggle>>),
+            finish_constructions.run_if(on_message::<TerrainEvent<Construct>>),
         )
             .chain(),
     )
@@ -90,6 +133,207 @@ pub(crate) fn toggle_doors(
     log_if_slow("toggle_doors", start);
 }
 
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn finish_constructions(
+    mut commands: Commands,
+    mut construct_reader: MessageReader<TerrainEvent<Construct>>,
+    mut spawner: TileSpawner,
+    mut visualization_update: ResMut<VisualizationUpdate>,
+    location: Res<LocationCache>,
+    sites: Query<(&Pos, &ObjectOn), With<ConstructionSite>>,
+    terrain: Query<(), With<Shared<TerrainInfo>>>,
+    furniture: Query<(), With<Shared<FurnitureInfo>>>,
+) {
+    let start = Instant::now();
+
+    for construct in construct_reader.read() {
+        let (&pos, &object_in) = sites
+            .get(construct.terrain_entity)
+            .expect("Construction site should be found");
+
+        commands.entity(construct.terrain_entity).despawn();
+
+        let replaced_terrain = location
+            .all(pos)
+            .copied()
+            .filter(|&entity| terrain.contains(entity));
+        let replaced_furniture = location
+            .all(pos)
+            .copied()
+            .filter(|&entity| furniture.contains(entity));
+        match &construct.change.construction.post_terrain {
+            Some(ConstructionTarget::Terrain(post_terrain)) => {
+                for entity in replaced_terrain {
+                    commands.entity(entity).despawn();
+                }
+                if let Some(terrain_info) = post_terrain.get_option() {
+                    let local_terrain = LocalTerrain::unconnected(terrain_info);
+                    spawner.spawn_terrain(object_in, pos, &local_terrain);
+                }
+            }
+            Some(ConstructionTarget::Furniture(post_furniture)) => {
+                for entity in replaced_furniture {
+                    commands.entity(entity).despawn();
+                }
+                if let Some(furniture_info) = post_furniture.get_option() {
+                    spawner.spawn_furniture(object_in, pos, &furniture_info);
+                }
+            }
+            Some(ConstructionTarget::NoFurniture) => {
+                for entity in replaced_furniture {
+                    commands.entity(entity).despawn();
+                }
+            }
+            None => {
+                // TODO support post_special
+            }
+        }
+        *visualization_update = VisualizationUpdate::Forced;
+    }
+
+    log_if_slow("finish_constructions", start);
+}
+
+/// Within this range, the player character may notice concealed traps
+const TRAP_DETECTION_RANGE: i32 = 5;
+
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn detect_traps(
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    currently_visible_builder: CurrentlyVisibleBuilder,
+    mut visualization_update: ResMut<VisualizationUpdate>,
+    players: Query<(&Pos, &Perception), (With<Player>, Changed<Pos>)>,
+    traps: Query<(Entity, &ObjectName, &Pos, &Shared<TrapInfo>), With<Concealed>>,
+) {
+    let start = Instant::now();
+
+    let Ok((&player_pos, perception)) = players.single() else {
+        return;
+    };
+
+    let mut currently_visible = currently_visible_builder.for_player(true);
+    for (trap_entity, trap_name, &trap_pos, trap_info) in &traps {
+        let offset = trap_pos - player_pos;
+        let distance = offset.x.abs().max(offset.z.abs());
+        if offset.level == LevelOffset::ZERO
+            && distance <= TRAP_DETECTION_RANGE
+            && currently_visible.can_see(trap_pos, None) == Visible::Seen
+            && perception.detects(trap_info, distance as u8)
+        {
+            commands.entity(trap_entity).remove::<Concealed>();
+            message_writer.send(Detect {
+                trap: trap_name.single(trap_pos),
+            });
+            *visualization_update = VisualizationUpdate::Forced;
+        }
+    }
+
+    log_if_slow("detect_traps", start);
+}
+
+pub(crate) fn trigger_traps(
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut trap_reader: MessageReader<CharacterEvent<TrapTrigger>>,
+    mut damage_writer: MessageWriter<CharacterEvent<Damage>>,
+    mut noise_writer: MessageWriter<Noise>,
+    mut visualization_update: ResMut<VisualizationUpdate>,
+    actors: Query<Actor>,
+    traps: Query<(&ObjectName, &Pos, &Shared<TrapInfo>)>,
+) {
+    let start = Instant::now();
+
+    for trigger in trap_reader.read() {
+        let Ok(actor) = actors.get(trigger.actor_entity) else {
+            error!("Actor {:?} of trap trigger not found", trigger.actor_entity);
+            continue;
+        };
+        let trap_entity = trigger.action.trap_entity;
+        let Ok((trap_name, &trap_pos, trap_info)) = traps.get(trap_entity) else {
+            // Possibly disarmed or set off earlier this turn
+            warn!("Triggered trap {trap_entity:?} not found");
+            continue;
+        };
+
+        let effect = TrapEffect::of(trap_info);
+        if effect == TrapEffect::Nothing {
+            continue;
+        }
+
+        message_writer.send(TriggerTrap {
+            victim: actor.subject(),
+            trap: trap_name.single(trap_pos),
+            into_pit: matches!(effect, TrapEffect::Pit { .. }),
+        });
+
+        let (damage, snared) = match effect {
+            TrapEffect::Nothing => (None, None),
+            TrapEffect::Alarm { sound } => {
+                message_writer.send(Alarm { sound });
+                noise_writer.write(Noise::new(
+                    trap_pos,
+                    trap_info.sound_volume.map_or(ALARM_VOLUME, u16::from),
+                ));
+                (None, None)
+            }
+            TrapEffect::Damage {
+                amount,
+                damage_type,
+            } => (Some((amount, damage_type)), None),
+            TrapEffect::Snare {
+                amount,
+                damage_type,
+                struggles,
+            } => (
+                Some((amount, damage_type)),
+                Some(Snared {
+                    in_pit: false,
+                    attempts_left: struggles,
+                }),
+            ),
+            TrapEffect::Pit {
+                amount,
+                damage_type,
+                climbs,
+            } => (
+                Some((amount, damage_type)),
+                Some(Snared {
+                    in_pit: true,
+                    attempts_left: climbs,
+                }),
+            ),
+        };
+
+        if let Some((amount, damage_type)) = damage {
+            let amount = rand_u16(amount);
+            if 0 < amount {
+                damage_writer.write(CharacterEvent::new(
+                    actor.entity,
+                    Damage {
+                        attacker: Subject::Other(Phrase::from_fragment(trap_name.single(trap_pos))),
+                        damage_type,
+                        amount,
+                    },
+                ));
+            }
+        }
+
+        if let Some(snared) = snared {
+            commands.entity(actor.entity).insert(snared);
+        }
+
+        if trap_info.single_use() {
+            commands.entity(trap_entity).despawn();
+        } else {
+            commands.entity(trap_entity).remove::<Concealed>();
+        }
+        *visualization_update = VisualizationUpdate::Forced;
+    }
+
+    log_if_slow("trigger_traps", start);
+}
+
 #[expect(clippy::needless_pass_by_value)]
 pub(crate) fn update_damaged_characters(
     mut commands: Commands,
@@ -97,12 +341,15 @@ pub(crate) fn update_damaged_characters(
     mut damage_reader: MessageReader<CharacterEvent<Damage>>,
     clock: Clock,
     mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>,
+    hierarchy: ItemHierarchy,
     mut characters: Query<
         (
             &ObjectName,
             &Pos,
             &mut Health,
             &mut Transform,
+            Option<&Shared<CharacterInfo>>,
+            Option<&BodyContainers>,
             Option<&Player>,
         ),
         (With<Faction>, With<Life>),
@@ -111,10 +358,30 @@ pub(crate) fn update_damaged_characters(
     let start = Instant::now();
 
     for damage in damage_reader.read() {
-        let (name, pos, mut health, mut transform, player) = characters
-            .get_mut(damage.actor_entity)
-            .expect("Actor found");
-        let evolution = health.lower(&damage.action);
+        let (name, pos, mut health, mut transform, character_info, body_containers, player) =
+            characters
+                .get_mut(damage.actor_entity)
+                .expect("Actor found");
+
+        // Natural armor, like the chitin of insects
+        let natural_armor = character_info.map_or(0, |character_info| {
+            damage
+                .action
+                .damage_type
+                .character_armor(character_info)
+                .round() as u16
+        });
+        let remaining = damage.action.reduced(natural_armor);
+        let worn_armor = body_containers.map_or(0, |body_containers| {
+            absorb_by_clothing(
+                &mut commands,
+                &mut message_writer,
+                &hierarchy,
+                body_containers,
+                &remaining,
+            )
+        });
+        let evolution = health.lower(&remaining.reduced(worn_armor));
         let victim = if player.is_some() {
             Fragment::you()
         } else {
@@ -155,6 +422,53 @@ pub(crate) fn update_damaged_characters(
     log_if_slow("update_damaged_characters", start);
 }
 
+/// Worn items that cover the hit body part absorb damage, and wear down by doing so
+///
+/// Returns the amount of absorbed damage
+fn absorb_by_clothing(
+    commands: &mut Commands,
+    message_writer: &mut LogMessageWriter,
+    hierarchy: &ItemHierarchy,
+    body_containers: &BodyContainers,
+    damage: &Damage,
+) -> u16 {
+    let body_part = BodyPart::random();
+    let mut absorbed = 0;
+    for worn in hierarchy.items_in_pocket(body_containers.clothing) {
+        if worn.integrity.broken()
+            || worn.common_info.coverage(body_part.cdda_id()) <= rand_u8(0..100)
+        {
+            continue;
+        }
+
+        let protection = worn
+            .common_info
+            .protection(body_part.cdda_id(), |resist| {
+                damage.damage_type.material_resist(resist)
+            })
+            .round() as u16;
+        let absorbed_by_worn = protection.min(damage.amount - absorbed);
+        absorbed += absorbed_by_worn;
+
+        // Rounded randomly, so small hits sometimes wear down the armor
+        let wear = (absorbed_by_worn + rand_u16(0..ARMOR_WEAR_PER_POINT)) / ARMOR_WEAR_PER_POINT;
+        if 0 < wear {
+            let integrity = worn.integrity.damaged(wear);
+            message_writer.send(DamageItem {
+                attacker: damage.attacker.clone(),
+                item: worn.fragments().collect(),
+                verb: Some(if integrity.broken() {
+                    "break"
+                } else {
+                    "damage"
+                }),
+            });
+            commands.entity(worn.entity).insert(integrity);
+        }
+    }
+    absorbed
+}
+
 pub(crate) fn update_healed_characters(
     mut message_writer: LogMessageWriter,
     mut healing_reader: MessageReader<CharacterEvent<Healing>>,
@@ -182,6 +496,243 @@ pub(crate) fn update_healed_characters(
     log_if_slow("update_healed_characters", start);
 }
 
+/// Characters in fields, like smoke or acid, get the effects of those fields
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn apply_field_effects(
+    mut effect_writer: MessageWriter<CharacterEvent<ApplyEffect>>,
+    clock: Clock,
+    mut last_exposure: Local<Option<Timestamp>>,
+    location: Res<LocationCache>,
+    fields: Query<(&Pos, &Shared<FieldInfo>)>,
+    characters: Query<Entity, (With<Effects>, With<Life>)>,
+) {
+    let start = Instant::now();
+
+    let now = clock.time();
+    let exposed = last_exposure.is_some_and(|last_exposure| last_exposure < now);
+    *last_exposure = Some(now);
+    if !exposed {
+        return;
+    }
+
+    for (&pos, field_info) in &fields {
+        for field_effect in field_info.effects() {
+            let Some(info) = field_effect.effect_id.get_option() else {
+                continue;
+            };
+            let min = field_effect.min_duration.milliseconds();
+            let max = field_effect.max_duration.milliseconds().max(min);
+            for &character in location
+                .all(pos)
+                .filter(|&&entity| characters.contains(entity))
+            {
+                effect_writer.write(CharacterEvent::new(
+                    character,
+                    ApplyEffect {
+                        info: info.clone(),
+                        duration: Duration::MILLISECOND * rand_u64(min..=max),
+                        intensity: field_effect.intensity,
+                    },
+                ));
+            }
+        }
+    }
+
+    log_if_slow("apply_field_effects", start);
+}
+
+/// Applies status effects, and reports new effects on the player character
+pub(crate) fn apply_effects(
+    mut message_writer: LogMessageWriter,
+    mut effect_reader: MessageReader<CharacterEvent<ApplyEffect>>,
+    mut characters: Query<(&mut Effects, Option<&Player>), With<Life>>,
+) {
+    let start = Instant::now();
+
+    for apply_effect in effect_reader.read() {
+        let Ok((mut effects, player)) = characters.get_mut(apply_effect.actor_entity) else {
+            // The character may have died
+            continue;
+        };
+        let info = &apply_effect.action.info;
+        if effects.apply(&apply_effect.action) && player.is_some() {
+            send_effect_message(&mut message_writer, info, info.apply_message.as_ref());
+        }
+    }
+
+    log_if_slow("apply_effects", start);
+}
+
+/// Lets status effects hurt and wear off
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn update_effects(
+    mut message_writer: LogMessageWriter,
+    mut damage_writer: MessageWriter<CharacterEvent<Damage>>,
+    clock: Clock,
+    mut last_update: Local<Option<Timestamp>>,
+    mut characters: Query<(Entity, &mut Effects, Option<&Player>), With<Life>>,
+) {
+    let start = Instant::now();
+
+    let now = clock.time();
+    let elapsed = last_update.map_or(Duration::ZERO, |last_update| now - last_update);
+    *last_update = Some(now);
+    if elapsed == Duration::ZERO {
+        return;
+    }
+
+    for (entity, mut effects, player) in &mut characters {
+        if effects.is_empty() {
+            continue;
+        }
+
+        let update = effects.update(elapsed);
+        for (info, amount) in update.hurt {
+            let name = info
+                .name(1)
+                .map_or_else(|| info.id.fallback_name(), |name| name.single.clone());
+            damage_writer.write(CharacterEvent::new(
+                entity,
+                Damage {
+                    attacker: Subject::Other(Phrase::new(&*name)),
+                    damage_type: DamageType::Pure,
+                    amount,
+                },
+            ));
+        }
+        if player.is_some() {
+            for info in update.expired {
+                send_effect_message(&mut message_writer, &info, info.remove_message.as_ref());
+            }
+        }
+    }
+
+    log_if_slow("update_effects", start);
+}
+
+fn send_effect_message(
+    message_writer: &mut LogMessageWriter,
+    info: &EffectInfo,
+    message: Option<&Arc<str>>,
+) {
+    let Some(message) = message.cloned() else {
+        return;
+    };
+    if info.rating == EffectRating::Bad {
+        message_writer.send(EffectWarning { message });
+    } else {
+        message_writer.send(EffectNotice { message });
+    }
+}
+
+/// Applies exercise, restores focus, and lets unpracticed skills rust
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn update_skills(
+    mut message_writer: LogMessageWriter,
+    mut exercise_reader: MessageReader<CharacterEvent<Exercise>>,
+    infos: Res<Infos>,
+    clock: Clock,
+    mut skills: Query<&mut Skills>,
+) {
+    let start = Instant::now();
+
+    let now = clock.time();
+    for exercise in exercise_reader.read() {
+        let Ok(mut actor_skills) = skills.get_mut(exercise.actor_entity) else {
+            // Only the player character has skills
+            continue;
+        };
+        if let Some(level) = actor_skills.exercise(&exercise.action, now) {
+            let skill = &exercise.action.skill;
+            message_writer.send(SkillImproved {
+                skill: infos.skills.get(&InfoId::new(skill.clone())).map_or_else(
+                    |_| skill.clone(),
+                    |skill_info| skill_info.name.single.clone(),
+                ),
+                level,
+            });
+        }
+    }
+
+    for mut actor_skills in &mut skills {
+        actor_skills.update(now);
+    }
+
+    log_if_slow("update_skills", start);
+}
+
+/// Applies eating and drinking, and lets characters become hungry and thirsty over time
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn update_nutrition(
+    mut message_writer: LogMessageWriter,
+    mut nourishment_reader: MessageReader<CharacterEvent<Nourishment>>,
+    clock: Clock,
+    mut last_update: Local<Option<Timestamp>>,
+    mut characters: Query<(&mut Nutrition, Option<&Player>), With<Life>>,
+) {
+    let start = Instant::now();
+
+    for nourishment in nourishment_reader.read() {
+        if let Ok((mut nutrition, _)) = characters.get_mut(nourishment.actor_entity) {
+            nutrition.nourish(&nourishment.action);
+        }
+    }
+
+    let now = clock.time();
+    let elapsed = last_update.map_or(Duration::ZERO, |last_update| now - last_update);
+    *last_update = Some(now);
+    if elapsed == Duration::ZERO {
+        return;
+    }
+
+    for (mut nutrition, player) in &mut characters {
+        let (hunger, thirst) = (nutrition.hunger(), nutrition.thirst());
+        // Only changes of hunger or thirst are relevant for change detection
+        nutrition.bypass_change_detection().update(elapsed);
+        if hunger != nutrition.hunger() || thirst != nutrition.thirst() {
+            nutrition.set_changed();
+        }
+
+        if player.is_some() {
+            if hunger < nutrition.hunger()
+                && let Some(state) = nutrition.hunger().as_str()
+            {
+                message_writer.send(NutritionWarning { state });
+            }
+            if thirst < nutrition.thirst()
+                && let Some(state) = nutrition.thirst().as_str()
+            {
+                message_writer.send(NutritionWarning { state });
+            }
+        }
+    }
+
+    log_if_slow("update_nutrition", start);
+}
+
+/// Worn clothing hinders the covered body parts
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn update_encumbrance(
+    mut commands: Commands,
+    hierarchy: ItemHierarchy,
+    characters: Query<(Entity, &BodyContainers, Option<&Encumbrance>), With<Life>>,
+) {
+    let start = Instant::now();
+
+    for (entity, body_containers, previous) in &characters {
+        let encumbrance = Encumbrance::new(
+            hierarchy
+                .items_in_pocket(body_containers.clothing)
+                .map(|worn| &**worn.common_info),
+        );
+        if previous != Some(&encumbrance) {
+            commands.entity(entity).insert(encumbrance);
+        }
+    }
+
+    log_if_slow("update_encumbrance", start);
+}
+
 pub(crate) fn update_damaged_corpses(
     mut commands: Commands,
     mut message_writer: LogMessageWriter,
@@ -260,6 +811,7 @@ pub(crate) fn update_damaged_terrain(
     mut commands: Commands,
     mut message_writer: LogMessageWriter,
     mut damage_reader: MessageReader<TerrainEvent<Damage>>,
+    mut noise_writer: MessageWriter<Noise>,
     mut spawner: TileSpawner,
     mut visualization_update: ResMut<VisualizationUpdate>,
     mut terrain: Query<(
@@ -281,6 +833,7 @@ pub(crate) fn update_damaged_terrain(
                 .expect("Terrain or furniture found");
         let evolution = integrity.lower(&damage.change);
         if integrity.0.is_zero() {
+            noise_writer.write(Noise::new(pos, BREAK_VOLUME));
             message_writer.send(Break {
                 breaker: damage.change.attacker.clone(),
                 broken: name.single(pos),
@@ -297,6 +850,7 @@ pub(crate) fn update_damaged_terrain(
             );
             *visualization_update = VisualizationUpdate::Forced;
         } else {
+            noise_writer.write(Noise::new(pos, HIT_VOLUME));
             message_writer.send(Hit {
                 attacker: damage.change.attacker.clone(),
                 object: name.single(pos),
@@ -308,6 +862,87 @@ pub(crate) fn update_damaged_terrain(
     log_if_slow("update_damaged_items", start);
 }
 
+/// Materials with a high resistance against the damage type protect the item
+pub(crate) fn update_damaged_items(
+    mut commands: Commands,
+    mut message_writer: LogMessageWriter,
+    mut damage_reader: MessageReader<ItemEvent<Damage>>,
+    items: Query<Item>,
+) {
+    let start = Instant::now();
+
+    for damage in damage_reader.read() {
+        let Ok(item) = items.get(damage.item_entity) else {
+            error!("Damaged item {:?} not found", damage.item_entity);
+            continue;
+        };
+
+        let resistance = item.common_info.material_average(|material| {
+            damage.change.damage_type.material_resist(&material.resist)
+        });
+        let points = (f32::from(damage.change.amount) - resistance)
+            .max(0.0)
+            .round() as u16;
+        let integrity = item.integrity.damaged(points);
+        message_writer.send(DamageItem {
+            attacker: damage.change.attacker.clone(),
+            item: item.fragments().collect(),
+            verb: if integrity.broken() && !item.integrity.broken() {
+                Some("break")
+            } else if 0 < points && !item.integrity.broken() {
+                Some("damage")
+            } else {
+                None
+            },
+        });
+        commands.entity(item.entity).insert(integrity);
+    }
+
+    log_if_slow("update_damaged_items", start);
+}
+
+/// Items in fire get damaged, depending on how well their materials burn
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn burn_items(
+    mut commands: Commands,
+    clock: Clock,
+    mut last_burn: Local<Option<Timestamp>>,
+    location: Res<LocationCache>,
+    fields: Query<(&Pos, &Shared<FieldInfo>)>,
+    items: Query<Item>,
+) {
+    let start = Instant::now();
+
+    let now = clock.time();
+    let elapsed = last_burn.map_or(Duration::ZERO, |last_burn| now - last_burn);
+    *last_burn = Some(now);
+    if elapsed == Duration::ZERO {
+        return;
+    }
+
+    for (&pos, _) in fields
+        .iter()
+        .filter(|(_, field_info)| field_info.has_fire == Some(true))
+    {
+        for item in location
+            .all(pos)
+            .filter_map(|&entity| items.get(entity).ok())
+        {
+            let burn_rate = item.common_info.burn_rate();
+            if 0.0 < burn_rate && !item.integrity.broken() {
+                let points = (burn_rate * (elapsed / Duration::SECOND))
+                    .round()
+                    .min(f32::from(u16::MAX)) as u16;
+                commands
+                    .entity(item.entity)
+                    .insert(item.integrity.damaged(points));
+            }
+        }
+    }
+
+    log_if_slow("burn_items", start);
+}
+
 #[expect(clippy::needless_pass_by_value)]
 pub(crate) fn combine_items(
     mut commands: Commands,
@@ -342,6 +977,8 @@ pub(crate) fn combine_items(
                     && sibling.common_info.id == moved.common_info.id
                     && sibling.pos == moved.pos
                     && sibling.filthy == moved.filthy
+                    && sibling.temperature.copied().map(ItemTemperature::frozen)
+                        == moved.temperature.copied().map(ItemTemperature::frozen)
                     && !all_merged.contains(&sibling.entity)
                 {
                     merges.push(sibling.entity);
@@ -1,7 +1,21 @@
 use gameplay_common::Evolution;
 use gameplay_log::{ProtoLogMessage, Severity};
+use std::sync::Arc;
 use text::{Fragment, Phrase, Subject};
 
+#[derive(Debug)]
+pub(super) struct Alarm {
+    pub(super) sound: &'static str,
+}
+
+impl ProtoLogMessage for Alarm {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        Phrase::from_fragment(Fragment::warn(self.sound))
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Break {
     pub(super) breaker: Subject,
@@ -16,6 +30,72 @@ impl ProtoLogMessage for Break {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct DamageItem {
+    pub(super) attacker: Subject,
+    pub(super) item: Vec<Fragment>,
+
+    /// `None` when the item resisted the damage
+    pub(super) verb: Option<&'static str>,
+}
+
+impl ProtoLogMessage for DamageItem {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        if let Some(verb) = self.verb {
+            self.attacker.verb(verb, "s").extend(self.item)
+        } else {
+            self.attacker
+                .verb("hit", "s")
+                .extend(self.item)
+                .soft("but it has")
+                .hard("no effect")
+        }
+    }
+}
+
+/// Applying or removing a harmful status effect
+#[derive(Debug)]
+pub(super) struct EffectWarning {
+    pub(super) message: Arc<str>,
+}
+
+impl ProtoLogMessage for EffectWarning {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        Phrase::new(&*self.message)
+    }
+}
+
+/// Applying or removing a status effect that is not harmful
+#[derive(Debug)]
+pub(super) struct EffectNotice {
+    pub(super) message: Arc<str>,
+}
+
+impl ProtoLogMessage for EffectNotice {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        Phrase::new(&*self.message)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Detect {
+    pub(super) trap: Fragment,
+}
+
+impl ProtoLogMessage for Detect {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        Subject::You.verb("spot", "s").push(self.trap)
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Heal {
     pub(super) subject: Subject,
@@ -107,6 +187,20 @@ impl ProtoLogMessage for NpcActionFailed {
     }
 }
 
+/// Becoming more hungry or thirsty
+#[derive(Debug)]
+pub(super) struct NutritionWarning {
+    pub(super) state: &'static str,
+}
+
+impl ProtoLogMessage for NutritionWarning {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        Phrase::new("You are").push(Fragment::warn(self.state.to_lowercase()))
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Pulp {
     pub(super) pulper: Subject,
@@ -120,3 +214,39 @@ impl ProtoLogMessage for Pulp {
         self.pulper.verb("pulp", "s").push(self.corpse)
     }
 }
+
+#[derive(Debug)]
+pub(super) struct SkillImproved {
+    pub(super) skill: Arc<str>,
+    pub(super) level: u8,
+}
+
+impl ProtoLogMessage for SkillImproved {
+    const SEVERITY: Severity = Severity::Success;
+
+    fn phrase(self) -> Phrase {
+        Phrase::new("Your")
+            .hard(&*self.skill)
+            .soft("skill improves to level")
+            .push(Fragment::good(format!("{}", self.level)))
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct TriggerTrap {
+    pub(super) victim: Subject,
+    pub(super) trap: Fragment,
+    pub(super) into_pit: bool,
+}
+
+impl ProtoLogMessage for TriggerTrap {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        if self.into_pit {
+            self.victim.verb("fall", "s").soft("into").push(self.trap)
+        } else {
+            self.victim.verb("trigger", "s").push(self.trap)
+        }
+    }
+}
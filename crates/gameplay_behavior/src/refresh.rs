@@ -14,12 +14,13 @@ use gameplay_model::Appearance;
 use gameplay_object::Mobile;
 use gameplay_perception::{CurrentlyVisible, CurrentlyVisibleBuilder};
 use gameplay_player::{Player, PlayerActionState};
-use gameplay_terrain::Accessible;
+use gameplay_terrain::{Accessible, Concealed};
 use gameplay_time::Clock;
 use gameplay_vehicle::Vehicle;
 use gameplay_visualization::{
     VisualizationUpdate, update_visualization, update_visualization_on_item_move,
 };
+use gameplay_weather::Weather;
 use std::cell::RefCell;
 use std::{cell::OnceCell, time::Instant};
 use thread_local::ThreadLocal;
@@ -107,15 +108,18 @@ fn update_visualization_on_player_move(
     elevation_visibility: Res<ElevationVisibility>,
     mut visualization_update: ResMut<VisualizationUpdate>,
     mut previous_camera_global_transform: GameplayLocal<GlobalTransform>,
-    mut items: Query<(
-        Option<&Player>,
-        &Pos,
-        &mut Visibility,
-        &mut LastSeen,
-        Option<&Accessible>,
-        Option<&Mobile>,
-        &Children,
-    )>,
+    mut items: Query<
+        (
+            Option<&Player>,
+            &Pos,
+            &mut Visibility,
+            &mut LastSeen,
+            Option<&Accessible>,
+            Option<&Mobile>,
+            &Children,
+        ),
+        Without<Concealed>,
+    >,
     child_items: Query<&Appearance, (With<ChildOf>, Without<Pos>)>,
     camera_global_transform: Single<&GlobalTransform, With<Camera>>,
 ) {
@@ -176,6 +180,7 @@ fn update_visualization_on_player_move(
 #[expect(clippy::needless_pass_by_value)]
 fn update_visualization_on_weather_change(
     clock: Clock,
+    weather: Option<Res<Weather>>,
     player_action_state: Res<State<PlayerActionState>>,
     mut visualization_update: ResMut<VisualizationUpdate>,
     mut last_viewing_disttance: GameplayLocal<Option<u8>>,
@@ -183,8 +188,12 @@ fn update_visualization_on_weather_change(
 ) {
     let start = Instant::now();
 
-    let viewing_distance =
-        CurrentlyVisible::viewing_distance(&clock, Some(&*player_action_state), player_pos.level);
+    let viewing_distance = CurrentlyVisible::viewing_distance(
+        &clock,
+        weather.as_deref(),
+        Some(&*player_action_state),
+        player_pos.level,
+    );
     if *last_viewing_disttance.get() != viewing_distance {
         *last_viewing_disttance.get() = viewing_distance;
 
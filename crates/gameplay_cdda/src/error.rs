@@ -1,4 +1,7 @@
-use cdda_json_files::{Error as CddaJsonError, InfoId, InfoIdDescription, Recipe, SpriteNumber};
+use cdda_json_files::{
+    Error as CddaJsonError, InfoId, InfoIdDescription, Recipe, SaveVersion, SpriteNumber,
+};
+use gameplay_cdda_active_sav::Error as ActiveSavError;
 use std::{error::Error as StdError, fmt, io, path::PathBuf, sync::Arc};
 
 #[derive(Debug)]
@@ -15,8 +18,20 @@ pub enum Error {
     UnknownSpriteNumber {
         _number: SpriteNumber,
     },
+    /// This file does not have the expected JSON layout
+    UnexpectedJsonStructure {
+        _file_path: PathBuf,
+    },
+    /// This file is from a newer version, that can not be written without losing data
+    NewerSaveVersion {
+        _file_path: PathBuf,
+        _version: SaveVersion,
+    },
 
     // Workspace error wrappers
+    ActiveSav {
+        _wrapped: ActiveSavError,
+    },
     CddaJsonFiles {
         _wrapped: CddaJsonError,
     },
@@ -30,6 +45,26 @@ pub enum Error {
         _file_path: PathBuf,
         _contents: Arc<str>,
     },
+    MessagePackDecode {
+        _wrapped: rmp_serde::decode::Error,
+    },
+    MessagePackEncode {
+        _wrapped: rmp_serde::encode::Error,
+    },
+}
+
+impl Error {
+    /// Like the debug output, but without the contents of files
+    pub(crate) fn summary(&self) -> String {
+        match self {
+            Self::JsonWithContext {
+                _wrapped: wrapped,
+                _file_path: file_path,
+                ..
+            } => format!("{wrapped} in {}", file_path.display()),
+            other => format!("{other:?}"),
+        }
+    }
 }
 
 // Requirement for StdError
@@ -39,6 +74,12 @@ impl fmt::Display for Error {
     }
 }
 
+impl From<ActiveSavError> for Error {
+    fn from(value: ActiveSavError) -> Self {
+        Self::ActiveSav { _wrapped: value }
+    }
+}
+
 impl From<CddaJsonError> for Error {
     fn from(value: CddaJsonError) -> Self {
         Self::CddaJsonFiles { _wrapped: value }
@@ -51,4 +92,16 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<rmp_serde::decode::Error> for Error {
+    fn from(value: rmp_serde::decode::Error) -> Self {
+        Self::MessagePackDecode { _wrapped: value }
+    }
+}
+
+impl From<rmp_serde::encode::Error> for Error {
+    fn from(value: rmp_serde::encode::Error) -> Self {
+        Self::MessagePackEncode { _wrapped: value }
+    }
+}
+
 impl StdError for Error {}
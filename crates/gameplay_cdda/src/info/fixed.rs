@@ -1,6 +1,7 @@
+use bevy::platform::collections::HashMap;
 use cdda_json_files::{
-    CddaItemName, CharacterInfo, Flags, Ignored, InfoId, ItemName, RequiredLinkedLater,
-    UntypedInfoId, VehiclePartInfo,
+    CddaItemName, CharacterInfo, Flags, Ignored, InfoId, ItemName, OptionalLinkedLater,
+    RequiredLinkedLater, UntypedInfoId, VehiclePartInfo,
 };
 use std::sync::Arc;
 use units::{Mass, Volume};
@@ -18,12 +19,14 @@ pub(super) fn human() -> CharacterInfo {
         melee_dice: 2,
         melee_dice_sides: 4,
         flags: Flags::default(),
+        harvest: OptionalLinkedLater::new_final_none(),
         absorb_ml_per_hp: None,
         absorb_move_cost_max: None,
         absorb_move_cost_per_ml: None,
         aggression: None,
         aggro_character: None,
         anger_triggers: None,
+        armor: HashMap::default(),
         armor_acid: None,
         armor_bash: None,
         armor_bullet: None,
@@ -33,7 +36,7 @@ pub(super) fn human() -> CharacterInfo {
         armor_fire: None,
         armor_stab: None,
         attack_cost: None,
-        attack_effs: None,
+        attack_effs: Vec::new(),
         baby_flags: None.into(),
         biosignature: None,
         bleed_rate: None,
@@ -44,24 +47,21 @@ pub(super) fn human() -> CharacterInfo {
         colour: None,
         death_drops: None,
         death_function: None,
-        delete: None,
         description: Arc::from(String::new()),
         diff: None,
         dissect: None,
         dodge: None,
         emit_fields: None,
-        extend: None,
         families: None,
         fear_triggers: None,
         fungalize_into: None,
         grab_strength: None,
-        harvest: None,
         luminance: None,
         material: None,
         mech_battery: None,
         mech_str_bonus: None,
         mech_weapon: None,
-        melee_damage: None,
+        melee_damage: Vec::new(),
         melee_skill: None,
         melee_training_cap: None,
         morale: None,
@@ -70,12 +70,10 @@ pub(super) fn human() -> CharacterInfo {
         petfood: None,
         phase: None,
         placate_triggers: None,
-        proportional: None,
         regen_morale: None,
         regenerates: None,
         regenerates_in_dark: None,
         regeneration_modifiers: None,
-        relative: None,
         reproduction: None,
         revert_to_itype: None,
         scents_ignored: None,
@@ -118,7 +116,6 @@ pub(super) fn wiring() -> VehiclePartInfo {
         damage_modifier: None,
         damage_reduction: None,
         damaged_power_factor: None,
-        delete: None,
         description: None,
         durability: 0,
         emissions: None,
@@ -126,7 +123,6 @@ pub(super) fn wiring() -> VehiclePartInfo {
         epower: None,
         exclusions: None,
         exhaust: None,
-        extend: None,
         floor_bedding_warmth: None,
         folded_volume: None,
         folding_time: None,
@@ -137,7 +133,6 @@ pub(super) fn wiring() -> VehiclePartInfo {
         muscle_power_factor: None,
         noise_factor: None,
         power: None,
-        proportional: None,
         pseudo_tools: None,
         qualities: None,
         requirements: None,
@@ -0,0 +1,212 @@
+use crate::info::mod_list::ModList;
+use crate::info::parsed_json::{Enriched, ParsedJson};
+use crate::{Error, TypeId};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{debug, info, warn};
+use cdda_json_files::{Diagnostic, UntypedInfoId};
+use rmp_serde::{decode::from_read, encode::write as write_msgpack};
+use std::fs::{File, create_dir_all, metadata, rename};
+use std::hash::{Hash as _, Hasher as _};
+use std::io::{BufReader, BufWriter, ErrorKind, Write as _};
+use std::path::PathBuf;
+use std::{env, time::Instant};
+use twox_hash::XxHash64;
+use util::AssetPaths;
+
+/// [`TypeId`] -> [`UntypedInfoId`] -> enriched info
+type EnrichedInfos = HashMap<TypeId, HashMap<UntypedInfoId, Enriched>>;
+
+/// The enriched json of a mod list, stored in `MessagePack` to skip reading, parsing, and enriching all json files
+///
+/// The cache holds the json from before linking, so the typed infos are still deserialized and linked on every load. Caching the typed infos themselves is out of scope: their links are shared `Arc`s that `MessagePack` can not represent.
+///
+/// Each mod list has its own cache file. The file starts with the format version, followed by a key that covers all json files of the mods. A cache file with a different version or key is rebuilt.
+/// Both hashes use `XxHash64`, because the standard library hasher may change between Rust versions.
+pub(super) struct InfoCache {
+    path: PathBuf,
+    key: u64,
+}
+
+impl InfoCache {
+    /// Increase this when [`Enriched`], or how json gets enriched, changes
+    const VERSION: u32 = 6;
+
+    pub(super) fn new(mods: &ModList) -> Self {
+        let mut mods_hasher = XxHash64::with_seed(0);
+        let mut key_hasher = XxHash64::with_seed(0);
+        env!("CARGO_PKG_VERSION").hash(&mut key_hasher);
+
+        for mod_json_path in mods.json_paths() {
+            mod_json_path.hash(&mut mods_hasher);
+            mod_json_path.hash(&mut key_hasher);
+            for json_path in ParsedJson::json_infos_paths(mod_json_path) {
+                json_path.hash(&mut key_hasher);
+                if let Ok(metadata) = metadata(&json_path) {
+                    metadata.len().hash(&mut key_hasher);
+                    metadata.modified().ok().hash(&mut key_hasher);
+                }
+            }
+        }
+
+        let file_name = format!("infos-{:016x}.msgpack", mods_hasher.finish());
+        Self {
+            path: AssetPaths::cache().join(file_name),
+            key: key_hasher.finish(),
+        }
+    }
+
+    /// Loads from the cache, or else from the json files. The latter also (re)builds the cache.
+    ///
+    /// Dumping or diagnosing the enriched json requires reading the json files.
+    pub(super) fn enriched(&self, mods: &ModList) -> EnrichedInfos {
+        if env::var("DUMP_ENRICHED").is_err() && !Diagnostic::is_collecting() {
+            let start = Instant::now();
+            match self.read() {
+                Ok(Some(enriched)) => {
+                    info!(
+                        "Loaded infos from {:?} in {duration:?}",
+                        self.path,
+                        duration = start.elapsed()
+                    );
+                    return enriched;
+                }
+                Ok(None) => debug!("No up-to-date info cache at {:?}", self.path),
+                Err(error) => warn!("Could not read info cache {:?}: {error:#?}", self.path),
+            }
+        }
+
+        let enriched = ParsedJson::enriched(mods);
+        if let Err(error) = self.write(&enriched) {
+            warn!("Could not write info cache {:?}: {error:#?}", self.path);
+        }
+        enriched
+    }
+
+    /// Returns `Ok(None)` when there is no cache, or when the cache is outdated or has another format
+    fn read(&self) -> Result<Option<EnrichedInfos>, Error> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        let mut reader = BufReader::new(file);
+
+        // Older formats may not even start with a version
+        if from_read::<_, u32>(&mut reader).ok() != Some(Self::VERSION) {
+            return Ok(None);
+        }
+        if from_read::<_, u64>(&mut reader)? != self.key {
+            return Ok(None);
+        }
+
+        let by_type = from_read::<_, Vec<(TypeId, Vec<(UntypedInfoId, Enriched)>)>>(reader)?;
+        Ok(Some(
+            by_type
+                .into_iter()
+                .map(|(type_id, enriched)| (type_id, enriched.into_iter().collect()))
+                .collect(),
+        ))
+    }
+
+    /// Writes to a temporary file first, so an interrupted write never leaves a broken cache behind
+    fn write(&self, enriched: &EnrichedInfos) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+        let temporary_path = self.path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&temporary_path)?);
+
+        write_msgpack(&mut writer, &Self::VERSION)?;
+        write_msgpack(&mut writer, &self.key)?;
+        let by_type = enriched
+            .iter()
+            .map(|(type_id, enriched)| (type_id, enriched.iter().collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        write_msgpack(&mut writer, &by_type)?;
+        writer.flush()?;
+        drop(writer);
+
+        rename(&temporary_path, &self.path)?;
+        debug!("Wrote info cache {:?}", self.path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod info_cache_tests {
+    use super::*;
+    use serde_json::{Map as JsonMap, json};
+    use std::fs::remove_file;
+
+    fn cache(key: u64) -> InfoCache {
+        InfoCache {
+            path: env::temp_dir().join(format!("rustaclysm-info-cache-test-{key}.msgpack")),
+            key,
+        }
+    }
+
+    #[test]
+    fn round_trip_works() {
+        let mut fields = JsonMap::new();
+        fields.insert(String::from("id"), json!("test_wall"));
+        fields.insert(String::from("color"), json!(["light_gray", 3, 0.5, null]));
+        let id = UntypedInfoId::new("test_wall");
+        let mut enriched = EnrichedInfos::default();
+        enriched.entry(TypeId::Terrain).or_default().insert(
+            id.clone(),
+            Enriched {
+                fields: fields.clone(),
+                alias_ids: vec![UntypedInfoId::new("test_wall_alias")],
+            },
+        );
+
+        let written = cache(8);
+        let result = written.write(&enriched);
+        assert!(result.is_ok(), "{result:?}");
+
+        let result = written.read();
+        let loaded = result
+            .as_ref()
+            .ok()
+            .and_then(Option::as_ref)
+            .and_then(|loaded| loaded.get(&TypeId::Terrain))
+            .and_then(|terrain| terrain.get(&id));
+        assert_eq!(
+            loaded.map(|loaded| &loaded.fields),
+            Some(&fields),
+            "{result:?}"
+        );
+        assert_eq!(
+            loaded.map(|loaded| loaded.alias_ids.len()),
+            Some(1),
+            "{result:?}"
+        );
+
+        let outdated = InfoCache {
+            path: written.path.clone(),
+            key: 9,
+        };
+        let result = outdated.read();
+        assert!(matches!(result, Ok(None)), "{result:?}");
+
+        remove_file(&written.path).expect("Test cache should be removable");
+    }
+
+    #[test]
+    fn other_format_is_outdated() {
+        let old = cache(10);
+        let mut writer = File::create(&old.path).expect("Test cache should be creatable");
+        write_msgpack(&mut writer, &old.key).expect("Old key should be writable");
+        write_msgpack(
+            &mut writer,
+            &Vec::<(TypeId, Vec<(UntypedInfoId, Enriched)>)>::new(),
+        )
+        .expect("Old infos should be writable");
+        drop(writer);
+
+        let result = old.read();
+        assert!(matches!(result, Ok(None)), "{result:?}");
+
+        remove_file(&old.path).expect("Test cache should be removable");
+    }
+}
@@ -4,10 +4,13 @@ use bevy::platform::collections::HashMap;
 use bevy::prelude::{debug, error, warn};
 use cdda_json_files::{
     Alternative, AmmobeltDetail, Bash, BashItem, BashItems, CharacterInfo, CommonItemInfo,
-    ConsumeDrugDetail, DeployTentDetail, DetailedUseAction, FieldInfo, FurnitureInfo, InfoId,
-    InfoIdDescription, ItemAction, ItemGroup, ItemMigration, ItemTypeDetails, ItemWithCommonInfo,
-    Link as _, LinkProvider, PlaceMonsterDetail, Quality, Recipe, RecipeResult, Requirement,
-    TerrainInfo, UnpackDetail, UntypedInfoId, UseAction, VehiclePartInfo, VehiclePartMigration,
+    Construction, ConstructionCategory, ConstructionGroup, ConstructionTarget, ConsumeDrugDetail,
+    DeployTentDetail, DetailedUseAction, Diagnostic, EffectInfo, FieldInfo, FurnitureInfo, Harvest,
+    HarvestDropType, InfoId, InfoIdDescription, ItemAction, ItemGroup, ItemGroupDetails,
+    ItemMigration, ItemOrGroup, ItemTypeDetails, ItemWithCommonInfo, Link as _, LinkProvider,
+    Material, PlaceMonsterDetail, Probability, Quality, Recipe, RecipeResult, RegionSettings,
+    RepairItemDetail, RequiredPart, Requirement, TerrainInfo, TrapInfo, Uncraft, UnpackDetail,
+    UntypedInfoId, UseAction, VehiclePartInfo, VehiclePartMigration, WeatherInfo,
 };
 use serde::de::DeserializeOwned;
 use serde_json::{Value as JsonValue, from_value as from_json_value};
@@ -45,13 +48,21 @@ impl<T: fmt::Debug + DeserializeOwned + 'static> InfoMap<T> {
                         "Failed loading json for {:?} {id:?}: {error:#?}",
                         type_name::<T>()
                     );
-                    if let Some(cause) = enriched.fields.keys().find(|key| {
+                    let likely_cause = enriched.fields.keys().find(|key| {
                         let mut copy = enriched.fields.clone();
                         copy.remove(*key);
                         from_json_value::<T>(JsonValue::Object(copy)).is_ok()
-                    }) {
+                    });
+                    if let Some(cause) = likely_cause {
                         warn!("Failure for {id:?} likely caused by the property '{cause}'");
                     }
+                    Diagnostic::InvalidInfo {
+                        info_type: type_name::<T>().into(),
+                        id: id.fallback_name(),
+                        message: error.to_string().into(),
+                        likely_cause: likely_cause.map(|cause| cause.as_str().into()),
+                    }
+                    .report();
                     debug!("Json for {id:?}: {:#?}", &enriched.fields);
                 }
             }
@@ -87,16 +98,31 @@ impl InfoMap<CharacterInfo> {
         self.map
             .insert(InfoId::new("human"), Arc::new(default_human));
     }
+
+    pub(super) fn link_characters(
+        &self,
+        effects: &InfoMap<EffectInfo>,
+        harvests: &InfoMap<Harvest>,
+    ) {
+        for character in self.map.values() {
+            character.harvest.finalize(harvests, "harvest");
+            for attack_effect in &character.attack_effs {
+                attack_effect.id.finalize(effects, "attack effect");
+            }
+        }
+    }
 }
 
 impl InfoMap<CommonItemInfo> {
     pub(super) fn link_common_items(
         &self,
         characters: &InfoMap<CharacterInfo>,
+        effects: &InfoMap<EffectInfo>,
         fields: &InfoMap<FieldInfo>,
         furniture: &InfoMap<FurnitureInfo>,
         item_actions: &InfoMap<ItemAction>,
         item_groups: &InfoMap<ItemGroup>,
+        materials: &InfoMap<Material>,
         qualities: &InfoMap<Quality>,
     ) {
         for common_item_info in self.map.values() {
@@ -109,11 +135,15 @@ impl InfoMap<CommonItemInfo> {
                             belt.finalize(self, "belt");
                         }
                         DetailedUseAction::ConsumeDrug(ConsumeDrugDetail {
+                            effects: drug_effects,
                             fields_produced,
                             tools_needed,
                             used_up_item,
                             ..
                         }) => {
+                            for drug_effect in drug_effects {
+                                drug_effect.id.finalize(effects, "consumed drug effect");
+                            }
                             for (field, _) in fields_produced {
                                 field.finalize(fields, "consumed drug field");
                             }
@@ -141,6 +171,14 @@ impl InfoMap<CommonItemInfo> {
                         }) => {
                             monster_id.finalize(characters, "placed monster");
                         }
+                        DetailedUseAction::RepairItem(RepairItemDetail {
+                            materials: repaired,
+                            ..
+                        }) => {
+                            for material in repaired {
+                                material.finalize(materials, "repaired material");
+                            }
+                        }
                         DetailedUseAction::Unpack(UnpackDetail { group, .. }) => {
                             group.finalize(item_groups, "unpack");
                         }
@@ -151,6 +189,34 @@ impl InfoMap<CommonItemInfo> {
             for quality in &common_item_info.qualities {
                 quality.id.finalize(qualities, "quality");
             }
+            for item_material in common_item_info
+                .material
+                .iter()
+                .flat_map(|item_materials| &item_materials.0)
+            {
+                item_material
+                    .material()
+                    .finalize(materials, "item material");
+            }
+            for armor_material in common_item_info
+                .armor
+                .iter()
+                .flat_map(|armor_portion| &armor_portion.material)
+            {
+                armor_material
+                    .material()
+                    .finalize(materials, "armor material");
+            }
+        }
+    }
+}
+
+impl InfoMap<FieldInfo> {
+    pub(super) fn link_fields(&self, effects: &InfoMap<EffectInfo>) {
+        for field in self.map.values() {
+            for field_effect in field.effects() {
+                field_effect.effect_id.finalize(effects, "field effect");
+            }
         }
     }
 }
@@ -179,6 +245,73 @@ impl InfoMap<FurnitureInfo> {
     }
 }
 
+impl InfoMap<Harvest> {
+    pub(super) fn link_harvests(
+        &self,
+        drop_types: &InfoMap<HarvestDropType>,
+        common_item_infos: &InfoMap<CommonItemInfo>,
+    ) {
+        for harvest in self.map.values() {
+            for entry in &harvest.entries {
+                entry.drop_type.finalize(drop_types, "harvest drop type");
+                if entry.butchered() {
+                    entry.drop.finalize(common_item_infos, "harvest drop");
+                }
+            }
+        }
+    }
+}
+
+impl InfoMap<ItemGroup> {
+    pub(super) fn link_item_groups(&self, common_item_infos: &InfoMap<CommonItemInfo>) {
+        for item_group in self.map.values() {
+            match &item_group.details {
+                Some(ItemGroupDetails::Collection { entries }) => {
+                    for entry in entries {
+                        self.link_item_or_group(&entry.result, common_item_infos);
+                    }
+                }
+                Some(ItemGroupDetails::Distribution { entries }) => {
+                    for entry in entries {
+                        match entry {
+                            Probability::Structured { result, .. } => {
+                                self.link_item_or_group(result, common_item_infos);
+                            }
+                            Probability::Array(item, _) => {
+                                item.finalize(common_item_infos, "item group item");
+                            }
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn link_item_or_group(
+        &self,
+        item_or_group: &ItemOrGroup,
+        common_item_infos: &InfoMap<CommonItemInfo>,
+    ) {
+        match item_or_group {
+            ItemOrGroup::Item { item, .. } => {
+                item.finalize(common_item_infos, "item group item");
+            }
+            ItemOrGroup::Group { group } => group.finalize(self, "nested item group"),
+        }
+    }
+}
+
+impl InfoMap<Material> {
+    pub(super) fn link_materials(&self, common_item_infos: &InfoMap<CommonItemInfo>) {
+        for material in self.map.values() {
+            material
+                .repaired_with
+                .finalize(common_item_infos, "material repaired with");
+        }
+    }
+}
+
 impl InfoMap<Quality> {
     pub(crate) fn link_qualities(&self, item_actions: &InfoMap<ItemAction>) {
         for quality in self.map.values() {
@@ -203,40 +336,147 @@ impl InfoMap<Recipe> {
                 required_quality.quality.finalize(qualities, "recipe");
             }
 
-            for alternatives in &recipe.components {
-                for alternative in alternatives {
-                    match alternative {
-                        Alternative::Item { item, .. } => {
-                            item.finalize(common_item_infos, "recipe item alternative");
-                        }
-                        Alternative::Requirement { requirement, .. } => {
-                            requirement
-                                .finalize(requirements, "recipe requirement item alternative");
-                        }
-                    }
+            link_alternatives(
+                &recipe.components,
+                requirements,
+                common_item_infos,
+                "recipe item",
+            );
+            link_alternatives(
+                &recipe.tools,
+                requirements,
+                common_item_infos,
+                "recipe tool",
+            );
+
+            for using in &recipe.using {
+                using.requirement.finalize(requirements, "recipe");
+            }
+
+            if let RecipeResult::Item { item_info } = &recipe.result {
+                item_info.finalize(common_item_infos, "recipe");
+            }
+        }
+    }
+}
+
+impl InfoMap<RegionSettings> {
+    pub(super) fn link_region_settings(&self, weather_types: &InfoMap<WeatherInfo>) {
+        for region_settings in self.map.values() {
+            if let Some(weather) = &region_settings.weather {
+                for weather_type in &weather.weather_types {
+                    weather_type.finalize(weather_types, "region weather type");
                 }
             }
+        }
+    }
+}
 
-            for alternatives in &recipe.tools {
-                for alternative in alternatives {
-                    match alternative {
-                        Alternative::Item { item, .. } => {
-                            item.finalize(common_item_infos, "recipe tool alternative");
-                        }
-                        Alternative::Requirement { requirement, .. } => {
-                            requirement
-                                .finalize(requirements, "recipe requirement tool alternative");
-                        }
+impl InfoMap<Uncraft> {
+    pub(super) fn link_uncrafts(
+        &self,
+        qualities: &InfoMap<Quality>,
+        requirements: &InfoMap<Requirement>,
+        common_item_infos: &InfoMap<CommonItemInfo>,
+    ) {
+        for uncraft in self.map.values() {
+            for required_quality in &uncraft.qualities.0 {
+                required_quality.quality.finalize(qualities, "uncraft");
+            }
+
+            link_alternatives(
+                &uncraft.components,
+                requirements,
+                common_item_infos,
+                "uncraft item",
+            );
+            link_alternatives(
+                &uncraft.tools,
+                requirements,
+                common_item_infos,
+                "uncraft tool",
+            );
+
+            for using in &uncraft.using {
+                using.requirement.finalize(requirements, "uncraft");
+            }
+
+            uncraft.result.finalize(common_item_infos, "uncraft");
+        }
+    }
+}
+
+impl InfoMap<Construction> {
+    pub(super) fn link_constructions(
+        &self,
+        construction_groups: &InfoMap<ConstructionGroup>,
+        construction_categories: &InfoMap<ConstructionCategory>,
+        qualities: &InfoMap<Quality>,
+        requirements: &InfoMap<Requirement>,
+        common_item_infos: &InfoMap<CommonItemInfo>,
+        terrain: &InfoMap<TerrainInfo>,
+        furniture: &InfoMap<FurnitureInfo>,
+    ) {
+        for construction in self.map.values() {
+            construction
+                .group
+                .finalize(construction_groups, "construction group");
+            construction
+                .category
+                .finalize(construction_categories, "construction category");
+
+            for required_quality in &construction.qualities.0 {
+                required_quality.quality.finalize(qualities, "construction");
+            }
+
+            link_alternatives(
+                &construction.components,
+                requirements,
+                common_item_infos,
+                "construction item",
+            );
+            link_alternatives(
+                &construction.tools,
+                requirements,
+                common_item_infos,
+                "construction tool",
+            );
+
+            for using in &construction.using {
+                using.requirement.finalize(requirements, "construction");
+            }
+
+            for target in [&construction.pre_terrain, &construction.post_terrain]
+                .into_iter()
+                .flatten()
+            {
+                match target {
+                    ConstructionTarget::Terrain(info) => {
+                        info.finalize(terrain, "construction terrain");
                     }
+                    ConstructionTarget::Furniture(info) => {
+                        info.finalize(furniture, "construction furniture");
+                    }
+                    ConstructionTarget::NoFurniture => {}
                 }
             }
+        }
+    }
+}
 
-            for using in &recipe.using {
-                using.requirement.finalize(requirements, "recipe");
+fn link_alternatives<R: RequiredPart>(
+    alternatives: &[Vec<Alternative<R>>],
+    requirements: &InfoMap<Requirement>,
+    common_item_infos: &InfoMap<CommonItemInfo>,
+    name: &str,
+) {
+    for alternative in alternatives.iter().flatten() {
+        match alternative {
+            Alternative::Item { item, .. } => {
+                item.finalize(common_item_infos, format!("{name} alternative"));
             }
-
-            if let RecipeResult::Item { item_info } = &recipe.result {
-                item_info.finalize(common_item_infos, "recipe");
+            Alternative::Requirement { requirement, .. } => {
+                requirement.finalize(requirements, format!("{name} requirement alternative"));
             }
         }
     }
@@ -290,6 +530,7 @@ impl InfoMap<TerrainInfo> {
         furniture: &InfoMap<FurnitureInfo>,
         common_item_infos: &InfoMap<CommonItemInfo>,
         item_groups: &InfoMap<ItemGroup>,
+        traps: &InfoMap<TrapInfo>,
     ) {
         if self.map.remove(&InfoId::new("t_null")).is_some() {
             warn!("The terrain t_null was not expected to be present");
@@ -298,6 +539,7 @@ impl InfoMap<TerrainInfo> {
         for terrain in self.map.values() {
             terrain.open.finalize(self, "open terrain");
             terrain.close.finalize(self, "closed terrain");
+            terrain.trap.finalize(traps, "terrain trap");
             if let Some(bash) = &terrain.bash {
                 link_bash(
                     bash,
@@ -312,6 +554,16 @@ impl InfoMap<TerrainInfo> {
     }
 }
 
+impl InfoMap<TrapInfo> {
+    pub(super) fn link_traps(&self, common_item_infos: &InfoMap<CommonItemInfo>) {
+        for trap in self.map.values() {
+            for drop in &trap.drops {
+                drop.finalize(common_item_infos, "trap drop");
+            }
+        }
+    }
+}
+
 pub(super) fn link_bash(
     bash: &Bash,
     terrain_info: &InfoMap<TerrainInfo>,
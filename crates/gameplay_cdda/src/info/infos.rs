@@ -1,17 +1,25 @@
+use crate::info::info_cache::InfoCache;
 use crate::info::info_map::{InfoMap, ItemInfoMapLoader};
+use crate::info::map_generator::{MapGenerator, MapgenIndex, monsters_from_group};
 use crate::info::migration_provider::{ItemMigrationProvider, VehiclePartMigrationProvider};
-use crate::info::parsed_json::ParsedJson;
+use crate::info::mod_list::ModList;
 use crate::{ObjectCategory, TypeId};
+use bevy::platform::collections::HashMap;
 use bevy::prelude::{Resource, debug, error, info, warn};
 use cdda_json_files::{
-    Ammo, BionicItem, Book, CddaItem, CharacterInfo, Clothing, Comestible, CommonItemInfo, Engine,
-    FieldInfo, FurnitureInfo, GenericItem, Gun, Gunmod, ItemAction, ItemGroup, ItemMigration,
-    Link as _, Magazine, Overmap, OvermapTerrainInfo, PetArmor, Practice, Quality, Recipe,
-    RequiredLinkedLater, Requirement, Submap, TerrainInfo, Tool, ToolClothing, Toolmod,
-    UntypedInfoId, VehiclePartInfo, VehiclePartMigration, Wheel,
+    Ammo, BionicItem, Book, CddaItem, CharacterInfo, Clothing, Comestible, CommonItemInfo,
+    Construction, ConstructionCategory, ConstructionGroup, DisassemblyRecipe, EffectInfo, Engine,
+    FieldInfo, FurnitureInfo, GenericItem, Gun, Gunmod, Harvest, HarvestDropType, InfoId,
+    ItemAction, ItemGroup, ItemMigration, Link as _, Magazine, Map, MapgenPalette, Material,
+    MonsterGroup, NestedCategory, Overmap, OvermapTerrainInfo, PetArmor, Practice, Profession,
+    Quality, Recipe, RegionSettings, RequiredLinkedLater, Requirement, Scenario, SkillInfo,
+    StartLocation, Submap, TerrainInfo, Tool, ToolClothing, Toolmod, TrapInfo, Uncraft,
+    UntypedInfoId, VehiclePartInfo, VehiclePartMigration, WeatherInfo, Wheel,
 };
-use std::{env, process::exit, time::Instant};
+use gameplay_location::ZoneLevel;
+use std::{env, process::exit, sync::Arc, time::Instant};
 use strum::VariantArray as _;
+use units::Timestamp;
 use util::AsyncNew;
 
 #[derive(Resource)]
@@ -22,7 +30,6 @@ pub struct Infos {
     #[expect(unused)]
     bionic_items: InfoMap<BionicItem>,
 
-    #[expect(unused)]
     books: InfoMap<Book>,
 
     pub characters: InfoMap<CharacterInfo>,
@@ -35,6 +42,11 @@ pub struct Infos {
 
     common_item_infos: InfoMap<CommonItemInfo>,
 
+    pub constructions: InfoMap<Construction>,
+    construction_categories: InfoMap<ConstructionCategory>,
+    construction_groups: InfoMap<ConstructionGroup>,
+
+    pub effects: InfoMap<EffectInfo>,
     #[expect(unused)]
     engines: InfoMap<Engine>,
 
@@ -50,6 +62,9 @@ pub struct Infos {
     #[expect(unused)]
     gunmods: InfoMap<Gunmod>,
 
+    harvests: InfoMap<Harvest>,
+    harvest_drop_types: InfoMap<HarvestDropType>,
+
     pub(crate) item_actions: InfoMap<ItemAction>,
 
     item_groups: InfoMap<ItemGroup>,
@@ -58,18 +73,38 @@ pub struct Infos {
     #[expect(unused)]
     magazines: InfoMap<Magazine>,
 
+    mapgen_index: MapgenIndex,
+    materials: InfoMap<Material>,
+
+    /// The mods that this info is loaded from
+    pub mods: ModList,
+
+    monster_groups: InfoMap<MonsterGroup>,
+
     #[expect(unused)]
-    pet_armors: InfoMap<PetArmor>,
+    nested_categories: InfoMap<NestedCategory>,
+
+    palettes: InfoMap<MapgenPalette>,
 
     #[expect(unused)]
-    practices: InfoMap<Practice>,
+    pet_armors: InfoMap<PetArmor>,
+
+    pub practices: InfoMap<Practice>,
+
+    pub professions: InfoMap<Profession>,
 
     qualities: InfoMap<Quality>,
 
     pub recipes: InfoMap<Recipe>,
 
+    pub region_settings: InfoMap<RegionSettings>,
+
     requirements: InfoMap<Requirement>,
 
+    pub scenarios: InfoMap<Scenario>,
+    pub skills: InfoMap<SkillInfo>,
+    pub start_locations: InfoMap<StartLocation>,
+
     terrain: InfoMap<TerrainInfo>,
 
     #[expect(unused)]
@@ -81,44 +116,38 @@ pub struct Infos {
     #[expect(unused)]
     toolmods: InfoMap<Toolmod>,
 
+    traps: InfoMap<TrapInfo>,
+
+    uncrafts: InfoMap<Uncraft>,
+
     vehicle_parts: InfoMap<VehiclePartInfo>,
     vehicle_part_migrations: InfoMap<VehiclePartMigration>,
 
     #[expect(unused)]
     wheels: InfoMap<Wheel>,
 
+    pub weather_types: InfoMap<WeatherInfo>,
+
     pub zone_levels: InfoMap<OvermapTerrainInfo>,
 }
 
 impl Infos {
     #[must_use]
-    fn load() -> Self {
+    pub fn load(mods: ModList) -> Self {
         let start = Instant::now();
 
-        let mut enriched_json_infos = ParsedJson::enriched();
+        let mut json_infos = InfoCache::new(&mods).enriched(&mods);
         debug!(
             "Collected {} enriched info types in {duration:?}",
-            enriched_json_infos.len(),
+            json_infos.len(),
             duration = start.elapsed()
         );
-        let mut missing_types = TypeId::VARIANTS
-            .iter()
-            .filter(|type_id| !enriched_json_infos.contains_key(*type_id))
-            .map(|type_id| format!("{type_id:?}"))
-            .collect::<Vec<_>>();
-        if !missing_types.is_empty() {
-            missing_types.sort();
-            error!(
-                "{} unused info types: {}",
-                missing_types.len(),
-                missing_types.join(", ")
-            );
-        }
+        log_missing_types(&json_infos);
 
-        let item_migrations = InfoMap::new(&mut enriched_json_infos, TypeId::ItemMigration);
+        let item_migrations = InfoMap::new(&mut json_infos, TypeId::ItemMigration);
         let mut common_item_infos = InfoMap::default();
         let mut item_loader = ItemInfoMapLoader {
-            enriched_json_infos: &mut enriched_json_infos,
+            enriched_json_infos: &mut json_infos,
             item_migrations: &item_migrations,
             common_item_infos: &mut common_item_infos,
         };
@@ -140,85 +169,118 @@ impl Infos {
         // item_loader is dropped
         //debug!("Collected {} common items", common_item_infos.len());
 
-        let vehicle_part_migrations = InfoMap::<VehiclePartMigration>::new(
-            &mut enriched_json_infos,
-            TypeId::VehiclePartMigration,
-        );
-
         let mut this = Self {
             ammos,
             bionic_items,
             books,
-            characters: InfoMap::new(&mut enriched_json_infos, TypeId::Character),
+            characters: InfoMap::new(&mut json_infos, TypeId::Character),
             clothings,
             comestibles,
             common_item_infos,
+            constructions: InfoMap::new(&mut json_infos, TypeId::Construction),
+            construction_categories: InfoMap::new(&mut json_infos, TypeId::ConstructionCategory),
+            construction_groups: InfoMap::new(&mut json_infos, TypeId::ConstructionGroup),
+            effects: InfoMap::new(&mut json_infos, TypeId::EffectType),
             engines,
-            fields: InfoMap::new(&mut enriched_json_infos, TypeId::Field),
-            furniture: InfoMap::new(&mut enriched_json_infos, TypeId::Furniture),
+            fields: InfoMap::new(&mut json_infos, TypeId::Field),
+            furniture: InfoMap::new(&mut json_infos, TypeId::Furniture),
             genenric_items,
             guns,
             gunmods,
-            item_actions: InfoMap::new(&mut enriched_json_infos, TypeId::ItemAction),
-            item_groups: InfoMap::new(&mut enriched_json_infos, TypeId::ItemGroup),
+            harvests: InfoMap::new(&mut json_infos, TypeId::Harvest),
+            harvest_drop_types: InfoMap::new(&mut json_infos, TypeId::HarvestDropType),
+            item_actions: InfoMap::new(&mut json_infos, TypeId::ItemAction),
+            item_groups: InfoMap::new(&mut json_infos, TypeId::ItemGroup),
             item_migrations,
             magazines,
+            mapgen_index: MapgenIndex::new(&InfoMap::new(&mut json_infos, TypeId::Mapgen)),
+            materials: InfoMap::new(&mut json_infos, TypeId::Material),
+            mods,
+            monster_groups: InfoMap::new(&mut json_infos, TypeId::MonsterGroup),
+            nested_categories: InfoMap::new(&mut json_infos, TypeId::NestedCategory),
+            palettes: InfoMap::new(&mut json_infos, TypeId::Palette),
             pet_armors,
-            practices: InfoMap::new(&mut enriched_json_infos, TypeId::Practice),
-            qualities: InfoMap::new(&mut enriched_json_infos, TypeId::ToolQuality),
-            recipes: InfoMap::new(&mut enriched_json_infos, TypeId::Recipe),
-            requirements: InfoMap::new(&mut enriched_json_infos, TypeId::Requirement),
-            terrain: InfoMap::new(&mut enriched_json_infos, TypeId::Terrain),
+            practices: InfoMap::new(&mut json_infos, TypeId::Practice),
+            professions: InfoMap::new(&mut json_infos, TypeId::Profession),
+            qualities: InfoMap::new(&mut json_infos, TypeId::ToolQuality),
+            recipes: InfoMap::new(&mut json_infos, TypeId::Recipe),
+            region_settings: InfoMap::new(&mut json_infos, TypeId::RegionSettings),
+            requirements: InfoMap::new(&mut json_infos, TypeId::Requirement),
+            scenarios: InfoMap::new(&mut json_infos, TypeId::Scenario),
+            skills: InfoMap::new(&mut json_infos, TypeId::Skill),
+            start_locations: InfoMap::new(&mut json_infos, TypeId::StartLocation),
+            terrain: InfoMap::new(&mut json_infos, TypeId::Terrain),
             tools,
             tool_clothings,
             toolmods,
-            vehicle_parts: InfoMap::new(&mut enriched_json_infos, TypeId::VehiclePart),
-            vehicle_part_migrations,
+            traps: InfoMap::new(&mut json_infos, TypeId::Trap),
+            uncrafts: InfoMap::new(&mut json_infos, TypeId::Uncraft),
+            vehicle_parts: InfoMap::new(&mut json_infos, TypeId::VehiclePart),
+            vehicle_part_migrations: InfoMap::new(&mut json_infos, TypeId::VehiclePartMigration),
+            weather_types: InfoMap::new(&mut json_infos, TypeId::WeatherType),
             wheels,
-            zone_levels: InfoMap::new(&mut enriched_json_infos, TypeId::OvermapTerrain),
+            zone_levels: InfoMap::new(&mut json_infos, TypeId::OvermapTerrain),
         }
         .link_all();
         this.characters.add_default_human();
 
-        assert!(
-            enriched_json_infos.is_empty(),
-            "All json info should be processed"
-        );
+        assert!(json_infos.is_empty(), "All json info should be processed");
 
         info!(
             "The creation of Infos took {duration:?}",
             duration = start.elapsed()
         );
 
-        if env::var("EXIT_AFTER_INFOS") == Ok(String::from("1")) {
-            warn!("Exiting, because EXIT_AFTER_INFOS is set to '1'");
-            exit(0);
-        }
+        exit_if_requested();
 
         this
     }
 
     #[must_use]
     fn link_all(mut self) -> Self {
+        self.characters
+            .link_characters(&self.effects, &self.harvests);
         self.common_item_infos.link_common_items(
             &self.characters,
+            &self.effects,
             &self.fields,
             &self.furniture,
             &self.item_actions,
             &self.item_groups,
+            &self.materials,
             &self.qualities,
         );
+        self.fields.link_fields(&self.effects);
         self.furniture
             .link_furniture(&self.common_item_infos, &self.item_groups);
+        self.harvests
+            .link_harvests(&self.harvest_drop_types, &self.common_item_infos);
+        self.item_groups.link_item_groups(&self.common_item_infos);
+        self.materials.link_materials(&self.common_item_infos);
         self.qualities.link_qualities(&self.item_actions);
         self.requirements
             .link_requirements(&self.qualities, &self.common_item_infos);
+        self.region_settings
+            .link_region_settings(&self.weather_types);
         self.recipes
             .link_recipes(&self.qualities, &self.requirements, &self.common_item_infos);
+        self.uncrafts
+            .link_uncrafts(&self.qualities, &self.requirements, &self.common_item_infos);
+        self.traps.link_traps(&self.common_item_infos);
         self.terrain.fix_and_link_terrain(
             &self.furniture,
             &self.common_item_infos,
             &self.item_groups,
+            &self.traps,
+        );
+        self.constructions.link_constructions(
+            &self.construction_groups,
+            &self.construction_categories,
+            &self.qualities,
+            &self.requirements,
+            &self.common_item_infos,
+            &self.terrain,
+            &self.furniture,
         );
         self.vehicle_parts.add_wiring();
         self.vehicle_parts.link_items(&self.common_item_infos);
@@ -260,6 +322,11 @@ impl Infos {
                 .get(&info_id.into())
                 .ok()
                 .and_then(|o| o.looks_like.clone()),
+            ObjectCategory::Trap => self
+                .traps
+                .get(&info_id.into())
+                .ok()
+                .and_then(|o| o.looks_like.clone()),
             ObjectCategory::VehiclePart => self
                 .vehicle_parts
                 .get(&info_id.into())
@@ -306,6 +373,28 @@ impl Infos {
         variants
     }
 
+    /// How the item can be taken apart, preferring an uncraft over a reversible recipe
+    #[must_use]
+    pub fn disassembly(&self, item_id: &InfoId<CommonItemInfo>) -> Option<DisassemblyRecipe> {
+        self.uncrafts
+            .get(&item_id.clone().into())
+            .ok()
+            .map(|uncraft| DisassemblyRecipe::Uncraft(uncraft.clone()))
+            .or_else(|| {
+                self.recipes
+                    .get(&item_id.clone().into())
+                    .ok()
+                    .filter(|recipe| recipe.reversed_time().is_some())
+                    .map(|recipe| DisassemblyRecipe::Reversed(recipe.clone()))
+            })
+    }
+
+    /// The book details of an item, if it can be read
+    #[must_use]
+    pub fn book(&self, item_id: &InfoId<CommonItemInfo>) -> Option<&Arc<Book>> {
+        self.books.get(&item_id.clone().into()).ok()
+    }
+
     pub fn link_overmap(&self, overmap: &Overmap) {
         if overmap.linked.set(()).is_err() {
             return;
@@ -316,6 +405,60 @@ impl Infos {
         }
     }
 
+    /// Picks monsters from the group, respecting its seasonal and time-of-day conditions
+    #[must_use]
+    pub fn monsters_from_group(
+        &self,
+        group_id: &InfoId<MonsterGroup>,
+        time: Timestamp,
+    ) -> Vec<InfoId<CharacterInfo>> {
+        monsters_from_group(&self.monster_groups, group_id, Some(time), 0)
+    }
+
+    /// Generates a map from a json mapgen for the overmap terrain, without linking it.
+    /// Tiles that the mapgen leaves empty get the fallback terrain.
+    ///
+    /// The monster density is used for monster groups without a density of their own, see [`Self::monster_density`].
+    #[must_use]
+    pub fn generate_map(
+        &self,
+        zone_level: ZoneLevel,
+        zone_object_id: &InfoId<OvermapTerrainInfo>,
+        fallback_terrain: &InfoId<TerrainInfo>,
+        monster_density: f32,
+    ) -> Option<Map> {
+        MapGenerator {
+            index: &self.mapgen_index,
+            palettes: &self.palettes,
+            item_groups: &self.item_groups,
+            monster_groups: &self.monster_groups,
+            monster_density,
+        }
+        .generate(zone_level, zone_object_id, fallback_terrain)
+    }
+
+    /// Like CDDA, the sum of the 'mondensity' of the overmap terrains around a zone, divided by 100
+    #[must_use]
+    pub fn monster_density<'a>(
+        &self,
+        nearby_zone_object_ids: impl IntoIterator<Item = &'a InfoId<OvermapTerrainInfo>>,
+    ) -> f32 {
+        let total = nearby_zone_object_ids
+            .into_iter()
+            .filter_map(|zone_object_id| {
+                let (base_id, _) = zone_object_id.without_direction();
+                let zone_level_info = self
+                    .zone_levels
+                    .get(zone_object_id)
+                    .or_else(|_| self.zone_levels.get(&base_id))
+                    .ok()?;
+                zone_level_info.mondensity
+            })
+            .map(u32::from)
+            .sum::<u32>();
+        total as f32 / 100.0
+    }
+
     pub fn link_submap(&self, submap: &Submap) {
         if submap.linked.set(()).is_err() {
             return;
@@ -333,6 +476,19 @@ impl Infos {
                 .finalize(&self.furniture, "submap furniture");
         }
 
+        for trap_at in &submap.traps {
+            trap_at.obj.finalize(&self.traps, "submap trap");
+        }
+
+        for partial_construction in &submap.partial_constructions.0 {
+            partial_construction
+                .construction
+                .finalize(&self.constructions, "submap partial construction");
+            for item in &partial_construction.components {
+                self.link_item(item);
+            }
+        }
+
         for fields_at in &submap.fields.0 {
             for field in &fields_at.obj.0 {
                 field.field_info.finalize(&self.fields, "submap field");
@@ -362,7 +518,7 @@ impl Infos {
         }
     }
 
-    fn link_item(&self, item: &CddaItem) {
+    pub fn link_item(&self, item: &CddaItem) {
         item.item_info.finalize(
             &ItemMigrationProvider {
                 info_map: &self.common_item_infos,
@@ -391,8 +547,31 @@ impl Infos {
     }
 }
 
+fn log_missing_types<T>(json_infos: &HashMap<TypeId, T>) {
+    let mut missing_types = TypeId::VARIANTS
+        .iter()
+        .filter(|type_id| !json_infos.contains_key(*type_id))
+        .map(|type_id| format!("{type_id:?}"))
+        .collect::<Vec<_>>();
+    if !missing_types.is_empty() {
+        missing_types.sort();
+        error!(
+            "{} unused info types: {}",
+            missing_types.len(),
+            missing_types.join(", ")
+        );
+    }
+}
+
+fn exit_if_requested() {
+    if env::var("EXIT_AFTER_INFOS") == Ok(String::from("1")) {
+        warn!("Exiting, because EXIT_AFTER_INFOS is set to '1'");
+        exit(0);
+    }
+}
+
 impl AsyncNew<Self> for Infos {
     async fn async_new() -> Self {
-        Self::load()
+        Self::load(ModList::core())
     }
 }
@@ -0,0 +1,456 @@
+use bevy::prelude::warn;
+use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
+use std::slice;
+
+/// The CDDA inheritance modifiers of a child, in the order they are applied
+pub(super) const MODIFIERS: [&str; 4] = ["relative", "proportional", "extend", "delete"];
+
+/// Amounts of units are rounded to this many decimals, to hide floating point errors
+const QUANTITY_DECIMALS: f64 = 1_000.0;
+
+/// Unit -> (size in the base unit, base unit)
+const UNITS: [(&str, f64, &str); 16] = [
+    ("mg", 1.0, "mg"),
+    ("g", 1_000.0, "mg"),
+    ("kg", 1_000_000.0, "mg"),
+    ("ml", 1.0, "ml"),
+    ("L", 1_000.0, "ml"),
+    ("l", 1_000.0, "ml"),
+    ("mm", 1.0, "mm"),
+    ("cm", 10.0, "mm"),
+    ("km", 1_000_000.0, "mm"),
+    ("mJ", 1.0, "mJ"),
+    ("J", 1_000.0, "mJ"),
+    ("kJ", 1_000_000.0, "mJ"),
+    ("s", 1.0, "s"),
+    ("m", 60.0, "s"),
+    ("h", 3_600.0, "s"),
+    ("d", 86_400.0, "s"),
+];
+
+#[derive(Clone, Copy, Debug)]
+enum Modifier {
+    Relative,
+    Proportional,
+}
+
+/// Combine the fields of a 'copy-from' parent with the fields of its child, like CDDA does:
+/// - Fields of the child replace the fields of the parent.
+/// - 'relative' adds numbers and amounts with units to the inherited values.
+/// - 'proportional' multiplies inherited numbers and amounts with units. Integers stay integers.
+/// - 'extend' adds entries to inherited lists, like flags.
+/// - 'delete' removes entries from inherited lists.
+///
+/// Damage instances are matched by their 'damage type'. Other nested objects are modified per field.
+///
+/// See <https://github.com/CleverRaven/Cataclysm-DDA/blob/master/doc/JSON/JSON_INHERITANCE.md>
+pub(super) fn inherit(
+    parent: &JsonMap<String, JsonValue>,
+    child: &JsonMap<String, JsonValue>,
+) -> JsonMap<String, JsonValue> {
+    let mut inherited = parent.clone();
+    for (key, value) in child {
+        if key != "copy-from" && !MODIFIERS.contains(&key.as_str()) {
+            inherited.insert(key.clone(), value.clone());
+        }
+    }
+
+    for modifier in MODIFIERS {
+        let Some(changes) = child.get(modifier) else {
+            continue;
+        };
+        let Some(changes) = changes.as_object() else {
+            warn!("Expected an object for {modifier:?}, found {changes:?}");
+            continue;
+        };
+
+        match modifier {
+            "relative" => apply_changes(&mut inherited, changes, Modifier::Relative),
+            "proportional" => apply_changes(&mut inherited, changes, Modifier::Proportional),
+            "extend" => {
+                for (key, addition) in changes {
+                    let extended = extend(inherited.remove(key), addition);
+                    inherited.insert(key.clone(), extended);
+                }
+            }
+            _ => {
+                for (key, removal) in changes {
+                    if let Some(remaining) = inherited
+                        .remove(key)
+                        .and_then(|value| delete(value, removal))
+                    {
+                        inherited.insert(key.clone(), remaining);
+                    }
+                }
+            }
+        }
+    }
+
+    inherited
+}
+
+fn apply_changes(
+    target: &mut JsonMap<String, JsonValue>,
+    changes: &JsonMap<String, JsonValue>,
+    modifier: Modifier,
+) {
+    for (key, change) in changes {
+        if let Some(modified) = modify(key, target.get(key), change, modifier) {
+            target.insert(key.clone(), modified);
+        }
+    }
+}
+
+/// Returns `None` when the inherited value should be kept
+fn modify(
+    key: &str,
+    inherited: Option<&JsonValue>,
+    change: &JsonValue,
+    modifier: Modifier,
+) -> Option<JsonValue> {
+    match (inherited, change, modifier) {
+        // Relative to the default value of zero
+        (None, _, Modifier::Relative) => Some(change.clone()),
+        (Some(JsonValue::Number(inherited)), JsonValue::Number(change), Modifier::Relative) => {
+            Some(add_numbers(inherited, change))
+        }
+        (Some(JsonValue::Number(inherited)), JsonValue::Number(factor), Modifier::Proportional) => {
+            factor
+                .as_f64()
+                .map(|factor| scale_number(inherited, factor))
+        }
+        (Some(JsonValue::String(inherited)), JsonValue::String(change), Modifier::Relative) => {
+            add_quantities(inherited, change)
+                .map(JsonValue::String)
+                .or_else(|| {
+                    warn!("Could not add {change:?} to {key:?} of {inherited:?}");
+                    None
+                })
+        }
+        (Some(JsonValue::String(inherited)), JsonValue::Number(factor), Modifier::Proportional) => {
+            factor
+                .as_f64()
+                .and_then(|factor| scale_quantity(inherited, factor))
+                .map(JsonValue::String)
+                .or_else(|| {
+                    warn!("Could not scale {key:?} of {inherited:?} by {factor:?}");
+                    None
+                })
+        }
+        (Some(inherited), _, _) if is_damage(inherited) && is_damage(change) => {
+            Some(modify_damage(inherited, change, modifier))
+        }
+        (Some(JsonValue::Object(inherited)), JsonValue::Object(changes), _) => {
+            let mut modified = inherited.clone();
+            apply_changes(&mut modified, changes, modifier);
+            Some(JsonValue::Object(modified))
+        }
+        _ => {
+            warn!("Could not apply {modifier:?} {change:?} to {key:?} of {inherited:?}");
+            None
+        }
+    }
+}
+
+fn add_numbers(inherited: &JsonNumber, change: &JsonNumber) -> JsonValue {
+    if let (Some(inherited), Some(change)) = (inherited.as_i64(), change.as_i64()) {
+        JsonValue::from(inherited + change)
+    } else {
+        let sum = inherited.as_f64().unwrap_or_default() + change.as_f64().unwrap_or_default();
+        if inherited.is_f64() {
+            JsonValue::from(sum)
+        } else {
+            JsonValue::from(sum.round() as i64)
+        }
+    }
+}
+
+fn scale_number(inherited: &JsonNumber, factor: f64) -> JsonValue {
+    let scaled = inherited.as_f64().unwrap_or_default() * factor;
+    if inherited.is_f64() {
+        JsonValue::from(scaled)
+    } else {
+        JsonValue::from(scaled.round() as i64)
+    }
+}
+
+/// Damage instances have a damage type, like `{ "damage_type": "bash", "amount": 5 }`.
+fn is_damage(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Object(fields) => fields.contains_key("damage_type"),
+        JsonValue::Array(units) => {
+            !units.is_empty()
+                && units.iter().all(|unit| {
+                    unit.as_object()
+                        .is_some_and(|unit| unit.contains_key("damage_type"))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Modifies the damage instances with a matching damage type
+fn modify_damage(inherited: &JsonValue, change: &JsonValue, modifier: Modifier) -> JsonValue {
+    let mut units = damage_units(inherited).cloned().collect::<Vec<_>>();
+    for change_unit in damage_units(change) {
+        let damage_type = change_unit.get("damage_type");
+        if let Some(unit) = units
+            .iter_mut()
+            .find(|unit| unit.get("damage_type") == damage_type)
+        {
+            let mut changes = change_unit.clone();
+            changes.remove("damage_type");
+            apply_changes(unit, &changes, modifier);
+        } else if matches!(modifier, Modifier::Relative) {
+            units.push(change_unit.clone());
+        } else {
+            warn!("Could not scale missing damage type {damage_type:?} of {inherited:?}");
+        }
+    }
+
+    if inherited.is_object() && units.len() == 1 {
+        JsonValue::Object(units.swap_remove(0))
+    } else {
+        JsonValue::Array(units.into_iter().map(JsonValue::Object).collect())
+    }
+}
+
+fn damage_units(value: &JsonValue) -> impl Iterator<Item = &JsonMap<String, JsonValue>> {
+    as_list(value).iter().filter_map(JsonValue::as_object)
+}
+
+/// Amounts with a unit look like "250 ml".
+fn parse_quantity(text: &str) -> Option<(f64, &str)> {
+    let (amount, unit) = text.trim().split_once(' ')?;
+    Some((amount.parse().ok()?, unit.trim()))
+}
+
+fn unit_size(unit: &str) -> Option<(f64, &'static str)> {
+    UNITS
+        .iter()
+        .find(|(name, _, _)| *name == unit)
+        .map(|&(_, size, base_unit)| (size, base_unit))
+}
+
+/// The result uses the unit of the inherited amount
+fn add_quantities(inherited: &str, change: &str) -> Option<String> {
+    let (inherited_amount, inherited_unit) = parse_quantity(inherited)?;
+    let (change_amount, change_unit) = parse_quantity(change)?;
+    if inherited_unit == change_unit {
+        return Some(format_quantity(
+            inherited_amount + change_amount,
+            inherited_unit,
+        ));
+    }
+
+    let (inherited_size, inherited_base) = unit_size(inherited_unit)?;
+    let (change_size, change_base) = unit_size(change_unit)?;
+    (inherited_base == change_base).then(|| {
+        format_quantity(
+            inherited_amount + change_amount * change_size / inherited_size,
+            inherited_unit,
+        )
+    })
+}
+
+fn scale_quantity(inherited: &str, factor: f64) -> Option<String> {
+    let (amount, unit) = parse_quantity(inherited)?;
+    Some(format_quantity(amount * factor, unit))
+}
+
+fn format_quantity(amount: f64, unit: &str) -> String {
+    let amount = (amount * QUANTITY_DECIMALS).round() / QUANTITY_DECIMALS;
+    format!("{amount} {unit}")
+}
+
+fn extend(inherited: Option<JsonValue>, addition: &JsonValue) -> JsonValue {
+    match (inherited, addition) {
+        (Some(JsonValue::Object(mut inherited)), JsonValue::Object(additions)) => {
+            for (key, addition) in additions {
+                let extended = extend(inherited.remove(key), addition);
+                inherited.insert(key.clone(), extended);
+            }
+            JsonValue::Object(inherited)
+        }
+        (inherited, addition) => {
+            let mut list = match inherited {
+                Some(JsonValue::Array(list)) => list,
+                Some(single) => vec![single],
+                None => Vec::new(),
+            };
+            for item in as_list(addition) {
+                if !list.contains(item) {
+                    list.push(item.clone());
+                }
+            }
+            JsonValue::Array(list)
+        }
+    }
+}
+
+/// Returns `None` when nothing remains
+fn delete(inherited: JsonValue, removal: &JsonValue) -> Option<JsonValue> {
+    match (inherited, removal) {
+        (JsonValue::Object(mut inherited), JsonValue::Object(removals)) => {
+            for (key, removal) in removals {
+                if let Some(remaining) = inherited
+                    .remove(key)
+                    .and_then(|value| delete(value, removal))
+                {
+                    inherited.insert(key.clone(), remaining);
+                }
+            }
+            Some(JsonValue::Object(inherited))
+        }
+        (JsonValue::Object(mut inherited), removal) => {
+            for key in as_list(removal).iter().filter_map(JsonValue::as_str) {
+                inherited.remove(key);
+            }
+            Some(JsonValue::Object(inherited))
+        }
+        (JsonValue::Array(mut list), removal) => {
+            list.retain(|item| !is_removed(item, removal));
+            Some(JsonValue::Array(list))
+        }
+        (single, removal) => (!is_removed(&single, removal)).then_some(single),
+    }
+}
+
+/// Entries like qualities, `[ "CUT", 2 ]`, can also be removed by their id, `"CUT"`.
+fn is_removed(item: &JsonValue, removal: &JsonValue) -> bool {
+    as_list(removal).iter().any(|removed| {
+        item == removed
+            || item
+                .as_array()
+                .and_then(|item| item.first())
+                .is_some_and(|id| id == removed)
+    })
+}
+
+fn as_list(value: &JsonValue) -> &[JsonValue] {
+    match value {
+        JsonValue::Array(list) => list,
+        single => slice::from_ref(single),
+    }
+}
+
+#[cfg(test)]
+mod inheritance_tests {
+    use super::*;
+    use serde_json::{from_str as from_json_str, json};
+
+    fn by_id(objects: &[JsonMap<String, JsonValue>], id: &str) -> JsonMap<String, JsonValue> {
+        objects
+            .iter()
+            .find(|object| object.get("id") == Some(&json!(id)))
+            .cloned()
+            .unwrap_or_else(|| panic!("{id} should be present"))
+    }
+
+    #[test]
+    fn relative_damage_works() {
+        let json = include_str!("test_data/inheritance_556.json");
+        let objects = from_json_str::<Vec<JsonMap<String, JsonValue>>>(json)
+            .expect("Test data should be valid");
+        let inherited = inherit(&by_id(&objects, "556"), &by_id(&objects, "556_m855a1"));
+
+        assert_eq!(
+            inherited.get("damage"),
+            Some(&json!({ "damage_type": "bullet", "amount": 30, "armor_penetration": 6 })),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("name"),
+            Some(&json!({ "str_sp": "5.56 NATO M855A1" })),
+            "{inherited:?}"
+        );
+        assert_eq!(inherited.get("price"), Some(&json!(350)), "{inherited:?}");
+        assert!(!inherited.contains_key("relative"), "{inherited:?}");
+        assert!(!inherited.contains_key("copy-from"), "{inherited:?}");
+    }
+
+    #[test]
+    fn proportional_extend_and_delete_work() {
+        let json = include_str!("test_data/inheritance_556.json");
+        let objects = from_json_str::<Vec<JsonMap<String, JsonValue>>>(json)
+            .expect("Test data should be valid");
+        let inherited = inherit(&by_id(&objects, "556"), &by_id(&objects, "reloaded_556"));
+
+        assert_eq!(inherited.get("price"), Some(&json!(245)), "{inherited:?}");
+        assert_eq!(
+            inherited.get("dispersion"),
+            Some(&json!(44)),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("damage"),
+            Some(&json!({ "damage_type": "bullet", "amount": 29, "armor_penetration": 2 })),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("effects"),
+            Some(&json!(["COOKOFF", "RECYCLED"])),
+            "{inherited:?}"
+        );
+        assert_eq!(inherited.get("flags"), Some(&json!([])), "{inherited:?}");
+    }
+
+    #[test]
+    fn monster_inheritance_works() {
+        let json = include_str!("test_data/inheritance_zombie.json");
+        let objects = from_json_str::<Vec<JsonMap<String, JsonValue>>>(json)
+            .expect("Test data should be valid");
+        let inherited = inherit(
+            &by_id(&objects, "mon_zombie"),
+            &by_id(&objects, "mon_zombie_fat"),
+        );
+
+        assert_eq!(inherited.get("hp"), Some(&json!(100)), "{inherited:?}");
+        assert_eq!(inherited.get("speed"), Some(&json!(56)), "{inherited:?}");
+        assert_eq!(
+            inherited.get("melee_damage"),
+            Some(&json!([{ "damage_type": "bash", "amount": 3 }])),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("armor"),
+            Some(&json!({ "bash": 3, "cut": 3, "bullet": 2 })),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("weight"),
+            Some(&json!("101500 g")),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("volume"),
+            Some(&json!("75000 ml")),
+            "{inherited:?}"
+        );
+        assert_eq!(
+            inherited.get("flags"),
+            Some(&json!(["SEES", "HEARS", "SMELLS", "POISON", "BASHES"])),
+            "{inherited:?}"
+        );
+    }
+
+    #[test]
+    fn chained_inheritance_works() {
+        let json = include_str!("test_data/inheritance_zombie.json");
+        let objects = from_json_str::<Vec<JsonMap<String, JsonValue>>>(json)
+            .expect("Test data should be valid");
+        let fat = inherit(
+            &by_id(&objects, "mon_zombie"),
+            &by_id(&objects, "mon_zombie_fat"),
+        );
+        let inherited = inherit(&fat, &by_id(&objects, "mon_zombie_fat_tough"));
+
+        assert_eq!(inherited.get("hp"), Some(&json!(150)), "{inherited:?}");
+        assert_eq!(inherited.get("speed"), Some(&json!(56)), "{inherited:?}");
+        assert_eq!(
+            inherited.get("flags"),
+            Some(&json!(["SEES", "HEARS", "POISON", "BASHES"])),
+            "{inherited:?}"
+        );
+    }
+}
@@ -0,0 +1,842 @@
+use crate::InfoMap;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{trace, warn};
+use cdda_json_files::{
+    At, CddaAmount, CddaItem, Character, CharacterInfo, CommonItemInfo, CountRange, FlatVec,
+    FurnitureInfo, InfoId, ItemGroup, Map, Mapgen, MapgenItem, MapgenItems, MapgenMapping,
+    MapgenMonster, MapgenMonsters, MapgenNested, MapgenObject, MapgenOption, MapgenPalette,
+    MonsterGroup, OvermapTerrainInfo, Repetition, RepetitionBlock, RequiredLinkedLater, Submap,
+    TerrainInfo, pick_weighted,
+};
+use fastrand::{f32 as rand_f32, u32 as rand_u32};
+use gameplay_location::{SubzoneLevel, ZoneLevel};
+use std::mem::take;
+use std::sync::{Arc, OnceLock};
+use units::Timestamp;
+
+/// The size of the map of a single overmap terrain, in tiles
+const OMT_SIZE: usize = 24;
+
+/// The size of a submap, in tiles
+const SUBMAP_SIZE: usize = 12;
+
+/// Protects against nested mapgens that include themselves
+const MAX_DEPTH: u8 = 5;
+
+/// The json mapgens, by the overmap terrains and nested mapgen ids they are used for
+#[derive(Default)]
+pub(super) struct MapgenIndex {
+    /// With the column and row of the part for each overmap terrain
+    by_om_terrain: HashMap<InfoId<OvermapTerrainInfo>, Vec<(Arc<Mapgen>, usize, usize)>>,
+    nested: HashMap<InfoId<Mapgen>, Vec<Arc<Mapgen>>>,
+}
+
+impl MapgenIndex {
+    pub(super) fn new(mapgens: &InfoMap<Mapgen>) -> Self {
+        let mut index = Self::default();
+        for mapgen in mapgens
+            .values()
+            .filter(|mapgen| &*mapgen.method == "json" && mapgen.object.is_some())
+        {
+            if let Some(om_terrain) = &mapgen.om_terrain {
+                for (id, col, row) in om_terrain.positioned() {
+                    index.by_om_terrain.entry(id.clone()).or_default().push((
+                        mapgen.clone(),
+                        col,
+                        row,
+                    ));
+                }
+            }
+            if let Some(nested_mapgen_id) = &mapgen.nested_mapgen_id {
+                index
+                    .nested
+                    .entry(nested_mapgen_id.clone())
+                    .or_default()
+                    .push(mapgen.clone());
+            }
+        }
+        index
+    }
+}
+
+/// What a single tile of a generated map will contain
+#[derive(Default)]
+struct Cell {
+    terrain: Option<InfoId<TerrainInfo>>,
+    furniture: Option<InfoId<FurnitureInfo>>,
+    items: Vec<(InfoId<CommonItemInfo>, u32)>,
+    monsters: Vec<InfoId<CharacterInfo>>,
+}
+
+/// Cells stored row by row
+struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: (0..width * height).map(|_| Cell::default()).collect(),
+        }
+    }
+
+    fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The part of a single overmap terrain
+    fn window(mut self, col: usize, row: usize) -> Self {
+        let mut window = Self::new(OMT_SIZE, OMT_SIZE);
+        for y in 0..OMT_SIZE {
+            for x in 0..OMT_SIZE {
+                if let (Some(from), Some(to)) = (
+                    self.get_mut(col * OMT_SIZE + x, row * OMT_SIZE + y),
+                    window.get_mut(x, y),
+                ) {
+                    *to = take(from);
+                }
+            }
+        }
+        window
+    }
+
+    /// A quarter turn clockwise: (x, y) becomes (height - 1 - y, x)
+    fn rotated(mut self) -> Self {
+        let mut rotated = Self::new(self.height, self.width);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if let Some(to) = rotated.get_mut(self.height - 1 - y, x) {
+                    *to = take(&mut self.cells[y * self.width + x]);
+                }
+            }
+        }
+        rotated
+    }
+}
+
+/// The area of the grid that an object applies to
+#[derive(Clone, Copy)]
+struct Area {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Area {
+    fn random_pos(self, x: &CountRange, y: &CountRange) -> (usize, usize) {
+        (self.x + x.random() as usize, self.y + y.random() as usize)
+    }
+}
+
+pub(super) struct MapGenerator<'a> {
+    pub(super) index: &'a MapgenIndex,
+    pub(super) palettes: &'a InfoMap<MapgenPalette>,
+    pub(super) item_groups: &'a InfoMap<ItemGroup>,
+    pub(super) monster_groups: &'a InfoMap<MonsterGroup>,
+
+    /// Used when `place_monsters` does not specify a density
+    pub(super) monster_density: f32,
+}
+
+impl MapGenerator<'_> {
+    /// Returns `None` when no json mapgen exists for this overmap terrain
+    pub(super) fn generate(
+        &self,
+        zone_level: ZoneLevel,
+        zone_object_id: &InfoId<OvermapTerrainInfo>,
+        fallback_terrain: &InfoId<TerrainInfo>,
+    ) -> Option<Map> {
+        let (base_id, direction_turns) = zone_object_id.without_direction();
+        let candidates = self.index.by_om_terrain.get(&base_id)?;
+        let (mapgen, col, row) = pick_weighted(
+            candidates
+                .iter()
+                .map(|candidate| (candidate, candidate.0.weight)),
+        )?;
+        let object = mapgen.object.as_ref()?;
+        trace!("Generating {zone_level:?} from {:?}", mapgen.id);
+
+        let (cols, rows) = mapgen.om_terrain.as_ref().map_or((1, 1), |om_terrain| {
+            om_terrain
+                .positioned()
+                .iter()
+                .fold((1, 1), |(cols, rows), (_, col, row)| {
+                    (cols.max(col + 1), rows.max(row + 1))
+                })
+        });
+        let mut grid = Grid::new(cols * OMT_SIZE, rows * OMT_SIZE);
+        let area = Area {
+            x: 0,
+            y: 0,
+            width: grid.width,
+            height: grid.height,
+        };
+        self.apply(object, &mut grid, area, 0);
+
+        let mut grid = grid.window(*col, *row);
+        let object_turns = object.rotation.as_ref().map_or(0, CountRange::random);
+        // Four quarter turns would change nothing
+        for _ in 0..(u32::from(direction_turns) + object_turns) & 3 {
+            grid = grid.rotated();
+        }
+
+        Some(Self::map(grid, zone_level, fallback_terrain))
+    }
+
+    fn apply(&self, object: &MapgenObject, grid: &mut Grid, area: Area, depth: u8) {
+        let mut mappings = vec![&object.mapping];
+        for palette in &object.palettes {
+            self.add_palette_mappings(palette.pick(), &mut mappings, depth);
+        }
+
+        if let Some(fill_ter) = object
+            .fill_ter
+            .as_ref()
+            .and_then(|fill_ter| fill_ter.pick())
+        {
+            for y in area.y..area.y + area.height {
+                for x in area.x..area.x + area.width {
+                    if let Some(cell) = grid.get_mut(x, y) {
+                        cell.terrain = Some(fill_ter.clone());
+                    }
+                }
+            }
+        }
+
+        for (y, row) in object.rows.iter().enumerate() {
+            for (x, symbol) in row.chars().enumerate() {
+                self.apply_symbol(symbol, &mappings, grid, (area.x + x, area.y + y), depth);
+            }
+        }
+
+        for placed in &object.place_terrain {
+            let (x, y) = area.random_pos(&placed.x, &placed.y);
+            if let Some(cell) = grid.get_mut(x, y) {
+                cell.terrain = Some(placed.what.ter.clone());
+            }
+        }
+        for placed in &object.place_furniture {
+            let (x, y) = area.random_pos(&placed.x, &placed.y);
+            if let Some(cell) = grid.get_mut(x, y) {
+                cell.furniture = Some(placed.what.furn.clone());
+            }
+        }
+        for placed in &object.place_items {
+            for _ in 0..repeat(placed.what.repeat.as_ref()) {
+                let (x, y) = area.random_pos(&placed.x, &placed.y);
+                self.add_item_group(&placed.what, grid, x, y);
+            }
+        }
+        for placed in &object.place_item {
+            for _ in 0..repeat(placed.what.repeat.as_ref()) {
+                let (x, y) = area.random_pos(&placed.x, &placed.y);
+                Self::add_item(&placed.what, grid, x, y);
+            }
+        }
+        for placed in &object.place_monsters {
+            for monster in self.monsters_from_groups(&placed.what) {
+                let (x, y) = area.random_pos(&placed.x, &placed.y);
+                if let Some(cell) = grid.get_mut(x, y) {
+                    cell.monsters.push(monster);
+                }
+            }
+        }
+        for placed in &object.place_monster {
+            for _ in 0..repeat(placed.what.repeat.as_ref()) {
+                let (x, y) = area.random_pos(&placed.x, &placed.y);
+                let monsters = self.monsters(&placed.what);
+                if let Some(cell) = grid.get_mut(x, y) {
+                    cell.monsters.extend(monsters);
+                }
+            }
+        }
+        for placed in &object.place_nested {
+            let (x, y) = area.random_pos(&placed.x, &placed.y);
+            self.apply_nested(&placed.what, grid, x, y, depth);
+        }
+    }
+
+    /// Palettes are applied after the object itself, and after the palettes that include them
+    fn add_palette_mappings<'a>(
+        &'a self,
+        palette_id: Option<&InfoId<MapgenPalette>>,
+        mappings: &mut Vec<&'a MapgenMapping>,
+        depth: u8,
+    ) {
+        let Some(palette_id) = palette_id else {
+            return;
+        };
+        if MAX_DEPTH <= depth {
+            warn!("Palette {palette_id:?} is nested too deep");
+            return;
+        }
+        let Ok(palette) = self.palettes.get(palette_id) else {
+            warn!("Unknown palette {palette_id:?}");
+            return;
+        };
+
+        mappings.push(&palette.mapping);
+        for nested in &palette.palettes {
+            self.add_palette_mappings(nested.pick(), mappings, depth + 1);
+        }
+    }
+
+    /// The first mapping that defines the symbol for a layer wins
+    fn apply_symbol(
+        &self,
+        symbol: char,
+        mappings: &[&MapgenMapping],
+        grid: &mut Grid,
+        (x, y): (usize, usize),
+        depth: u8,
+    ) {
+        let Some(cell) = grid.get_mut(x, y) else {
+            return;
+        };
+
+        if let Some(terrain) = mappings
+            .iter()
+            .find_map(|mapping| mapping.terrain.get(&symbol))
+            .and_then(|terrain| terrain.pick())
+        {
+            cell.terrain = Some(terrain.clone());
+        }
+        if let Some(furniture) = mappings
+            .iter()
+            .find_map(|mapping| mapping.furniture.get(&symbol))
+            .and_then(|furniture| furniture.pick())
+        {
+            cell.furniture = Some(furniture.clone());
+        }
+        if let Some(monster) = mappings
+            .iter()
+            .find_map(|mapping| mapping.monster.get(&symbol))
+        {
+            for monster in &monster.0 {
+                for _ in 0..repeat(monster.repeat.as_ref()) {
+                    cell.monsters.extend(self.monsters(monster));
+                }
+            }
+        }
+        if let Some(monsters) = mappings
+            .iter()
+            .find_map(|mapping| mapping.monsters.get(&symbol))
+        {
+            for monsters in &monsters.0 {
+                cell.monsters.extend(self.monsters_from_groups(monsters));
+            }
+        }
+
+        if let Some(items) = mappings
+            .iter()
+            .find_map(|mapping| mapping.items.get(&symbol))
+        {
+            for items in &items.0 {
+                for _ in 0..repeat(items.repeat.as_ref()) {
+                    self.add_item_group(items, grid, x, y);
+                }
+            }
+        }
+        if let Some(item) = mappings
+            .iter()
+            .find_map(|mapping| mapping.item.get(&symbol))
+        {
+            for item in &item.0 {
+                for _ in 0..repeat(item.repeat.as_ref()) {
+                    Self::add_item(item, grid, x, y);
+                }
+            }
+        }
+        if let Some(nested) = mappings
+            .iter()
+            .find_map(|mapping| mapping.nested.get(&symbol))
+        {
+            for nested in &nested.0 {
+                self.apply_nested(nested, grid, x, y, depth);
+            }
+        }
+    }
+
+    fn apply_nested(&self, nested: &MapgenNested, grid: &mut Grid, x: usize, y: usize, depth: u8) {
+        if MAX_DEPTH <= depth {
+            warn!("Mapgen is nested too deep at ({x}, {y})");
+            return;
+        }
+
+        let Some(chunk_id) = pick_weighted(nested.chunks.iter().map(MapgenOption::weighted)) else {
+            return;
+        };
+        if chunk_id == &InfoId::new("null") {
+            return;
+        }
+        let Some(chunk) = self
+            .index
+            .nested
+            .get(chunk_id)
+            .and_then(|chunks| pick_weighted(chunks.iter().map(|chunk| (chunk, chunk.weight))))
+        else {
+            warn!("Unknown nested mapgen {chunk_id:?}");
+            return;
+        };
+        let Some(object) = &chunk.object else {
+            return;
+        };
+
+        let (width, height) = object.mapgensize.map_or_else(
+            || {
+                (
+                    object
+                        .rows
+                        .iter()
+                        .map(|row| row.chars().count())
+                        .max()
+                        .unwrap_or(0),
+                    object.rows.len(),
+                )
+            },
+            |(width, height)| (usize::from(width), usize::from(height)),
+        );
+        let area = Area {
+            x,
+            y,
+            width,
+            height,
+        };
+        self.apply(object, grid, area, depth + 1);
+    }
+
+    fn add_item_group(&self, items: &MapgenItems, grid: &mut Grid, x: usize, y: usize) {
+        if !percent_chance(items.chance) {
+            return;
+        }
+        let Ok(item_group) = self.item_groups.get(&items.item) else {
+            warn!("Unknown item group {:?}", items.item);
+            return;
+        };
+        if let Some(cell) = grid.get_mut(x, y) {
+            cell.items.extend(
+                item_group
+                    .items()
+                    .map(|spawn_item| (spawn_item.item_info.id.clone(), spawn_item.amount)),
+            );
+        }
+    }
+
+    fn add_item(item: &MapgenItem, grid: &mut Grid, x: usize, y: usize) {
+        if !percent_chance(item.chance) {
+            return;
+        }
+        if let Some(cell) = grid.get_mut(x, y) {
+            let amount = item.amount.as_ref().map_or(1, CountRange::random);
+            cell.items.push((item.item.clone(), amount));
+        }
+    }
+
+    /// Like `place_monsters` in CDDA: when the 'one in ...' chance hits, a single monster for 'individual', and otherwise 10 to 50 monsters per unit of density
+    fn monsters_from_groups(&self, monsters: &MapgenMonsters) -> Vec<InfoId<CharacterInfo>> {
+        if rand_u32(0..monsters.chance.max(1)) != 0 {
+            return Vec::new();
+        }
+
+        let count = if monsters.individual {
+            1
+        } else {
+            let density = monsters.density.unwrap_or(self.monster_density);
+            roll_remainder(density * (10.0 + 40.0 * rand_f32()))
+        };
+        (0..count)
+            .flat_map(|_| monsters_from_group(self.monster_groups, &monsters.monster, None, 0))
+            .collect()
+    }
+
+    fn monsters(&self, monster: &MapgenMonster) -> Vec<InfoId<CharacterInfo>> {
+        if !percent_chance(monster.chance) {
+            return Vec::new();
+        }
+
+        let pack_size = monster.pack_size.as_ref().map_or(1, CountRange::random);
+        if let Some(monster_id) = &monster.monster {
+            (0..pack_size).map(|_| monster_id.clone()).collect()
+        } else if let Some(group) = &monster.group {
+            (0..pack_size)
+                .flat_map(|_| monsters_from_group(self.monster_groups, group, None, 0))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn map(mut grid: Grid, zone_level: ZoneLevel, fallback_terrain: &InfoId<TerrainInfo>) -> Map {
+        Map(zone_level.subzone_levels().map(|subzone_level| {
+            let offset_x = (subzone_level.x - 2 * zone_level.zone.x) as usize * SUBMAP_SIZE;
+            let offset_z = (subzone_level.z - 2 * zone_level.zone.z) as usize * SUBMAP_SIZE;
+            Self::submap(
+                &mut grid,
+                subzone_level,
+                (offset_x, offset_z),
+                fallback_terrain,
+            )
+        }))
+    }
+
+    fn submap(
+        grid: &mut Grid,
+        subzone_level: SubzoneLevel,
+        (offset_x, offset_z): (usize, usize),
+        fallback_terrain: &InfoId<TerrainInfo>,
+    ) -> Submap {
+        let mut terrain = Vec::<CddaAmount<InfoId<TerrainInfo>>>::new();
+        let mut furniture = Vec::new();
+        let mut items = Vec::new();
+        let mut spawns = Vec::new();
+
+        // Terrain is stored row by row
+        for z in 0..SUBMAP_SIZE {
+            for x in 0..SUBMAP_SIZE {
+                let Some(cell) = grid.get_mut(offset_x + x, offset_z + z) else {
+                    continue;
+                };
+                let cell = take(cell);
+
+                let terrain_id = cell.terrain.unwrap_or_else(|| fallback_terrain.clone());
+                match terrain.last_mut() {
+                    Some(last) if last.obj == terrain_id => last.amount += 1,
+                    _ => terrain.push(CddaAmount {
+                        obj: terrain_id,
+                        amount: 1,
+                    }),
+                }
+
+                let (x, z) = (x as u8, z as u8);
+                if let Some(furniture_id) = cell.furniture {
+                    furniture.push(At {
+                        x,
+                        y: z,
+                        obj: RequiredLinkedLater::from(furniture_id),
+                    });
+                }
+                if !cell.items.is_empty() {
+                    items.push(At {
+                        x,
+                        y: z,
+                        obj: cell
+                            .items
+                            .into_iter()
+                            .map(|(item_id, amount)| {
+                                Repetition::Multiple(CddaAmount {
+                                    obj: CddaItem::unlinked(item_id),
+                                    amount,
+                                })
+                            })
+                            .collect(),
+                    });
+                }
+                spawns.extend(
+                    cell.monsters
+                        .into_iter()
+                        .map(|monster_id| Character::new(monster_id, x.into(), z.into())),
+                );
+            }
+        }
+
+        Submap {
+            version: 0,
+            turn_last_touched: 0,
+            coordinates: subzone_level.coordinates(),
+            temperature: 0,
+            radiation: Vec::new(),
+            terrain: RepetitionBlock(
+                terrain
+                    .into_iter()
+                    .map(|amount| {
+                        Repetition::Multiple(CddaAmount {
+                            obj: RequiredLinkedLater::from(amount.obj),
+                            amount: amount.amount,
+                        })
+                    })
+                    .collect(),
+            ),
+            furniture,
+            items: FlatVec(items),
+            traps: Vec::new(),
+            fields: FlatVec(Vec::new()),
+            cosmetics: Vec::new(),
+            spawns,
+            vehicles: Vec::new(),
+            partial_constructions: FlatVec(Vec::new()),
+            computers: Vec::new(),
+            linked: OnceLock::default(),
+        }
+    }
+}
+
+fn repeat(repeat: Option<&CountRange>) -> u32 {
+    repeat.map_or(1, CountRange::random)
+}
+
+/// Rounds down or up, with the chance to round up equal to the fraction, like in CDDA
+fn roll_remainder(value: f32) -> u32 {
+    let whole = value.max(0.0).floor();
+    let round_up = rand_f32() < value - whole;
+    whole as u32 + u32::from(round_up)
+}
+
+fn percent_chance(chance: u32) -> bool {
+    rand_u32(0..100) < chance
+}
+
+/// Without a time, the conditions of the group entries are ignored
+pub(super) fn monsters_from_group(
+    monster_groups: &InfoMap<MonsterGroup>,
+    group_id: &InfoId<MonsterGroup>,
+    time: Option<Timestamp>,
+    depth: u8,
+) -> Vec<InfoId<CharacterInfo>> {
+    if MAX_DEPTH <= depth {
+        warn!("Monster group {group_id:?} is nested too deep");
+        return Vec::new();
+    }
+    let Ok(group) = monster_groups.get(group_id) else {
+        warn!("Unknown monster group {group_id:?}");
+        return Vec::new();
+    };
+
+    let monsters = match group.pick(time) {
+        Some(entry) => {
+            let pack_size = entry.pack_size.as_ref().map_or(1, CountRange::random);
+            if let Some(monster_id) = &entry.monster {
+                (0..pack_size).map(|_| monster_id.clone()).collect()
+            } else if let Some(nested) = &entry.group {
+                (0..pack_size)
+                    .flat_map(|_| monsters_from_group(monster_groups, nested, time, depth + 1))
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        }
+        None => group.default.iter().cloned().collect::<Vec<_>>(),
+    };
+    monsters
+        .into_iter()
+        .filter(|monster_id| monster_id != &InfoId::new("mon_null"))
+        .collect()
+}
+
+#[cfg(test)]
+mod map_generator_tests {
+    use super::*;
+    use crate::{TypeId, info::parsed_json::Enriched};
+    use cdda_json_files::UntypedInfoId;
+    use serde::de::DeserializeOwned;
+    use serde_json::{Value as JsonValue, from_value as from_json_value, json};
+    use std::fmt;
+
+    fn info_map<T: fmt::Debug + DeserializeOwned + 'static>(
+        type_id: TypeId,
+        infos: &[JsonValue],
+    ) -> InfoMap<T> {
+        let enriched = infos
+            .iter()
+            .map(|info| {
+                let fields = info.as_object().expect("Infos should be objects").clone();
+                let id = fields["id"].as_str().expect("Infos should have an id");
+                let enriched = Enriched {
+                    fields: fields.clone(),
+                    alias_ids: Vec::new(),
+                };
+                (UntypedInfoId::new(id), enriched)
+            })
+            .collect();
+        InfoMap::new(&mut HashMap::from_iter([(type_id, enriched)]), type_id)
+    }
+
+    struct Fixture {
+        index: MapgenIndex,
+        palettes: InfoMap<MapgenPalette>,
+        item_groups: InfoMap<ItemGroup>,
+        monster_groups: InfoMap<MonsterGroup>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                index: MapgenIndex::default(),
+                palettes: info_map(
+                    TypeId::Palette,
+                    &[
+                        json!({
+                            "type": "palette",
+                            "id": "test_outer",
+                            "palettes": ["test_inner"],
+                            "terrain": { ".": "t_floor" },
+                            "furniture": { "c": "f_chair" }
+                        }),
+                        json!({
+                            "type": "palette",
+                            "id": "test_inner",
+                            "terrain": { ".": "t_dirt", "#": "t_wall", "c": "t_floor" }
+                        }),
+                    ],
+                ),
+                item_groups: info_map(TypeId::ItemGroup, &[]),
+                monster_groups: info_map(
+                    TypeId::MonsterGroup,
+                    &[json!({
+                        "type": "monstergroup",
+                        "id": "GROUP_TEST",
+                        "default": "mon_zombie",
+                        "monsters": []
+                    })],
+                ),
+            }
+        }
+
+        fn generator(&self, monster_density: f32) -> MapGenerator<'_> {
+            MapGenerator {
+                index: &self.index,
+                palettes: &self.palettes,
+                item_groups: &self.item_groups,
+                monster_groups: &self.monster_groups,
+                monster_density,
+            }
+        }
+    }
+
+    fn applied(generator: &MapGenerator, object: JsonValue, size: usize) -> Grid {
+        let object = from_json_value::<MapgenObject>(object).expect("The object should be valid");
+        let mut grid = Grid::new(size, size);
+        let area = Area {
+            x: 0,
+            y: 0,
+            width: size,
+            height: size,
+        };
+        generator.apply(&object, &mut grid, area, 0);
+        grid
+    }
+
+    fn terrain(grid: &mut Grid, x: usize, y: usize) -> Option<InfoId<TerrainInfo>> {
+        grid.get_mut(x, y).and_then(|cell| cell.terrain.clone())
+    }
+
+    #[test]
+    fn palettes_resolve_in_order() {
+        let fixture = Fixture::new();
+        let mut grid = applied(
+            &fixture.generator(0.0),
+            json!({
+                "rows": ["#.c", "x.."],
+                "palettes": ["test_outer"],
+                "terrain": { "x": "t_dirt" }
+            }),
+            3,
+        );
+
+        assert_eq!(
+            terrain(&mut grid, 0, 1),
+            Some(InfoId::new("t_dirt")),
+            "The object wins"
+        );
+        assert_eq!(
+            terrain(&mut grid, 1, 0),
+            Some(InfoId::new("t_floor")),
+            "A palette wins from the palettes it includes"
+        );
+        assert_eq!(
+            terrain(&mut grid, 0, 0),
+            Some(InfoId::new("t_wall")),
+            "Included palettes are used"
+        );
+        assert_eq!(
+            terrain(&mut grid, 2, 0),
+            Some(InfoId::new("t_floor")),
+            "Layers are resolved separately"
+        );
+        let chair = grid.get_mut(2, 0).and_then(|cell| cell.furniture.clone());
+        assert_eq!(chair, Some(InfoId::new("f_chair")), "Furniture is mapped");
+    }
+
+    #[test]
+    fn rotation_is_clockwise() {
+        let mut grid = Grid::new(3, 2);
+        if let Some(cell) = grid.get_mut(0, 0) {
+            cell.terrain = Some(InfoId::new("t_wall"));
+        }
+        if let Some(cell) = grid.get_mut(2, 1) {
+            cell.terrain = Some(InfoId::new("t_dirt"));
+        }
+
+        let mut rotated = grid.rotated();
+        assert_eq!((rotated.width, rotated.height), (2, 3), "Sides are swapped");
+        assert_eq!(
+            terrain(&mut rotated, 1, 0),
+            Some(InfoId::new("t_wall")),
+            "The top left goes to the top right"
+        );
+        assert_eq!(
+            terrain(&mut rotated, 0, 2),
+            Some(InfoId::new("t_dirt")),
+            "The bottom right goes to the bottom left"
+        );
+
+        let mut full_turn = rotated.rotated().rotated().rotated();
+        assert_eq!(
+            terrain(&mut full_turn, 0, 0),
+            Some(InfoId::new("t_wall")),
+            "Full turn"
+        );
+        assert_eq!(
+            terrain(&mut full_turn, 2, 1),
+            Some(InfoId::new("t_dirt")),
+            "Full turn"
+        );
+    }
+
+    #[test]
+    fn monster_density_works() {
+        let fixture = Fixture::new();
+        let monsters = |density: f32, place: JsonValue| {
+            let mut grid = applied(
+                &fixture.generator(density),
+                json!({ "place_monsters": [place] }),
+                24,
+            );
+            (0..24)
+                .flat_map(|y| (0..24).map(move |x| (x, y)))
+                .filter_map(|(x, y)| grid.get_mut(x, y).map(|cell| cell.monsters.len()))
+                .sum::<usize>()
+        };
+        let group = json!({ "monster": "GROUP_TEST", "x": [0, 23], "y": [0, 23] });
+
+        assert_eq!(monsters(0.0, group.clone()), 0, "No density, no monsters");
+        let count = monsters(1.0, group.clone());
+        assert!((10..=50).contains(&count), "{count}");
+
+        let mut explicit = group.clone();
+        explicit["density"] = json!(0.1);
+        let count = monsters(100.0, explicit);
+        assert!((1..=5).contains(&count), "{count}");
+
+        let mut individual = group;
+        individual["individual"] = json!(true);
+        assert_eq!(monsters(100.0, individual), 1, "Only one individual");
+    }
+
+    #[test]
+    fn roll_remainder_works() {
+        assert_eq!(roll_remainder(3.0), 3, "Whole numbers stay the same");
+        assert_eq!(roll_remainder(-2.5), 0, "Negative amounts are empty");
+        for _ in 0..20 {
+            assert!((2..=3).contains(&roll_remainder(2.5)), "Either way");
+        }
+    }
+}
@@ -1,8 +1,13 @@
 mod fixed;
+mod info_cache;
 mod info_map;
 mod infos;
+mod inheritance;
+mod map_generator;
 mod migration_provider;
+mod mod_list;
 mod parsed_json;
 
 pub use self::info_map::InfoMap;
 pub use self::infos::Infos;
+pub use self::mod_list::{LoadedMod, ModList};
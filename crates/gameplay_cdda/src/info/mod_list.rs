@@ -0,0 +1,168 @@
+use crate::Error;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::{debug, error, warn};
+use cdda_json_files::{InfoId, ModInfo, WorldMods};
+use glob::glob;
+use serde_json::from_str as from_json_str;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use util::AssetPaths;
+
+pub(super) const MOD_INFO_FILE: &str = "modinfo.json";
+
+/// A mod from `data/mods`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoadedMod {
+    pub id: InfoId<ModInfo>,
+    pub name: Arc<str>,
+    json_path: PathBuf,
+}
+
+/// The mods of a world, in load order: each mod comes after its dependencies.
+///
+/// The json of later mods overrides or extends the json of earlier mods.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModList(Vec<LoadedMod>);
+
+impl ModList {
+    const CORE: &str = "dda";
+
+    /// Only the base game
+    #[must_use]
+    pub fn core() -> Self {
+        Self::resolve(&[InfoId::new(Self::CORE)])
+    }
+
+    /// The mods from the `mods.json` of the world. Without that file, only the base game is used.
+    #[must_use]
+    pub fn for_world(world_path: &Path) -> Self {
+        let mods_path = world_path.join("mods.json");
+        let world_mods = read_to_string(&mods_path)
+            .ok()
+            .and_then(|contents| {
+                from_json_str::<WorldMods>(&contents)
+                    .inspect_err(|error| error!("Could not parse {mods_path:?}: {error:#?}"))
+                    .ok()
+            })
+            .map_or_else(|| vec![InfoId::new(Self::CORE)], |world_mods| world_mods.0);
+        Self::resolve(&world_mods)
+    }
+
+    fn resolve(ids: &[InfoId<ModInfo>]) -> Self {
+        let available = available_mods();
+
+        let mut resolved = Vec::new();
+        let mut visited = HashSet::default();
+        if !available.values().any(|(mod_info, _)| mod_info.core) {
+            warn!(
+                "No core mod found in {:?}, using the json directory",
+                mods_path()
+            );
+            visited.insert(InfoId::new(Self::CORE));
+            resolved.push(LoadedMod {
+                id: InfoId::new(Self::CORE),
+                name: Arc::from("Dark Days Ahead"),
+                json_path: AssetPaths::data().join("json"),
+            });
+        }
+
+        // The base game always comes first
+        let core = available
+            .values()
+            .filter(|(mod_info, _)| mod_info.core)
+            .map(|(mod_info, _)| mod_info.id.clone());
+        for id in core.chain(ids.iter().cloned()) {
+            add_with_dependencies(&available, &id, &mut visited, &mut resolved);
+        }
+
+        debug!("Mods in load order: {resolved:?}");
+        Self(resolved)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LoadedMod> {
+        self.0.iter()
+    }
+
+    pub(super) fn json_paths(&self) -> impl Iterator<Item = &Path> {
+        self.0
+            .iter()
+            .map(|loaded_mod| loaded_mod.json_path.as_path())
+    }
+}
+
+fn mods_path() -> PathBuf {
+    AssetPaths::data().join("mods")
+}
+
+/// Mod id -> (mod info, json directory)
+fn available_mods() -> HashMap<InfoId<ModInfo>, (ModInfo, PathBuf)> {
+    let pattern = mods_path().join("**").join(MOD_INFO_FILE);
+    let pattern = pattern
+        .to_str()
+        .expect("Path pattern should be valid UTF-8");
+
+    let mut available = HashMap::default();
+    for mod_info_path in glob(pattern)
+        .expect("Glob pattern should match some readable paths")
+        .filter_map(Result::ok)
+    {
+        let mod_infos = match load_mod_infos(&mod_info_path) {
+            Ok(mod_infos) => mod_infos,
+            Err(error) => {
+                error!("Could not load {mod_info_path:?}: {error:#?}");
+                continue;
+            }
+        };
+
+        let mod_directory = mod_info_path
+            .parent()
+            .expect("Mod info file should be in a directory");
+        for mod_info in mod_infos {
+            let json_path = mod_info.path.as_deref().map_or_else(
+                || mod_directory.to_path_buf(),
+                |path| mod_directory.join(path),
+            );
+            available.insert(mod_info.id.clone(), (mod_info, json_path));
+        }
+    }
+    available
+}
+
+fn load_mod_infos(mod_info_path: &Path) -> Result<Vec<ModInfo>, Error> {
+    let contents = read_to_string(mod_info_path)?;
+    from_json_str::<Vec<ModInfo>>(&contents).map_err(|error| Error::JsonWithContext {
+        _wrapped: error,
+        _file_path: mod_info_path.to_path_buf(),
+        _contents: contents.as_str().into(),
+    })
+}
+
+fn add_with_dependencies(
+    available: &HashMap<InfoId<ModInfo>, (ModInfo, PathBuf)>,
+    id: &InfoId<ModInfo>,
+    visited: &mut HashSet<InfoId<ModInfo>>,
+    resolved: &mut Vec<LoadedMod>,
+) {
+    if !visited.insert(id.clone()) {
+        return;
+    }
+
+    let Some((mod_info, json_path)) = available.get(id) else {
+        warn!("Mod {id:?} not found in {:?}", mods_path());
+        return;
+    };
+    if mod_info.obsolete {
+        warn!("Mod {id:?} is obsolete");
+    }
+
+    for dependency in &mod_info.dependencies {
+        add_with_dependencies(available, dependency, visited, resolved);
+    }
+
+    resolved.push(LoadedMod {
+        id: id.clone(),
+        name: mod_info.name.clone(),
+        json_path: json_path.clone(),
+    });
+}
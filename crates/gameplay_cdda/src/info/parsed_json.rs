@@ -1,21 +1,23 @@
+use crate::info::inheritance::{MODIFIERS, inherit};
+use crate::info::mod_list::{MOD_INFO_FILE, ModList};
 use crate::{Error, TypeId};
-use bevy::platform::collections::HashMap;
+use bevy::platform::collections::{HashMap, HashSet};
 use bevy::prelude::{debug, error, warn};
 use bevy::tasks::IoTaskPool;
-use cdda_json_files::UntypedInfoId;
+use cdda_json_files::{Diagnostic, UntypedInfoId};
 use fastrand::alphabetic;
 use glob::glob;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{
     Map as JsonMap, Value as JsonValue, from_str as from_json_str,
     to_string_pretty as to_string_pretty_json,
 };
+use std::ffi::OsStr;
 use std::fs::{File, read_to_string};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::{env, io::Write as _};
-use util::AssetPaths;
 
 #[derive(Debug, Deserialize)]
 struct Typed {
@@ -45,7 +47,7 @@ impl Proto {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize, Serialize)]
 pub(super) struct Enriched {
     pub(super) fields: JsonMap<String, JsonValue>,
     pub(super) alias_ids: Vec<UntypedInfoId>,
@@ -58,8 +60,8 @@ pub(super) struct ParsedJson {
 }
 
 impl ParsedJson {
-    fn json_infos_paths() -> impl Iterator<Item = PathBuf> {
-        let json_file_pattern = AssetPaths::data().join("json").join("**").join("*.json");
+    pub(super) fn json_infos_paths(mod_json_path: &Path) -> impl Iterator<Item = PathBuf> {
+        let json_file_pattern = mod_json_path.join("**").join("*.json");
         let json_file_pattern = json_file_pattern
             .as_path()
             .to_str()
@@ -68,9 +70,24 @@ impl ParsedJson {
         glob(json_file_pattern)
             .expect("Glob pattern should match some readable paths")
             .map(|json_path_result| json_path_result.expect("JSON path should be valid"))
+            .filter(|json_path| json_path.file_name() != Some(OsStr::new(MOD_INFO_FILE)))
     }
 
-    fn load() -> HashMap<TypeId, HashMap<UntypedInfoId, Proto>> {
+    /// Later mods replace or extend the objects of earlier mods
+    fn load(mods: &ModList) -> HashMap<TypeId, HashMap<UntypedInfoId, Proto>> {
+        let mut objects_by_type = HashMap::default();
+        for mod_json_path in mods.json_paths() {
+            merge_mod(&mut objects_by_type, Self::load_mod(mod_json_path));
+        }
+
+        assert!(
+            !objects_by_type.is_empty(),
+            "Some info should have been found"
+        );
+        objects_by_type
+    }
+
+    fn load_mod(mod_json_path: &Path) -> HashMap<TypeId, HashMap<UntypedInfoId, Proto>> {
         let this = Self::default();
         let parsed_file_count = AtomicUsize::new(0);
         let skipped_file_count = AtomicUsize::new(0);
@@ -81,7 +98,7 @@ impl ParsedJson {
             let parsed_file_count = &parsed_file_count;
             let skipped_file_count = &skipped_file_count;
             let skipped_id_count = &skipped_id_count;
-            for json_path in Self::json_infos_paths() {
+            for json_path in Self::json_infos_paths(mod_json_path) {
                 s.spawn(async move {
                     match this.parse_json_info_file(&json_path, skipped_id_count) {
                         Ok(()) => {
@@ -89,6 +106,11 @@ impl ParsedJson {
                         }
                         Err(error) => {
                             error!("Error while processing {json_path:?}: {error:#?}");
+                            Diagnostic::InvalidFile {
+                                path: json_path.to_string_lossy().into(),
+                                message: error.summary().into(),
+                            }
+                            .report();
                             skipped_file_count.fetch_add(1, Ordering::Relaxed);
                         }
                     }
@@ -103,11 +125,7 @@ impl ParsedJson {
 
         let id_count = objects_by_type.values().map(HashMap::len).sum::<usize>();
         debug!(
-            "Found {id_count} ids ({skipped_id_count:?} skipped ids) in {parsed_file_count:?} info files ({skipped_file_count:?} skipped files)"
-        );
-        assert!(
-            !objects_by_type.is_empty(),
-            "Some info should have been found"
+            "Found {id_count} ids ({skipped_id_count:?} skipped ids) in {parsed_file_count:?} info files ({skipped_file_count:?} skipped files) in {mod_json_path:?}"
         );
         objects_by_type
     }
@@ -165,14 +183,14 @@ impl ParsedJson {
     }
 
     /// [`TypeId`] -> [`UntypedInfoId`] -> property name -> property value
-    pub(super) fn enriched() -> HashMap<TypeId, HashMap<UntypedInfoId, Enriched>> {
+    pub(super) fn enriched(mods: &ModList) -> HashMap<TypeId, HashMap<UntypedInfoId, Enriched>> {
         let mut enriched_json_infos = HashMap::default();
-        let objects_by_type = &Self::load();
+        let objects_by_type = &Self::load(mods);
         for (&type_id, literal_entry) in objects_by_type {
             let enriched_of_type = enriched_json_infos
                 .entry(type_id)
                 .or_insert_with(HashMap::default);
-            'enricheds: for (object_id, literal) in literal_entry {
+            for (object_id, literal) in literal_entry {
                 let Proto::Primary { fields, alias_ids } = literal else {
                     continue;
                 };
@@ -180,48 +198,29 @@ impl ParsedJson {
                     continue;
                 }
                 //trace!("{:?}", &object_id);
-                let mut enriched = (**fields).clone();
-                let mut ancestors = vec![object_id.clone()];
-                while let Some(copy_from) = enriched.remove("copy-from") {
-                    //trace!("Copy from {:?}", &copy_from);
-                    let copy_from = UntypedInfoId::new(
-                        copy_from
-                            .as_str()
-                            .expect("'copy-from' should have a string value"),
-                    );
-                    ancestors.push(copy_from.clone());
-                    assert!(ancestors.len() < 10, "{ancestors:?}");
-                    let copy_from = if let Some(found) = literal_entry.get(&copy_from) {
-                        found
-                    } else {
-                        let mut other_types = objects_by_type
-                            .into_iter()
-                            .filter(|(type_, _)| **type_ != TypeId::Recipe)
-                            .filter_map(|(_, literal_entry)| literal_entry.get(&copy_from));
-                        let Some(single) = other_types.next() else {
-                            error!(
-                                "copy-from {copy_from:?} not found for ({:?}) {:?}",
-                                &type_id, &copy_from
-                            );
-                            continue 'enricheds;
-                        };
-                        if other_types.next().is_some() {
-                            error!(
-                                "Multiple copy-from {copy_from:?} found for {:?} {:?}",
-                                &type_id, &object_id
-                            );
-                            continue 'enricheds;
-                        }
-                        single
-                    };
-                    for (key, value) in &**copy_from.fields() {
-                        enriched.entry(key.clone()).or_insert(value.clone());
+                let lineage = match lineage(objects_by_type, type_id, object_id, fields) {
+                    Ok(lineage) => lineage,
+                    Err(broken) => {
+                        error!(
+                            "Copy-from {:?} of {type_id:?} {object_id:?} is {}",
+                            broken.copy_from, broken.problem
+                        );
+                        broken_copy_from(type_id, object_id, &broken.copy_from, broken.problem);
+                        continue;
                     }
-                }
+                };
+
+                let mut lineage = lineage.into_iter().rev();
+                let oldest = lineage.next().expect("The object itself should be present");
+                let mut enriched =
+                    lineage.fold((**oldest).clone(), |parent, child| inherit(&parent, child));
 
-                enriched.remove("abstract");
-                enriched.remove("copy-from");
-                enriched.remove("id_suffix");
+                for key in ["abstract", "copy-from", "id_suffix"]
+                    .into_iter()
+                    .chain(MODIFIERS)
+                {
+                    enriched.remove(key);
+                }
 
                 enriched_of_type.insert(
                     object_id.clone(),
@@ -253,6 +252,136 @@ impl ParsedJson {
     }
 }
 
+/// A `copy-from` that could not be resolved
+#[derive(Debug, PartialEq, Eq)]
+struct BrokenLineage {
+    copy_from: UntypedInfoId,
+    problem: &'static str,
+}
+
+/// The fields of the object itself, followed by those of its ancestors, up to the oldest one
+///
+/// Chains of any length are allowed, like the `(earlier)` aliases of [`merge_mod`], as long as no ancestor repeats.
+fn lineage<'a>(
+    objects_by_type: &'a HashMap<TypeId, HashMap<UntypedInfoId, Proto>>,
+    type_id: TypeId,
+    object_id: &UntypedInfoId,
+    fields: &'a Arc<JsonMap<String, JsonValue>>,
+) -> Result<Vec<&'a Arc<JsonMap<String, JsonValue>>>, BrokenLineage> {
+    let mut lineage = vec![fields];
+    let mut visited = HashSet::<UntypedInfoId>::default();
+    visited.insert(object_id.clone());
+    while let Some(copy_from) = lineage.last().and_then(|fields| fields.get("copy-from")) {
+        //trace!("Copy from {:?}", &copy_from);
+        let copy_from = UntypedInfoId::new(
+            copy_from
+                .as_str()
+                .expect("'copy-from' should have a string value"),
+        );
+        if !visited.insert(copy_from.clone()) {
+            return Err(BrokenLineage {
+                copy_from,
+                problem: "circular",
+            });
+        }
+
+        let found = if let Some(found) = objects_by_type
+            .get(&type_id)
+            .and_then(|objects| objects.get(&copy_from))
+        {
+            found
+        } else {
+            let mut other_types = objects_by_type
+                .iter()
+                .filter(|(type_, _)| !matches!(type_, TypeId::Recipe | TypeId::Uncraft))
+                .filter_map(|(_, objects)| objects.get(&copy_from));
+            let Some(single) = other_types.next() else {
+                return Err(BrokenLineage {
+                    copy_from,
+                    problem: "not found",
+                });
+            };
+            if other_types.next().is_some() {
+                return Err(BrokenLineage {
+                    copy_from,
+                    problem: "ambiguous",
+                });
+            }
+            single
+        };
+        lineage.push(found.fields());
+    }
+    Ok(lineage)
+}
+
+/// Add the objects of a mod.
+///
+/// An object that copies from its own id extends the object of an earlier mod.
+/// The earlier object is kept as a hidden alias, to inherit from.
+fn merge_mod(
+    objects_by_type: &mut HashMap<TypeId, HashMap<UntypedInfoId, Proto>>,
+    mod_objects_by_type: HashMap<TypeId, HashMap<UntypedInfoId, Proto>>,
+) {
+    for (type_id, mod_objects) in mod_objects_by_type {
+        let objects = objects_by_type.entry(type_id).or_default();
+        for (id, proto) in mod_objects {
+            let proto = match proto {
+                Proto::Primary { fields, alias_ids }
+                    if copies_from(&fields, &id) && objects.contains_key(&id) =>
+                {
+                    let mut earlier_id = id.suffix(" (earlier)");
+                    while objects.contains_key(&earlier_id) {
+                        earlier_id = earlier_id.suffix(" (earlier)");
+                    }
+                    let earlier = objects
+                        .remove(&id)
+                        .expect("The earlier object should be present");
+                    objects.insert(
+                        earlier_id.clone(),
+                        Proto::Alias {
+                            fields: earlier.fields().clone(),
+                        },
+                    );
+
+                    let mut fields = (*fields).clone();
+                    fields.insert(
+                        String::from("copy-from"),
+                        JsonValue::String(String::from(&*earlier_id.fallback_name())),
+                    );
+                    Proto::Primary {
+                        fields: Arc::new(fields),
+                        alias_ids,
+                    }
+                }
+                proto => proto,
+            };
+            objects.insert(id, proto);
+        }
+    }
+}
+
+fn broken_copy_from(
+    type_id: TypeId,
+    object_id: &UntypedInfoId,
+    copy_from: &UntypedInfoId,
+    problem: &str,
+) {
+    Diagnostic::BrokenCopyFrom {
+        info_type: format!("{type_id:?}").into(),
+        id: object_id.fallback_name(),
+        copy_from: copy_from.fallback_name(),
+        problem: problem.into(),
+    }
+    .report();
+}
+
+fn copies_from(fields: &JsonMap<String, JsonValue>, id: &UntypedInfoId) -> bool {
+    fields
+        .get("copy-from")
+        .and_then(JsonValue::as_str)
+        .is_some_and(|copy_from| UntypedInfoId::new(copy_from) == *id)
+}
+
 fn load_ids(
     mut content: JsonMap<String, JsonValue>,
     by_type: &mut HashMap<UntypedInfoId, Proto>,
@@ -272,7 +401,7 @@ fn load_ids(
             if content == **previous.fields() {
                 //trace!("Ignoring exact duplicate info for {id:?}");
                 None
-            } else if type_id == TypeId::Recipe {
+            } else if [TypeId::Recipe, TypeId::Mapgen].contains(&type_id) {
                 //trace!("Old: {:#?}", by_type.get(&id));
                 //trace!("New: {content:#?}");
                 let random_string: String = [(); 16]
@@ -361,7 +490,7 @@ fn id_values(
     }
 
     let id = match type_id {
-        TypeId::Recipe => {
+        TypeId::Recipe | TypeId::Uncraft => {
             match (
                 content.get("result"),
                 content.get("abstract"),
@@ -374,6 +503,27 @@ fn id_values(
                 }
             }
         }
+        TypeId::Mapgen => {
+            // Several mapgens may exist for the same overmap terrain, so only the first one is used as id.
+            let first = ["om_terrain", "nested_mapgen_id", "update_mapgen_id"]
+                .iter()
+                .filter_map(|key| content.get(*key))
+                .find_map(first_string);
+            if let Some(first) = first {
+                return vec![UntypedInfoId::new(first)];
+            }
+            error!("Could not determine id for {type_id:?} in {json_path:?}: {content:#?}");
+            return Vec::new();
+        }
+        TypeId::MonsterGroup => {
+            // Older versions of CDDA use 'name' instead of 'id'
+            if let Some(id) = content.get("id").or_else(|| content.get("name")) {
+                id
+            } else {
+                error!("Could not determine id for {type_id:?} in {json_path:?}: {content:#?}");
+                return Vec::new();
+            }
+        }
         TypeId::VehiclePartMigration => {
             if let Some(from) = content.get("from") {
                 from
@@ -414,6 +564,15 @@ fn id_values(
     }
 }
 
+/// The first string in a possibly nested list
+fn first_string(value: &JsonValue) -> Option<&str> {
+    match value {
+        JsonValue::String(string) => Some(string.as_str()),
+        JsonValue::Array(values) => values.iter().find_map(first_string),
+        _ => None,
+    }
+}
+
 fn alias_values(
     content: &JsonMap<String, JsonValue>,
     type_id: TypeId,
@@ -442,3 +601,82 @@ fn alias_values(
         None => Vec::new(),
     }
 }
+
+#[cfg(test)]
+mod parsed_json_tests {
+    use super::*;
+    use serde_json::json;
+
+    fn primary(fields: &JsonValue) -> Proto {
+        Proto::Primary {
+            fields: Arc::new(fields.as_object().expect("Object").clone()),
+            alias_ids: Vec::new(),
+        }
+    }
+
+    fn objects<'a>(
+        objects: impl IntoIterator<Item = (&'a str, JsonValue)>,
+    ) -> HashMap<TypeId, HashMap<UntypedInfoId, Proto>> {
+        let mut by_type = HashMap::default();
+        by_type.insert(
+            TypeId::Furniture,
+            objects
+                .into_iter()
+                .map(|(id, fields)| (UntypedInfoId::new(id), primary(&fields)))
+                .collect(),
+        );
+        by_type
+    }
+
+    fn lineage_of(
+        objects_by_type: &HashMap<TypeId, HashMap<UntypedInfoId, Proto>>,
+        id: &str,
+    ) -> Result<usize, BrokenLineage> {
+        let id = UntypedInfoId::new(id);
+        let fields = objects_by_type[&TypeId::Furniture][&id].fields();
+        lineage(objects_by_type, TypeId::Furniture, &id, fields).map(|lineage| lineage.len())
+    }
+
+    #[test]
+    fn long_lineage_works() {
+        // Like many mods that each extend the same chair
+        let ids = (0..=12)
+            .map(|depth| String::from("f_chair") + &" (earlier)".repeat(depth))
+            .collect::<Vec<_>>();
+        let objects_by_type = objects(ids.iter().enumerate().map(|(depth, id)| {
+            let fields = ids.get(depth + 1).map_or_else(
+                || json!({ "name": "chair" }),
+                |earlier| json!({ "copy-from": earlier }),
+            );
+            (id.as_str(), fields)
+        }));
+        assert_eq!(lineage_of(&objects_by_type, "f_chair"), Ok(13));
+    }
+
+    #[test]
+    fn circular_lineage_is_an_error() {
+        let objects_by_type = objects([
+            ("f_a", json!({ "copy-from": "f_b" })),
+            ("f_b", json!({ "copy-from": "f_a" })),
+        ]);
+        assert_eq!(
+            lineage_of(&objects_by_type, "f_a"),
+            Err(BrokenLineage {
+                copy_from: UntypedInfoId::new("f_a"),
+                problem: "circular",
+            })
+        );
+    }
+
+    #[test]
+    fn missing_ancestor_is_an_error() {
+        let objects_by_type = objects([("f_a", json!({ "copy-from": "f_missing" }))]);
+        assert_eq!(
+            lineage_of(&objects_by_type, "f_a"),
+            Err(BrokenLineage {
+                copy_from: UntypedInfoId::new("f_missing"),
+                problem: "not found",
+            })
+        );
+    }
+}
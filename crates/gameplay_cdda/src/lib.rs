@@ -6,11 +6,12 @@ mod paths;
 mod plugin;
 mod regions;
 mod repetition_block_ext;
+mod save_writer;
 mod tiles;
 mod type_id;
 
 pub use self::error::Error;
-pub use self::info::{InfoMap, Infos};
+pub use self::info::{InfoMap, Infos, LoadedMod, ModList};
 pub use self::object_category::ObjectCategory;
 pub use self::plugin::CddaPlugin;
 pub use self::regions::{
@@ -18,6 +19,7 @@ pub use self::regions::{
     OvermapBufferAsset, OvermapBufferManager, OvermapManager,
 };
 pub use self::repetition_block_ext::RepetitionBlockExt;
+pub use self::save_writer::{JsonObject, SaveWriter, expand_repetitions, repetitions_json};
 pub use self::tiles::{
     Layers, MeshInfo, Model, ModelShape, SpriteOrientation, TileLoader, TileVariant, Transform2d,
 };
@@ -5,6 +5,7 @@ const SEPARATION_OFFSET: f32 = 0.005;
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub enum ObjectCategory {
     Terrain,
+    Trap,
     Field,
     Furniture,
     Item,
@@ -26,13 +27,14 @@ impl ObjectCategory {
         let level = 2 * match self {
             Self::ZoneLevel => -1,
             Self::Terrain => 0,
-            Self::Field => 1,
-            Self::Furniture => 2,
-            Self::Item => 3,
-            Self::Character => 5,
+            Self::Trap => 1,
+            Self::Field => 2,
+            Self::Furniture => 3,
+            Self::Item => 4,
+            Self::Character => 6,
             Self::Vehicle => unimplemented!(),
-            Self::VehiclePart => 4,
-            Self::Meta => 6,
+            Self::VehiclePart => 5,
+            Self::Meta => 7,
         } + match &layer {
             SpriteLayer::Front => 1,
             SpriteLayer::Back => 0,
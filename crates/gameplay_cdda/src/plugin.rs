@@ -1,8 +1,8 @@
-use crate::{Infos, TileLoader, regions::RegionsPlugin};
+use crate::{ActiveSavExt as _, Infos, ModList, TileLoader, regions::RegionsPlugin};
 use application_state::ApplicationState;
-use bevy::prelude::{App, Commands, OnExit, Plugin};
+use bevy::prelude::{App, Commands, OnEnter, OnExit, Plugin, Res, info};
 use gameplay_cdda_active_sav::ActiveSav;
-use util::async_resource_plugin;
+use util::{async_resource_plugin, reload_async_resource};
 
 pub struct CddaPlugin;
 
@@ -14,11 +14,31 @@ impl Plugin for CddaPlugin {
             RegionsPlugin,
         ));
 
+        app.add_systems(OnEnter(ApplicationState::PreGameplay), load_world_mods);
+
         // ActiveSav is created in the main menu
         app.add_systems(OnExit(ApplicationState::Gameplay), remove_active_sav);
     }
 }
 
+/// Reload the infos when the mods of the active world differ from the loaded mods
+#[expect(clippy::needless_pass_by_value)]
+fn load_world_mods(mut commands: Commands, active_sav: Res<ActiveSav>, infos: Option<Res<Infos>>) {
+    let mods = ModList::for_world(&active_sav.world_path().0);
+
+    // Without infos, the infos for only the base game are still loading
+    let loaded_mods = infos.map_or_else(ModList::core, |infos| infos.mods.clone());
+    if mods != loaded_mods {
+        info!(
+            "Loading the infos for the mods {:?}",
+            mods.iter()
+                .map(|loaded_mod| &loaded_mod.name)
+                .collect::<Vec<_>>()
+        );
+        reload_async_resource(&mut commands, async move { Infos::load(mods) });
+    }
+}
+
 fn remove_active_sav(mut commands: Commands) {
     commands.remove_resource::<ActiveSav>();
 }
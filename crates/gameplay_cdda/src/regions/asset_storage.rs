@@ -1,6 +1,7 @@
 use crate::{AssetState, PathFor, regions::RegionAsset};
 use bevy::platform::collections::HashMap;
 use bevy::prelude::{AssetId, AssetServer, Assets, Handle, Resource};
+use cdda_json_files::SaveVersion;
 
 #[derive(Resource)]
 pub(super) struct AssetStorage<A: RegionAsset> {
@@ -15,9 +16,14 @@ impl<A: RegionAsset> AssetStorage<A> {
         assets: &'a Assets<A>,
         region: A::Region,
         path: PathFor<A>,
+        version: SaveVersion,
     ) -> AssetState<'a, A> {
         if path.0.exists() {
-            let region_handle = asset_server.load::<A>(path.0);
+            // The loaders use the version of the save for files without a version of their own
+            let region_handle = asset_server
+                .load_builder()
+                .with_settings(move |settings: &mut SaveVersion| *settings = version)
+                .load::<A>(path.0);
             let id = region_handle.id();
             self.live_handles.push(region_handle);
             self.regions.insert(id, region);
@@ -1,12 +1,19 @@
 use bevy::prelude::{Asset, TypePath};
-use cdda_json_files::{Map, MapMemory, Overmap, OvermapBuffer};
+use cdda_json_files::{Map, MapMemory, Overmap, OvermapBuffer, SaveVersion};
 use gameplay_location::{Overzone, ZoneLevel};
 use serde::Deserialize;
+use serde_json::Value as JsonValue;
 
 pub(super) trait RegionAsset: Asset {
     type Region: Clone + Copy;
 }
 
+/// An asset from a file in the overmap format, that starts with a version header
+pub(super) trait OvermapFileAsset: Asset + for<'de> Deserialize<'de> {
+    /// Converts the json of another save version to the current layout
+    fn adapt(version: SaveVersion, json: &mut JsonValue);
+}
+
 #[derive(Debug, Deserialize, Asset, TypePath)]
 pub struct MapAsset(pub Map);
 
@@ -28,9 +35,21 @@ impl RegionAsset for OvermapAsset {
     type Region = Overzone;
 }
 
+impl OvermapFileAsset for OvermapAsset {
+    fn adapt(version: SaveVersion, json: &mut JsonValue) {
+        version.adapt_overmap(json);
+    }
+}
+
 #[derive(Debug, Deserialize, Asset, TypePath)]
 pub struct OvermapBufferAsset(pub OvermapBuffer);
 
 impl RegionAsset for OvermapBufferAsset {
     type Region = Overzone;
 }
+
+impl OvermapFileAsset for OvermapBufferAsset {
+    fn adapt(version: SaveVersion, json: &mut JsonValue) {
+        version.adapt_overmap_buffer(json);
+    }
+}
@@ -2,7 +2,10 @@ use crate::MapAsset;
 use crate::error::Error;
 use bevy::asset::{AssetLoader, LoadContext, io::Reader};
 use bevy::prelude::TypePath;
-use serde_json::from_slice as from_json_slice;
+use cdda_json_files::SaveVersion;
+use serde_json::{
+    Value as JsonValue, from_slice as from_json_slice, from_value as from_json_value,
+};
 use std::{str::from_utf8, sync::Arc};
 
 #[derive(Default, TypePath)]
@@ -10,13 +13,14 @@ pub(super) struct MapLoader;
 
 impl AssetLoader for MapLoader {
     type Asset = MapAsset;
-    type Settings = ();
+    /// Only used for submaps without a version
+    type Settings = SaveVersion;
     type Error = Error;
 
     async fn load(
         &self,
         reader: &mut dyn Reader,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
@@ -25,12 +29,28 @@ impl AssetLoader for MapLoader {
             .await
             .map_err(|err| Error::Io { _wrapped: err })?;
 
-        let map = from_json_slice::<MapAsset>(&bytes).map_err(|err| Error::JsonWithContext {
-            _wrapped: err,
-            _file_path: load_context.path().path().to_path_buf(),
-            _contents: Arc::from(from_utf8(&bytes[0..1000]).unwrap_or("(invalid UTF8)")),
-        })?;
-        Ok(map)
+        from_json_slice::<MapAsset>(&bytes)
+            .or_else(|err| {
+                // Maps of other save versions are adapted first
+                let Ok(mut json) = from_json_slice::<JsonValue>(&bytes) else {
+                    return Err(err);
+                };
+                let current = json.as_array().is_some_and(|submaps| {
+                    submaps.iter().all(|submap| {
+                        SaveVersion::from_json(submap).unwrap_or(*settings) == SaveVersion::CURRENT
+                    })
+                });
+                if current {
+                    return Err(err);
+                }
+                settings.adapt_map(&mut json);
+                from_json_value::<MapAsset>(json)
+            })
+            .map_err(|err| Error::JsonWithContext {
+                _wrapped: err,
+                _file_path: load_context.path().path().to_path_buf(),
+                _contents: Arc::from(from_utf8(&bytes[0..1000]).unwrap_or("(invalid UTF8)")),
+            })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -22,8 +22,13 @@ impl MapManager<'_, '_> {
 
     fn map(&mut self, zone_level: ZoneLevel) -> AssetState<'_, MapAsset> {
         let path = self.path(zone_level);
-        self.storage
-            .handle(&self.asset_server, &self.assets, zone_level, path)
+        self.storage.handle(
+            &self.asset_server,
+            &self.assets,
+            zone_level,
+            path,
+            self.active_sav.version(),
+        )
     }
 
     pub fn submap(&mut self, subzone_level: SubzoneLevel) -> AssetState<'_, Submap> {
@@ -1,8 +1,11 @@
 use crate::MapMemoryAsset;
 use bevy::asset::{AssetLoader, LoadContext, io::Reader};
 use bevy::prelude::{TypePath, error};
+use cdda_json_files::SaveVersion;
 use either::Either;
-use serde_json::from_slice as from_json_slice;
+use serde_json::{
+    Value as JsonValue, from_slice as from_json_slice, from_value as from_json_value,
+};
 use std::str::from_utf8;
 
 #[derive(Default, TypePath)]
@@ -10,13 +13,14 @@ pub(super) struct MapMemoryLoader;
 
 impl AssetLoader for MapMemoryLoader {
     type Asset = MapMemoryAsset;
-    type Settings = ();
+    /// Map memory files have no version of their own, so this is the version of the save
+    type Settings = SaveVersion;
     type Error = Either<std::io::Error, serde_json::Error>;
 
     async fn load(
         &self,
         reader: &mut dyn Reader,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
@@ -28,7 +32,15 @@ impl AssetLoader for MapMemoryLoader {
             })
             .map_err(Either::Left)?;
 
-        let map_memory = from_json_slice::<MapMemoryAsset>(&bytes)
+        let map_memory = if *settings == SaveVersion::CURRENT {
+            from_json_slice::<MapMemoryAsset>(&bytes)
+        } else {
+            from_json_slice::<JsonValue>(&bytes).and_then(|mut json| {
+                settings.adapt_map_memory(&mut json);
+                from_json_value::<MapMemoryAsset>(json)
+            })
+        };
+        let map_memory = map_memory
             .map_err(|e| {
                 error!(
                     "Map memory json loading error: {:?} {:?} {e:?}",
@@ -28,9 +28,13 @@ impl MapMemoryManager<'_, '_> {
         };
         let path = MapMemoryPath::new(&sav_path, base_zone_level);
         //trace!("{:?}", &path);
-        let map_memory =
-            self.storage
-                .handle(&self.asset_server, &self.assets, base_zone_level, path);
+        let map_memory = self.storage.handle(
+            &self.asset_server,
+            &self.assets,
+            base_zone_level,
+            path,
+            self.active_sav.version(),
+        );
         match map_memory {
             AssetState::Available { asset: map_memory } => {
                 let index =
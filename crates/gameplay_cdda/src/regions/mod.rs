@@ -22,7 +22,7 @@ pub use self::overmap_manager::OvermapManager;
 pub(crate) use self::plugin::RegionsPlugin;
 
 use self::asset_storage::AssetStorage;
-use self::assets::RegionAsset;
+use self::assets::{OvermapFileAsset, RegionAsset};
 use self::map_loader::MapLoader;
 use self::map_memory_loader::MapMemoryLoader;
 use self::overmap_loader::OvermapLoader;
@@ -19,8 +19,13 @@ pub struct OvermapBufferManager<'w, 's> {
 impl OvermapBufferManager<'_, '_> {
     pub fn load(&mut self, overzone: Overzone) -> AssetState<'_, OvermapBufferAsset> {
         let path = OvermapBufferPath::new(&self.active_sav.sav_path(), overzone);
-        self.storage
-            .handle(&self.asset_server, &self.assets, overzone, path)
+        self.storage.handle(
+            &self.asset_server,
+            &self.assets,
+            overzone,
+            path,
+            self.active_sav.version(),
+        )
     }
 
     pub fn read_seen_zone_levels(&mut self) -> impl Iterator<Item = Exploration> + use<'_> + '_ {
@@ -1,27 +1,30 @@
-use bevy::asset::{Asset, AssetLoader, LoadContext, io::Reader};
-use bevy::prelude::{TypePath, error};
+use crate::regions::OvermapFileAsset;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::{TypePath, debug, error};
+use cdda_json_files::SaveVersion;
 use either::Either;
-use serde::Deserialize;
-use serde_json::from_slice as from_json_slice;
+use serde_json::{
+    Value as JsonValue, from_slice as from_json_slice, from_value as from_json_value,
+};
 use std::{marker::PhantomData, str::from_utf8};
 
 #[derive(TypePath)]
 pub(super) struct OvermapLoader<T>(PhantomData<T>)
 where
-    T: Asset;
+    T: OvermapFileAsset;
 
 impl<T> AssetLoader for OvermapLoader<T>
 where
-    for<'de> T: Asset + Deserialize<'de>,
+    T: OvermapFileAsset,
 {
     type Asset = T;
-    type Settings = ();
+    type Settings = SaveVersion;
     type Error = Either<std::io::Error, serde_json::Error>;
 
     async fn load(
         &self,
         reader: &mut dyn Reader,
-        _settings: &Self::Settings,
+        settings: &Self::Settings,
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
@@ -37,7 +40,11 @@ where
             .windows(1)
             .position(|window| window == b"\n")
             .expect("Version line");
-        let after_first_line = bytes.split_at(newline_pos).1;
+        let (first_line, after_first_line) = bytes.split_at(newline_pos);
+        let version = from_utf8(first_line)
+            .ok()
+            .and_then(SaveVersion::from_sav_header)
+            .unwrap_or(*settings);
 
         let file_name = load_context
             .path()
@@ -47,14 +54,22 @@ where
             .to_str()
             .expect("Unicode filename");
 
-        from_json_slice::<T>(after_first_line)
-            .inspect_err(|e| {
-                error!(
-                    "Overmap (buffer?) loading error: {file_name:?} {:?} {e:?}",
-                    from_utf8(&bytes[0..40])
-                );
+        if version == SaveVersion::CURRENT {
+            from_json_slice::<T>(after_first_line)
+        } else {
+            debug!("Adapting {file_name:?} from save version {version:?}");
+            from_json_slice::<JsonValue>(after_first_line).and_then(|mut json| {
+                T::adapt(version, &mut json);
+                from_json_value::<T>(json)
             })
-            .map_err(Either::Right)
+        }
+        .inspect_err(|e| {
+            error!(
+                "Overmap (buffer?) loading error: {file_name:?} {:?} {e:?}",
+                from_utf8(&bytes[0..40])
+            );
+        })
+        .map_err(Either::Right)
     }
 
     fn extensions(&self) -> &[&str] {
@@ -63,9 +78,9 @@ where
 }
 
 // `#[derive(Default)]` does not work.
-impl<'de, T> Default for OvermapLoader<T>
+impl<T> Default for OvermapLoader<T>
 where
-    T: Asset + Deserialize<'de>,
+    T: OvermapFileAsset,
 {
     fn default() -> Self {
         Self(PhantomData)
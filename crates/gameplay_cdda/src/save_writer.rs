@@ -0,0 +1,478 @@
+use crate::{
+    ActiveSavExt as _, Error, MapMemoryPath, MapPath, OvermapPath, PathFor, SavPath, WorldPath,
+};
+use bevy::prelude::debug;
+use cdda_json_files::SaveVersion;
+use gameplay_cdda_active_sav::ActiveSav;
+use gameplay_location::{Overzone, SubzoneLevel, Zone, ZoneLevel};
+use serde_json::{
+    Map as JsonMap, Value as JsonValue, from_str as from_json_str, json,
+    to_string as to_json_string,
+};
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::{iter::repeat_n, path::Path, sync::Arc};
+
+pub type JsonObject = JsonMap<String, JsonValue>;
+
+/// Writes the in-game state back to the files of the active save and its world.
+///
+/// Files are patched: only the given changes are applied. All unknown data is kept as is.
+/// Files of older versions are upgraded to [`SaveVersion::CURRENT`] first. Files of newer versions are not written.
+pub struct SaveWriter {
+    sav_path: SavPath,
+    world_path: WorldPath,
+    version: SaveVersion,
+}
+
+impl SaveWriter {
+    #[must_use]
+    pub fn new(active_sav: &ActiveSav) -> Self {
+        Self {
+            sav_path: active_sav.sav_path(),
+            world_path: active_sav.world_path(),
+            version: active_sav.version(),
+        }
+    }
+
+    /// Update the .sav file
+    ///
+    /// # Errors
+    /// When the file could not be read, parsed, or written
+    pub fn update_sav(&self, update: impl FnOnce(&mut JsonObject)) -> Result<(), Error> {
+        let versioning = Versioning::Header(self.version, SaveVersion::upgrade_sav);
+        update_file(&self.sav_path, versioning, |value| {
+            update(as_object(value, &self.sav_path.0)?);
+            Ok(())
+        })
+    }
+
+    /// Update the four submaps of a map file. Missing map files are only created when `create_missing` is set.
+    ///
+    /// Returns whether the file was written.
+    ///
+    /// # Errors
+    /// When the file could not be read, parsed, or written
+    pub fn update_map(
+        &self,
+        zone_level: ZoneLevel,
+        create_missing: bool,
+        mut update: impl FnMut(SubzoneLevel, &mut JsonObject),
+    ) -> Result<bool, Error> {
+        let path = MapPath::new(&self.world_path, zone_level);
+        if !path.0.exists() {
+            if !create_missing {
+                debug!("Skipped saving {path:?}, because it would be incomplete");
+                return Ok(false);
+            }
+            write_new(&path, &new_map(zone_level))?;
+        }
+
+        let versioning = Versioning::Json(self.version, SaveVersion::upgrade_map);
+        update_file(&path, versioning, |value| {
+            let JsonValue::Array(submaps) = value else {
+                return Err(unexpected_structure(&path.0));
+            };
+            for subzone_level in zone_level.subzone_levels() {
+                let coordinates = coordinates(subzone_level);
+                let submap = submaps
+                    .iter_mut()
+                    .filter_map(JsonValue::as_object_mut)
+                    .find(|submap| submap.get("coordinates") == Some(&coordinates))
+                    .ok_or_else(|| unexpected_structure(&path.0))?;
+                update(subzone_level, submap);
+            }
+            Ok(())
+        })?;
+        Ok(true)
+    }
+
+    /// Update the map memory of a submap, with the ids of the seen tiles.
+    ///
+    /// # Errors
+    /// When the file could not be read, parsed, or written
+    pub fn update_map_memory(
+        &self,
+        subzone_level: SubzoneLevel,
+        seen: &[Option<Arc<str>>; 144],
+    ) -> Result<(), Error> {
+        let zone = ZoneLevel::from(subzone_level).zone;
+        let base_zone_level = ZoneLevel {
+            zone: Zone {
+                x: 4 * zone.x.div_euclid(4),
+                z: 4 * zone.z.div_euclid(4),
+            },
+            level: subzone_level.level,
+        };
+        let path = MapMemoryPath::new(&self.sav_path, base_zone_level);
+        if !path.0.exists() {
+            write_new(&path, &JsonValue::Array(vec![JsonValue::Null; 64]))?;
+        }
+
+        let versioning = Versioning::Json(self.version, SaveVersion::upgrade_map_memory);
+        update_file(&path, versioning, |value| {
+            let list = match value {
+                JsonValue::Array(list) => list,
+                JsonValue::Object(map) => match map.get_mut("data") {
+                    Some(JsonValue::Array(list)) => list,
+                    _ => return Err(unexpected_structure(&path.0)),
+                },
+                _ => return Err(unexpected_structure(&path.0)),
+            };
+            let index =
+                (subzone_level.z.rem_euclid(8) * 8 + subzone_level.x.rem_euclid(8)) as usize;
+            let submap_memory = list
+                .get_mut(index)
+                .ok_or_else(|| unexpected_structure(&path.0))?;
+            *submap_memory = submap_memory_json(seen);
+            Ok(())
+        })
+    }
+
+    /// Update an existing overmap file
+    ///
+    /// # Errors
+    /// When the file could not be read, parsed, or written
+    pub fn update_overmap(
+        &self,
+        overzone: Overzone,
+        update: impl FnOnce(&mut JsonObject),
+    ) -> Result<(), Error> {
+        let path = OvermapPath::new(&self.world_path, overzone);
+        if !path.0.exists() {
+            return Ok(());
+        }
+
+        let versioning = Versioning::Header(self.version, SaveVersion::upgrade_overmap);
+        update_file(&path, versioning, |value| {
+            update(as_object(value, &path.0)?);
+            Ok(())
+        })
+    }
+}
+
+/// Compress a list of values, using the CDDA format for repetitions.
+#[must_use]
+pub fn repetitions_json(values: impl IntoIterator<Item = JsonValue>) -> JsonValue {
+    let mut result = Vec::<(JsonValue, u32)>::new();
+    for value in values {
+        match result.last_mut() {
+            Some((last, amount)) if *last == value => *amount += 1,
+            _ => result.push((value, 1)),
+        }
+    }
+
+    JsonValue::Array(
+        result
+            .into_iter()
+            .map(|(value, amount)| {
+                if amount == 1 {
+                    value
+                } else {
+                    json!([value, amount])
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Expand a list of values, that uses the CDDA format for repetitions.
+#[must_use]
+pub fn expand_repetitions(compressed: &JsonValue) -> Vec<JsonValue> {
+    let mut result = Vec::new();
+    for repetition in compressed.as_array().into_iter().flatten() {
+        match repetition.as_array().map(Vec::as_slice) {
+            Some([value, amount]) if amount.is_u64() => {
+                let amount = amount.as_u64().unwrap_or(1) as usize;
+                result.extend(repeat_n(value.clone(), amount));
+            }
+            _ => result.push(repetition.clone()),
+        }
+    }
+    result
+}
+
+fn submap_memory_json(seen: &[Option<Arc<str>>; 144]) -> JsonValue {
+    // Old format: type id, subtile, rotation, symbol, amount
+    let mut result = Vec::<(Option<&Arc<str>>, u8)>::new();
+    for type_id in seen {
+        match result.last_mut() {
+            Some((last, amount)) if *last == type_id.as_ref() => *amount += 1,
+            _ => result.push((type_id.as_ref(), 1)),
+        }
+    }
+
+    JsonValue::Array(
+        result
+            .into_iter()
+            .map(|(type_id, amount)| json!([type_id.map_or("", |t| &**t), 0, 0, 0, amount]))
+            .collect(),
+    )
+}
+
+fn new_map(zone_level: ZoneLevel) -> JsonValue {
+    let mut submaps = zone_level.subzone_levels();
+    submaps.sort_by_key(SubzoneLevel::index);
+    JsonValue::Array(
+        submaps
+            .into_iter()
+            .map(|subzone_level| {
+                json!({
+                    "version": SaveVersion::CURRENT,
+                    "coordinates": coordinates(subzone_level),
+                    "turn_last_touched": 0,
+                    "temperature": 0,
+                    "radiation": [],
+                    "terrain": [["t_open_air", 144]],
+                    "furniture": [],
+                    "items": [],
+                    "traps": [],
+                    "fields": [],
+                    "cosmetics": [],
+                    "spawns": [],
+                    "vehicles": [],
+                    "partial_constructions": []
+                })
+            })
+            .collect(),
+    )
+}
+
+fn coordinates(subzone_level: SubzoneLevel) -> JsonValue {
+    let (x, z, h) = subzone_level.coordinates();
+    json!([x, z, h])
+}
+
+fn as_object<'a>(value: &'a mut JsonValue, path: &Path) -> Result<&'a mut JsonObject, Error> {
+    value
+        .as_object_mut()
+        .ok_or_else(|| unexpected_structure(path))
+}
+
+fn unexpected_structure(path: &Path) -> Error {
+    Error::UnexpectedJsonStructure {
+        _file_path: path.to_path_buf(),
+    }
+}
+
+/// Converts the json of a file from a version to [`SaveVersion::CURRENT`], or returns the version when that is not possible
+type Upgrade = fn(SaveVersion, &mut JsonValue) -> Result<(), SaveVersion>;
+
+/// Where the version of a file is stored, with the version to assume when it is missing
+#[derive(Clone, Copy)]
+enum Versioning {
+    /// A non-JSON version line before the json, like in .sav and overmap files
+    Header(SaveVersion, Upgrade),
+    /// Inside the json, like in submaps, or not at all, like in map memory files
+    Json(SaveVersion, Upgrade),
+}
+
+/// Read, upgrade, update, and write back a file
+fn update_file<T>(
+    path: &PathFor<T>,
+    versioning: Versioning,
+    update: impl FnOnce(&mut JsonValue) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let contents = read_to_string(&path.0)?;
+    let (with_version_line, version, upgrade, json) = match versioning {
+        Versioning::Header(fallback, upgrade) => {
+            let (version_line, json) = contents
+                .split_once('\n')
+                .ok_or_else(|| unexpected_structure(&path.0))?;
+            let version = SaveVersion::from_sav_header(version_line).unwrap_or(fallback);
+            (true, version, upgrade, json)
+        }
+        Versioning::Json(fallback, upgrade) => (false, fallback, upgrade, contents.as_str()),
+    };
+
+    let mut value = from_json_str::<JsonValue>(json).map_err(|err| Error::JsonWithContext {
+        _wrapped: err,
+        _file_path: path.0.clone(),
+        _contents: Arc::from(json),
+    })?;
+    upgrade(version, &mut value).map_err(|version| Error::NewerSaveVersion {
+        _file_path: path.0.clone(),
+        _version: version,
+    })?;
+    update(&mut value)?;
+
+    let mut written = if with_version_line {
+        format!("# version {}\n", SaveVersion::CURRENT.0)
+    } else {
+        String::new()
+    };
+    written.push_str(&to_json_string(&value).expect("JSON values should be serializable"));
+
+    // Writing to a temporary file first prevents a half written file on failure.
+    let temporary_path = path.0.with_extension("tmp");
+    write(&temporary_path, written)?;
+    rename(temporary_path, &path.0)?;
+
+    debug!("Saved {path:?}");
+    Ok(())
+}
+
+fn write_new<T>(path: &PathFor<T>, value: &JsonValue) -> Result<(), Error> {
+    if let Some(parent) = path.0.parent() {
+        create_dir_all(parent)?;
+    }
+
+    write(
+        &path.0,
+        to_json_string(value).expect("JSON values should be serializable"),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod save_writer_tests {
+    use super::*;
+    use cdda_json_files::{Map, Repetition, RepetitionBlock, SubmapMemory};
+    use gameplay_location::Level;
+    use serde_json::from_value as from_json_value;
+    use std::env;
+
+    fn terrain() -> Vec<JsonValue> {
+        [
+            "t_grass", "t_grass", "t_grass", "t_dirt", "t_grass", "t_wall", "t_wall",
+        ]
+        .into_iter()
+        .map(JsonValue::from)
+        .collect()
+    }
+
+    #[test]
+    fn repetitions_round_trip() {
+        let compressed = repetitions_json(terrain());
+        assert_eq!(
+            compressed,
+            json!([["t_grass", 3], "t_dirt", "t_grass", ["t_wall", 2]])
+        );
+        assert_eq!(expand_repetitions(&compressed), terrain());
+    }
+
+    #[test]
+    fn repetitions_are_readable() {
+        let block = from_json_value::<RepetitionBlock<Arc<str>>>(repetitions_json(terrain()))
+            .expect("Valid repetitions");
+        let read = block
+            .0
+            .iter()
+            .map(Repetition::as_amount)
+            .flat_map(|amount| repeat_n(JsonValue::from(&*amount.obj), amount.amount as usize))
+            .collect::<Vec<_>>();
+        assert_eq!(read, terrain());
+    }
+
+    #[test]
+    fn expand_keeps_unknown_entries() {
+        let compressed = json!([["t_grass", 2], ["t_dirt", "extra"], 7]);
+        assert_eq!(
+            expand_repetitions(&compressed),
+            vec![
+                json!("t_grass"),
+                json!("t_grass"),
+                json!(["t_dirt", "extra"]),
+                json!(7)
+            ]
+        );
+        assert_eq!(expand_repetitions(&json!(null)), Vec::<JsonValue>::new());
+    }
+
+    #[test]
+    fn submap_memory_is_readable() {
+        let mut seen = [const { None }; 144];
+        seen[0] = Some(Arc::from("t_grass"));
+        seen[1] = Some(Arc::from("t_grass"));
+        seen[13] = Some(Arc::from("t_wall"));
+        seen[143] = Some(Arc::from("t_dirt"));
+
+        let memory =
+            from_json_value::<SubmapMemory>(submap_memory_json(&seen)).expect("Valid memory");
+        for z in 0..12 {
+            for x in 0..12 {
+                let index = usize::from(z * 12 + x);
+                assert_eq!(memory.seen(x, z), seen[index].is_some(), "({x}, {z})");
+            }
+        }
+    }
+
+    #[test]
+    fn new_map_is_current() {
+        let zone_level = ZoneLevel {
+            zone: Zone { x: 12, z: -3 },
+            level: Level::ZERO,
+        };
+        let map = new_map(zone_level);
+        for submap in map.as_array().expect("A map should be a list") {
+            assert_eq!(
+                SaveVersion::from_json(submap),
+                Some(SaveVersion::CURRENT),
+                "New submaps should have the current version"
+            );
+        }
+        let result = from_json_value::<Map>(map);
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    fn writer(directory: &str, version: SaveVersion) -> SaveWriter {
+        let world_path = env::temp_dir().join(directory);
+        create_dir_all(&world_path).expect("The world directory should be created");
+        SaveWriter {
+            sav_path: SavPath::init(world_path.join("#Tester.sav")),
+            world_path: WorldPath::init(world_path),
+            version,
+        }
+    }
+
+    #[test]
+    fn older_sav_is_upgraded() {
+        let writer = writer("rustaclysm-older-sav-test", SaveVersion(32));
+        write(
+            &writer.sav_path.0,
+            "# version 32\n{\"turn\": 7, \"fork_only\": 1}",
+        )
+        .expect("The sav should be written");
+
+        writer
+            .update_sav(|sav| {
+                sav.insert(String::from("turn"), json!(8));
+            })
+            .expect("An older sav should be upgradable");
+        let contents = read_to_string(&writer.sav_path.0).expect("The sav should be readable");
+        let (header, json) = contents.split_once('\n').expect("A version line");
+        assert_eq!(
+            SaveVersion::from_sav_header(header),
+            Some(SaveVersion::CURRENT)
+        );
+        let sav = from_json_str::<JsonValue>(json).expect("The sav should be json");
+        assert_eq!(sav["turn"], json!(8), "The update should be applied");
+        assert_eq!(sav["fork_only"], json!(1), "Unknown fields should be kept");
+        assert!(
+            sav.get("kill_tracker").is_some(),
+            "The sav should be adapted"
+        );
+    }
+
+    #[test]
+    fn newer_sav_is_refused() {
+        let writer = writer("rustaclysm-newer-sav-test", SaveVersion::CURRENT);
+        let original = "# version 36\n{\"turn\": 7}";
+        write(&writer.sav_path.0, original).expect("The sav should be written");
+
+        let result = writer.update_sav(|sav| {
+            sav.insert(String::from("turn"), json!(8));
+        });
+        assert!(
+            matches!(
+                result,
+                Err(Error::NewerSaveVersion {
+                    _version: SaveVersion(36),
+                    ..
+                })
+            ),
+            "{result:?}"
+        );
+        let contents = read_to_string(&writer.sav_path.0).expect("The sav should be readable");
+        assert_eq!(contents, original, "A newer sav should be unchanged");
+    }
+}
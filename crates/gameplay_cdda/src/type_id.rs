@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use strum::VariantArray;
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, Deserialize, VariantArray)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, Deserialize, Serialize, VariantArray)]
 pub(crate) enum TypeId {
     // Use this shell command to list all json types used:
     // find assets/data/json/ -type f | xargs -I {} jq '.[].type' {} 2>/dev/null | sort -u
@@ -17,6 +17,8 @@ pub(crate) enum TypeId {
     OvermapTerrain,
     #[serde(rename = "terrain")]
     Terrain,
+    #[serde(rename = "trap")]
+    Trap,
     #[serde(rename = "vehicle_part")]
     VehiclePart,
 
@@ -55,18 +57,62 @@ pub(crate) enum TypeId {
     Wheel,
 
     // Abstract types
+    #[serde(rename = "effect_type")]
+    EffectType,
     #[serde(rename = "item_action")]
     ItemAction,
     #[serde(rename = "item_group")]
     ItemGroup,
+    #[serde(rename = "material")]
+    Material,
     #[serde(rename = "practice")]
     Practice,
     #[serde(rename = "recipe")]
     Recipe,
     #[serde(rename = "requirement")]
     Requirement,
+    #[serde(rename = "region_settings")]
+    RegionSettings,
+    #[serde(rename = "skill")]
+    Skill,
     #[serde(rename = "tool_quality")]
     ToolQuality,
+    #[serde(rename = "uncraft")]
+    Uncraft,
+    #[serde(rename = "weather_type")]
+    WeatherType,
+
+    // Character creation types
+    #[serde(rename = "profession")]
+    Profession,
+    #[serde(rename = "scenario")]
+    Scenario,
+    #[serde(rename = "start_location")]
+    StartLocation,
+
+    // Construction types
+    #[serde(rename = "construction")]
+    Construction,
+    #[serde(rename = "construction_category")]
+    ConstructionCategory,
+    #[serde(rename = "construction_group")]
+    ConstructionGroup,
+
+    // Harvest types
+    #[serde(rename = "harvest")]
+    Harvest,
+    #[serde(rename = "harvest_drop_type")]
+    HarvestDropType,
+
+    // Map generation types
+    #[serde(rename = "mapgen")]
+    Mapgen,
+    #[serde(rename = "monstergroup")]
+    MonsterGroup,
+    #[serde(rename = "nested_category")]
+    NestedCategory,
+    #[serde(rename = "palette")]
+    Palette,
 
     // Migrations types
     #[serde(rename = "MIGRATION")]
@@ -121,20 +167,12 @@ pub(crate) enum TypeId {
     Conduct,
     #[serde(rename = "connect_group")]
     ConnectGroup,
-    #[serde(rename = "construction")]
-    Construction,
-    #[serde(rename = "construction_category")]
-    ConstructionCategory,
-    #[serde(rename = "construction_group")]
-    ConstructionGroup,
     #[serde(rename = "disease_type")]
     DiseaseType,
     #[serde(rename = "dream")]
     Dream,
     #[serde(rename = "effect_on_condition")]
     EffectOnCondition,
-    #[serde(rename = "effect_type")]
-    EffectType,
     #[serde(rename = "emit")]
     Emit,
     #[serde(rename = "enchantment")]
@@ -149,10 +187,6 @@ pub(crate) enum TypeId {
     Fault,
     #[serde(rename = "gate")]
     Gate,
-    #[serde(rename = "harvest")]
-    Harvest,
-    #[serde(rename = "harvest_drop_type")]
-    HarvestDropType,
     #[serde(rename = "hit_range")]
     HitRange,
     #[serde(rename = "ITEM_CATEGORY")]
@@ -168,12 +202,8 @@ pub(crate) enum TypeId {
     #[serde(rename = "map_extra")]
     MapExtra,
 
-    #[serde(rename = "mapgen")]
-    Mapgen,
     #[serde(rename = "martial_art")]
     MartialArt,
-    #[serde(rename = "material")]
-    Material,
     #[serde(rename = "mission_definition")]
     MissionDefinition,
     #[serde(rename = "monster_attack")]
@@ -184,8 +214,6 @@ pub(crate) enum TypeId {
     MonsterFaction,
     #[serde(rename = "monster_flag")]
     MonsterFlag,
-    #[serde(rename = "monstergroup")]
-    MonsterGroup,
     #[serde(rename = "mood_face")]
     MoodFace,
     #[serde(rename = "morale_type")]
@@ -198,8 +226,6 @@ pub(crate) enum TypeId {
     MutationCategory,
     #[serde(rename = "mutation_type")]
     MutationType,
-    #[serde(rename = "nested_category")]
-    NestedCategory,
     #[serde(rename = "npc")]
     Npc,
     #[serde(rename = "npc_class")]
@@ -224,10 +250,6 @@ pub(crate) enum TypeId {
 
     #[serde(rename = "overmap_special_migration")]
     OvermapSpecialMigration,
-    #[serde(rename = "palette")]
-    Palette,
-    #[serde(rename = "profession")]
-    Profession,
     #[serde(rename = "profession_item_substitutions")]
     ProfessionItemSubstitutions,
     #[serde(rename = "proficiency")]
@@ -238,14 +260,10 @@ pub(crate) enum TypeId {
     RecipeCategory,
     #[serde(rename = "recipe_group")]
     RecipeGroup,
-    #[serde(rename = "region_settings")]
-    RegionSettings,
     #[serde(rename = "relic_procgen_data")]
     RelicProcgenData,
     #[serde(rename = "rotatable_symbol")]
     RotatableSymbol,
-    #[serde(rename = "scenario")]
-    Scenario,
     #[serde(rename = "scent_type")]
     ScentType,
     #[serde(rename = "score")]
@@ -254,8 +272,6 @@ pub(crate) enum TypeId {
     ShopkeeperBlacklist,
     #[serde(rename = "shopkeeper_consumption_rates")]
     ShopkeeperConsumptionRates,
-    #[serde(rename = "skill")]
-    Skill,
     #[serde(rename = "skill_display_type")]
     SkillDisplayType,
     #[serde(rename = "snippet")]
@@ -268,8 +284,6 @@ pub(crate) enum TypeId {
     SpeedDescription,
     #[serde(rename = "SPELL")]
     Spell,
-    #[serde(rename = "start_location")]
-    StartLocation,
     #[serde(rename = "sub_body_part")]
     SubBodyPart,
     #[serde(rename = "talk_topic")]
@@ -282,10 +296,6 @@ pub(crate) enum TypeId {
     TraitGroup,
     #[serde(rename = "TRAIT_MIGRATION")]
     TraitMigration,
-    #[serde(rename = "trap")]
-    Trap,
-    #[serde(rename = "uncraft")]
-    Uncraft,
     #[serde(rename = "vehicle")]
     Vehicle,
     #[serde(rename = "vehicle_group")]
@@ -302,8 +312,6 @@ pub(crate) enum TypeId {
     WeakpointSet,
     #[serde(rename = "weapon_category")]
     WeaponCategory,
-    #[serde(rename = "weather_type")]
-    WeatherType,
     #[serde(rename = "widget")]
     Widget,
 }
@@ -1,7 +1,7 @@
 use crate::Error;
-use bevy::prelude::{Resource, debug};
-use cdda_json_files::Sav;
-use serde_json::from_str as from_json_str;
+use bevy::prelude::{Resource, debug, warn};
+use cdda_json_files::{Sav, SaveVersion};
+use serde_json::{Value as JsonValue, from_str as from_json_str, from_value as from_json_value};
 use std::{fs::read_to_string, path::PathBuf};
 use util::AssetPaths;
 
@@ -11,6 +11,7 @@ use util::AssetPaths;
 #[derive(Resource)]
 pub struct ActiveSav {
     sav_path: PathBuf,
+    version: SaveVersion,
     sav: Sav,
 }
 
@@ -18,33 +19,48 @@ impl ActiveSav {
     /// # Errors
     /// - When the file could not be read
     /// - When the first non-JSON line is missing
-    /// - When the rest of the file could not be parsed
+    /// - When the rest of the file could not be parsed, even after adapting it to the current save version
     pub fn new(path: &PathBuf) -> Result<Self, Error> {
         let sav_path = AssetPaths::save().join(path);
         //trace!("Loading {}...", sav_path.display());
 
-        let sav = read_to_string(&sav_path)
-            .map_err(|err| Error::Io { _wrapped: err })
-            .inspect(|_| {
-                debug!("Loading {}...", sav_path.display());
-            })
-            .and_then(|s: String| {
-                Ok(String::from(
-                    s.split_at(s.find('\n').ok_or_else(|| Error::MissingFirstLine {
-                        _path: sav_path.clone(),
-                        _contents: s.as_str().into(),
-                    })?)
-                    .1,
-                ))
-            })
-            .and_then(|s| {
-                from_json_str::<Sav>(s.as_str()).map_err(|err| Error::JsonWithContext {
-                    _wrapped: err,
-                    _file_path: path.clone(),
-                    _contents: s.as_str().into(),
-                })
-            })?;
-        Ok(Self { sav_path, sav })
+        let contents = read_to_string(&sav_path).map_err(|err| Error::Io { _wrapped: err })?;
+        debug!("Loading {}...", sav_path.display());
+
+        let (header, json) =
+            contents.split_at(contents.find('\n').ok_or_else(|| Error::MissingFirstLine {
+                _path: sav_path.clone(),
+                _contents: contents.as_str().into(),
+            })?);
+        let version = SaveVersion::from_sav_header(header).unwrap_or_else(|| {
+            warn!(
+                "Unexpected header {header:?} in {}, assuming the current version",
+                sav_path.display()
+            );
+            SaveVersion::CURRENT
+        });
+
+        let json_error = |err| Error::JsonWithContext {
+            _wrapped: err,
+            _file_path: path.clone(),
+            _contents: json.into(),
+        };
+        let sav = if version == SaveVersion::CURRENT {
+            from_json_str::<Sav>(json).map_err(json_error)?
+        } else {
+            debug!(
+                "Adapting {} from save version {version:?}",
+                sav_path.display()
+            );
+            let mut sav = from_json_str::<JsonValue>(json).map_err(json_error)?;
+            version.adapt_sav(&mut sav);
+            from_json_value::<Sav>(sav).map_err(json_error)?
+        };
+        Ok(Self {
+            sav_path,
+            version,
+            sav,
+        })
     }
 
     #[must_use]
@@ -52,6 +68,12 @@ impl ActiveSav {
         &self.sav_path
     }
 
+    /// From the header of the .sav file
+    #[must_use]
+    pub const fn version(&self) -> SaveVersion {
+        self.version
+    }
+
     #[must_use]
     pub const fn sav(&self) -> &Sav {
         &self.sav
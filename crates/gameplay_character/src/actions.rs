@@ -1,8 +1,9 @@
 use crate::{Actor, ActorItem};
 use bevy::prelude::{Entity, Query};
-use gameplay_crafting::RecipeSituation;
+use gameplay_crafting::{ConstructionSituation, DisassemblySituation, RecipeSituation};
 use gameplay_item::{Item, ItemItem};
-use gameplay_location::{CardinalDirection, HorizontalDirection, Nbor};
+use gameplay_location::{CardinalDirection, HorizontalDirection, Nbor, Pos};
+use std::sync::Arc;
 
 /// An action that an actor can perform
 pub trait Action: Clone + Send + Sync + 'static {}
@@ -51,6 +52,14 @@ pub struct Attack {
 
 impl Action for Attack {}
 
+/// Shoot the wielded gun at any visible position
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fire {
+    pub target: Pos,
+}
+
+impl Action for Fire {}
+
 #[derive(Clone, Debug)]
 pub struct Smash {
     pub target: Nbor,
@@ -65,6 +74,13 @@ pub struct Pulp {
 
 impl Action for Pulp {}
 
+#[derive(Clone, Debug)]
+pub struct Butcher {
+    pub target: HorizontalDirection,
+}
+
+impl Action for Butcher {}
+
 #[derive(Clone, Debug)]
 pub struct Peek {
     pub target: CardinalDirection,
@@ -79,6 +95,13 @@ pub struct Close {
 
 impl Action for Close {}
 
+#[derive(Clone, Debug)]
+pub struct Disarm {
+    pub target: HorizontalDirection,
+}
+
+impl Action for Disarm {}
+
 pub trait ItemAction: Action {
     fn item_entity(&self) -> Entity;
 
@@ -113,6 +136,32 @@ impl ItemAction for Unwield {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct Wear {
+    pub item_entity: Entity,
+}
+
+impl Action for Wear {}
+
+impl ItemAction for Wear {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TakeOff {
+    pub item_entity: Entity,
+}
+
+impl Action for TakeOff {}
+
+impl ItemAction for TakeOff {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Pickup {
     pub item_entity: Entity,
@@ -161,6 +210,106 @@ impl ItemAction for ContinueCraft {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct StartConstruction {
+    pub construction_situation: ConstructionSituation,
+    pub target: HorizontalDirection,
+}
+
+impl Action for StartConstruction {}
+
+#[derive(Clone, Debug)]
+pub struct ContinueConstruction {
+    pub site_entity: Entity,
+}
+
+impl Action for ContinueConstruction {}
+
+#[derive(Clone, Debug)]
+pub struct StartDisassembly {
+    pub disassembly_situation: DisassemblySituation,
+}
+
+impl Action for StartDisassembly {}
+
+#[derive(Clone, Debug)]
+pub struct ContinueDisassembly {
+    pub item_entity: Entity,
+}
+
+impl Action for ContinueDisassembly {}
+
+impl ItemAction for ContinueDisassembly {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Eat {
+    pub item_entity: Entity,
+}
+
+impl Action for Eat {}
+
+impl ItemAction for Eat {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drink {
+    pub item_entity: Entity,
+}
+
+impl Action for Drink {}
+
+impl ItemAction for Drink {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
+/// Loading ammo into a magazine, or a magazine into a gun or tool
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reload {
+    pub item_entity: Entity,
+}
+
+impl Action for Reload {}
+
+impl ItemAction for Reload {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
+/// Removing the ammo from a magazine, or the magazine from a gun or tool
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unload {
+    pub item_entity: Entity,
+}
+
+impl Action for Unload {}
+
+impl ItemAction for Unload {
+    fn item_entity(&self) -> Entity {
+        self.item_entity
+    }
+}
+
+/// Reading or practicing
+#[derive(Clone, Debug)]
+pub struct Train {
+    pub skill: Arc<str>,
+
+    /// No progress is made at or above this level
+    pub cap: Option<u8>,
+}
+
+impl Action for Train {}
+
 /// Redundantly named to avoid confusion
 #[derive(Clone, Debug, PartialEq)]
 pub struct ExamineItem {
@@ -1,10 +1,16 @@
+use crate::{ApplyEffect, Exercise, Nourishment};
 use bevy::prelude::{Entity, Message};
 use gameplay_object::{Damage, Healing};
+use gameplay_terrain::TrapTrigger;
 
 pub trait CharacterChange: Clone + Send + Sync + 'static {}
 
+impl CharacterChange for ApplyEffect {}
 impl CharacterChange for Damage {}
+impl CharacterChange for Exercise {}
 impl CharacterChange for Healing {}
+impl CharacterChange for Nourishment {}
+impl CharacterChange for TrapTrigger {}
 
 #[must_use]
 #[derive(Clone, Debug, Message)]
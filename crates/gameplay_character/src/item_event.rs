@@ -0,0 +1,22 @@
+use bevy::prelude::{Entity, Message};
+use gameplay_object::Damage;
+
+pub trait ItemChange: Clone + Send + Sync + 'static {}
+
+impl ItemChange for Damage {}
+
+#[must_use]
+#[derive(Clone, Debug, Message)]
+pub struct ItemEvent<C: ItemChange> {
+    pub item_entity: Entity,
+    pub change: C,
+}
+
+impl<C: ItemChange> ItemEvent<C> {
+    pub const fn new(item_entity: Entity, change: C) -> Self {
+        Self {
+            item_entity,
+            change,
+        }
+    }
+}
@@ -4,23 +4,30 @@ mod character_event;
 mod corpse_event;
 mod faction;
 mod impact;
+mod item_event;
 mod messages;
 mod plugin;
 mod query_data;
+mod shot;
 mod stats;
 
 pub use self::actions::{
-    Action, ActionIn, Attack, ChangePace, Close, ContinueCraft, ExamineItem, ItemAction, MoveItem,
-    Peek, Pickup, Pulp, Sleep, Smash, StartCraft, Stay, Step, Unwield, Wield,
+    Action, ActionIn, Attack, Butcher, ChangePace, Close, ContinueConstruction, ContinueCraft,
+    ContinueDisassembly, Disarm, Drink, Eat, ExamineItem, Fire, ItemAction, MoveItem, Peek, Pickup,
+    Pulp, Reload, Sleep, Smash, StartConstruction, StartCraft, StartDisassembly, Stay, Step,
+    TakeOff, Train, Unload, Unwield, Wear, Wield,
 };
 pub use self::breath::Breath;
 pub use self::character_event::CharacterEvent;
 pub use self::corpse_event::CorpseEvent;
 pub use self::faction::{BaseFaction, Faction, Intelligence, LastEnemy};
 pub use self::impact::{ActorImpact, Impact};
+pub use self::item_event::ItemEvent;
 pub use self::plugin::CharacterPlugin;
 pub use self::query_data::{Actor, ActorItem};
 pub use self::stats::{
-    Aquatic, BaseSpeed, HealingDuration, Health, Melee, Stamina, StaminaCost, StaminaImpact,
+    ActiveEffect, ApplyEffect, Aquatic, BaseSpeed, BodyTemperature, Effects, EffectsUpdate,
+    Encumbrance, Exercise, HealingDuration, Health, Hunger, Melee, Nourishment, Nutrition,
+    Perception, SkillLevel, Skills, Snared, Stamina, StaminaCost, StaminaImpact, Thirst,
     WalkingMode,
 };
@@ -19,6 +19,19 @@ impl ProtoLogMessage for AttackNothing {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct ButcherNothing {
+    pub(super) subject: Subject,
+}
+
+impl ProtoLogMessage for ButcherNothing {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("butcher", "s").hard("nothing")
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct CantClose {
     pub(super) subject: Subject,
@@ -52,6 +65,146 @@ impl ProtoLogMessage for CantCloseOn {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct CantConsume {
+    pub(super) subject: Subject,
+    pub(super) verb: &'static str,
+    pub(super) item: Fragment,
+}
+
+impl ProtoLogMessage for CantConsume {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.simple("can't").hard(self.verb).push(self.item)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantReload {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+    pub(super) reason: &'static str,
+}
+
+impl ProtoLogMessage for CantReload {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .simple("can't reload")
+            .extend(self.item)
+            .hard(self.reason)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantTakeOff {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for CantTakeOff {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.simple("can't take off").extend(self.item)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantUnload {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for CantUnload {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .simple("can't unload")
+            .extend(self.item)
+            .hard("without anything loaded")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantWear {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for CantWear {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.simple("can't wear").extend(self.item)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantWearWith {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+    pub(super) worn: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for CantWearWith {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .simple("can't wear")
+            .extend(self.item)
+            .soft("together with")
+            .extend(self.worn)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantDisarm {
+    pub(super) subject: Subject,
+    pub(super) trap: Fragment,
+}
+
+impl ProtoLogMessage for CantDisarm {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.simple("can't disarm").push(self.trap)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct CantFire {
+    pub(super) subject: Subject,
+    pub(super) reason: &'static str,
+}
+
+impl ProtoLogMessage for CantFire {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.simple("can't fire").hard(self.reason)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Consume {
+    pub(super) subject: Subject,
+    pub(super) verb: &'static str,
+    pub(super) item: Fragment,
+}
+
+impl ProtoLogMessage for Consume {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb(self.verb, "s").push(self.item)
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct CrashInto<'a> {
     pub(super) subject: Subject,
@@ -70,6 +223,33 @@ impl ProtoLogMessage for CrashInto<'_> {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct Disarmed {
+    pub(super) subject: Subject,
+    pub(super) trap: Fragment,
+}
+
+impl ProtoLogMessage for Disarmed {
+    const SEVERITY: Severity = Severity::Success;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("disarm", "s").push(self.trap)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct DisarmNothing {
+    pub(super) subject: Subject,
+}
+
+impl ProtoLogMessage for DisarmNothing {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("disarm", "s").hard("nothing")
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Drop {
     pub(super) subject: Subject,
@@ -84,6 +264,44 @@ impl ProtoLogMessage for Drop {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct FailToDisarm {
+    pub(super) subject: Subject,
+    pub(super) trap: Fragment,
+    pub(super) set_off: bool,
+}
+
+impl ProtoLogMessage for FailToDisarm {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        let phrase = self
+            .subject
+            .verb("fail", "s")
+            .soft("to disarm")
+            .push(self.trap);
+        if self.set_off {
+            phrase.soft("and").hard("set it off")
+        } else {
+            phrase
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct FireGun {
+    pub(super) subject: Subject,
+    pub(super) gun: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for FireGun {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("fire", "s").extend(self.gun)
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct HaltAtTheLedge {
     pub(super) subject: Subject,
@@ -115,6 +333,25 @@ impl ProtoLogMessage for IsTooExhaustedTo {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct LoadInto {
+    pub(super) subject: Subject,
+    pub(super) loaded: Vec<Fragment>,
+    pub(super) into: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for LoadInto {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .verb("load", "s")
+            .extend(self.loaded)
+            .hard("into")
+            .extend(self.into)
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Move {
     pub(super) subject: Subject,
@@ -129,6 +366,36 @@ impl ProtoLogMessage for Move {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct Miss {
+    pub(super) subject: Subject,
+}
+
+impl ProtoLogMessage for Miss {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("miss", "es")
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct NeedCuttingTool {
+    pub(super) subject: Subject,
+}
+
+impl ProtoLogMessage for NeedCuttingTool {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .verb("need", "s")
+            .hard("a cutting tool")
+            .soft("to")
+            .hard("butcher")
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct PickUp {
     pub(super) subject: Subject,
@@ -143,6 +410,20 @@ impl ProtoLogMessage for PickUp {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct PutOn {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for PutOn {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("put", "s").hard("on").extend(self.item)
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct PulpNothing {
     pub(super) subject: Subject,
@@ -170,6 +451,30 @@ impl ProtoLogMessage for SmashInvalid {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct Struggle {
+    pub(super) subject: Subject,
+    pub(super) in_pit: bool,
+    pub(super) free: bool,
+}
+
+impl ProtoLogMessage for Struggle {
+    const SEVERITY: Severity = Severity::Danger;
+
+    fn phrase(self) -> Phrase {
+        let phrase = self.subject.verb("struggle", "s").soft(if self.in_pit {
+            "to climb out of the pit"
+        } else {
+            "against the trap"
+        });
+        if self.free {
+            phrase.soft("and").hard("break free")
+        } else {
+            phrase
+        }
+    }
+}
+
 #[derive()]
 pub(super) struct SubzoneNotFoundWhileMovingAnItem;
 
@@ -181,6 +486,20 @@ impl ProtoLogMessage for SubzoneNotFoundWhileMovingAnItem {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct TakeOff {
+    pub(super) subject: Subject,
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for TakeOff {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject.verb("take", "s").hard("off").extend(self.item)
+    }
+}
+
 #[derive()]
 pub(super) struct TooFarToMove;
 
@@ -192,6 +511,44 @@ impl ProtoLogMessage for TooFarToMove {
     }
 }
 
+#[derive(Debug)]
+pub(super) struct TooFull {
+    pub(super) subject: Subject,
+    pub(super) verb: &'static str,
+    pub(super) item: Fragment,
+}
+
+impl ProtoLogMessage for TooFull {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .is()
+            .hard("too full to")
+            .hard(self.verb)
+            .push(self.item)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct UnloadFrom {
+    pub(super) subject: Subject,
+    pub(super) unloaded: Vec<Fragment>,
+    pub(super) from: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for UnloadFrom {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.subject
+            .verb("unload", "s")
+            .extend(self.unloaded)
+            .hard("from")
+            .extend(self.from)
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct YouCant {
     pub(super) verb: &'static str,
@@ -225,10 +582,10 @@ impl<const SUCCESS: bool> ProtoLogMessage for YouFinish<SUCCESS> {
     const SEVERITY: Severity = Self::severity();
 
     fn phrase(self) -> Phrase {
-        Self::you("finish").hard(if let PlayerActionState::Crafting { .. } = self.action {
-            String::from("your craft")
-        } else {
-            self.action.to_string().to_lowercase()
+        Self::you("finish").hard(match self.action {
+            PlayerActionState::Crafting { .. } => String::from("your craft"),
+            PlayerActionState::Constructing { .. } => String::from("your construction"),
+            _ => self.action.to_string().to_lowercase(),
         })
     }
 }
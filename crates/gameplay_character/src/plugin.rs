@@ -1,17 +1,28 @@
-use crate::{CharacterEvent, CorpseEvent};
+use crate::{ApplyEffect, CharacterEvent, CorpseEvent, Exercise, ItemEvent, Nourishment};
 use application_state::ApplicationState;
 use bevy::prelude::{App, Plugin, StateScopedMessagesAppExt as _};
 use gameplay_object::{Damage, Healing};
+use gameplay_terrain::TrapTrigger;
 
 pub struct CharacterPlugin;
 
 impl Plugin for CharacterPlugin {
     fn build(&self, app: &mut App) {
+        app.add_message::<CharacterEvent<ApplyEffect>>()
+            .clear_messages_on_exit::<CharacterEvent<ApplyEffect>>(ApplicationState::Gameplay);
         app.add_message::<CharacterEvent<Damage>>()
             .clear_messages_on_exit::<CharacterEvent<Damage>>(ApplicationState::Gameplay);
+        app.add_message::<CharacterEvent<Exercise>>()
+            .clear_messages_on_exit::<CharacterEvent<Exercise>>(ApplicationState::Gameplay);
         app.add_message::<CharacterEvent<Healing>>()
             .clear_messages_on_exit::<CharacterEvent<Healing>>(ApplicationState::Gameplay);
+        app.add_message::<CharacterEvent<Nourishment>>()
+            .clear_messages_on_exit::<CharacterEvent<Nourishment>>(ApplicationState::Gameplay);
+        app.add_message::<CharacterEvent<TrapTrigger>>()
+            .clear_messages_on_exit::<CharacterEvent<TrapTrigger>>(ApplicationState::Gameplay);
         app.add_message::<CorpseEvent<Damage>>()
             .clear_messages_on_exit::<CorpseEvent<Damage>>(ApplicationState::Gameplay);
+        app.add_message::<ItemEvent<Damage>>()
+            .clear_messages_on_exit::<ItemEvent<Damage>>(ApplicationState::Gameplay);
     }
 }
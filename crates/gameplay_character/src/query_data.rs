@@ -1,38 +1,53 @@
 use crate::messages::{
-    AttackNothing, CantClose, CantCloseOn, CrashInto, Drop, HaltAtTheLedge, IsTooExhaustedTo, Move,
-    PickUp, PulpNothing, SmashInvalid, SubzoneNotFoundWhileMovingAnItem, TooFarToMove, YouCant,
+    AttackNothing, ButcherNothing, CantClose, CantCloseOn, CantConsume, CantDisarm, CantFire,
+    CantReload, CantTakeOff, CantUnload, CantWear, CantWearWith, Consume, CrashInto, DisarmNothing,
+    Disarmed, Drop, FailToDisarm, FireGun, HaltAtTheLedge, IsTooExhaustedTo, LoadInto, Miss, Move,
+    NeedCuttingTool, PickUp, PulpNothing, PutOn, SmashInvalid, Struggle,
+    SubzoneNotFoundWhileMovingAnItem, TakeOff, TooFarToMove, TooFull, UnloadFrom, YouCant,
     YouFinish, YouSleepFor,
 };
+use crate::shot::Shot;
 use crate::{
-    ActorImpact, Aquatic, Attack, BaseSpeed, Breath, ChangePace, CharacterEvent, Close,
-    CorpseEvent, Faction, HealingDuration, Health, LastEnemy, Melee, Peek, Pulp, Smash, Stamina,
-    StaminaCost, StartCraft, Step, WalkingMode,
+    ActorImpact, ApplyEffect, Aquatic, Attack, BaseSpeed, Breath, Butcher, ChangePace,
+    CharacterEvent, Close, CorpseEvent, Disarm, Effects, Encumbrance, Exercise, Faction,
+    HealingDuration, Health, ItemEvent, LastEnemy, Melee, Nourishment, Nutrition, Peek, Perception,
+    Pulp, Skills, Smash, Snared, Stamina, StaminaCost, StartConstruction, StartCraft,
+    StartDisassembly, Step, Train, WalkingMode,
 };
 use bevy::ecs::query::{QueryData, With};
 use bevy::prelude::{
     Commands, Entity, Message, MessageWriter, NextState, Query, Transform, Visibility, error,
 };
-use cdda_json_files::CddaItem;
+use cdda_json_files::{CddaItem, CharacterInfo, CommonItemInfo, PocketInfo, PocketType};
 use either::Either;
-use gameplay_common::{LastSeen, Tile};
-use gameplay_crafting::{Consumed, Craft, CraftProgressLeft, CraftSpawner};
+use fastrand::{bool as rand_bool, u8 as rand_u8, u16 as rand_u16};
+use gameplay_common::{LastSeen, Shared, Tile};
+use gameplay_crafting::{
+    Butchery, ButcheryProgressLeft, ConstructionProgressLeft, ConstructionSite,
+    ConstructionSpawner, Consumed, Craft, CraftProgressLeft, CraftSpawner, Disassembly,
+    DisassemblyProgressLeft, HarvestSpawner,
+};
 use gameplay_item::{
     Amount, BodyContainers, Container, InPocket, Item, ItemHierarchy, ItemItem, ItemSpawner,
-    WieldedBy,
+    ItemTemperature, WieldedBy,
 };
 use gameplay_location::{HorizontalDirection, LevelOffset, LocationCache, Nbor, Pos};
 use gameplay_log::LogMessageWriter;
 use gameplay_object::Life;
-use gameplay_object::{Damage, Healing, ObjectName};
+use gameplay_object::{Damage, DamageType, Healing, ObjectName};
 use gameplay_player::{Player, PlayerActionState};
 use gameplay_relations::ObjectOn;
-use gameplay_terrain::{TerrainEvent, Toggle};
+use gameplay_terrain::{Construct, TerrainEvent, Toggle, TrapDropSpawner, TrapTrigger};
 use gameplay_time::Clock;
-use gameplay_world::{Collision, Envir};
-use text::{Phrase, Subject};
+use gameplay_world::{Collision, Envir, Noise};
+use std::iter::{once, successors};
+use text::{Fragment, Phrase, Subject};
 use units::{Distance, Duration, Speed};
 use util::Maybe;
 
+const MELEE_SKILL: &str = "melee";
+const GUN_SKILL: &str = "gun";
+
 #[derive(QueryData)]
 #[query_data(derive(Debug))]
 pub struct Actor {
@@ -41,15 +56,22 @@ pub struct Actor {
     pub pos: &'static Pos,
     pub base_speed: &'static BaseSpeed,
     pub health: &'static Health,
+    pub character_info: Option<&'static Shared<CharacterInfo>>,
     pub faction: &'static Faction,
     pub melee: &'static Melee,
     pub body_containers: Option<&'static BodyContainers>,
     pub aquatic: Option<&'static Aquatic>,
+    pub effects: Option<&'static Effects>,
+    pub nutrition: Option<&'static Nutrition>,
+    pub encumbrance: Option<&'static Encumbrance>,
     pub last_enemy: Option<&'static LastEnemy>,
     pub stamina: &'static Stamina,
     pub walking_mode: &'static WalkingMode,
     pub life: &'static Life,
     pub player: Option<&'static Player>,
+    pub perception: Option<&'static Perception>,
+    pub skills: Option<&'static Skills>,
+    pub snared: Option<&'static Snared>,
 }
 
 impl ActorItem<'_, '_> {
@@ -61,20 +83,27 @@ impl ActorItem<'_, '_> {
         }
     }
 
-    pub const fn speed(&self) -> Speed {
+    pub fn speed(&self) -> Speed {
         self.base_speed
             .speed(self.walking_mode, self.stamina.breath())
+            * self.speed_factor()
     }
 
-    const fn high_speed(&self) -> Option<Speed> {
+    fn high_speed(&self) -> Option<Speed> {
         match self.stamina.breath() {
-            Breath::Normal | Breath::AlmostWinded => {
-                Some(self.base_speed.speed(&WalkingMode::Running, Breath::Normal))
-            }
+            Breath::Normal | Breath::AlmostWinded => Some(
+                self.base_speed.speed(&WalkingMode::Running, Breath::Normal) * self.speed_factor(),
+            ),
             Breath::Winded => None,
         }
     }
 
+    fn speed_factor(&self) -> f32 {
+        self.effects.map_or(1.0, Effects::speed_factor)
+            * self.nutrition.map_or(1.0, Nutrition::speed_factor)
+            * self.encumbrance.map_or(1.0, Encumbrance::speed_factor)
+    }
+
     const fn hands<'a>(&self, hierarchy: &'a ItemHierarchy) -> Container<'a> {
         Container::new(
             self.body_containers.expect("Body containers present").hands,
@@ -91,6 +120,21 @@ impl ActorItem<'_, '_> {
         )
     }
 
+    /// The first worn storage pocket with room for the item, otherwise the hands
+    fn stowage<'a>(&self, hierarchy: &'a ItemHierarchy, stowed: &ItemItem) -> Container<'a> {
+        let body_containers = self.body_containers.expect("Body containers present");
+        hierarchy
+            .storage_pockets(body_containers.clothing, stowed.entity)
+            .into_iter()
+            .map(|in_pocket| Container::new(in_pocket, hierarchy))
+            .find(|container| {
+                container
+                    .check_add(self.subject(), stowed.containable, *stowed.amount)
+                    .is_ok()
+            })
+            .unwrap_or_else(|| self.hands(hierarchy))
+    }
+
     const fn no_impact(&self) -> ActorImpact {
         ActorImpact::none(self.entity)
     }
@@ -161,15 +205,26 @@ impl ActorItem<'_, '_> {
         commands: &mut Commands,
         message_writer: &mut LogMessageWriter,
         toggle_writer: &mut MessageWriter<TerrainEvent<Toggle>>,
+        trap_writer: &mut MessageWriter<CharacterEvent<TrapTrigger>>,
         envir: &mut Envir,
         step: &Step,
     ) -> ActorImpact {
+        if let Some(snared) = self.snared {
+            return self.struggle(commands, message_writer, snared);
+        }
+
         let from = *self.pos;
         let to = envir.get_nbor(from, step.to).expect("Valid pos");
 
         match envir.collide(from, to, true) {
             Collision::Pass => {
                 commands.entity(self.entity).insert(to);
+                if let Some(trap_entity) = envir.find_trap(to) {
+                    trap_writer.write(CharacterEvent::new(
+                        self.entity,
+                        TrapTrigger { trap_entity },
+                    ));
+                }
                 match envir.nbor_walking_cost(from, step.to) {
                     Ok(walking_cost) => self.impact_from_nbor(
                         walking_cost.duration(self.speed()),
@@ -211,6 +266,30 @@ impl ActorItem<'_, '_> {
         }
     }
 
+    fn struggle(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        snared: &Snared,
+    ) -> ActorImpact {
+        let attempts_left = snared.attempts_left.saturating_sub(1);
+        if attempts_left == 0 {
+            commands.entity(self.entity).remove::<Snared>();
+        } else {
+            commands.entity(self.entity).insert(Snared {
+                in_pit: snared.in_pit,
+                attempts_left,
+            });
+        }
+
+        message_writer.send(Struggle {
+            subject: self.subject(),
+            in_pit: snared.in_pit,
+            free: attempts_left == 0,
+        });
+        self.impact_from_duration(Duration::SECOND, StaminaCost::HEAVY)
+    }
+
     fn damage<E: Message, N>(
         &self,
         damage_writer: &mut MessageWriter<E>,
@@ -221,27 +300,88 @@ impl ActorItem<'_, '_> {
     where
         N: Fn(Entity, Damage) -> E,
     {
-        let mut melee_weapon = None;
-        if let Some(body_containers) = self.body_containers {
-            let mut hands_children = hierarchy.items_in_pocket(body_containers.hands);
-            if let Some(weapon) = hands_children.next() {
-                melee_weapon = Some(weapon.common_info);
+        damage_writer.write(new(damaged, self.melee_damage(hierarchy)));
+
+        self.impact_from_duration(Duration::SECOND, StaminaCost::HEAVY)
+    }
+
+    /// Effects of monster attacks, like venom
+    fn apply_attack_effects(
+        &self,
+        effect_writer: &mut MessageWriter<CharacterEvent<ApplyEffect>>,
+        defender: Entity,
+    ) {
+        let Some(character_info) = self.character_info else {
+            return;
+        };
+        for attack_effect in &character_info.attack_effs {
+            if rand_u8(0..100) < attack_effect.chance {
+                let Some(info) = attack_effect.id.get_option() else {
+                    continue;
+                };
+                effect_writer.write(CharacterEvent::new(
+                    defender,
+                    ApplyEffect {
+                        info,
+                        duration: attack_effect.duration,
+                        intensity: attack_effect.intensity,
+                    },
+                ));
             }
         }
+    }
 
-        let damage = Damage {
-            attacker: self.subject(),
-            amount: self.melee.damage(melee_weapon),
+    fn melee_weapon<'a>(&self, hierarchy: &'a ItemHierarchy) -> Option<&'a Shared<CommonItemInfo>> {
+        let body_containers = self.body_containers?;
+        let mut hands_children = hierarchy.items_in_pocket(body_containers.hands);
+        hands_children.next().map(|weapon| weapon.common_info)
+    }
+
+    fn melee_damage(&self, hierarchy: &ItemHierarchy) -> Damage {
+        let melee_weapon = self.melee_weapon(hierarchy);
+        let skill_level = self.skills.map_or(0, |skills| {
+            skills
+                .level(MELEE_SKILL)
+                .saturating_add(skills.level(Melee::weapon_skill(melee_weapon)))
+        });
+
+        // Natural weapons, like claws, add to the melee dice
+        let natural_damage = self
+            .character_info
+            .map(|character_info| character_info.melee_damage.as_slice())
+            .unwrap_or_default();
+        let natural_amount = natural_damage
+            .iter()
+            .map(|damage_unit| damage_unit.amount.max(0.0).round() as u16)
+            .fold(0, u16::saturating_add);
+        let damage_type = if let Some(melee_weapon) = melee_weapon {
+            DamageType::from_cdda(melee_weapon.melee_damage_type())
+        } else {
+            natural_damage
+                .iter()
+                .filter(|damage_unit| 0.0 < damage_unit.amount)
+                .max_by(|a, b| a.amount.total_cmp(&b.amount))
+                .map_or(DamageType::Bash, |damage_unit| {
+                    DamageType::from_cdda(&damage_unit.damage_type)
+                })
         };
-        damage_writer.write(new(damaged, damage));
 
-        self.impact_from_duration(Duration::SECOND, StaminaCost::HEAVY)
+        Damage {
+            attacker: self.subject(),
+            damage_type,
+            amount: self
+                .melee
+                .damage(melee_weapon, skill_level)
+                .saturating_add(natural_amount),
+        }
     }
 
     pub fn attack(
         &self,
         message_writer: &mut LogMessageWriter,
         damage_writer: &mut MessageWriter<CharacterEvent<Damage>>,
+        effect_writer: &mut MessageWriter<CharacterEvent<ApplyEffect>>,
+        exercise_writer: &mut MessageWriter<CharacterEvent<Exercise>>,
         envir: &Envir,
         hierarchy: &ItemHierarchy,
         attack: &Attack,
@@ -257,6 +397,16 @@ impl ActorItem<'_, '_> {
         let target = envir.get_nbor(*self.pos, attack.target).expect("Valid pos");
 
         if let Some((defender, _)) = envir.find_character(target) {
+            if self.skills.is_some() {
+                let weapon_skill = Melee::weapon_skill(self.melee_weapon(hierarchy));
+                for skill in [MELEE_SKILL, weapon_skill] {
+                    exercise_writer.write(CharacterEvent::new(
+                        self.entity,
+                        Exercise::new(skill, rand_u16(2..=5), None),
+                    ));
+                }
+            }
+            self.apply_attack_effects(effect_writer, defender);
             self.damage(damage_writer, hierarchy, defender, CharacterEvent::new)
         } else {
             message_writer.send(AttackNothing {
@@ -266,10 +416,147 @@ impl ActorItem<'_, '_> {
         }
     }
 
+    /// The first item in the hands, if it is a gun
+    fn gun<'a>(&self, hierarchy: &'a ItemHierarchy) -> Option<ItemItem<'a, 'a>> {
+        let body_containers = self.body_containers?;
+        hierarchy
+            .items_in_pocket(body_containers.hands)
+            .next()
+            .filter(|item| item.common_info.gun().is_some())
+    }
+
+    /// In tiles, `None` without a loaded gun
+    #[must_use]
+    pub fn fire_range(&self, hierarchy: &ItemHierarchy) -> Option<usize> {
+        let gun = self.gun(hierarchy)?;
+        let ammo = hierarchy
+            .loaded_ammo(&gun)
+            .filter(|ammo| 0 < ammo.amount.0)?;
+        Shot::new(gun.common_info, ammo.common_info).map(|shot| shot.range())
+    }
+
+    fn cant_fire(
+        &self,
+        message_writer: &mut LogMessageWriter,
+        reason: &'static str,
+    ) -> ActorImpact {
+        message_writer.send(CantFire {
+            subject: self.subject(),
+            reason,
+        });
+        self.no_impact()
+    }
+
+    /// The line of fire is `None` when the target is not visible
+    pub fn fire(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        damage_writer: &mut MessageWriter<CharacterEvent<Damage>>,
+        terrain_damage_writer: &mut MessageWriter<TerrainEvent<Damage>>,
+        exercise_writer: &mut MessageWriter<CharacterEvent<Exercise>>,
+        noise_writer: &mut MessageWriter<Noise>,
+        envir: &Envir,
+        hierarchy: &ItemHierarchy,
+        line_of_fire: Option<Vec<Pos>>,
+    ) -> ActorImpact {
+        let Some(gun) = self.gun(hierarchy) else {
+            return self.cant_fire(message_writer, "without a gun");
+        };
+        let Some(ammo) = hierarchy.loaded_ammo(&gun) else {
+            return self.cant_fire(message_writer, "without ammo");
+        };
+        if ammo.amount.0 == 0 {
+            return self.cant_fire(message_writer, "with an empty magazine");
+        }
+        let Some(shot) = Shot::new(gun.common_info, ammo.common_info) else {
+            return self.cant_fire(message_writer, "without ammo");
+        };
+        let Some(line_of_fire) = line_of_fire.filter(|line| line.first() != Some(self.pos)) else {
+            return self.cant_fire(message_writer, "at an unseen target");
+        };
+        let target = *line_of_fire.last().expect("Non-empty line of fire");
+        if !self.pos.vision_distance(target).in_range(shot.range()) {
+            return self.cant_fire(message_writer, "that far");
+        }
+
+        message_writer.send(FireGun {
+            subject: self.subject(),
+            gun: gun.fragments().collect(),
+        });
+        noise_writer.write(Noise::new(*self.pos, shot.loudness()));
+        // Guns that use several rounds per shot fire the last rounds anyway
+        let rounds = shot.rounds().min(ammo.amount.0);
+        if rounds < ammo.amount.0 {
+            commands
+                .entity(ammo.entity)
+                .insert(Amount(ammo.amount.0 - rounds));
+        } else {
+            commands.entity(ammo.entity).despawn();
+        }
+
+        let skill_level = if let Some(skills) = self.skills {
+            for skill in [GUN_SKILL, shot.skill()] {
+                exercise_writer.write(CharacterEvent::new(
+                    self.entity,
+                    Exercise::new(skill, rand_u16(2..=5), None),
+                ));
+            }
+            skills
+                .level(GUN_SKILL)
+                .saturating_add(skills.level(shot.skill()))
+                / 2
+        } else {
+            0
+        };
+
+        // A miss continues past the target, in the same pattern, up to the range of the shot
+        let step = target - *self.pos;
+        let beyond = successors(Some(line_of_fire.clone()), |previous| {
+            previous
+                .iter()
+                .map(|pos| pos.offset(step))
+                .collect::<Option<Vec<_>>>()
+        })
+        .skip(1)
+        .flatten()
+        .take_while(|pos| self.pos.vision_distance(*pos).in_range(shot.range()));
+
+        let impact = self.impact_from_duration(Duration::SECOND, StaminaCost::NEUTRAL);
+        for (index, pos) in line_of_fire.iter().copied().chain(beyond).enumerate() {
+            let distance = index + 1;
+            if let Some((character, _)) = envir.find_character(pos) {
+                // Anyone else in the line of fire is hit by half of the shots
+                let hit = if pos == target {
+                    shot.hits(distance, skill_level)
+                } else {
+                    rand_bool()
+                };
+                if hit {
+                    damage_writer
+                        .write(CharacterEvent::new(character, shot.damage(self.subject())));
+                    return impact;
+                }
+            } else if envir.find_obstacle(pos).is_some() {
+                if let Some(smashable) = envir.find_smashable(pos) {
+                    terrain_damage_writer
+                        .write(TerrainEvent::new(smashable, shot.damage(self.subject())));
+                }
+                return impact;
+            }
+        }
+
+        message_writer.send(Miss {
+            subject: self.subject(),
+        });
+        impact
+    }
+
     pub fn smash(
         &self,
         message_writer: &mut LogMessageWriter,
         damage_writer: &mut MessageWriter<TerrainEvent<Damage>>,
+        item_damage_writer: &mut MessageWriter<ItemEvent<Damage>>,
         envir: &Envir,
         hierarchy: &ItemHierarchy,
         smash: &Smash,
@@ -301,6 +588,15 @@ impl ActorItem<'_, '_> {
             self.no_impact()
         } else if let Some(smashable) = envir.find_smashable(target) {
             self.damage(damage_writer, hierarchy, smashable, TerrainEvent::new)
+        } else if envir.find_item(target).is_some() {
+            // Without terrain or furniture to smash, all items on the tile get hit
+            let damage = self.melee_damage(hierarchy);
+            item_damage_writer.write_batch(
+                envir
+                    .all_items(target)
+                    .map(|item| ItemEvent::new(item.entity, damage.clone())),
+            );
+            self.impact_from_duration(Duration::SECOND, StaminaCost::HEAVY)
         } else {
             message_writer.send(SmashInvalid {
                 subject: self.subject(),
@@ -343,6 +639,64 @@ impl ActorItem<'_, '_> {
         }
     }
 
+    pub fn butcher(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        transient_message_writer: &mut LogMessageWriter<PlayerActionState>,
+        player_action_state: &PlayerActionState,
+        next_player_action_state: &mut NextState<PlayerActionState>,
+        spawner: &mut impl HarvestSpawner,
+        envir: &Envir,
+        butcheries: &Query<&Butchery>,
+        cutting_quality: Option<i8>,
+        butcher: &Butcher,
+    ) -> ActorImpact {
+        let target = self.pos.horizontal_nbor(butcher.target);
+
+        let Some((corpse_entity, character_info)) = envir.find_butcherable(target) else {
+            message_writer.send(ButcherNothing {
+                subject: self.subject(),
+            });
+            next_player_action_state.set(PlayerActionState::Normal);
+            return self.no_impact();
+        };
+
+        if cutting_quality.is_none() {
+            message_writer.send(NeedCuttingTool {
+                subject: self.subject(),
+            });
+            next_player_action_state.set(PlayerActionState::Normal);
+            return self.no_impact();
+        }
+
+        let butchery_progress = Duration::SECOND * 3;
+
+        let mut butchery = butcheries
+            .get(corpse_entity)
+            .map_or_else(|_| Butchery::new(character_info), Clone::clone);
+        butchery.work(butchery_progress);
+        if butchery.finished() {
+            message_writer.send(YouFinish::<true> {
+                action: PlayerActionState::Butchering {
+                    direction: butcher.target,
+                },
+            });
+            spawner.spawn_harvest(target, character_info);
+            commands.entity(corpse_entity).despawn();
+            next_player_action_state.set(PlayerActionState::Normal);
+        } else {
+            transient_message_writer.send_transient(
+                ButcheryProgressLeft {
+                    butchery: &butchery,
+                },
+                player_action_state.clone(),
+            );
+            commands.entity(corpse_entity).insert(butchery);
+        }
+        self.impact_from_duration(butchery_progress, StaminaCost::NEUTRAL)
+    }
+
     pub fn peek(
         &self,
         message_writer: &mut LogMessageWriter,
@@ -417,6 +771,62 @@ impl ActorItem<'_, '_> {
         }
     }
 
+    pub fn disarm(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        trap_writer: &mut MessageWriter<CharacterEvent<TrapTrigger>>,
+        spawner: &mut impl TrapDropSpawner,
+        envir: &Envir,
+        disarm: &Disarm,
+    ) -> ActorImpact {
+        let target = self.pos.horizontal_nbor(disarm.target);
+
+        let Some((trap_entity, trap_name, trap_info)) = envir.find_detected_trap(target) else {
+            message_writer.send(DisarmNothing {
+                subject: self.subject(),
+            });
+            return self.no_impact();
+        };
+
+        if !trap_info.disarmable() {
+            message_writer.send(CantDisarm {
+                subject: self.subject(),
+                trap: trap_name.single(target),
+            });
+            return self.no_impact();
+        }
+
+        let roll = self.perception.map_or_else(
+            || Perception::default().disarm_roll(),
+            Perception::disarm_roll,
+        );
+        if trap_info.difficulty <= roll {
+            spawner.spawn_trap_drops(target, trap_info);
+            commands.entity(trap_entity).despawn();
+            message_writer.send(Disarmed {
+                subject: self.subject(),
+                trap: trap_name.single(target),
+            });
+        } else {
+            // Barely failing is harmless
+            let set_off = roll.saturating_add(3) < trap_info.difficulty;
+            if set_off {
+                trap_writer.write(CharacterEvent::new(
+                    self.entity,
+                    TrapTrigger { trap_entity },
+                ));
+            }
+            message_writer.send(FailToDisarm {
+                subject: self.subject(),
+                trap: trap_name.single(target),
+                set_off,
+            });
+        }
+
+        self.impact_from_duration(Duration::SECOND * 30, StaminaCost::NEUTRAL)
+    }
+
     pub fn wield(
         &self,
         commands: &mut Commands,
@@ -440,7 +850,12 @@ impl ActorItem<'_, '_> {
         hierarchy: &ItemHierarchy,
         item: &ItemItem,
     ) -> ActorImpact {
-        let impact = self.take(commands, message_writer, &self.clothing(hierarchy), item);
+        let impact = self.take(
+            commands,
+            message_writer,
+            &self.stowage(hierarchy, item),
+            item,
+        );
         if impact.is_some() {
             commands.entity(item.entity).remove::<WieldedBy>();
         }
@@ -454,7 +869,393 @@ impl ActorItem<'_, '_> {
         hierarchy: &ItemHierarchy,
         item: &ItemItem,
     ) -> ActorImpact {
-        self.take(commands, message_writer, &self.clothing(hierarchy), item)
+        self.take(
+            commands,
+            message_writer,
+            &self.stowage(hierarchy, item),
+            item,
+        )
+    }
+
+    pub fn wear(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        hierarchy: &ItemHierarchy,
+        item: &ItemItem,
+    ) -> ActorImpact {
+        let clothing = self.clothing(hierarchy);
+        if !item.common_info.wearable() || item.in_pocket == Some(&clothing.in_pocket) {
+            message_writer.send(CantWear {
+                subject: self.subject(),
+                item: item.fragments().collect(),
+            });
+            return self.no_impact();
+        }
+        if let Some(worn) = Self::layer_conflict(hierarchy, clothing.in_pocket, item) {
+            message_writer.send(CantWearWith {
+                subject: self.subject(),
+                item: item.fragments().collect(),
+                worn: worn.fragments().collect(),
+            });
+            return self.no_impact();
+        }
+        self.assert_nearby(item);
+
+        match clothing.check_add(self.subject(), item.containable, Amount::SINGLE) {
+            Ok(_) => {
+                message_writer.send(PutOn {
+                    subject: self.subject(),
+                    item: item.fragments().collect(),
+                });
+
+                if &Amount::SINGLE < item.amount {
+                    Self::take_some(commands, clothing.in_pocket, Amount::SINGLE, item);
+                } else {
+                    Self::take_all(commands, clothing.in_pocket, item.entity);
+                }
+                commands.entity(item.entity).remove::<WieldedBy>();
+                hierarchy.concretize_pockets(commands, item);
+                self.impact_from_duration(Duration::SECOND * 30, StaminaCost::NEUTRAL)
+            }
+            Err(addition_failure) => {
+                addition_failure.write(message_writer);
+                self.no_impact()
+            }
+        }
+    }
+
+    /// Only one item with the `ONE_PER_LAYER` flag fits on each layer of a body part
+    fn layer_conflict<'a>(
+        hierarchy: &'a ItemHierarchy,
+        clothing: InPocket,
+        item: &ItemItem,
+    ) -> Option<ItemItem<'a, 'a>> {
+        let layer = item.common_info.clothing_layer();
+        let body_parts = item.common_info.covered_body_parts();
+        hierarchy.items_in_pocket(clothing).find(|worn| {
+            (item.common_info.one_per_layer() || worn.common_info.one_per_layer())
+                && worn.common_info.clothing_layer() == layer
+                && worn
+                    .common_info
+                    .covered_body_parts()
+                    .iter()
+                    .any(|body_part| body_parts.contains(body_part))
+        })
+    }
+
+    pub fn take_off(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        hierarchy: &ItemHierarchy,
+        item: &ItemItem,
+    ) -> ActorImpact {
+        if item.in_pocket != Some(&self.clothing(hierarchy).in_pocket) {
+            message_writer.send(CantTakeOff {
+                subject: self.subject(),
+                item: item.fragments().collect(),
+            });
+            return self.no_impact();
+        }
+
+        let target = self.stowage(hierarchy, item);
+        match target.check_add(self.subject(), item.containable, *item.amount) {
+            Ok(_) => {
+                message_writer.send(TakeOff {
+                    subject: self.subject(),
+                    item: item.fragments().collect(),
+                });
+                Self::take_all(commands, target.in_pocket, item.entity);
+                self.impact_from_duration(Duration::SECOND * 20, StaminaCost::NEUTRAL)
+            }
+            Err(addition_failure) => {
+                addition_failure.write(message_writer);
+                self.no_impact()
+            }
+        }
+    }
+
+    /// The items in the hands and in carried storage, except `excluded` and its contents
+    fn carried<'a>(&self, hierarchy: &'a ItemHierarchy, excluded: Entity) -> Vec<ItemItem<'a, 'a>> {
+        let body_containers = self.body_containers.expect("Body containers present");
+        let storage = [body_containers.hands, body_containers.clothing]
+            .into_iter()
+            .flat_map(|in_pocket| hierarchy.storage_pockets(in_pocket, excluded));
+        once(body_containers.hands)
+            .chain(storage)
+            .flat_map(|in_pocket| hierarchy.items_in_pocket(in_pocket))
+            .filter(|carried| carried.entity != excluded)
+            .collect()
+    }
+
+    fn cant_reload(
+        &self,
+        message_writer: &mut LogMessageWriter,
+        item: &ItemItem,
+        reason: &'static str,
+    ) -> ActorImpact {
+        message_writer.send(CantReload {
+            subject: self.subject(),
+            item: item.fragments().collect(),
+            reason,
+        });
+        self.no_impact()
+    }
+
+    /// CDDA expresses reload times in moves, with 100 moves per second
+    fn reload_duration(item: &ItemItem, pocket_type: PocketType, rounds: u32) -> Duration {
+        let gun_moves = item.common_info.gun().and_then(|gun| gun.reload);
+        let moves = if pocket_type == PocketType::MagazineWell {
+            gun_moves
+        } else {
+            item.common_info
+                .magazine()
+                .and_then(|magazine| magazine.reload_time)
+                .or(gun_moves)
+        };
+        Duration::MILLISECOND * 10 * u64::from(moves.unwrap_or(100)) * u64::from(rounds)
+    }
+
+    /// Loads the fullest compatible magazine, or as much compatible ammo as fits
+    pub fn reload(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        hierarchy: &ItemHierarchy,
+        item: &ItemItem,
+    ) -> ActorImpact {
+        self.assert_nearby(item);
+
+        let pockets = hierarchy.pockets_in(item);
+        let Some((pocket_index, pocket_wrapper)) =
+            pockets.iter().enumerate().find(|(_, pocket_wrapper)| {
+                matches!(
+                    pocket_wrapper.pocket_type(),
+                    PocketType::MagazineWell | PocketType::Magazine
+                )
+            })
+        else {
+            return self.cant_reload(message_writer, item, "at all");
+        };
+        let loaded = pocket_wrapper
+            .in_pocket()
+            .and_then(|in_pocket| hierarchy.items_in_pocket(in_pocket).next());
+        let carried = self.carried(hierarchy, item.entity);
+
+        if pocket_wrapper.pocket_type() == PocketType::MagazineWell {
+            self.reload_magazine_well(
+                commands,
+                message_writer,
+                hierarchy,
+                item,
+                pocket_index,
+                pocket_wrapper.info(),
+                loaded,
+                carried,
+            )
+        } else {
+            self.reload_magazine(
+                commands,
+                message_writer,
+                hierarchy,
+                item,
+                pocket_index,
+                pocket_wrapper.info(),
+                loaded,
+                carried,
+            )
+        }
+    }
+
+    /// Swaps the loaded magazine, if any, for the fullest compatible carried magazine
+    fn reload_magazine_well(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        hierarchy: &ItemHierarchy,
+        item: &ItemItem,
+        well_index: usize,
+        well_info: &PocketInfo,
+        loaded: Option<ItemItem>,
+        carried: Vec<ItemItem>,
+    ) -> ActorImpact {
+        let rounds_in = |magazine: &ItemItem| {
+            hierarchy
+                .loaded_ammo(magazine)
+                .map_or(0, |ammo| ammo.amount.0)
+        };
+        let Some(magazine) = carried
+            .into_iter()
+            .filter(|carried| {
+                carried.common_info.magazine().is_some() && well_info.allows(carried.common_info)
+            })
+            .max_by_key(rounds_in)
+        else {
+            return self.cant_reload(message_writer, item, "without a compatible magazine");
+        };
+
+        if let Some(loaded) = loaded {
+            if rounds_in(&magazine) <= rounds_in(&loaded) {
+                return self.cant_reload(message_writer, item, "without a fuller magazine");
+            }
+
+            let stowage = self.stowage(hierarchy, &loaded);
+            if let Err(addition_failure) =
+                stowage.check_add(self.subject(), loaded.containable, *loaded.amount)
+            {
+                addition_failure.write(message_writer);
+                return self.no_impact();
+            }
+            Self::take_all(commands, stowage.in_pocket, loaded.entity);
+        }
+
+        message_writer.send(LoadInto {
+            subject: self.subject(),
+            loaded: magazine.fragments().collect(),
+            into: item.fragments().collect(),
+        });
+        let well = hierarchy.concretize_pockets(commands, item)[well_index];
+        Self::take_all(commands, well, magazine.entity);
+        commands.entity(magazine.entity).remove::<WieldedBy>();
+        self.impact_from_duration(
+            Self::reload_duration(item, PocketType::MagazineWell, 1),
+            StaminaCost::NEUTRAL,
+        )
+    }
+
+    /// Adds carried ammo of the same kind as the loaded ammo, or of any allowed ammo type when empty
+    fn reload_magazine(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        hierarchy: &ItemHierarchy,
+        item: &ItemItem,
+        magazine_index: usize,
+        magazine_info: &PocketInfo,
+        loaded: Option<ItemItem>,
+        carried: Vec<ItemItem>,
+    ) -> ActorImpact {
+        let Some((ammo, capacity)) = carried
+            .into_iter()
+            .filter(|carried| {
+                carried.common_info.ammo().is_some()
+                    && loaded
+                        .as_ref()
+                        .is_none_or(|loaded| loaded.common_info.id == carried.common_info.id)
+            })
+            .find_map(|carried| {
+                let capacity = carried
+                    .common_info
+                    .ammo_types()
+                    .iter()
+                    .find_map(|ammo_type| magazine_info.ammo_capacity(ammo_type))?;
+                Some((carried, capacity))
+            })
+        else {
+            return self.cant_reload(message_writer, item, "without compatible ammo");
+        };
+
+        let present = loaded.as_ref().map_or(0, |loaded| loaded.amount.0);
+        let rounds = capacity.saturating_sub(present).min(ammo.amount.0);
+        if rounds == 0 {
+            return self.cant_reload(message_writer, item, "that is already full");
+        }
+
+        message_writer.send(LoadInto {
+            subject: self.subject(),
+            loaded: vec![
+                Fragment::hard(format!("{rounds}")),
+                ammo.name.amount(rounds, *self.pos),
+            ],
+            into: item.fragments().collect(),
+        });
+        if let Some(loaded) = loaded {
+            commands
+                .entity(loaded.entity)
+                .insert(Amount(present + rounds));
+            if rounds < ammo.amount.0 {
+                commands
+                    .entity(ammo.entity)
+                    .insert(Amount(ammo.amount.0 - rounds));
+            } else {
+                commands.entity(ammo.entity).despawn();
+            }
+        } else {
+            let magazine = hierarchy.concretize_pockets(commands, item)[magazine_index];
+            if rounds < ammo.amount.0 {
+                Self::take_some(commands, magazine, Amount(rounds), &ammo);
+            } else {
+                Self::take_all(commands, magazine, ammo.entity);
+            }
+        }
+        self.impact_from_duration(
+            Self::reload_duration(item, PocketType::Magazine, rounds),
+            StaminaCost::NEUTRAL,
+        )
+    }
+
+    /// Stows the loaded magazine or ammo
+    pub fn unload(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        hierarchy: &ItemHierarchy,
+        item: &ItemItem,
+    ) -> ActorImpact {
+        self.assert_nearby(item);
+
+        let Some((pocket_type, unloaded)) = hierarchy
+            .pockets_in(item)
+            .into_iter()
+            .filter(|pocket_wrapper| {
+                matches!(
+                    pocket_wrapper.pocket_type(),
+                    PocketType::MagazineWell | PocketType::Magazine
+                )
+            })
+            .find_map(|pocket_wrapper| {
+                let in_pocket = pocket_wrapper.in_pocket()?;
+                let unloaded = hierarchy.items_in_pocket(in_pocket).next()?;
+                Some((pocket_wrapper.pocket_type(), unloaded))
+            })
+        else {
+            message_writer.send(CantUnload {
+                subject: self.subject(),
+                item: item.fragments().collect(),
+            });
+            return self.no_impact();
+        };
+
+        let target = self.stowage(hierarchy, &unloaded);
+        match target.check_add(self.subject(), unloaded.containable, *unloaded.amount) {
+            Ok(allowed_amount) => {
+                message_writer.send(UnloadFrom {
+                    subject: self.subject(),
+                    unloaded: unloaded.fragments().collect(),
+                    from: item.fragments().collect(),
+                });
+
+                if &allowed_amount < unloaded.amount {
+                    Self::take_some(commands, target.in_pocket, allowed_amount, &unloaded);
+                } else {
+                    Self::take_all(commands, target.in_pocket, unloaded.entity);
+                }
+                let rounds = if pocket_type == PocketType::MagazineWell {
+                    1
+                } else {
+                    allowed_amount.0
+                };
+                self.impact_from_duration(
+                    Self::reload_duration(item, pocket_type, rounds),
+                    StaminaCost::NEUTRAL,
+                )
+            }
+            Err(addition_failure) => {
+                addition_failure.write(message_writer);
+                self.no_impact()
+            }
+        }
     }
 
     fn take(
@@ -464,22 +1265,7 @@ impl ActorItem<'_, '_> {
         target: &Container,
         taken: &ItemItem,
     ) -> ActorImpact {
-        if let Some(taken_pos) = taken.pos {
-            let offset = *taken_pos - *self.pos;
-            assert!(
-                offset.x.abs() <= 1,
-                "Taking is not possible from more than one tile away"
-            );
-            assert!(
-                offset.level == LevelOffset::ZERO,
-                "Taking is only possible on the same level"
-            );
-            assert!(
-                offset.z.abs() <= 1,
-                "Taking is not possible from more than one tile away"
-            );
-        }
-        // TODO check position of root item
+        self.assert_nearby(taken);
 
         match target.check_add(self.subject(), taken.containable, *taken.amount) {
             Ok(allowed_amount) => {
@@ -502,6 +1288,25 @@ impl ActorItem<'_, '_> {
         }
     }
 
+    fn assert_nearby(&self, taken: &ItemItem) {
+        if let Some(taken_pos) = taken.pos {
+            let offset = *taken_pos - *self.pos;
+            assert!(
+                offset.x.abs() <= 1,
+                "Taking is not possible from more than one tile away"
+            );
+            assert!(
+                offset.level == LevelOffset::ZERO,
+                "Taking is only possible on the same level"
+            );
+            assert!(
+                offset.z.abs() <= 1,
+                "Taking is not possible from more than one tile away"
+            );
+        }
+        // TODO check position of root item
+    }
+
     fn take_some(
         commands: &mut Commands,
         to_in_pocket: InPocket,
@@ -525,6 +1330,7 @@ impl ActorItem<'_, '_> {
             Maybe(taken.on_tile.copied()),
             Maybe(taken.in_pocket.copied()),
             Maybe(taken.filthy.copied()),
+            Maybe(taken.temperature.copied()),
             Maybe(taken.pos.copied()),
         ));
 
@@ -606,57 +1412,16 @@ impl ActorItem<'_, '_> {
     ) -> ActorImpact {
         let pos = self.pos.horizontal_nbor(start_craft.target);
 
-        for Consumed {
-            amount,
-            from_entities,
-        } in start_craft.recipe_situation.consumed_tool_charges()
-        {
-            //trace!("Consume {required} from {from_entities:?}:");
-            let mut missing = amount.get();
-            for &consumed_entity in from_entities {
-                let mut item_amount = item_amounts
-                    .get_mut(consumed_entity)
-                    .expect("Consumed tool charges should be found");
-                if item_amount.0 <= missing {
-                    //trace!(" - Consume {consumed_entity} fully ({:?}x)", item_amount.0);
-                    commands.entity(consumed_entity).despawn();
-                    missing -= item_amount.0;
-                    if missing == 0 {
-                        break;
-                    }
-                } else {
-                    //trace!(" - Consume {consumed_entity:?} partially ({}/{})",missing, item_amount.0);
-                    item_amount.0 -= missing;
-                    break;
-                }
-            }
-        }
-
-        for Consumed {
-            amount,
-            from_entities,
-        } in start_craft.recipe_situation.consumed_components()
-        {
-            //trace!("Consume {required} from {item_entities:?}:");
-            let mut missing = amount.get();
-            for &item_entity in from_entities {
-                let mut item_amount = item_amounts
-                    .get_mut(item_entity)
-                    .expect("Consumed component items should be found");
-                if item_amount.0 <= missing {
-                    //trace!(" - Consume {item_entity} fully ({:?}x)", item_amount.0);
-                    commands.entity(item_entity).despawn();
-                    missing -= item_amount.0;
-                    if missing == 0 {
-                        break;
-                    }
-                } else {
-                    //trace!(" - Consume {item_entity:?} partially ({}/{})",missing, item_amount.0);
-                    item_amount.0 -= missing;
-                    break;
-                }
-            }
-        }
+        consume(
+            commands,
+            item_amounts,
+            start_craft.recipe_situation.consumed_tool_charges(),
+        );
+        consume(
+            commands,
+            item_amounts,
+            start_craft.recipe_situation.consumed_components(),
+        );
 
         let item = spawner.spawn_craft(pos, start_craft.recipe_situation.recipe().clone());
         match item {
@@ -674,6 +1439,7 @@ impl ActorItem<'_, '_> {
         commands: &mut Commands,
         message_writer: &mut LogMessageWriter,
         transient_message_writer: &mut LogMessageWriter<PlayerActionState>,
+        exercise_writer: &mut MessageWriter<CharacterEvent<Exercise>>,
         player_action_state: &PlayerActionState,
         next_player_action_state: &mut NextState<PlayerActionState>,
         spawner: &mut impl ItemSpawner,
@@ -684,11 +1450,34 @@ impl ActorItem<'_, '_> {
 
         let crafting_progress = Duration::SECOND * 3;
 
-        craft.work(crafting_progress);
+        let recipe = craft.recipe().clone();
+        let work_speed = match (self.skills, &recipe.skill_used) {
+            (Some(skills), Some(skill_used)) => skills.work_speed(skill_used, recipe.difficulty),
+            _ => 100,
+        };
+        craft.work(crafting_progress * work_speed / 100);
         if let Some(craft_result) = craft.finished_result() {
             message_writer.send(YouFinish::<true> {
                 action: PlayerActionState::Crafting { item: craft_entity },
             });
+            if let Some(skill_used) = &recipe.skill_used {
+                // Longer and harder recipes give more practice, up to a bit above their difficulty
+                let minutes = recipe
+                    .time
+                    .map_or(1, |time| {
+                        time.milliseconds() / Duration::MINUTE.milliseconds()
+                    })
+                    .max(1);
+                exercise_writer.write(CharacterEvent::new(
+                    self.entity,
+                    Exercise::new(
+                        skill_used.clone(),
+                        (minutes * (u64::from(recipe.difficulty) + 1)).min(u64::from(u16::MAX))
+                            as u16,
+                        Some(recipe.difficulty.saturating_add(recipe.difficulty / 4 + 1)),
+                    ),
+                ));
+            }
             let pos = *item.pos.unwrap_or(self.pos);
             let amount = *item.amount;
             commands.entity(item.entity).despawn();
@@ -715,6 +1504,244 @@ impl ActorItem<'_, '_> {
         self.impact_from_duration(crafting_progress, StaminaCost::NEUTRAL)
     }
 
+    pub fn start_construction(
+        &self,
+        commands: &mut Commands,
+        next_player_action_state: &mut NextState<PlayerActionState>,
+        spawner: &mut impl ConstructionSpawner,
+        item_amounts: &mut Query<&mut Amount>,
+        start_construction: &StartConstruction,
+    ) -> ActorImpact {
+        let situation = &start_construction.construction_situation;
+        let site = situation.site().unwrap_or_else(|| {
+            consume(commands, item_amounts, situation.consumed_tool_charges());
+            consume(commands, item_amounts, situation.consumed_components());
+
+            let pos = self.pos.horizontal_nbor(start_construction.target);
+            spawner.spawn_construction_site(
+                pos,
+                ConstructionSite::new(situation.construction().clone()),
+            )
+        });
+        next_player_action_state.set(PlayerActionState::Constructing { site });
+
+        self.no_impact()
+    }
+
+    pub fn continue_construction(
+        &self,
+        message_writer: &mut LogMessageWriter,
+        transient_message_writer: &mut LogMessageWriter<PlayerActionState>,
+        construct_writer: &mut MessageWriter<TerrainEvent<Construct>>,
+        player_action_state: &PlayerActionState,
+        next_player_action_state: &mut NextState<PlayerActionState>,
+        sites: &mut Query<&mut ConstructionSite>,
+        site_entity: Entity,
+    ) -> ActorImpact {
+        let mut site = sites
+            .get_mut(site_entity)
+            .expect("Construction site should be found");
+
+        let construction_progress = Duration::SECOND * 3;
+
+        site.work(construction_progress);
+        if site.finished() {
+            message_writer.send(YouFinish::<true> {
+                action: PlayerActionState::Constructing { site: site_entity },
+            });
+            construct_writer.write(TerrainEvent::new(
+                site_entity,
+                Construct {
+                    construction: site.construction().clone(),
+                },
+            ));
+            next_player_action_state.set(PlayerActionState::Normal);
+        } else {
+            transient_message_writer.send_transient(
+                ConstructionProgressLeft { site: &site },
+                player_action_state.clone(),
+            );
+        }
+        self.impact_from_duration(construction_progress, StaminaCost::NEUTRAL)
+    }
+
+    pub fn start_disassembly(
+        &self,
+        commands: &mut Commands,
+        next_player_action_state: &mut NextState<PlayerActionState>,
+        disassemblies: &Query<&Disassembly>,
+        start_disassembly: &StartDisassembly,
+    ) -> ActorImpact {
+        let situation = &start_disassembly.disassembly_situation;
+        let item = situation.item();
+        if !disassemblies.contains(item) {
+            commands.entity(item).insert(Disassembly::new(situation));
+        }
+        next_player_action_state.set(PlayerActionState::Disassembling { item });
+
+        self.no_impact()
+    }
+
+    pub fn continue_disassembly(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        transient_message_writer: &mut LogMessageWriter<PlayerActionState>,
+        player_action_state: &PlayerActionState,
+        next_player_action_state: &mut NextState<PlayerActionState>,
+        spawner: &mut impl ItemSpawner,
+        disassemblies: &mut Query<(Item, &mut Disassembly)>,
+        item_entity: Entity,
+    ) -> ActorImpact {
+        let (item, mut disassembly) = disassemblies
+            .get_mut(item_entity)
+            .expect("Disassembly should be found");
+
+        let disassembly_progress = Duration::SECOND * 3;
+
+        disassembly.work(disassembly_progress);
+        if disassembly.finished() {
+            message_writer.send(YouFinish::<true> {
+                action: PlayerActionState::Disassembling { item: item_entity },
+            });
+            let pos = *item.pos.unwrap_or(self.pos);
+            for returned in disassembly.returned() {
+                let cdda_item = CddaItem::new(&returned.item_info);
+                let amount = Amount(returned.amount.get());
+                if let Err(error) = match item.parentage().cloned() {
+                    Either::Left(child_of) => {
+                        spawner.spawn_item(child_of, Some(pos), &cdda_item, amount)
+                    }
+                    Either::Right(in_pocket) => {
+                        spawner.spawn_item(in_pocket, Some(pos), &cdda_item, amount)
+                    }
+                } {
+                    error!("Spawning disassembled component failed: {error:#?}");
+                }
+            }
+            if 1 < item.amount.0 {
+                // Only one item of the stack is disassembled
+                commands
+                    .entity(item.entity)
+                    .insert(Amount(item.amount.0 - 1))
+                    .remove::<Disassembly>();
+            } else {
+                commands.entity(item.entity).despawn();
+            }
+            next_player_action_state.set(PlayerActionState::Normal);
+        } else {
+            transient_message_writer.send_transient(
+                DisassemblyProgressLeft {
+                    disassembly: &disassembly,
+                },
+                player_action_state.clone(),
+            );
+        }
+        self.impact_from_duration(disassembly_progress, StaminaCost::NEUTRAL)
+    }
+
+    /// Reading or practicing, one minute at a time
+    pub fn train(
+        &self,
+        exercise_writer: &mut MessageWriter<CharacterEvent<Exercise>>,
+        train: &Train,
+    ) -> ActorImpact {
+        exercise_writer.write(CharacterEvent::new(
+            self.entity,
+            Exercise::new(train.skill.clone(), 1, train.cap),
+        ));
+        self.impact_from_duration(Duration::MINUTE, StaminaCost::NEUTRAL)
+    }
+
+    pub fn eat(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        nourishment_writer: &mut MessageWriter<CharacterEvent<Nourishment>>,
+        item: &ItemItem,
+    ) -> ActorImpact {
+        self.consume(commands, message_writer, nourishment_writer, item, false)
+    }
+
+    pub fn drink(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        nourishment_writer: &mut MessageWriter<CharacterEvent<Nourishment>>,
+        item: &ItemItem,
+    ) -> ActorImpact {
+        self.consume(commands, message_writer, nourishment_writer, item, true)
+    }
+
+    /// Eating or drinking a single portion
+    fn consume(
+        &self,
+        commands: &mut Commands,
+        message_writer: &mut LogMessageWriter,
+        nourishment_writer: &mut MessageWriter<CharacterEvent<Nourishment>>,
+        item: &ItemItem,
+        drink: bool,
+    ) -> ActorImpact {
+        let verb = if drink { "drink" } else { "eat" };
+        let portion = item.name.single(item.pos.copied().unwrap_or(*self.pos));
+
+        // Frozen drinks have to thaw first
+        let liquid = !drink
+            || !item
+                .temperature
+                .copied()
+                .is_some_and(ItemTemperature::frozen);
+        let Some(comestible) = item
+            .common_info
+            .comestible()
+            .filter(|comestible| comestible.is_drink() == drink && liquid)
+        else {
+            message_writer.send(CantConsume {
+                subject: self.subject(),
+                verb,
+                item: portion,
+            });
+            return self.no_impact();
+        };
+
+        let nourishment = Nourishment {
+            kcal: comestible.calories,
+            quench: comestible.quench.unwrap_or(0),
+        };
+        if self
+            .nutrition
+            .is_some_and(|nutrition| !nutrition.wants(&nourishment))
+        {
+            message_writer.send(TooFull {
+                subject: self.subject(),
+                verb,
+                item: portion,
+            });
+            return self.no_impact();
+        }
+
+        message_writer.send(Consume {
+            subject: self.subject(),
+            verb,
+            item: portion,
+        });
+        nourishment_writer.write(CharacterEvent::new(self.entity, nourishment));
+        if 1 < item.amount.0 {
+            commands
+                .entity(item.entity)
+                .insert(Amount(item.amount.0 - 1));
+        } else {
+            commands.entity(item.entity).despawn();
+        }
+
+        let duration = if drink {
+            Duration::SECOND * 20
+        } else {
+            Duration::MINUTE
+        };
+        self.impact_from_duration(duration, StaminaCost::NEUTRAL)
+    }
+
     pub fn examine_item(
         &self,
         message_writer: &mut LogMessageWriter,
@@ -731,3 +1758,36 @@ impl ActorItem<'_, '_> {
         self.no_impact()
     }
 }
+
+/// Consume tools charges or components
+fn consume<'a>(
+    commands: &mut Commands,
+    item_amounts: &mut Query<&mut Amount>,
+    consumed: impl Iterator<Item = Consumed<'a>>,
+) {
+    for Consumed {
+        amount,
+        from_entities,
+    } in consumed
+    {
+        //trace!("Consume {required} from {from_entities:?}:");
+        let mut missing = amount.get();
+        for &consumed_entity in from_entities {
+            let mut item_amount = item_amounts
+                .get_mut(consumed_entity)
+                .expect("Consumed items should be found");
+            if item_amount.0 <= missing {
+                //trace!(" - Consume {consumed_entity} fully ({:?}x)", item_amount.0);
+                commands.entity(consumed_entity).despawn();
+                missing -= item_amount.0;
+                if missing == 0 {
+                    break;
+                }
+            } else {
+                //trace!(" - Consume {consumed_entity:?} partially ({}/{})",missing, item_amount.0);
+                item_amount.0 -= missing;
+                break;
+            }
+        }
+    }
+}
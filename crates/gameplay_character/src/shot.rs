@@ -0,0 +1,191 @@
+use cdda_json_files::{Ammo, CommonItemInfo, Gun, RangedDamage};
+use fastrand::f32 as rand_f32;
+use gameplay_object::{Damage, DamageType};
+use text::Subject;
+
+/// A projectile fired from a gun, with its ammo
+#[derive(Debug)]
+pub(crate) struct Shot<'a> {
+    gun: &'a Gun,
+    ammo: &'a Ammo,
+}
+
+impl<'a> Shot<'a> {
+    /// Each missing skill level makes a shot this much less accurate
+    const ARCMINUTES_PER_MISSING_SKILL_LEVEL: f32 = 45.0;
+
+    /// Skills above this level don't improve the accuracy
+    const MAX_SKILL_LEVEL: u8 = 10;
+
+    /// Shots that deviate less than this end on the target tile
+    const MAX_DEVIATION: f32 = 0.5;
+
+    /// Very inaccurate shots still go forward
+    const MAX_DEGREES: f32 = 89.0;
+
+    /// `None` when the gun is not a gun, or the ammo is not ammo
+    pub(crate) fn new(gun: &'a CommonItemInfo, ammo: &'a CommonItemInfo) -> Option<Self> {
+        Some(Self {
+            gun: gun.gun()?,
+            ammo: ammo.ammo()?,
+        })
+    }
+
+    pub(crate) fn skill(&self) -> &str {
+        &self.gun.skill
+    }
+
+    /// How many rounds are used per shot
+    pub(crate) fn rounds(&self) -> u32 {
+        u32::from(self.gun.ammo_to_fire.unwrap_or(1).max(1))
+    }
+
+    /// In tiles
+    pub(crate) fn range(&self) -> usize {
+        let range = self.gun.common.range.unwrap_or(0) + self.ammo.common.range.unwrap_or(0);
+        range.max(1) as usize
+    }
+
+    /// Loud ammo, or otherwise high damage, can be heard from far away
+    pub(crate) fn loudness(&self) -> u16 {
+        self.ammo
+            .common
+            .loudness
+            .unwrap_or_else(|| self.damage_amount())
+            .saturating_add(self.gun.common.loudness.unwrap_or(0))
+    }
+
+    /// In arcminutes, where lower is more accurate
+    fn dispersion(&self, skill_level: u8) -> f32 {
+        let missing_skill_levels = Self::MAX_SKILL_LEVEL.saturating_sub(skill_level);
+        f32::from(self.gun.common.dispersion.unwrap_or(0))
+            + f32::from(self.ammo.common.dispersion.unwrap_or(0))
+            + f32::from(missing_skill_levels) * Self::ARCMINUTES_PER_MISSING_SKILL_LEVEL
+    }
+
+    /// Whether a shot over the given distance in tiles ends on the target tile
+    pub(crate) fn hits(&self, distance: usize, skill_level: u8) -> bool {
+        self.hits_with_roll(distance, skill_level, rand_f32())
+    }
+
+    /// The `roll`, from 0 to 1, is the part of the dispersion that the shot deviates
+    fn hits_with_roll(&self, distance: usize, skill_level: u8, roll: f32) -> bool {
+        let degrees = (roll * self.dispersion(skill_level) / 60.0).min(Self::MAX_DEGREES);
+        (distance as f32) * degrees.to_radians().tan() < Self::MAX_DEVIATION
+    }
+
+    /// The damage of the ammo, modified by the gun
+    pub(crate) fn damage(&self, attacker: Subject) -> Damage {
+        let damage_type = self
+            .ammo
+            .damage
+            .as_ref()
+            .and_then(RangedDamage::main_damage_type)
+            .or_else(|| {
+                self.gun
+                    .ranged_damage
+                    .as_ref()
+                    .and_then(RangedDamage::main_damage_type)
+            })
+            .map_or(DamageType::Bullet, DamageType::from_cdda);
+        Damage {
+            attacker,
+            damage_type,
+            amount: self.damage_amount(),
+        }
+    }
+
+    fn damage_amount(&self) -> u16 {
+        [&self.ammo.damage, &self.gun.ranged_damage]
+            .into_iter()
+            .flatten()
+            .map(RangedDamage::amount)
+            .sum::<f32>()
+            .max(0.0)
+            .round() as u16
+    }
+}
+
+#[cfg(test)]
+mod shot_tests {
+    use super::*;
+    use serde_json::from_str as from_json_str;
+    use std::sync::Arc;
+
+    fn gun_and_ammo() -> (Gun, Ammo) {
+        (
+            from_json_str(include_str!(
+                "../../cdda_json_files/src/info/test_data/glock_19.json"
+            ))
+            .expect("Valid gun json"),
+            from_json_str(include_str!(
+                "../../cdda_json_files/src/info/test_data/9mm.json"
+            ))
+            .expect("Valid ammo json"),
+        )
+    }
+
+    /// Just below 1, the worst possible roll
+    const WORST_ROLL: f32 = 1.0 - f32::EPSILON;
+
+    #[test]
+    fn dispersion_at_skill_edges() {
+        let (gun, ammo) = gun_and_ammo();
+        let shot = Shot {
+            gun: &gun,
+            ammo: &ammo,
+        };
+        assert!(
+            (shot.dispersion(0) - 970.0).abs() < f32::EPSILON,
+            "{shot:?}"
+        );
+        assert!((shot.dispersion(Shot::MAX_SKILL_LEVEL) - 520.0).abs() < f32::EPSILON);
+        assert!((shot.dispersion(u8::MAX) - 520.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn point_blank_always_hits() {
+        let (gun, ammo) = gun_and_ammo();
+        let shot = Shot {
+            gun: &gun,
+            ammo: &ammo,
+        };
+        assert!(shot.hits_with_roll(0, 0, WORST_ROLL), "{shot:?}");
+        assert!(shot.hits_with_roll(1, 0, WORST_ROLL), "{shot:?}");
+    }
+
+    #[test]
+    fn max_range_depends_on_skill() {
+        let (gun, ammo) = gun_and_ammo();
+        let shot = Shot {
+            gun: &gun,
+            ammo: &ammo,
+        };
+        let max_range = shot.range();
+        assert_eq!(max_range, 14, "{shot:?}");
+
+        assert!(shot.hits_with_roll(max_range, 0, 0.0), "{shot:?}");
+        assert!(!shot.hits_with_roll(max_range, 0, 0.2), "{shot:?}");
+        assert!(
+            shot.hits_with_roll(max_range, Shot::MAX_SKILL_LEVEL, 0.2),
+            "{shot:?}"
+        );
+        assert!(
+            !shot.hits_with_roll(max_range, Shot::MAX_SKILL_LEVEL, WORST_ROLL),
+            "{shot:?}"
+        );
+    }
+
+    #[test]
+    fn wild_shots_miss() {
+        let (mut gun, ammo) = gun_and_ammo();
+        Arc::get_mut(&mut gun.common)
+            .expect("Unique gun info")
+            .dispersion = Some(u16::MAX);
+        let shot = Shot {
+            gun: &gun,
+            ammo: &ammo,
+        };
+        assert!(!shot.hits_with_roll(2, 0, WORST_ROLL), "{shot:?}");
+    }
+}
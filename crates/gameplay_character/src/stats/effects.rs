@@ -0,0 +1,187 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::Component;
+use cdda_json_files::{EffectInfo, ItemName, SavedEffect};
+use std::sync::Arc;
+use units::Duration;
+
+/// Applying a status effect to a character
+#[derive(Clone, Debug)]
+pub struct ApplyEffect {
+    pub info: Arc<EffectInfo>,
+    pub duration: Duration,
+    pub intensity: u8,
+}
+
+#[derive(Clone, Debug)]
+pub struct ActiveEffect {
+    pub info: Arc<EffectInfo>,
+    pub intensity: u8,
+
+    /// Until the effect wears off
+    pub remaining: Duration,
+
+    /// Since the effect last hurt
+    hurt_progress: Duration,
+}
+
+impl ActiveEffect {
+    /// `None` for effects that are not shown
+    #[must_use]
+    pub fn name(&self) -> Option<&ItemName> {
+        self.info.name(self.intensity)
+    }
+
+    /// The intensity follows the remaining duration, when the effect defines how
+    fn adjust_intensity(&mut self) {
+        if let Some(int_dur_factor) = self.info.int_dur_factor
+            && Duration::ZERO < int_dur_factor
+        {
+            let intensity = self
+                .remaining
+                .milliseconds()
+                .div_ceil(int_dur_factor.milliseconds());
+            self.intensity = intensity.min(u64::from(u8::MAX)) as u8;
+        }
+        self.intensity = self.intensity.clamp(1, self.info.max_intensity.max(1));
+    }
+
+    /// Returns the damage since the previous update
+    fn hurt(&mut self, elapsed: Duration) -> u16 {
+        let amount = self.info.modifier("hurt_amount", self.intensity);
+        if amount < 1.0 {
+            return 0;
+        }
+
+        // Every turn, unless specified otherwise
+        let tick =
+            Duration::SECOND * (self.info.modifier("hurt_tick", self.intensity).max(1.0) as u64);
+        self.hurt_progress += elapsed;
+        let ticks = self.hurt_progress.extract_div(tick);
+        (amount as u64 * ticks).min(u64::from(u16::MAX)) as u16
+    }
+}
+
+/// What happened during [`Effects::update`]
+#[derive(Debug, Default)]
+pub struct EffectsUpdate {
+    /// Damage per effect
+    pub hurt: Vec<(Arc<EffectInfo>, u16)>,
+
+    pub expired: Vec<Arc<EffectInfo>>,
+}
+
+/// Status effects, like bleeding or being stunned
+///
+/// Mutable component
+#[derive(Debug, Default, Component)]
+pub struct Effects(Vec<ActiveEffect>);
+
+impl Effects {
+    /// Convert from the effects of a CDDA save
+    #[must_use]
+    pub fn from_cdda(
+        saved: &HashMap<Arc<str>, HashMap<Arc<str>, SavedEffect>>,
+        effect_info: impl Fn(&str) -> Option<Arc<EffectInfo>>,
+    ) -> Self {
+        let mut effects = Self::default();
+        for (id, per_body_part) in saved {
+            let Some(info) = effect_info(id) else {
+                continue;
+            };
+            // Effects on different body parts are combined
+            for saved_effect in per_body_part.values() {
+                effects.apply(&ApplyEffect {
+                    info: info.clone(),
+                    duration: Duration::SECOND * saved_effect.duration,
+                    intensity: saved_effect.intensity,
+                });
+            }
+        }
+        effects
+    }
+
+    /// Returns whether the effect is new
+    ///
+    /// Applying an existing effect extends its duration, up to its maximum, and may raise its intensity.
+    pub fn apply(&mut self, apply_effect: &ApplyEffect) -> bool {
+        let info = &apply_effect.info;
+        let (active, new) =
+            if let Some(index) = self.0.iter().position(|active| active.info.id == info.id) {
+                let active = &mut self.0[index];
+                active.remaining += apply_effect.duration;
+                active.intensity = active.intensity.max(apply_effect.intensity);
+                (active, false)
+            } else {
+                self.0.push(ActiveEffect {
+                    info: info.clone(),
+                    intensity: apply_effect.intensity,
+                    remaining: apply_effect.duration,
+                    hurt_progress: Duration::ZERO,
+                });
+                (self.0.last_mut().expect("Just added"), true)
+            };
+        if let Some(max_duration) = info.max_duration {
+            active.remaining = active.remaining.min(max_duration);
+        }
+        active.adjust_intensity();
+        new
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveEffect> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Multiplier for the speed of the character, based on `speed_mod`
+    #[must_use]
+    pub fn speed_factor(&self) -> f32 {
+        let speed_mod = self
+            .0
+            .iter()
+            .map(|active| active.info.modifier("speed_mod", active.intensity))
+            .sum::<f32>();
+        ((100.0 + speed_mod) / 100.0).max(0.1)
+    }
+
+    /// Lets effects hurt and wear off
+    pub fn update(&mut self, elapsed: Duration) -> EffectsUpdate {
+        let mut update = EffectsUpdate::default();
+        for active in &mut self.0 {
+            let damage = active.hurt(elapsed.min(active.remaining));
+            if 0 < damage {
+                update.hurt.push((active.info.clone(), damage));
+            }
+
+            active.remaining = active.remaining - elapsed;
+            if active.remaining == Duration::ZERO {
+                update.expired.push(active.info.clone());
+            } else {
+                active.adjust_intensity();
+            }
+        }
+        self.0.retain(|active| Duration::ZERO < active.remaining);
+        update
+    }
+
+    /// Remaining duration and intensity per effect
+    #[must_use]
+    pub fn to_cdda(&self) -> HashMap<Arc<str>, HashMap<Arc<str>, SavedEffect>> {
+        self.0
+            .iter()
+            .map(|active| {
+                let per_body_part = HashMap::from_iter([(
+                    Arc::from("bp_null"),
+                    SavedEffect::new(
+                        active.remaining.milliseconds() / Duration::SECOND.milliseconds(),
+                        active.intensity,
+                    ),
+                )]);
+                (active.info.id.fallback_name(), per_body_part)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::Component;
+use cdda_json_files::CommonItemInfo;
+use gameplay_object::BodyPart;
+
+/// How much the worn clothing hinders each body part, like in CDDA
+#[derive(Clone, Debug, Default, PartialEq, Eq, Component)]
+#[component(immutable)]
+pub struct Encumbrance(HashMap<BodyPart, u16>);
+
+impl Encumbrance {
+    /// Extra encumbrance for every additional item on the same layer of a body part
+    const LAYERING_PENALTY: u16 = 2;
+
+    /// Encumbered legs and feet never slow down more than this
+    const MAX_SLOWDOWN_PERCENT: u32 = 50;
+
+    #[must_use]
+    pub fn new<'a>(worn: impl IntoIterator<Item = &'a CommonItemInfo>) -> Self {
+        let worn = worn.into_iter().collect::<Vec<_>>();
+        Self(
+            BodyPart::ALL
+                .into_iter()
+                .filter_map(|body_part| {
+                    let covering = worn
+                        .iter()
+                        .filter(|item| item.covered_body_parts().contains(&body_part.cdda_id()))
+                        .collect::<Vec<_>>();
+
+                    let mut layers = covering
+                        .iter()
+                        .map(|item| item.clothing_layer())
+                        .collect::<Vec<_>>();
+                    layers.sort_unstable();
+                    layers.dedup();
+                    let layering = (covering.len() - layers.len()) as u16 * Self::LAYERING_PENALTY;
+
+                    let total = covering
+                        .iter()
+                        .map(|item| item.encumbrance(body_part.cdda_id()))
+                        .fold(layering, u16::saturating_add);
+                    (0 < total).then_some((body_part, total))
+                })
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn of(&self, body_part: BodyPart) -> u16 {
+        self.0.get(&body_part).copied().unwrap_or(0)
+    }
+
+    /// The most encumbered body part, if any
+    #[must_use]
+    pub fn worst(&self) -> Option<(BodyPart, u16)> {
+        BodyPart::ALL
+            .into_iter()
+            .map(|body_part| (body_part, self.of(body_part)))
+            .filter(|(_, encumbrance)| 0 < *encumbrance)
+            .max_by_key(|(_, encumbrance)| *encumbrance)
+    }
+
+    /// Encumbered legs, and to a lesser extent feet, slow down by 1 % per point
+    #[must_use]
+    pub fn speed_factor(&self) -> f32 {
+        let legs = u32::from(self.of(BodyPart::LegLeft)) + u32::from(self.of(BodyPart::LegRight));
+        let feet = u32::from(self.of(BodyPart::FootLeft)) + u32::from(self.of(BodyPart::FootRight));
+        let slowdown_percent = (legs / 2 + feet / 4).min(Self::MAX_SLOWDOWN_PERCENT);
+        1.0 - slowdown_percent as f32 / 100.0
+    }
+}
+
+#[cfg(test)]
+mod encumbrance_tests {
+    use super::*;
+    use cdda_json_files::Clothing;
+    use serde_json::from_str as from_json_str;
+    use std::sync::Arc;
+
+    fn jacket() -> Arc<CommonItemInfo> {
+        from_json_str::<Clothing>(include_str!("../test_data/jacket_leather.json"))
+            .expect("Valid jacket json")
+            .common
+    }
+
+    fn pants() -> Arc<CommonItemInfo> {
+        from_json_str::<Clothing>(include_str!("../test_data/pants.json"))
+            .expect("Valid pants json")
+            .common
+    }
+
+    #[test]
+    fn sum_per_body_part() {
+        let (jacket, pants) = (jacket(), pants());
+        let encumbrance = Encumbrance::new([&*jacket, &*pants]);
+        assert_eq!(encumbrance.of(BodyPart::Torso), 14, "{encumbrance:?}");
+        assert_eq!(encumbrance.of(BodyPart::ArmLeft), 14, "{encumbrance:?}");
+        assert_eq!(encumbrance.of(BodyPart::LegRight), 10, "{encumbrance:?}");
+        assert_eq!(encumbrance.of(BodyPart::Head), 0, "{encumbrance:?}");
+        assert_eq!(encumbrance.worst().map(|(_, worst)| worst), Some(14));
+    }
+
+    #[test]
+    fn nothing_worn() {
+        let encumbrance = Encumbrance::new([]);
+        assert_eq!(encumbrance, Encumbrance::default());
+        assert_eq!(encumbrance.worst(), None);
+        assert!((encumbrance.speed_factor() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn same_layer_is_penalized() {
+        let jacket = jacket();
+        let encumbrance = Encumbrance::new([&*jacket, &*jacket]);
+        assert_eq!(
+            encumbrance.of(BodyPart::Torso),
+            14 + 14 + Encumbrance::LAYERING_PENALTY,
+            "{encumbrance:?}"
+        );
+
+        let three = Encumbrance::new([&*jacket, &*jacket, &*jacket]);
+        assert_eq!(
+            three.of(BodyPart::ArmRight),
+            3 * 14 + 2 * Encumbrance::LAYERING_PENALTY,
+            "{three:?}"
+        );
+    }
+
+    #[test]
+    fn legs_slow_down_up_to_a_limit() {
+        let pants = pants();
+        let encumbrance = Encumbrance::new([&*pants]);
+        assert!((encumbrance.speed_factor() - 0.9).abs() < f32::EPSILON);
+
+        let many = Encumbrance::new(vec![&*pants; 10]);
+        assert!((many.speed_factor() - 0.5).abs() < f32::EPSILON, "{many:?}");
+    }
+}
@@ -15,6 +15,13 @@ impl Health {
         Self(Limited::full(max))
     }
 
+    /// Restore a saved fraction of the maximum health
+    pub fn restored(max: u16, relative: f32) -> Self {
+        let mut limited = Limited::full(max);
+        limited.current = (f32::from(max) * relative.clamp(0.0, 1.0)).round() as u16;
+        Self(limited)
+    }
+
     pub fn lower(&mut self, damage: &Damage) -> Evolution {
         self.0.lower(damage.amount)
     }
@@ -14,14 +14,25 @@ pub struct Melee {
 }
 
 impl Melee {
-    pub(crate) fn damage(&self, melee_weapon: Option<&Shared<CommonItemInfo>>) -> u16 {
-        (1..=self.dices)
+    /// Each skill level adds 5% damage
+    pub(crate) fn damage(
+        &self,
+        melee_weapon: Option<&Shared<CommonItemInfo>>,
+        skill_level: u8,
+    ) -> u16 {
+        let damage = (1..=self.dices)
             .map(|_| {
                 rand_u16(
                     1..=self.sides
                         + melee_weapon.map_or(0, |common_info| common_info.melee_damage()),
                 )
             })
-            .sum()
+            .sum::<u16>();
+        (u32::from(damage) * (20 + u32::from(skill_level)) / 20).min(u32::from(u16::MAX)) as u16
+    }
+
+    /// The skill that is trained by fighting, besides melee itself
+    pub(crate) fn weapon_skill(melee_weapon: Option<&Shared<CommonItemInfo>>) -> &'static str {
+        melee_weapon.map_or("unarmed", |common_info| common_info.melee_skill())
     }
 }
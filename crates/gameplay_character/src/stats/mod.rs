@@ -1,13 +1,27 @@
 //! Stats for characters
 
 mod aquatic;
+mod effects;
+mod encumbrance;
 mod health;
 mod melee;
+mod nutrition;
+mod perception;
+mod skills;
+mod snared;
 mod speed;
 mod stamina;
+mod temperature;
 
 pub use self::aquatic::Aquatic;
+pub use self::effects::{ActiveEffect, ApplyEffect, Effects, EffectsUpdate};
+pub use self::encumbrance::Encumbrance;
 pub use self::health::{HealingDuration, Health};
 pub use self::melee::Melee;
+pub use self::nutrition::{Hunger, Nourishment, Nutrition, Thirst};
+pub use self::perception::Perception;
+pub use self::skills::{Exercise, SkillLevel, Skills};
+pub use self::snared::Snared;
 pub use self::speed::{BaseSpeed, WalkingMode};
 pub use self::stamina::{Stamina, StaminaCost, StaminaImpact};
+pub use self::temperature::BodyTemperature;
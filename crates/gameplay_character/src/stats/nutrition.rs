@@ -0,0 +1,350 @@
+use bevy::prelude::{Component, TextColor};
+use hud::{BAD_TEXT_COLOR, GOOD_TEXT_COLOR, SOFT_TEXT_COLOR, WARN_TEXT_COLOR};
+use units::Duration;
+
+/// What eating or drinking something provides
+#[derive(Clone, Debug)]
+pub struct Nourishment {
+    pub kcal: u16,
+
+    /// Negative for salty food, that makes thirsty
+    pub quench: i8,
+}
+
+/// Stored energy and hydration - Mutable component
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct Nutrition {
+    stored_kcal: u32,
+
+    /// Like in CDDA, this increases when becoming thirsty
+    thirst: u16,
+
+    /// Time that passed without burning a full kilocalorie
+    burn_progress: Duration,
+
+    /// Time that passed without getting a full point more thirsty
+    thirst_progress: Duration,
+}
+
+impl Nutrition {
+    /// Roughly the stored energy of a new CDDA character
+    const DEFAULT_KCAL: u32 = 57_000;
+
+    /// Burning about 2500 kcal per day
+    const SECONDS_PER_KCAL: u64 = 35;
+
+    /// Getting about 290 points more thirsty per day
+    const MINUTES_PER_THIRST: u64 = 5;
+
+    /// CDDA stores calories instead of kilocalories
+    const CDDA_CALORIES_PER_KCAL: u64 = 1000;
+
+    /// Convert from the `stored_calories` and `thirst` of a CDDA save
+    #[must_use]
+    pub fn from_cdda(stored_calories: Option<u64>, thirst: Option<i64>) -> Self {
+        Self {
+            stored_kcal: stored_calories.map_or(Self::DEFAULT_KCAL, |calories| {
+                (calories / Self::CDDA_CALORIES_PER_KCAL).min(u64::from(u32::MAX)) as u32
+            }),
+            thirst: thirst.map_or(0, |thirst| thirst.clamp(0, i64::from(u16::MAX)) as u16),
+            burn_progress: Duration::ZERO,
+            thirst_progress: Duration::ZERO,
+        }
+    }
+
+    /// Convert to the `stored_calories` of a CDDA save
+    #[must_use]
+    pub fn cdda_stored_calories(&self) -> u64 {
+        u64::from(self.stored_kcal) * Self::CDDA_CALORIES_PER_KCAL
+    }
+
+    /// Convert to the `thirst` of a CDDA save
+    #[must_use]
+    pub const fn cdda_thirst(&self) -> u16 {
+        self.thirst
+    }
+
+    #[must_use]
+    pub const fn hunger(&self) -> Hunger {
+        Hunger::from_kcal(self.stored_kcal)
+    }
+
+    #[must_use]
+    pub const fn thirst(&self) -> Thirst {
+        Thirst::from_cdda(self.thirst)
+    }
+
+    /// Starving and dehydration slow down
+    #[must_use]
+    pub const fn speed_factor(&self) -> f32 {
+        self.hunger().speed_factor() * self.thirst().speed_factor()
+    }
+
+    /// Whether the character has room for the calories or need for the hydration
+    ///
+    /// Things without calories or hydration, like medicine, are always accepted.
+    #[must_use]
+    pub fn wants(&self, nourishment: &Nourishment) -> bool {
+        (nourishment.kcal == 0 && nourishment.quench <= 0)
+            || (0 < nourishment.kcal && self.hunger() != Hunger::Full)
+            || (0 < nourishment.quench && 0 < self.thirst)
+    }
+
+    pub fn nourish(&mut self, nourishment: &Nourishment) {
+        self.stored_kcal = self.stored_kcal.saturating_add(u32::from(nourishment.kcal));
+        self.thirst = self
+            .thirst
+            .saturating_add_signed(-i16::from(nourishment.quench));
+    }
+
+    /// Burn calories and get thirsty as time passes
+    pub fn update(&mut self, elapsed: Duration) {
+        self.burn_progress += elapsed;
+        let burned = self
+            .burn_progress
+            .extract_div(Duration::SECOND * Self::SECONDS_PER_KCAL);
+        self.stored_kcal = self
+            .stored_kcal
+            .saturating_sub(burned.min(u64::from(u32::MAX)) as u32);
+
+        self.thirst_progress += elapsed;
+        let thirst = self
+            .thirst_progress
+            .extract_div(Duration::MINUTE * Self::MINUTES_PER_THIRST);
+        self.thirst = self
+            .thirst
+            .saturating_add(thirst.min(u64::from(u16::MAX)) as u16);
+    }
+}
+
+impl Default for Nutrition {
+    fn default() -> Self {
+        Self::from_cdda(None, None)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Hunger {
+    Full,
+    Sated,
+    Hungry,
+    VeryHungry,
+    Starving,
+}
+
+impl Hunger {
+    const fn from_kcal(stored_kcal: u32) -> Self {
+        match stored_kcal {
+            58_000.. => Self::Full,
+            56_000.. => Self::Sated,
+            53_000.. => Self::Hungry,
+            45_000.. => Self::VeryHungry,
+            _ => Self::Starving,
+        }
+    }
+
+    const fn speed_factor(self) -> f32 {
+        match self {
+            Self::Full | Self::Sated | Self::Hungry => 1.0,
+            Self::VeryHungry => 0.95,
+            Self::Starving => 0.8,
+        }
+    }
+
+    /// `None` when nothing needs to be shown
+    #[must_use]
+    pub const fn as_str(self) -> Option<&'static str> {
+        match self {
+            Self::Full => Some("Full"),
+            Self::Sated => None,
+            Self::Hungry => Some("Hungry"),
+            Self::VeryHungry => Some("Very hungry"),
+            Self::Starving => Some("Starving"),
+        }
+    }
+
+    #[must_use]
+    pub const fn color(self) -> TextColor {
+        match self {
+            Self::Full => GOOD_TEXT_COLOR,
+            Self::Sated => SOFT_TEXT_COLOR,
+            Self::Hungry | Self::VeryHungry => WARN_TEXT_COLOR,
+            Self::Starving => BAD_TEXT_COLOR,
+        }
+    }
+}
+
+/// Follows the thresholds of CDDA
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Thirst {
+    Hydrated,
+    Thirsty,
+    VeryThirsty,
+    Dehydrated,
+    Parched,
+}
+
+impl Thirst {
+    const fn from_cdda(thirst: u16) -> Self {
+        match thirst {
+            521.. => Self::Parched,
+            241.. => Self::Dehydrated,
+            81.. => Self::VeryThirsty,
+            41.. => Self::Thirsty,
+            _ => Self::Hydrated,
+        }
+    }
+
+    const fn speed_factor(self) -> f32 {
+        match self {
+            Self::Hydrated | Self::Thirsty | Self::VeryThirsty => 1.0,
+            Self::Dehydrated => 0.9,
+            Self::Parched => 0.7,
+        }
+    }
+
+    /// `None` when nothing needs to be shown
+    #[must_use]
+    pub const fn as_str(self) -> Option<&'static str> {
+        match self {
+            Self::Hydrated => None,
+            Self::Thirsty => Some("Thirsty"),
+            Self::VeryThirsty => Some("Very thirsty"),
+            Self::Dehydrated => Some("Dehydrated"),
+            Self::Parched => Some("Parched"),
+        }
+    }
+
+    #[must_use]
+    pub const fn color(self) -> TextColor {
+        match self {
+            Self::Hydrated => SOFT_TEXT_COLOR,
+            Self::Thirsty | Self::VeryThirsty => WARN_TEXT_COLOR,
+            Self::Dehydrated | Self::Parched => BAD_TEXT_COLOR,
+        }
+    }
+}
+
+#[cfg(test)]
+mod nutrition_tests {
+    use super::*;
+
+    #[test]
+    fn daily_rates() {
+        let mut nutrition = Nutrition::default();
+        nutrition.update(Duration::HOUR * 24);
+        assert_eq!(nutrition.stored_kcal, Nutrition::DEFAULT_KCAL - 2468);
+        assert_eq!(nutrition.cdda_thirst(), 288);
+    }
+
+    #[test]
+    fn partial_progress_is_kept() {
+        let mut nutrition = Nutrition::default();
+        nutrition.update(Duration::SECOND * 20);
+        assert_eq!(nutrition.stored_kcal, Nutrition::DEFAULT_KCAL);
+        nutrition.update(Duration::SECOND * 20);
+        assert_eq!(nutrition.stored_kcal, Nutrition::DEFAULT_KCAL - 1);
+        assert_eq!(nutrition.burn_progress, Duration::SECOND * 5);
+
+        nutrition.update(Duration::MINUTE * 4);
+        assert_eq!(nutrition.cdda_thirst(), 0);
+        nutrition.update(Duration::MINUTE * 2);
+        assert_eq!(nutrition.cdda_thirst(), 1);
+    }
+
+    #[test]
+    fn nourish_and_quench() {
+        let mut nutrition = Nutrition::from_cdda(Some(50_000_000), Some(30));
+        nutrition.nourish(&Nourishment {
+            kcal: 300,
+            quench: 10,
+        });
+        assert_eq!(nutrition.cdda_stored_calories(), 50_300_000);
+        assert_eq!(nutrition.cdda_thirst(), 20);
+
+        // Salty food
+        nutrition.nourish(&Nourishment {
+            kcal: 0,
+            quench: -5,
+        });
+        assert_eq!(nutrition.cdda_thirst(), 25);
+
+        nutrition.nourish(&Nourishment {
+            kcal: 0,
+            quench: 100,
+        });
+        assert_eq!(nutrition.cdda_thirst(), 0, "Saturates");
+    }
+
+    #[test]
+    fn wants() {
+        let food = Nourishment {
+            kcal: 200,
+            quench: 0,
+        };
+        let water = Nourishment {
+            kcal: 0,
+            quench: 20,
+        };
+        let medicine = Nourishment { kcal: 0, quench: 0 };
+
+        let full = Nutrition::from_cdda(Some(60_000_000), Some(0));
+        assert!(!full.wants(&food), "{full:?}");
+        assert!(!full.wants(&water), "{full:?}");
+        assert!(full.wants(&medicine), "{full:?}");
+
+        let thirsty = Nutrition::from_cdda(None, Some(50));
+        assert!(thirsty.wants(&food), "{thirsty:?}");
+        assert!(thirsty.wants(&water), "{thirsty:?}");
+    }
+
+    #[test]
+    fn hunger_thresholds() {
+        for (kcal, hunger) in [
+            (58_000, Hunger::Full),
+            (57_999, Hunger::Sated),
+            (56_000, Hunger::Sated),
+            (55_999, Hunger::Hungry),
+            (53_000, Hunger::Hungry),
+            (52_999, Hunger::VeryHungry),
+            (45_000, Hunger::VeryHungry),
+            (44_999, Hunger::Starving),
+            (0, Hunger::Starving),
+        ] {
+            assert_eq!(Hunger::from_kcal(kcal), hunger, "{kcal}");
+        }
+    }
+
+    #[test]
+    fn thirst_thresholds() {
+        for (thirst, expected) in [
+            (40, Thirst::Hydrated),
+            (41, Thirst::Thirsty),
+            (80, Thirst::Thirsty),
+            (81, Thirst::VeryThirsty),
+            (240, Thirst::VeryThirsty),
+            (241, Thirst::Dehydrated),
+            (520, Thirst::Dehydrated),
+            (521, Thirst::Parched),
+        ] {
+            assert_eq!(Thirst::from_cdda(thirst), expected, "{thirst}");
+        }
+    }
+
+    #[test]
+    fn crossing_thresholds_over_time() {
+        let mut nutrition = Nutrition::from_cdda(Some(56_000_000), Some(40));
+        assert_eq!(nutrition.hunger(), Hunger::Sated);
+        assert_eq!(nutrition.thirst(), Thirst::Hydrated);
+        assert!((nutrition.speed_factor() - 1.0).abs() < f32::EPSILON);
+
+        nutrition.update(Duration::MINUTE * 5);
+        assert_eq!(nutrition.hunger(), Hunger::Hungry);
+        assert_eq!(nutrition.thirst(), Thirst::Thirsty);
+
+        let mut parched = Nutrition::from_cdda(Some(44_000_000), Some(520));
+        parched.update(Duration::MINUTE * 5);
+        assert_eq!(parched.thirst(), Thirst::Parched);
+        assert!((parched.speed_factor() - 0.8 * 0.7).abs() < f32::EPSILON);
+    }
+}
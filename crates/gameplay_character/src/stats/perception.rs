@@ -0,0 +1,33 @@
+use bevy::prelude::Component;
+use cdda_json_files::TrapInfo;
+use fastrand::u8 as rand_u8;
+
+/// Only known for the player character
+#[derive(Debug, Component)]
+#[component(immutable)]
+pub struct Perception(pub u8);
+
+impl Default for Perception {
+    /// The starting value in CDDA
+    fn default() -> Self {
+        Self(8)
+    }
+}
+
+impl Perception {
+    /// Farther away and less visible traps are harder to detect
+    #[must_use]
+    pub fn detects(&self, trap_info: &TrapInfo, distance: u8) -> bool {
+        if trap_info.always_invisible {
+            return false;
+        }
+
+        let difficulty = i16::from(trap_info.visibility) + i16::from(distance);
+        difficulty <= i16::from(rand_u8(0..=self.0))
+    }
+
+    /// Between half and all of the perception
+    pub(crate) fn disarm_roll(&self) -> u8 {
+        rand_u8(self.0 / 2..=self.0)
+    }
+}
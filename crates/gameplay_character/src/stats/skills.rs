@@ -0,0 +1,253 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::Component;
+use cdda_json_files::Skill;
+use fastrand::u16 as rand_u16;
+use std::sync::Arc;
+use units::{Duration, Timestamp};
+
+/// Training of a skill, up to an optional level
+#[derive(Clone, Debug)]
+pub struct Exercise {
+    pub skill: Arc<str>,
+
+    /// Before applying focus
+    pub amount: u16,
+
+    /// No progress is made at or above this level
+    pub cap: Option<u8>,
+}
+
+impl Exercise {
+    #[must_use]
+    pub fn new(skill: impl Into<Arc<str>>, amount: u16, cap: Option<u8>) -> Self {
+        Self {
+            skill: skill.into(),
+            amount,
+            cap,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SkillLevel {
+    pub level: u8,
+
+    /// Progress towards the next level
+    pub exercise: u32,
+
+    /// `None` until the first update
+    last_practiced: Option<Timestamp>,
+}
+
+impl SkillLevel {
+    /// Exercise needed to reach the next level, as in CDDA
+    #[must_use]
+    pub const fn threshold(&self) -> u32 {
+        let next = self.level as u32 + 1;
+        100 * 100 * next * next
+    }
+
+    /// From 0 to 100
+    #[must_use]
+    pub const fn progress(&self) -> u8 {
+        (100 * self.exercise as u64 / self.threshold() as u64) as u8
+    }
+}
+
+/// Only known for the player character
+///
+/// Mutable component
+#[derive(Debug, Component)]
+pub struct Skills {
+    levels: HashMap<Arc<str>, SkillLevel>,
+
+    /// Percentage of the exercise that is effective
+    focus: u8,
+
+    /// `None` until the first update
+    last_update: Option<Timestamp>,
+}
+
+impl Skills {
+    /// The focus that is slowly restored when not exercising
+    const FOCUS_EQUILIBRIUM: u8 = 100;
+
+    /// How long skills stay fresh after being practiced
+    const RUST_DELAY: Duration = Duration::DAY;
+
+    /// Convert from the skills and the focus of a CDDA save
+    #[must_use]
+    pub fn from_cdda(skills: &HashMap<Arc<str>, Skill>, focus_pool: Option<u64>) -> Self {
+        Self {
+            levels: skills
+                .iter()
+                .map(|(name, skill)| {
+                    (
+                        name.clone(),
+                        SkillLevel {
+                            level: skill.level,
+                            exercise: skill.exercise,
+                            last_practiced: None,
+                        },
+                    )
+                })
+                .collect(),
+            focus: focus_pool.map_or(Self::FOCUS_EQUILIBRIUM, |focus_pool| {
+                focus_pool.min(u64::from(Self::FOCUS_EQUILIBRIUM)) as u8
+            }),
+            last_update: None,
+        }
+    }
+
+    #[must_use]
+    pub fn level(&self, skill: &str) -> u8 {
+        self.levels
+            .get(skill)
+            .map_or(0, |skill_level| skill_level.level)
+    }
+
+    /// From 0 to 100
+    #[must_use]
+    pub fn progress(&self, skill: &str) -> u8 {
+        self.levels.get(skill).map_or(0, SkillLevel::progress)
+    }
+
+    #[must_use]
+    pub const fn focus(&self) -> u8 {
+        self.focus
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Arc<str>, &SkillLevel)> {
+        self.levels.iter()
+    }
+
+    /// Returns the new level, if the skill improved
+    pub fn exercise(&mut self, exercise: &Exercise, now: Timestamp) -> Option<u8> {
+        let skill_level = self
+            .levels
+            .entry(exercise.skill.clone())
+            .or_insert_with(|| SkillLevel {
+                level: 0,
+                exercise: 0,
+                last_practiced: None,
+            });
+        skill_level.last_practiced = Some(now);
+        if exercise.cap.is_some_and(|cap| cap <= skill_level.level) {
+            return None;
+        }
+
+        skill_level.exercise += u32::from(exercise.amount) * u32::from(self.focus.max(1));
+
+        // Exercising drains focus, a tenth of the amount on average
+        let drain = exercise.amount / 10 + u16::from(rand_u16(0..10) < exercise.amount % 10);
+        self.focus = self
+            .focus
+            .saturating_sub(drain.min(u16::from(u8::MAX)) as u8);
+
+        let before = skill_level.level;
+        while skill_level.threshold() <= skill_level.exercise
+            && exercise.cap.is_none_or(|cap| skill_level.level < cap)
+        {
+            skill_level.exercise -= skill_level.threshold();
+            skill_level.level += 1;
+        }
+        (before < skill_level.level).then_some(skill_level.level)
+    }
+
+    /// Restores focus and lets unpracticed skills rust
+    ///
+    /// Rust only removes exercise, never a level.
+    pub fn update(&mut self, now: Timestamp) {
+        let last_update = self.last_update.get_or_insert(now);
+        let minutes = (now - *last_update).milliseconds() / Duration::MINUTE.milliseconds();
+        if minutes == 0 {
+            return;
+        }
+        *last_update += Duration::MINUTE * minutes;
+
+        for _ in 0..minutes.min(u64::from(Self::FOCUS_EQUILIBRIUM)) {
+            // A tenth of the gap, rounded up
+            let gap = Self::FOCUS_EQUILIBRIUM.saturating_sub(self.focus);
+            self.focus += gap.div_ceil(10);
+        }
+
+        for skill_level in self.levels.values_mut() {
+            let unpracticed = now - *skill_level.last_practiced.get_or_insert(now);
+            if Self::RUST_DELAY < unpracticed && 0 < skill_level.level {
+                // One percent of a level per hour
+                let rusting = (unpracticed - Self::RUST_DELAY).min(Duration::MINUTE * minutes);
+                let rust = u64::from(skill_level.threshold() / 100) * rusting.milliseconds()
+                    / Duration::HOUR.milliseconds();
+                skill_level.exercise = skill_level
+                    .exercise
+                    .saturating_sub(rust.min(u64::from(u32::MAX)) as u32);
+            }
+        }
+    }
+
+    /// Percentage of the normal speed when working on a task with the given difficulty
+    ///
+    /// Each level above or below the difficulty adds or removes 10%, between 50% and 150%.
+    #[must_use]
+    pub fn work_speed(&self, skill: &str, difficulty: u8) -> u64 {
+        let difference = i64::from(self.level(skill)) - i64::from(difficulty);
+        (100 + 10 * difference).clamp(50, 150) as u64
+    }
+}
+
+#[cfg(test)]
+mod skills_tests {
+    use super::*;
+
+    fn skills() -> Skills {
+        let mut cdda_skills = HashMap::default();
+        cdda_skills.insert(Arc::from("melee"), Skill::new(1));
+        let mut skills = Skills::from_cdda(&cdda_skills, None);
+        skills.update(Timestamp::ZERO);
+        skills
+    }
+
+    #[test]
+    fn exercise_raises_level() {
+        let mut skills = skills();
+        let exercise = Exercise::new("melee", 400, None);
+        assert_eq!(
+            skills.exercise(&exercise, Timestamp::ZERO),
+            Some(2),
+            "{skills:?}"
+        );
+        assert_eq!(skills.level("melee"), 2, "{skills:?}");
+        assert!(skills.focus() < 100, "{skills:?}");
+    }
+
+    #[test]
+    fn cap_prevents_progress() {
+        let mut skills = skills();
+        let exercise = Exercise::new("melee", 400, Some(1));
+        assert_eq!(
+            skills.exercise(&exercise, Timestamp::ZERO),
+            None,
+            "{skills:?}"
+        );
+        assert_eq!(skills.level("melee"), 1, "{skills:?}");
+    }
+
+    #[test]
+    fn rust_keeps_level() {
+        let mut skills = skills();
+        let exercise = Exercise::new("melee", 100, None);
+        skills.exercise(&exercise, Timestamp::ZERO);
+        let later = Timestamp::ZERO + Duration::DAY * 30;
+        skills.update(later);
+        assert_eq!(skills.level("melee"), 1, "{skills:?}");
+        assert_eq!(
+            skills
+                .iter()
+                .find(|(name, _)| &***name == "melee")
+                .map(|(_, level)| level.exercise),
+            Some(0),
+            "{skills:?}"
+        );
+        assert_eq!(skills.focus(), 100, "{skills:?}");
+    }
+}
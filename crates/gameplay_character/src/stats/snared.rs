@@ -0,0 +1,12 @@
+use bevy::prelude::Component;
+
+/// Held by a trap, like a bear trap or a pit
+#[derive(Debug, Component)]
+#[component(immutable)]
+pub struct Snared {
+    /// Climbing out, instead of struggling free
+    pub in_pit: bool,
+
+    /// The number of steps it takes to get free
+    pub attempts_left: u8,
+}
@@ -16,6 +16,29 @@ impl Stamina {
 
     pub const FULL: Self = Self::Limited(Limited::full(Self::MAX));
 
+    /// The default maximum stamina in CDDA
+    const CDDA_MAX: u16 = 10_000;
+
+    /// Convert from the stamina value of a CDDA save
+    #[must_use]
+    pub fn from_cdda(stamina: u64) -> Self {
+        let mut limited = Limited::full(Self::MAX);
+        limited.current = (stamina.min(u64::from(Self::CDDA_MAX)) * u64::from(Self::MAX)
+            / u64::from(Self::CDDA_MAX)) as u16;
+        Self::Limited(limited)
+    }
+
+    /// Convert to the stamina value of a CDDA save
+    #[must_use]
+    pub const fn cdda_value(&self) -> Option<u16> {
+        match self {
+            Self::Unlimited => None,
+            Self::Limited(limited) => Some(
+                (limited.current as u32 * Self::CDDA_MAX as u32 / Self::MAX as u32) as u16,
+            ),
+        }
+    }
+
     pub const fn breath(&self) -> Breath {
         match self {
             Self::Unlimited => Breath::Normal,
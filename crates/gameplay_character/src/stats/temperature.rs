@@ -0,0 +1,104 @@
+use bevy::prelude::Component;
+
+/// The core temperature of a character, in degrees Celsius
+///
+/// Mutable component
+#[derive(Clone, Copy, Debug, PartialEq, Component)]
+pub struct BodyTemperature(f32);
+
+impl BodyTemperature {
+    pub const NORMAL: f32 = 37.0;
+
+    /// Thresholds for the intensities of being cold, matching 'chilly', 'cold', and 'freezing' in CDDA
+    const COLD: [f32; 3] = [34.0, 31.0, 28.0];
+
+    /// Thresholds for the intensities of being hot, matching 'warm', 'hot', and 'scorching' in CDDA
+    const HOT: [f32; 3] = [40.0, 43.0, 46.0];
+
+    /// The normal temperature in CDDA
+    const CDDA_NORMAL: i64 = 5000;
+
+    /// CDDA uses 1500 units between the thresholds
+    const CDDA_UNITS_PER_DEGREE: f32 = 500.0;
+
+    /// Convert from the `temp_cur` of a CDDA save
+    #[must_use]
+    pub fn from_cdda(units: i64) -> Self {
+        Self(Self::NORMAL + (units - Self::CDDA_NORMAL) as f32 / Self::CDDA_UNITS_PER_DEGREE)
+    }
+
+    /// Convert to the `temp_cur` of a CDDA save
+    #[must_use]
+    pub fn to_cdda(self) -> i64 {
+        Self::CDDA_NORMAL + ((self.0 - Self::NORMAL) * Self::CDDA_UNITS_PER_DEGREE).round() as i64
+    }
+
+    #[must_use]
+    pub const fn celsius(self) -> f32 {
+        self.0
+    }
+
+    /// Move towards the target temperature, by the given part of the difference
+    pub fn approach(&mut self, target: f32, factor: f32) {
+        self.0 += (target - self.0) * factor.clamp(0.0, 1.0);
+    }
+
+    /// Between 0 (comfortable) and 3 (freezing)
+    #[must_use]
+    pub fn cold(self) -> u8 {
+        Self::COLD
+            .iter()
+            .filter(|&&threshold| self.0 < threshold)
+            .count() as u8
+    }
+
+    /// Between 0 (comfortable) and 3 (scorching)
+    #[must_use]
+    pub fn hot(self) -> u8 {
+        Self::HOT
+            .iter()
+            .filter(|&&threshold| threshold < self.0)
+            .count() as u8
+    }
+}
+
+impl Default for BodyTemperature {
+    fn default() -> Self {
+        Self(Self::NORMAL)
+    }
+}
+
+#[cfg(test)]
+mod temperature_tests {
+    use super::*;
+
+    #[test]
+    fn cdda_round_trip() {
+        for units in [0, 3500, 5000, 6501, 9000] {
+            assert_eq!(BodyTemperature::from_cdda(units).to_cdda(), units);
+        }
+    }
+
+    #[test]
+    fn thresholds() {
+        let normal = BodyTemperature::default();
+        assert_eq!((normal.cold(), normal.hot()), (0, 0), "{normal:?}");
+
+        for (celsius, cold) in [(33.9, 1), (30.9, 2), (27.9, 3)] {
+            assert_eq!(BodyTemperature(celsius).cold(), cold, "{celsius}");
+        }
+        for (celsius, hot) in [(40.1, 1), (43.1, 2), (46.1, 3)] {
+            assert_eq!(BodyTemperature(celsius).hot(), hot, "{celsius}");
+        }
+    }
+
+    #[test]
+    fn approach_is_clamped() {
+        let mut temperature = BodyTemperature::default();
+        temperature.approach(30.0, 2.0);
+        assert!(
+            (temperature.celsius() - 30.0).abs() < f32::EPSILON,
+            "{temperature:?}"
+        );
+    }
+}
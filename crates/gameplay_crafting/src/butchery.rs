@@ -0,0 +1,83 @@
+use bevy::prelude::{AnyOf, Component, Query};
+use cdda_json_files::{CharacterInfo, FurnitureInfo, InfoId, TerrainInfo};
+use gameplay_common::{LastSeen, Shared};
+use gameplay_item::{BodyContainers, Item};
+use gameplay_location::{LocationCache, Pos};
+use gameplay_nearby::{find_nearby, find_nearby_pseudo, nearby_qualities};
+use units::{Duration, Mass, Volume};
+
+/// Mutable component of a corpse that is partially butchered
+#[derive(Clone, Debug, Component)]
+pub struct Butchery {
+    work_done: Duration,
+    total: Duration,
+}
+
+impl Butchery {
+    /// Larger and heavier corpses take longer
+    #[must_use]
+    pub fn new(character_info: &CharacterInfo) -> Self {
+        let liters = character_info.volume.map_or(0, |volume| {
+            volume / Volume::try_from("1 L").expect("Well formatted")
+        });
+        let kilograms = character_info.mass.map_or(0, |mass| {
+            mass / Mass::try_from("1 kg").expect("Well formatted")
+        });
+        Self {
+            work_done: Duration::ZERO,
+            total: Duration::MINUTE * u64::from((liters + kilograms).max(1)),
+        }
+    }
+
+    pub fn work(&mut self, duration: Duration) {
+        self.work_done += duration;
+    }
+
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        self.total <= self.work_done
+    }
+
+    pub(crate) fn percent_progress(&self) -> f32 {
+        if self.finished() {
+            100.0
+        } else {
+            100.0 * (self.work_done / self.total)
+        }
+    }
+
+    pub(crate) fn time_left(&self) -> Duration {
+        if self.finished() {
+            Duration::ZERO
+        } else {
+            self.total - self.work_done
+        }
+    }
+}
+
+/// The best cutting quality nearby, if any
+#[must_use]
+pub fn nearby_cutting_quality(
+    location: &LocationCache,
+    items: &Query<(Item, &LastSeen)>,
+    infrastructure: &Query<(
+        AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
+        &LastSeen,
+    )>,
+    butcher_pos: Pos,
+    butcher_body_containers: &BodyContainers,
+) -> Option<i8> {
+    let nearby_items = find_nearby(location, items, butcher_pos, butcher_body_containers);
+    let nearby_pseudo_items = find_nearby_pseudo(location, infrastructure, butcher_pos);
+    let cutting = InfoId::new("CUT");
+    nearby_qualities(&nearby_items, &nearby_pseudo_items)
+        .into_iter()
+        .find(|(quality, _)| quality.id == cutting)
+        .map(|(_, level)| level)
+        .filter(|level| 0 < *level)
+}
+
+pub trait HarvestSpawner {
+    /// Spawns the butchery drops of the corpse as items on the tile
+    fn spawn_harvest(&mut self, pos: Pos, character_info: &CharacterInfo);
+}
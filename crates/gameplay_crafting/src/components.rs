@@ -4,6 +4,7 @@ use cdda_json_files::{
 };
 use hud::{BAD_TEXT_COLOR, GOOD_TEXT_COLOR, HARD_TEXT_COLOR, SOFT_TEXT_COLOR, WARN_TEXT_COLOR};
 use std::{cmp::Ordering, num::NonZeroU32, sync::Arc};
+use text::Fragment;
 use units::Duration;
 
 /// Mutable component
@@ -23,6 +24,11 @@ impl Craft {
         }
     }
 
+    #[must_use]
+    pub const fn recipe(&self) -> &Arc<Recipe> {
+        &self.recipe
+    }
+
     pub fn work(&mut self, duration: Duration) {
         self.work_done += duration;
     }
@@ -69,43 +75,14 @@ impl RecipeSituation {
     pub fn consumed_tool_charges(&self) -> impl Iterator<Item = Consumed<'_>> {
         self.tools
             .iter()
-            .filter_map(|tool| Self::consumed(&tool.alternatives))
+            .filter_map(|tool| consumed(&tool.alternatives))
     }
 
     /// Assumes being craftable
     pub fn consumed_components(&self) -> impl Iterator<Item = Consumed<'_>> {
         self.components
             .iter()
-            .filter_map(|component| Self::consumed(&component.alternatives))
-    }
-
-    /// Assumes being craftable
-    fn consumed<R: RequiredPart>(
-        alternative_situations: &[AlternativeSituation<R>],
-    ) -> Option<Consumed<'_>> {
-        if alternative_situations
-            .iter()
-            .any(|alternative| match alternative.detected {
-                DetectedQuantity::Missing => false,
-                DetectedQuantity::Limited { .. } => !alternative.required.needs_quantity(),
-                DetectedQuantity::Infinite => true,
-            })
-        {
-            return None;
-        }
-
-        alternative_situations.iter().find_map(|alternative| {
-            if let DetectedQuantity::Limited { from_entities, .. } = &alternative.detected {
-                NonZeroU32::try_from(alternative.required.used_amount())
-                    .ok()
-                    .map(|amount| Consumed {
-                        amount,
-                        from_entities,
-                    })
-            } else {
-                None
-            }
-        })
+            .filter_map(|component| consumed(&component.alternatives))
     }
 
     #[must_use]
@@ -190,6 +167,35 @@ impl RecipeSituation {
     }
 }
 
+/// Assumes being craftable or constructable
+pub(crate) fn consumed<R: RequiredPart>(
+    alternative_situations: &[AlternativeSituation<R>],
+) -> Option<Consumed<'_>> {
+    if alternative_situations
+        .iter()
+        .any(|alternative| match alternative.detected {
+            DetectedQuantity::Missing => false,
+            DetectedQuantity::Limited { .. } => !alternative.required.needs_quantity(),
+            DetectedQuantity::Infinite => true,
+        })
+    {
+        return None;
+    }
+
+    alternative_situations.iter().find_map(|alternative| {
+        if let DetectedQuantity::Limited { from_entities, .. } = &alternative.detected {
+            NonZeroU32::try_from(alternative.required.used_amount())
+                .ok()
+                .map(|amount| Consumed {
+                    amount,
+                    from_entities,
+                })
+        } else {
+            None
+        }
+    })
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct QualitySituation {
     pub(super) name: Arc<str>,
@@ -203,7 +209,19 @@ impl QualitySituation {
             .is_some_and(|present| self.required as i8 <= present)
     }
 
-    fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
+    /// Short, to fit in a single line
+    pub(super) fn fragment(&self) -> Fragment {
+        Fragment::colorized(
+            format!("{} {}", self.name, self.required),
+            if self.is_present() {
+                GOOD_TEXT_COLOR
+            } else {
+                BAD_TEXT_COLOR
+            },
+        )
+    }
+
+    pub(super) fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
         let checked_color = if self.is_present() {
             GOOD_TEXT_COLOR
         } else {
@@ -262,7 +280,21 @@ impl ToolSituation {
             .any(AlternativeSituation::is_present)
     }
 
-    fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
+    /// Short, to fit in a single line
+    pub(super) fn fragment(&self) -> Fragment {
+        // The alternatives are sorted by presence
+        self.alternatives.first().map_or_else(
+            || Fragment::bad("?"),
+            |alternative| {
+                Fragment::colorized(
+                    alternative.required.format(&alternative.name),
+                    alternative.presence_color(),
+                )
+            },
+        )
+    }
+
+    pub(super) fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
         let mut text_sections = Vec::new();
 
         for (index, alternative) in self.alternatives.iter().enumerate() {
@@ -292,7 +324,7 @@ impl ComponentSituation {
             .any(AlternativeSituation::is_present)
     }
 
-    fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
+    pub(super) fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
         let mut text_sections = Vec::new();
 
         for (index, alternative) in self.alternatives.iter().enumerate() {
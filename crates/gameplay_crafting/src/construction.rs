@@ -0,0 +1,205 @@
+use crate::components::consumed;
+use crate::{ComponentSituation, Consumed, QualitySituation, ToolSituation};
+use bevy::prelude::{Bundle, Component, Entity, Text, TextColor, TextSpan};
+use cdda_json_files::{Construction, PartialConstruction};
+use gameplay_location::Pos;
+use hud::{BAD_TEXT_COLOR, GOOD_TEXT_COLOR, HARD_TEXT_COLOR, SOFT_TEXT_COLOR, WARN_TEXT_COLOR};
+use std::sync::Arc;
+use units::Duration;
+
+/// Mutable component of an unfinished construction
+///
+/// The construction can be resumed later, possibly after saving and loading.
+#[derive(Debug, Component)]
+pub struct ConstructionSite {
+    pub(crate) construction: Arc<Construction>,
+    pub(crate) work_done: Duration,
+}
+
+impl ConstructionSite {
+    #[must_use]
+    pub const fn new(construction: Arc<Construction>) -> Self {
+        Self {
+            construction,
+            work_done: Duration::ZERO,
+        }
+    }
+
+    /// Using the CDDA progress counter of [`PartialConstruction`]
+    #[must_use]
+    pub fn from_counter(construction: Arc<Construction>, counter: u32) -> Self {
+        let counter = counter.min(PartialConstruction::COUNTER_FINISHED);
+        let work_done = Duration::MILLISECOND
+            * (construction.time.milliseconds() * u64::from(counter)
+                / u64::from(PartialConstruction::COUNTER_FINISHED));
+        Self {
+            construction,
+            work_done,
+        }
+    }
+
+    /// The CDDA progress counter, as used by [`PartialConstruction`]
+    #[must_use]
+    pub fn counter(&self) -> u32 {
+        if self.finished() {
+            PartialConstruction::COUNTER_FINISHED
+        } else {
+            (self.work_done.milliseconds() * u64::from(PartialConstruction::COUNTER_FINISHED)
+                / self.construction.time.milliseconds()) as u32
+        }
+    }
+
+    #[must_use]
+    pub const fn construction(&self) -> &Arc<Construction> {
+        &self.construction
+    }
+
+    pub fn work(&mut self, duration: Duration) {
+        self.work_done += duration;
+    }
+
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        self.construction.time <= self.work_done
+    }
+
+    pub(crate) fn percent_progress(&self) -> f32 {
+        if self.finished() {
+            100.0
+        } else {
+            100.0 * (self.work_done / self.construction.time)
+        }
+    }
+
+    pub(crate) fn time_left(&self) -> Duration {
+        if self.finished() {
+            Duration::ZERO
+        } else {
+            self.construction.time - self.work_done
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Component)]
+#[component(immutable)]
+pub struct ConstructionSituation {
+    pub(super) construction: Arc<Construction>,
+    pub(super) name: Arc<str>,
+
+    /// The unfinished construction to resume, if any
+    pub(super) site: Option<Entity>,
+
+    pub(super) qualities: Vec<QualitySituation>,
+    pub(super) tools: Vec<ToolSituation>,
+
+    /// Empty when resuming, because the components are already used
+    pub(super) components: Vec<ComponentSituation>,
+}
+
+impl ConstructionSituation {
+    #[must_use]
+    pub const fn construction(&self) -> &Arc<Construction> {
+        &self.construction
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &Arc<str> {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn site(&self) -> Option<Entity> {
+        self.site
+    }
+
+    /// Assumes being constructable
+    pub fn consumed_tool_charges(&self) -> impl Iterator<Item = Consumed<'_>> {
+        self.tools
+            .iter()
+            .filter_map(|tool| consumed(&tool.alternatives))
+    }
+
+    /// Assumes being constructable
+    pub fn consumed_components(&self) -> impl Iterator<Item = Consumed<'_>> {
+        self.components
+            .iter()
+            .filter_map(|component| consumed(&component.alternatives))
+    }
+
+    #[must_use]
+    pub fn color(&self, selected: bool) -> TextColor {
+        if self.constructable() {
+            if selected {
+                GOOD_TEXT_COLOR
+            } else {
+                HARD_TEXT_COLOR
+            }
+        } else if selected {
+            BAD_TEXT_COLOR
+        } else {
+            SOFT_TEXT_COLOR
+        }
+    }
+
+    #[must_use]
+    pub fn constructable(&self) -> bool {
+        self.qualities.iter().all(QualitySituation::is_present)
+            && self.tools.iter().all(ToolSituation::is_present)
+            && self.components.iter().all(ComponentSituation::is_present)
+    }
+
+    #[must_use]
+    pub fn text_sections(&self) -> Vec<(TextSpan, TextColor)> {
+        let mut text_sections = vec![
+            (TextSpan::new("Construction: "), SOFT_TEXT_COLOR),
+            (TextSpan::new(&*self.name), self.color(true)),
+            (
+                TextSpan::new(format!("\n({})", self.construction.id.fallback_name())),
+                SOFT_TEXT_COLOR,
+            ),
+        ];
+        if self.site.is_some() {
+            text_sections.push((TextSpan::new("\n\nUnfinished"), WARN_TEXT_COLOR));
+        }
+        for (skill, level) in &self.construction.required_skills {
+            text_sections.push((TextSpan::new("\n\nSkill: "), SOFT_TEXT_COLOR));
+            text_sections.push((TextSpan::new(&**skill), WARN_TEXT_COLOR));
+            text_sections.push((TextSpan::new("\nDifficulty: "), SOFT_TEXT_COLOR));
+            text_sections.push((TextSpan::new(format!("{level}")), WARN_TEXT_COLOR));
+        }
+        text_sections.push((TextSpan::new("\n\nDuration: "), SOFT_TEXT_COLOR));
+        text_sections.push((
+            TextSpan::new(self.construction.time.to_string()),
+            WARN_TEXT_COLOR,
+        ));
+        if !self.qualities.is_empty() || !self.tools.is_empty() {
+            text_sections.push((TextSpan::new("\n\nTools"), SOFT_TEXT_COLOR));
+        }
+        for quality in &self.qualities {
+            text_sections.extend_from_slice(&quality.text_sections());
+        }
+        for tool in &self.tools {
+            text_sections.extend_from_slice(&tool.text_sections());
+        }
+        if !self.components.is_empty() {
+            text_sections.push((TextSpan::new("\n\nComponents"), SOFT_TEXT_COLOR));
+        }
+        for component in &self.components {
+            text_sections.extend_from_slice(&component.text_sections());
+        }
+        if let Some(pre_note) = &self.construction.pre_note {
+            text_sections.push((TextSpan::new(format!("\n\n{pre_note}")), SOFT_TEXT_COLOR));
+        }
+
+        text_sections
+    }
+
+    #[must_use]
+    pub fn to_text_bundle(&self) -> impl Bundle {
+        (Text::from(&*self.name), self.color(false), self.clone())
+    }
+}
+
+pub trait ConstructionSpawner {
+    fn spawn_construction_site(&mut self, pos: Pos, site: ConstructionSite) -> Entity;
+}
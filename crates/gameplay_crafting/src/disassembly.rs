@@ -0,0 +1,142 @@
+use crate::{QualitySituation, ToolSituation};
+use bevy::prelude::{Component, Entity};
+use cdda_json_files::{CommonItemInfo, DisassemblyRecipe};
+use hud::{BAD_TEXT_COLOR, GOOD_TEXT_COLOR};
+use std::{num::NonZeroU32, sync::Arc};
+use text::{Fragment, Phrase};
+use units::Duration;
+
+/// Mutable component of an item that is partially disassembled
+///
+/// For a stack of items, only one item gets disassembled.
+#[derive(Debug, Component)]
+pub struct Disassembly {
+    returned: Vec<ReturnedComponent>,
+    work_needed: Duration,
+    work_done: Duration,
+}
+
+impl Disassembly {
+    #[must_use]
+    pub fn new(disassembly_situation: &DisassemblySituation) -> Self {
+        Self {
+            returned: disassembly_situation.returned.clone(),
+            work_needed: disassembly_situation.recipe.time(),
+            work_done: Duration::ZERO,
+        }
+    }
+
+    pub fn work(&mut self, duration: Duration) {
+        self.work_done += duration;
+    }
+
+    #[must_use]
+    pub fn finished(&self) -> bool {
+        self.work_needed <= self.work_done
+    }
+
+    #[must_use]
+    pub fn returned(&self) -> &[ReturnedComponent] {
+        &self.returned
+    }
+
+    pub(crate) fn percent_progress(&self) -> f32 {
+        if self.finished() {
+            100.0
+        } else {
+            100.0 * (self.work_done / self.work_needed)
+        }
+    }
+
+    pub(crate) fn time_left(&self) -> Duration {
+        self.work_needed - self.work_done
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReturnedComponent {
+    pub item_info: Arc<CommonItemInfo>,
+    pub amount: NonZeroU32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DisassemblySituation {
+    pub(super) recipe: DisassemblyRecipe,
+
+    /// The item to disassemble
+    pub(super) item: Entity,
+
+    pub(super) name: Arc<str>,
+    pub(super) qualities: Vec<QualitySituation>,
+    pub(super) tools: Vec<ToolSituation>,
+    pub(super) returned: Vec<ReturnedComponent>,
+}
+
+impl DisassemblySituation {
+    #[must_use]
+    pub const fn item(&self) -> Entity {
+        self.item
+    }
+
+    #[must_use]
+    pub fn disassemblable(&self) -> bool {
+        self.qualities.iter().all(QualitySituation::is_present)
+            && self.tools.iter().all(ToolSituation::is_present)
+    }
+
+    /// A single line, to fit in the log
+    pub(crate) fn phrase(&self) -> Phrase {
+        let mut phrase = Phrase::new("Disassembling")
+            .push(Fragment::colorized(
+                &*self.name,
+                if self.disassemblable() {
+                    GOOD_TEXT_COLOR
+                } else {
+                    BAD_TEXT_COLOR
+                },
+            ))
+            .soft("takes")
+            .push(Fragment::warn(self.recipe.time().short_format()));
+
+        let requirements = self
+            .qualities
+            .iter()
+            .map(QualitySituation::fragment)
+            .chain(self.tools.iter().map(ToolSituation::fragment))
+            .collect::<Vec<_>>();
+        if !requirements.is_empty() {
+            phrase = phrase.soft("with").extend(comma_separated(requirements));
+        }
+
+        phrase = phrase.soft("and returns");
+        if self.returned.is_empty() {
+            phrase = phrase.soft("nothing");
+        } else {
+            phrase = phrase.extend(comma_separated(
+                self.returned
+                    .iter()
+                    .map(|returned| {
+                        Fragment::warn(format!(
+                            "{} {}",
+                            returned.amount,
+                            returned.item_info.name.amount(returned.amount.get())
+                        ))
+                    })
+                    .collect(),
+            ));
+        }
+
+        phrase
+    }
+}
+
+fn comma_separated(fragments: Vec<Fragment>) -> Vec<Fragment> {
+    let mut separated = Vec::new();
+    for (index, fragment) in fragments.into_iter().enumerate() {
+        if 0 < index {
+            separated.push(Fragment::soft(","));
+        }
+        separated.push(fragment);
+    }
+    separated
+}
@@ -1,12 +1,21 @@
+mod butchery;
 mod components;
+mod construction;
 mod craft_spawner;
+mod disassembly;
 mod lookup;
 mod messages;
 
+pub use self::butchery::{Butchery, HarvestSpawner, nearby_cutting_quality};
 pub use self::components::{
     AlternativeSituation, ComponentSituation, Consumed, Craft, DetectedQuantity, QualitySituation,
     RecipeSituation, ToolSituation,
 };
-pub use self::messages::CraftProgressLeft;
+pub use self::construction::{ConstructionSite, ConstructionSituation, ConstructionSpawner};
+pub use self::disassembly::{Disassembly, DisassemblySituation, ReturnedComponent};
+pub use self::messages::{
+    ButcheryProgressLeft, ConstructionProgressLeft, CraftProgressLeft, DisassemblyDetails,
+    DisassemblyProgressLeft,
+};
 pub use craft_spawner::CraftSpawner;
-pub use lookup::shown_recipes;
+pub use lookup::{disassembly_situation, shown_constructions, shown_recipes};
@@ -1,13 +1,14 @@
 use crate::{
-    AlternativeSituation, ComponentSituation, DetectedQuantity, QualitySituation, RecipeSituation,
+    AlternativeSituation, ComponentSituation, ConstructionSite, ConstructionSituation,
+    DetectedQuantity, DisassemblySituation, QualitySituation, RecipeSituation, ReturnedComponent,
     ToolSituation,
 };
 use bevy::platform::collections::{HashMap, HashSet};
-use bevy::prelude::{AnyOf, Query, error, warn};
+use bevy::prelude::{AnyOf, Entity, Query, error, warn};
 use cdda_json_files::{
-    Alternative, AutoLearn, BookLearn, BookLearnItem, CalculatedRequirement, CommonItemInfo,
-    FurnitureInfo, InfoId, PocketType, Quality, Recipe, RequiredComponent, RequiredPart,
-    RequiredQuality, RequiredTool, Requirement, Sav, Skill, TerrainInfo,
+    Alternative, AutoLearn, CalculatedRequirement, CommonItemInfo, Construction, FurnitureInfo,
+    InfoId, PocketType, Quality, Recipe, RequiredComponent, RequiredPart, RequiredQuality,
+    RequiredTool, Requirement, TerrainInfo,
 };
 use gameplay_cdda::{Error, Infos};
 use gameplay_common::{LastSeen, Shared};
@@ -41,7 +42,7 @@ fn nearby_manuals(nearby_items: &[ItemItem]) -> HashMap<InfoId<CommonItemInfo>,
 pub fn shown_recipes(
     location: &LocationCache,
     infos: &Infos,
-    sav: &Sav,
+    skill_level: &impl Fn(&str) -> u8,
     hierarchy: &ItemHierarchy,
     crafter_pos: Pos,
     crafter_body_containers: &BodyContainers,
@@ -63,8 +64,8 @@ pub fn shown_recipes(
         .map(|recipe| {
             (
                 recipe,
-                autolearn_recipe(recipe, &sav.player.skills),
-                recipe_manuals(recipe, &nearby_manuals),
+                autolearn_recipe(recipe, skill_level),
+                recipe_manuals(recipe, skill_level, &nearby_manuals),
             )
         })
         .filter(|(.., autolearn, recipe_manuals)| *autolearn || !recipe_manuals.is_empty())
@@ -109,45 +110,174 @@ pub fn shown_recipes(
     shown_recipes
 }
 
-fn autolearn_recipe(recipe: &Recipe, skills: &HashMap<Arc<str>, Skill>) -> bool {
+/// When there is an unfinished construction at the target, only that construction is shown.
+#[must_use]
+pub fn shown_constructions(
+    location: &LocationCache,
+    infos: &Infos,
+    hierarchy: &ItemHierarchy,
+    constructor_pos: Pos,
+    constructor_body_containers: &BodyContainers,
+    items: &Query<(Item, &LastSeen)>,
+    infrastructure: &Query<(
+        AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
+        &LastSeen,
+    )>,
+    target_terrain: &TerrainInfo,
+    target_furniture: Option<&FurnitureInfo>,
+    target_site: Option<(Entity, &ConstructionSite)>,
+) -> Vec<ConstructionSituation> {
+    let nearby_items = find_nearby(
+        location,
+        items,
+        constructor_pos,
+        constructor_body_containers,
+    );
+    let nearby_pseudo_items = find_nearby_pseudo(location, infrastructure, constructor_pos);
+    let nearby_sources = find_sources(location, infrastructure, constructor_pos);
+    let nearby_qualities = nearby_qualities(&nearby_items, &nearby_pseudo_items);
+
+    let construction_situation = |construction: &Arc<Construction>, site: Option<Entity>| {
+        let name = construction.group.get().ok()?.name.single.clone();
+        CalculatedRequirement::try_from(&**construction)
+            .inspect_err(|error| error!("{error:?}"))
+            .ok()
+            .map(|calculated_requirements| ConstructionSituation {
+                construction: construction.clone(),
+                name: uppercase_first(name),
+                site,
+                qualities: recipe_qualities(
+                    &calculated_requirements.qualities.0,
+                    &nearby_qualities,
+                ),
+                tools: recipe_tools(
+                    hierarchy,
+                    &calculated_requirements.tools,
+                    &nearby_items,
+                    &nearby_pseudo_items,
+                    &nearby_sources,
+                ),
+                components: if site.is_some() {
+                    Vec::new()
+                } else {
+                    recipe_components(
+                        &calculated_requirements.components,
+                        &nearby_items,
+                        &nearby_sources,
+                    )
+                },
+            })
+    };
+
+    if let Some((site_entity, site)) = target_site {
+        return construction_situation(site.construction(), Some(site_entity))
+            .into_iter()
+            .collect();
+    }
+
+    let mut shown_constructions = infos
+        .constructions
+        .values()
+        .filter(|construction| construction.applicable(target_terrain, target_furniture))
+        .filter_map(|construction| construction_situation(construction, None))
+        .collect::<Vec<_>>();
+
+    shown_constructions.sort_by_key(|construction| {
+        (
+            !construction.constructable(),
+            construction.name.clone(),
+            construction.construction.id.fallback_name(),
+        )
+    });
+    shown_constructions
+}
+
+/// [`None`] when the item can not be disassembled at all
+#[must_use]
+pub fn disassembly_situation(
+    location: &LocationCache,
+    infos: &Infos,
+    hierarchy: &ItemHierarchy,
+    disassembler_pos: Pos,
+    disassembler_body_containers: &BodyContainers,
+    items: &Query<(Item, &LastSeen)>,
+    infrastructure: &Query<(
+        AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
+        &LastSeen,
+    )>,
+    item: &ItemItem,
+) -> Option<DisassemblySituation> {
+    let recipe = infos.disassembly(&item.common_info.id)?;
+    let calculated_requirements = CalculatedRequirement::try_from(&recipe)
+        .inspect_err(|error| error!("{error:?}"))
+        .ok()?;
+
+    let nearby_items = find_nearby(
+        location,
+        items,
+        disassembler_pos,
+        disassembler_body_containers,
+    );
+    let nearby_pseudo_items = find_nearby_pseudo(location, infrastructure, disassembler_pos);
+    let nearby_sources = find_sources(location, infrastructure, disassembler_pos);
+    let nearby_qualities = nearby_qualities(&nearby_items, &nearby_pseudo_items);
+
+    Some(DisassemblySituation {
+        recipe,
+        item: item.entity,
+        name: item.common_info.name.single.clone(),
+        qualities: recipe_qualities(&calculated_requirements.qualities.0, &nearby_qualities),
+        tools: recipe_tools(
+            hierarchy,
+            &calculated_requirements.tools,
+            &nearby_items,
+            &nearby_pseudo_items,
+            &nearby_sources,
+        ),
+        returned: returned_components(&calculated_requirements.components),
+    })
+}
+
+fn autolearn_recipe(recipe: &Recipe, skill_level: &impl Fn(&str) -> u8) -> bool {
     match &recipe.autolearn {
         AutoLearn::Bool(autolearn) => {
             *autolearn
-                && recipe.skill_used.as_ref().is_none_or(|skill_used| {
-                    recipe.difficulty
-                        <= skills
-                            .get(skill_used)
-                            .unwrap_or_else(|| panic!("Skill {:?} not found", recipe.skill_used))
-                            .level
-                })
-        }
-        AutoLearn::Skills(autolearn_skills) => {
-            autolearn_skills.iter().all(|(skill_name, skill_level)| {
-                *skill_level
-                    <= skills
-                        .get(skill_name)
-                        .unwrap_or_else(|| panic!("Skill {:?} not found", recipe.skill_used))
-                        .level
-            })
+                && recipe
+                    .skill_used
+                    .as_ref()
+                    .is_none_or(|skill_used| recipe.difficulty <= skill_level(skill_used))
         }
+        AutoLearn::Skills(autolearn_skills) => autolearn_skills
+            .iter()
+            .all(|(skill_name, required)| *required <= skill_level(skill_name)),
     }
 }
 
+/// Manuals only teach the recipe from their skill level, defaulting to the recipe difficulty
 fn recipe_manuals(
     recipe: &Recipe,
+    skill_level: &impl Fn(&str) -> u8,
     nearby_manuals: &HashMap<InfoId<CommonItemInfo>, Arc<str>>,
 ) -> Vec<Arc<str>> {
-    // TODO check skill level
+    let crafter_level = recipe
+        .skill_used
+        .as_ref()
+        .map_or(u8::MAX, |skill_used| skill_level(skill_used));
 
-    let mut manuals = match &recipe.book_learn {
-        BookLearn::List(list) => list.iter().map(BookLearnItem::id).collect::<Vec<_>>(),
-        BookLearn::Map(map) => map.keys().cloned().collect::<Vec<_>>(),
-        BookLearn::Other(other) => todo!("{other:?}"),
-    }
-    .iter()
-    .filter_map(|from_book| nearby_manuals.get(from_book))
-    .cloned()
-    .collect::<Vec<_>>();
+    let Some(books) = recipe.book_learn.books() else {
+        warn!(
+            "Unknown book_learn format of {:?}: {:?}",
+            recipe.id, recipe.book_learn
+        );
+        return Vec::new();
+    };
+
+    let mut manuals = books
+        .iter()
+        .filter(|(_, level)| level.unwrap_or(recipe.difficulty) <= crafter_level)
+        .filter_map(|(from_book, _)| nearby_manuals.get(from_book))
+        .cloned()
+        .collect::<Vec<_>>();
 
     manuals.sort();
     manuals
@@ -287,6 +417,35 @@ fn recipe_components(
         .collect::<Vec<_>>()
 }
 
+/// Like CDDA, only the first alternative gets returned
+fn returned_components(required: &[Vec<Alternative<RequiredComponent>>]) -> Vec<ReturnedComponent> {
+    required
+        .iter()
+        .filter_map(|alternatives| alternatives.first())
+        .filter(|alternative| {
+            !matches!(
+                alternative,
+                Alternative::Item {
+                    recoverable: false,
+                    ..
+                }
+            )
+        })
+        .filter_map(|alternative| {
+            expand_items(alternative, |requirement| &requirement.components)
+                .inspect_err(|error| {
+                    error!("Could not process alternative {alternative:?}: {error:#?}");
+                })
+                .ok()
+                .and_then(|expanded| expanded.into_iter().next())
+        })
+        .map(|(item_info, required)| ReturnedComponent {
+            item_info,
+            amount: required.amount,
+        })
+        .collect()
+}
+
 fn expand_alternatives<
     R: RequiredPart<Output = R>,
     S: Clone + Copy + Fn(&Requirement) -> &Vec<Vec<Alternative<R>>>,
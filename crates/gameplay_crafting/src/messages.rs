@@ -1,4 +1,4 @@
-use crate::Craft;
+use crate::{Butchery, ConstructionSite, Craft, Disassembly, DisassemblySituation};
 use gameplay_log::{ProtoLogMessage, Severity};
 use hud::text_color_expect_full;
 use text::{Fragment, Phrase};
@@ -24,3 +24,83 @@ impl ProtoLogMessage for CraftProgressLeft<'_> {
             .hard("left")
     }
 }
+
+#[derive(Debug)]
+pub struct ConstructionProgressLeft<'a> {
+    pub site: &'a ConstructionSite,
+}
+
+impl ProtoLogMessage for ConstructionProgressLeft<'_> {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        let percent_progress = self.site.percent_progress();
+        let color = text_color_expect_full(percent_progress / 100.0);
+        let percent_progress = format!("{percent_progress:.1}");
+        let time_left = self.site.time_left().short_format();
+
+        Phrase::new("Construction:")
+            .push(Fragment::colorized(percent_progress, color))
+            .hard("% progress -")
+            .push(Fragment::colorized(time_left, color))
+            .hard("left")
+    }
+}
+
+#[derive(Debug)]
+pub struct ButcheryProgressLeft<'a> {
+    pub butchery: &'a Butchery,
+}
+
+impl ProtoLogMessage for ButcheryProgressLeft<'_> {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        let percent_progress = self.butchery.percent_progress();
+        let color = text_color_expect_full(percent_progress / 100.0);
+        let percent_progress = format!("{percent_progress:.1}");
+        let time_left = self.butchery.time_left().short_format();
+
+        Phrase::new("Butchery:")
+            .push(Fragment::colorized(percent_progress, color))
+            .hard("% progress -")
+            .push(Fragment::colorized(time_left, color))
+            .hard("left")
+    }
+}
+
+#[derive(Debug)]
+pub struct DisassemblyProgressLeft<'a> {
+    pub disassembly: &'a Disassembly,
+}
+
+impl ProtoLogMessage for DisassemblyProgressLeft<'_> {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        let percent_progress = self.disassembly.percent_progress();
+        let color = text_color_expect_full(percent_progress / 100.0);
+        let percent_progress = format!("{percent_progress:.1}");
+        let time_left = self.disassembly.time_left().short_format();
+
+        Phrase::new("Disassembly:")
+            .push(Fragment::colorized(percent_progress, color))
+            .hard("% progress -")
+            .push(Fragment::colorized(time_left, color))
+            .hard("left")
+    }
+}
+
+/// The tools required, and the components returned
+#[derive(Debug)]
+pub struct DisassemblyDetails<'a> {
+    pub disassembly_situation: &'a DisassemblySituation,
+}
+
+impl ProtoLogMessage for DisassemblyDetails<'_> {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        self.disassembly_situation.phrase()
+    }
+}
@@ -26,6 +26,17 @@ impl Focus<'_, '_> {
         });
     }
 
+    /// Starts aiming from the player, or returns the target when already aiming
+    pub fn toggle_targeting(&self, next_focus_state: &mut NextState<FocusState>) -> Option<Pos> {
+        if let FocusState::Targeting(target) = **self.state {
+            next_focus_state.set(FocusState::Normal);
+            Some(target)
+        } else {
+            next_focus_state.set(FocusState::Targeting(**self.player_pos));
+            None
+        }
+    }
+
     #[must_use]
     pub fn is_changed(&self) -> bool {
         self.state.is_changed() || self.player_pos.is_changed()
@@ -35,7 +46,7 @@ impl Focus<'_, '_> {
     pub fn is_pos_shown(&self, shown_pos: Pos, elevation_visibility: ElevationVisibility) -> bool {
         let focus_pos = match **self.state {
             FocusState::Normal => **self.player_pos,
-            FocusState::ExaminingPos(pos) => pos,
+            FocusState::ExaminingPos(pos) | FocusState::Targeting(pos) => pos,
             FocusState::ExaminingZoneLevel(zone_level) => {
                 let focus_level = zone_level.level;
                 return match (focus_level.compare_to_ground(), elevation_visibility) {
@@ -65,7 +76,7 @@ impl Focus<'_, '_> {
             FocusState::Normal => {
                 return Vec3::ZERO;
             }
-            FocusState::ExaminingPos(pos) => pos,
+            FocusState::ExaminingPos(pos) | FocusState::Targeting(pos) => pos,
             FocusState::ExaminingZoneLevel(zone_level) => zone_level.center_pos(),
         };
         (target_pos - **self.player_pos).vec3()
@@ -76,7 +87,7 @@ impl From<&Focus<'_, '_>> for Level {
     fn from(focus: &Focus) -> Self {
         match **focus.state {
             FocusState::Normal => focus.player_pos.level,
-            FocusState::ExaminingPos(pos) => pos.level,
+            FocusState::ExaminingPos(pos) | FocusState::Targeting(pos) => pos.level,
             FocusState::ExaminingZoneLevel(zone_level) => zone_level.level,
         }
     }
@@ -86,7 +97,7 @@ impl From<&Focus<'_, '_>> for Pos {
     fn from(focus: &Focus) -> Self {
         match **focus.state {
             FocusState::Normal => **focus.player_pos,
-            FocusState::ExaminingPos(pos) => pos,
+            FocusState::ExaminingPos(pos) | FocusState::Targeting(pos) => pos,
             FocusState::ExaminingZoneLevel(zone_level) => zone_level.center_pos(),
         }
     }
@@ -96,7 +107,7 @@ impl From<&Focus<'_, '_>> for ZoneLevel {
     fn from(focus: &Focus) -> Self {
         match **focus.state {
             FocusState::Normal => Self::from(**focus.player_pos),
-            FocusState::ExaminingPos(pos) => Self::from(pos),
+            FocusState::ExaminingPos(pos) | FocusState::Targeting(pos) => Self::from(pos),
             FocusState::ExaminingZoneLevel(zone_level) => zone_level,
         }
     }
@@ -17,6 +17,8 @@ pub enum FocusState {
     Normal,
     ExaminingPos(Pos),
     ExaminingZoneLevel(ZoneLevel),
+    /// Choosing where to fire at
+    Targeting(Pos),
 }
 
 impl FocusState {
@@ -41,6 +43,7 @@ impl fmt::Display for FocusState {
             Self::Normal => "",
             Self::ExaminingPos(_) => "Examining",
             Self::ExaminingZoneLevel(_) => "Examining map",
+            Self::Targeting(_) => "Aiming",
         })
     }
 }
@@ -17,7 +17,10 @@ pub(super) fn update_focus_cursor_visibility(
 
     if let Some(mut cursor) = cursor {
         let &mut (ref mut visibility, ref mut transform) = &mut *cursor;
-        let examine_pos = matches!(**focus_state, FocusState::ExaminingPos(_));
+        let examine_pos = matches!(
+            **focus_state,
+            FocusState::ExaminingPos(_) | FocusState::Targeting(_)
+        );
         let examine_zone_level = matches!(**focus_state, FocusState::ExaminingZoneLevel(_));
         **visibility = if examine_pos || examine_zone_level {
             Visibility::Inherited
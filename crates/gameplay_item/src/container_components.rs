@@ -1,5 +1,6 @@
 use crate::{Amount, InPocket};
 use bevy::prelude::Component;
+use cdda_json_files::PocketInfo;
 use units::{Mass, Volume};
 
 #[derive(Clone, Debug, Component)]
@@ -9,7 +10,7 @@ pub struct Containable {
     pub mass: Mass,
 }
 
-#[derive(Component)]
+#[derive(Clone, Component)]
 #[component(immutable)]
 pub struct ContainerLimits {
     pub max_volume: Volume,
@@ -17,7 +18,22 @@ pub struct ContainerLimits {
     pub max_amount: Option<Amount>,
 }
 
-#[derive(Debug, Component)]
+impl From<&PocketInfo> for ContainerLimits {
+    /// Based on the defaults of CDDA for pockets without explicit limits
+    fn from(pocket_info: &PocketInfo) -> Self {
+        Self {
+            max_volume: pocket_info
+                .max_contains_volume
+                .unwrap_or_else(|| Volume::try_from("200 L").expect("Well formatted")),
+            max_mass: pocket_info
+                .max_contains_weight
+                .unwrap_or_else(|| Mass::try_from("2000 kg").expect("Well formatted")),
+            max_amount: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Component)]
 #[component(immutable)]
 pub struct BodyContainers {
     pub hands: InPocket,
@@ -1,10 +1,11 @@
 use crate::{
-    Amount, ContainerLimits, InPocket, Item, ItemItem, PocketContents, PocketItem, Pockets,
-    SealedPocket,
+    Amount, ContainerLimits, InPocket, Item, ItemItem, PocketContents, PocketItem, PocketOf,
+    Pockets, SealedPocket,
 };
 use bevy::ecs::system::SystemParam;
-use bevy::prelude::{Entity, Query, error, warn};
-use cdda_json_files::{ItemTypeDetails, PocketInfo, PocketType, UntypedInfoId};
+use bevy::prelude::{Commands, Entity, Query, Transform, Visibility, error, warn};
+use cdda_json_files::{PocketInfo, PocketType, UntypedInfoId};
+use gameplay_common::Shared;
 use gameplay_relations::{ObjectOn, Objects};
 use std::{iter::once, num::NonZeroUsize, sync::Arc};
 use text::{Fragment, Phrase};
@@ -15,6 +16,7 @@ pub enum PocketWrapper<'w, 's> {
 }
 
 impl PocketWrapper<'_, '_> {
+    #[must_use]
     pub const fn in_pocket(&self) -> Option<InPocket> {
         match self {
             Self::Concrete(pocket) => Some(InPocket {
@@ -24,6 +26,7 @@ impl PocketWrapper<'_, '_> {
         }
     }
 
+    #[must_use]
     pub fn info(&self) -> &Arc<PocketInfo> {
         match self {
             Self::Concrete(pocket) => pocket.info.as_ref(),
@@ -31,6 +34,7 @@ impl PocketWrapper<'_, '_> {
         }
     }
 
+    #[must_use]
     pub fn pocket_type(&self) -> PocketType {
         self.info().pocket_type
     }
@@ -116,11 +120,88 @@ impl<'w, 's> ItemHierarchy<'w, 's> {
             .collect()
     }
 
+    /// Body containers have explicit limits, item pockets use their pocket info
     #[must_use]
-    pub fn container(&self, in_pocket: InPocket) -> &ContainerLimits {
-        self.limits
-            .get(in_pocket.pocket_entity)
-            .expect("An existing container")
+    pub fn container(&self, in_pocket: InPocket) -> ContainerLimits {
+        if let Ok(limits) = self.limits.get(in_pocket.pocket_entity) {
+            limits.clone()
+        } else {
+            let pocket = self
+                .pockets
+                .get(in_pocket.pocket_entity)
+                .expect("An existing container");
+            ContainerLimits::from(&**pocket.info)
+        }
+    }
+
+    /// The unsealed container pockets of the items in `in_pocket`, like those of worn backpacks
+    ///
+    /// The pockets of `excluded` are skipped, to prevent storing an item in itself.
+    #[must_use]
+    pub fn storage_pockets(&self, in_pocket: InPocket, excluded: Entity) -> Vec<InPocket> {
+        self.items_in_pocket(in_pocket)
+            .filter(|item| item.entity != excluded)
+            .flat_map(|item| self.pockets_in(&item))
+            .filter(|pocket_wrapper| {
+                pocket_wrapper.pocket_type() == PocketType::Container
+                    && pocket_wrapper.sealed().is_none()
+            })
+            .filter_map(|pocket_wrapper| pocket_wrapper.in_pocket())
+            .collect()
+    }
+
+    /// The ammo in a magazine, or in the magazine in the magazine well of a gun
+    #[must_use]
+    pub fn loaded_ammo(&self, item: &ItemItem) -> Option<ItemItem<'_, '_>> {
+        self.pockets_in(item)
+            .into_iter()
+            .find_map(|pocket_wrapper| {
+                let in_pocket = pocket_wrapper.in_pocket()?;
+                match pocket_wrapper.pocket_type() {
+                    PocketType::Magazine => self.items_in_pocket(in_pocket).next(),
+                    PocketType::MagazineWell => self
+                        .items_in_pocket(in_pocket)
+                        .find_map(|magazine| self.loaded_ammo(&magazine)),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Spawns the pockets that are only known from the item info, so items can be put in them
+    ///
+    /// Returns all pockets of the item, in the same order as [`Self::pockets_in`].
+    pub fn concretize_pockets(&self, commands: &mut Commands, item: &ItemItem) -> Vec<InPocket> {
+        self.pockets_in(item)
+            .into_iter()
+            .map(|pocket_wrapper| match pocket_wrapper {
+                PocketWrapper::Concrete(pocket) => InPocket {
+                    pocket_entity: pocket.entity,
+                },
+                PocketWrapper::Lazy(pocket_info) => {
+                    let sealed = pocket_info.sealed_data.as_ref().map(SealedPocket::from);
+                    let mut pocket = commands.spawn((
+                        Visibility::Hidden,
+                        Transform::IDENTITY,
+                        Shared::new(pocket_info),
+                        PocketOf {
+                            item_entity: item.entity,
+                        },
+                    ));
+                    if let Some(sealed) = sealed {
+                        pocket.insert(sealed);
+                    }
+                    InPocket {
+                        pocket_entity: pocket.id(),
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The item with its charges and what is loaded in it, but without its other contents
+    #[must_use]
+    pub fn loaded_fragments(&self, item: &ItemItem) -> Vec<Fragment> {
+        self.item_fragments(None, None, item, None)
     }
 
     pub fn walk(
@@ -220,7 +301,7 @@ impl<'w, 's> ItemHierarchy<'w, 's> {
         let phrase = Phrase::from_fragments(prefix.into_iter().collect())
             .extend(item.fragments())
             .debug(format!("[{}]", item.common_info.id.fallback_name()))
-            .extend(Self::battery_charge_fragments(item, &mut magazine_pockets))
+            .extend(Self::charge_fragments(item, &mut magazine_pockets))
             .extend(self.magazine_fragments(magazine_wells, magazine_pockets))
             .extend(Self::item_tag_fragments(item, &shown_contents));
 
@@ -247,67 +328,69 @@ impl<'w, 's> ItemHierarchy<'w, 's> {
         .fragments
     }
 
-    fn battery_charge_fragments(
+    /// The current and maximum charges of magazines, batteries, and revolver-like guns
+    fn charge_fragments(
         item: &ItemItem<'_, '_>,
         magazine_pockets: &mut Vec<Subitems>,
-    ) -> impl Iterator<Item = Fragment> + use<> {
-        let item_type_details = item
-            .common_info
-            .type_details
-            .get()
-            .expect("Item type details should have been set");
-        let is_battery = item_type_details
-            .ammo_type()
-            .is_some_and(|ammo_types| ammo_types.0.contains(&UntypedInfoId::new("battery")));
-
-        is_battery
-            .then(|| {
-                if 1 < magazine_pockets.len() {
-                    warn!(
-                        "{} magazines found in {:?} instead of 0 or 1",
-                        magazine_pockets.len(),
-                          item.common_info.id
-                    );
-                }
-                magazine_pockets.pop()
-            }).flatten()
-            .map(|subitems| {
-                if 1 < subitems.items.len() {
-                    warn!(
-                        "{} magazine items found in {:?}: ignoring any magazine items after the first one",
-                        subitems.items.len(),
-                            item.common_info.id
-                    );
-                }
-
-                let charges_present = subitems.items.first().map(|item| item.amount).copied().unwrap_or(Amount::ZERO);
+    ) -> Vec<Fragment> {
+        if 1 < magazine_pockets.len() {
+            warn!(
+                "{} magazines found in {:?} instead of 0 or 1",
+                magazine_pockets.len(),
+                item.common_info.id
+            );
+        }
+        let Some(subitems) = magazine_pockets.last() else {
+            return Vec::new();
+        };
+        if 1 < subitems.items.len() {
+            warn!(
+                "{} magazine items found in {:?}: ignoring any magazine items after the first one",
+                subitems.items.len(),
+                item.common_info.id
+            );
+        }
 
-                let restriction = subitems.pocket_wrapper.info().ammo_restriction.as_ref().and_then(|ammo_restriction| ammo_restriction.get("battery")).copied();
-                let capacity = restriction.or_else(|| {
-                    if let ItemTypeDetails::Magazine(magazine) = item_type_details {
-                        magazine.capacity
-                    } else {
-                        None
-                    }
-                });
-                let capacity = capacity.unwrap_or_else(|| {
-                    warn!(
-                        "No capacity found for magazine in {:?}",
-                        item.common_info.id
-                    );
+        let loaded = subitems.items.first();
+        let charges_present = loaded.map_or(Amount::ZERO, |loaded| *loaded.amount);
+
+        let pocket_info = subitems.pocket_wrapper.info();
+        let restriction = loaded
+            .and_then(|loaded| {
+                loaded
+                    .common_info
+                    .ammo_types()
+                    .iter()
+                    .find_map(|ammo_type| pocket_info.ammo_capacity(ammo_type))
+            })
+            .or_else(|| pocket_info.max_ammo_capacity());
+        let capacity = restriction.or_else(|| {
+            item.common_info
+                .magazine()
+                .and_then(|magazine| magazine.capacity)
+        });
+        let capacity = capacity.unwrap_or_else(|| {
+            warn!(
+                "No capacity found for magazine in {:?}",
+                item.common_info.id
+            );
+            charges_present.0.max(1)
+        });
 
-                    charges_present.0.max(1)
-                });
+        // The charges of a battery say it all
+        let is_battery = item
+            .common_info
+            .ammo_types()
+            .contains(&UntypedInfoId::new("battery"));
+        if is_battery {
+            magazine_pockets.pop();
+        }
 
-                [
-                    Fragment::soft("at"),
-                    charges_present
-        .fragment_in_range(capacity),
-                    Fragment::soft(format!("/{capacity}")),
-                ]
-            })
-            .into_iter()
-            .flatten()
+        vec![
+            Fragment::soft("at"),
+            charges_present.fragment_in_range(capacity),
+            Fragment::soft(format!("/{capacity}")),
+        ]
     }
 
     fn magazine_fragments<'a>(
@@ -13,11 +13,29 @@ impl ItemIntegrity {
     const BROKEN_DAMAGE: i64 = 4000;
     const REINFORCED_DAMAGE: i64 = -1000;
 
+    /// Ten damage points take an item one damage level closer to broken
+    const DAMAGE_PER_POINT: i64 = 100;
+
+    /// The CDDA damage value, as stored in save files
+    #[must_use]
+    pub const fn damage(&self) -> i64 {
+        self.damage
+    }
+
     #[must_use]
     pub const fn broken(&self) -> bool {
         Self::BROKEN_DAMAGE <= self.damage
     }
 
+    /// Immutable, so the result should replace the current component
+    #[must_use]
+    pub fn damaged(&self, points: u16) -> Self {
+        Self {
+            damage: (self.damage + i64::from(points) * Self::DAMAGE_PER_POINT)
+                .min(Self::BROKEN_DAMAGE),
+        }
+    }
+
     pub(crate) fn fragment(&self) -> Option<Fragment> {
         //trace!("{self:?}");
         Some(Fragment::colorized(
@@ -12,12 +12,14 @@ mod plugin;
 mod pocket;
 mod query_data;
 mod relations;
+mod saved;
+mod temperature;
 
 pub use self::amount::Amount;
 pub use self::container::Container;
 pub use self::container_components::{BodyContainers, Containable, ContainerLimits};
 pub use self::filthy::Filthy;
-pub use self::hierarchy::{ItemHandler, ItemHierarchy};
+pub use self::hierarchy::{ItemHandler, ItemHierarchy, PocketWrapper};
 pub use self::integrity::ItemIntegrity;
 pub use self::item_spawner::ItemSpawner;
 pub use self::phase::Phase;
@@ -25,6 +27,8 @@ pub use self::plugin::GameplayItemPlugin;
 pub use self::pocket::SealedPocket;
 pub use self::query_data::{Item, ItemItem};
 pub use self::relations::{InPocket, PocketContents, PocketOf, Pockets, WieldedBy, WieldedItems};
+pub use self::saved::SavedItem;
+pub use self::temperature::ItemTemperature;
 
 pub(crate) use self::checks::ItemChecksPlugin;
 pub(crate) use self::pocket::PocketItem;
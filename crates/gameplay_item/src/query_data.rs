@@ -1,4 +1,7 @@
-use crate::{Amount, Containable, Filthy, InPocket, ItemIntegrity, Phase, Pockets};
+use crate::{
+    Amount, Containable, Filthy, InPocket, ItemIntegrity, ItemTemperature, Phase, Pockets,
+    SavedItem,
+};
 use bevy::ecs::query::QueryData;
 use bevy::prelude::{Children, Entity, ops::atan2};
 use cdda_json_files::{CommonItemInfo, InfoId};
@@ -20,6 +23,7 @@ pub struct Item {
     pub amount: &'static Amount,
     pub filthy: Option<&'static Filthy>,
     pub integrity: &'static ItemIntegrity,
+    pub temperature: Option<&'static ItemTemperature>,
     pub phase: &'static Phase,
     pub containable: &'static Containable,
     pub on_tile: Option<&'static ObjectOn>,
@@ -27,6 +31,7 @@ pub struct Item {
     pub pockets: Option<&'static Pockets>,
     pub models: Option<&'static Children>,
     pub common_info: &'static Shared<CommonItemInfo>,
+    pub saved: Option<&'static SavedItem>,
 }
 
 impl<'w, 's> ItemItem<'w, 's> {
@@ -61,15 +66,19 @@ impl<'w, 's> ItemItem<'w, 's> {
                 None,
                 self.filthy.map(|_| Filthy::fragment()),
                 self.integrity.fragment(),
+                None,
                 Some(Fragment::colorized(dollars, color)),
             ]
         } else if self.common_info.id == InfoId::new("battery") {
-            [self.amount.fragment(), None, None, None]
+            [self.amount.fragment(), None, None, None, None]
         } else {
             [
                 self.amount.fragment(),
                 self.filthy.map(|_| Filthy::fragment()),
                 self.integrity.fragment(),
+                self.temperature
+                    .copied()
+                    .and_then(ItemTemperature::fragment),
                 Some(self.name.amount(self.amount.0, Pos::ORIGIN)),
             ]
         };
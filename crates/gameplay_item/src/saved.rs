@@ -0,0 +1,23 @@
+use bevy::prelude::Component;
+use cdda_json_files::CddaItem;
+use serde_json::{Map as JsonObject, Value as JsonValue, to_value as to_json_value};
+use std::sync::Arc;
+
+/// The json the item was spawned from, without its contents
+///
+/// This keeps the fields that are not modeled by components, like `item_vars`, `rot`, and `owner`, when saving the item again.
+#[derive(Clone, Debug, Component)]
+#[component(immutable)]
+pub struct SavedItem(pub Arc<JsonObject<String, JsonValue>>);
+
+impl SavedItem {
+    #[must_use]
+    pub fn new(item: &CddaItem) -> Option<Self> {
+        let JsonValue::Object(mut json) = to_json_value(item).ok()? else {
+            return None;
+        };
+        // Contents get spawned as separate entities
+        json.remove("contents");
+        Some(Self(Arc::new(json)))
+    }
+}
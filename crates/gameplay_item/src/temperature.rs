@@ -0,0 +1,118 @@
+use bevy::prelude::Component;
+use hud::BLUE_TEXT_COLOR;
+use text::Fragment;
+
+/// The temperature of food and drinks
+#[derive(Clone, Copy, Debug, PartialEq, Component)]
+#[component(immutable)]
+pub struct ItemTemperature {
+    /// In degrees Celsius
+    celsius: f32,
+
+    frozen: bool,
+}
+
+impl ItemTemperature {
+    /// Used when the save does not specify the temperature
+    const ROOM_TEMPERATURE: f32 = 20.0;
+
+    /// Frozen items without a known temperature are this much below their freezing point
+    const FROZEN_MARGIN: f32 = 5.0;
+
+    #[must_use]
+    pub fn new(celsius: Option<f32>, frozen: bool, freezing_point: f32) -> Self {
+        Self {
+            celsius: celsius.unwrap_or(if frozen {
+                freezing_point - Self::FROZEN_MARGIN
+            } else {
+                Self::ROOM_TEMPERATURE
+            }),
+            frozen,
+        }
+    }
+
+    #[must_use]
+    pub const fn celsius(self) -> f32 {
+        self.celsius
+    }
+
+    #[must_use]
+    pub const fn frozen(self) -> bool {
+        self.frozen
+    }
+
+    /// Immutable, so the result should replace the current component
+    ///
+    /// The `factor` is the part of the difference with the ambient temperature that disappears.
+    #[must_use]
+    pub fn approach(self, ambient: f32, factor: f32, freezing_point: f32) -> Self {
+        let celsius = self.celsius + (ambient - self.celsius) * factor.clamp(0.0, 1.0);
+        Self {
+            celsius,
+            frozen: if self.frozen {
+                celsius <= freezing_point
+            } else {
+                celsius < freezing_point
+            },
+        }
+    }
+
+    pub(crate) fn fragment(self) -> Option<Fragment> {
+        self.frozen
+            .then(|| Fragment::colorized("frozen", BLUE_TEXT_COLOR))
+    }
+}
+
+#[cfg(test)]
+mod temperature_tests {
+    use super::*;
+
+    #[test]
+    fn unknown_temperature_depends_on_frozen() {
+        let thawed = ItemTemperature::new(None, false, 0.0);
+        assert!((thawed.celsius() - 20.0).abs() < f32::EPSILON, "{thawed:?}");
+
+        let frozen = ItemTemperature::new(None, true, -2.0);
+        assert!((frozen.celsius() + 7.0).abs() < f32::EPSILON, "{frozen:?}");
+        assert!(frozen.frozen(), "{frozen:?}");
+    }
+
+    #[test]
+    fn approach_closes_part_of_the_gap() {
+        let temperature = ItemTemperature::new(Some(20.0), false, 0.0);
+        let cooled = temperature.approach(10.0, 0.2, 0.0);
+        assert!((cooled.celsius() - 18.0).abs() < f32::EPSILON, "{cooled:?}");
+
+        let unchanged = temperature.approach(10.0, -1.0, 0.0);
+        assert_eq!(unchanged, temperature);
+
+        let reached = temperature.approach(10.0, 2.0, 0.0);
+        assert!(
+            (reached.celsius() - 10.0).abs() < f32::EPSILON,
+            "{reached:?}"
+        );
+    }
+
+    #[test]
+    fn freezes_below_and_thaws_above_the_freezing_point() {
+        let water = ItemTemperature::new(Some(1.0), false, 0.0);
+        assert!(
+            !water.approach(0.0, 1.0, 0.0).frozen(),
+            "At the freezing point"
+        );
+        assert!(
+            water.approach(-1.0, 1.0, 0.0).frozen(),
+            "Below the freezing point"
+        );
+
+        let ice = ItemTemperature::new(Some(-1.0), true, 0.0);
+        assert!(
+            ice.approach(0.0, 1.0, 0.0).frozen(),
+            "At the freezing point"
+        );
+        assert!(
+            !ice.approach(1.0, 1.0, 0.0).frozen(),
+            "Above the freezing point"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Level, Pos, SubzoneLevelCache};
+use crate::{Level, Overzone, Pos, SubzoneLevelCache};
 use bevy::prelude::Component;
 use cdda_json_files::SubzoneOffset;
 use std::fmt;
@@ -26,6 +26,17 @@ impl SubzoneLevel {
         (self.x, self.z, self.level.h)
     }
 
+    /// The inverse of `SubzoneOffset::from`
+    #[must_use]
+    pub const fn from_offset(overzone: Overzone, offset: SubzoneOffset) -> Self {
+        let base_zone = overzone.base_zone();
+        Self {
+            x: 2 * base_zone.x + offset.0 as i32,
+            level: Level::new(offset.2),
+            z: 2 * base_zone.z + offset.1 as i32,
+        }
+    }
+
     #[must_use]
     pub const fn base_corner(&self) -> Pos {
         Pos::new(Self::SIZE * self.x, self.level, Self::SIZE * self.z)
@@ -55,9 +66,32 @@ impl From<Pos> for SubzoneLevel {
 impl From<SubzoneLevel> for SubzoneOffset {
     fn from(subzone_level: SubzoneLevel) -> Self {
         Self(
-            subzone_level.x.div_euclid(180) as u16,
-            subzone_level.z.div_euclid(180) as u16,
+            subzone_level.x.rem_euclid(2 * 180) as u16,
+            subzone_level.z.rem_euclid(2 * 180) as u16,
             subzone_level.level.h,
         )
     }
 }
+
+#[cfg(test)]
+mod subzone_level_tests {
+    use super::*;
+    use crate::ZoneLevel;
+
+    #[test]
+    fn offset_round_trip() {
+        let subzone_level = SubzoneLevel {
+            x: -3,
+            level: Level::new(2),
+            z: 725,
+        };
+        let overzone = Overzone::from(ZoneLevel::from(subzone_level).zone);
+        let offset = SubzoneOffset::from(subzone_level);
+        assert_eq!(offset, SubzoneOffset(357, 5, 2), "{offset:?}");
+        assert_eq!(
+            SubzoneLevel::from_offset(overzone, offset),
+            subzone_level,
+            "{overzone:?}"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use fastrand::u8 as rand_u8;
+
+/// The main body parts of CDDA, that clothing covers
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BodyPart {
+    Torso,
+    Head,
+    Eyes,
+    Mouth,
+    ArmLeft,
+    ArmRight,
+    HandLeft,
+    HandRight,
+    LegLeft,
+    LegRight,
+    FootLeft,
+    FootRight,
+}
+
+impl BodyPart {
+    pub const ALL: [Self; 12] = [
+        Self::Torso,
+        Self::Head,
+        Self::Eyes,
+        Self::Mouth,
+        Self::ArmLeft,
+        Self::ArmRight,
+        Self::HandLeft,
+        Self::HandRight,
+        Self::LegLeft,
+        Self::LegRight,
+        Self::FootLeft,
+        Self::FootRight,
+    ];
+
+    /// Larger body parts are hit more often
+    #[must_use]
+    pub fn random() -> Self {
+        let mut roll = rand_u8(0..100);
+        for body_part in Self::ALL {
+            if roll < body_part.hit_chance() {
+                return body_part;
+            }
+            roll -= body_part.hit_chance();
+        }
+        Self::Torso
+    }
+
+    /// In percent, adds up to 100 for all body parts
+    const fn hit_chance(self) -> u8 {
+        match self {
+            Self::Torso => 36,
+            Self::Head => 8,
+            Self::Eyes | Self::Mouth => 1,
+            Self::ArmLeft | Self::ArmRight => 9,
+            Self::HandLeft | Self::HandRight | Self::FootLeft | Self::FootRight => 3,
+            Self::LegLeft | Self::LegRight => 12,
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Torso => "torso",
+            Self::Head => "head",
+            Self::Eyes => "eyes",
+            Self::Mouth => "mouth",
+            Self::ArmLeft => "left arm",
+            Self::ArmRight => "right arm",
+            Self::HandLeft => "left hand",
+            Self::HandRight => "right hand",
+            Self::LegLeft => "left leg",
+            Self::LegRight => "right leg",
+            Self::FootLeft => "left foot",
+            Self::FootRight => "right foot",
+        }
+    }
+
+    /// As used by the 'covers' of clothing
+    #[must_use]
+    pub const fn cdda_id(self) -> &'static str {
+        match self {
+            Self::Torso => "torso",
+            Self::Head => "head",
+            Self::Eyes => "eyes",
+            Self::Mouth => "mouth",
+            Self::ArmLeft => "arm_l",
+            Self::ArmRight => "arm_r",
+            Self::HandLeft => "hand_l",
+            Self::HandRight => "hand_r",
+            Self::LegLeft => "leg_l",
+            Self::LegRight => "leg_r",
+            Self::FootLeft => "foot_l",
+            Self::FootRight => "foot_r",
+        }
+    }
+}
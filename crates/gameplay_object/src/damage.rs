@@ -1,8 +1,100 @@
+use cdda_json_files::{CharacterInfo, MaterialResist};
 use text::Subject;
 
 #[derive(Clone, Debug)]
 pub struct Damage {
-    // TODO damage types
     pub attacker: Subject, // for logging
+    pub damage_type: DamageType,
     pub amount: u16,
 }
+
+impl Damage {
+    /// What is left after armor or other resistance absorbed a part
+    #[must_use]
+    pub fn reduced(&self, resistance: u16) -> Self {
+        Self {
+            attacker: self.attacker.clone(),
+            damage_type: self.damage_type,
+            amount: self.amount.saturating_sub(resistance),
+        }
+    }
+}
+
+/// The damage types of CDDA
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageType {
+    Bash,
+    Cut,
+    Stab,
+    Bullet,
+    Acid,
+    Heat,
+    Cold,
+    Electric,
+    Biological,
+
+    /// Not affected by armor, like damage from effects
+    Pure,
+}
+
+impl DamageType {
+    /// Unknown damage types are considered pure
+    #[must_use]
+    pub fn from_cdda(id: &str) -> Self {
+        match id {
+            "bash" => Self::Bash,
+            "cut" => Self::Cut,
+            "stab" => Self::Stab,
+            "bullet" => Self::Bullet,
+            "acid" => Self::Acid,
+            "heat" => Self::Heat,
+            "cold" => Self::Cold,
+            "electric" => Self::Electric,
+            "biological" => Self::Biological,
+            _ => Self::Pure,
+        }
+    }
+
+    #[must_use]
+    pub const fn cdda_id(self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Cut => "cut",
+            Self::Stab => "stab",
+            Self::Bullet => "bullet",
+            Self::Acid => "acid",
+            Self::Heat => "heat",
+            Self::Cold => "cold",
+            Self::Electric => "electric",
+            Self::Biological => "biological",
+            Self::Pure => "pure",
+        }
+    }
+
+    /// Resistance per mm of the material
+    #[must_use]
+    pub fn material_resist(self, resist: &MaterialResist) -> f32 {
+        match self {
+            Self::Bash => resist.bash,
+            Self::Cut => resist.cut,
+            Self::Stab => resist.stab_or_cut(),
+            Self::Bullet => resist.bullet_or_cut(),
+            Self::Acid => resist.acid,
+            Self::Heat => resist.heat,
+            Self::Cold => resist.cold,
+            Self::Electric => resist.electric,
+            Self::Biological => resist.biological,
+            Self::Pure => 0.0,
+        }
+    }
+
+    /// The natural armor of a monster
+    #[must_use]
+    pub fn character_armor(self, character_info: &CharacterInfo) -> f32 {
+        if self == Self::Pure {
+            0.0
+        } else {
+            character_info.armor(self.cdda_id())
+        }
+    }
+}
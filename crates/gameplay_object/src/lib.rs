@@ -1,6 +1,7 @@
 //! Gameplay object components shared among multiple types of gameplay objects:
 //! items, corpses, actors, furniture, etc.
 
+mod body_part;
 mod closeable;
 mod corpse;
 mod damage;
@@ -14,9 +15,10 @@ mod opaque;
 mod openable;
 mod standard_integrity;
 
+pub use body_part::BodyPart;
 pub use closeable::Closeable;
 pub use corpse::{Corpse, CorpseRaise};
-pub use damage::Damage;
+pub use damage::{Damage, DamageType};
 pub use healing::Healing;
 pub use hurdle::Hurdle;
 pub use life::Life;
@@ -6,6 +6,7 @@ use gameplay_log::PosPerceiver;
 use gameplay_player::{Player, PlayerActionState};
 use gameplay_terrain::Accessible;
 use gameplay_time::Clock;
+use gameplay_weather::Weather;
 use gameplay_world::Envir;
 
 const WIDTH: usize = 2 * VisionDistance::MAX_VISION_TILES as usize + 1;
@@ -60,13 +61,19 @@ pub struct CurrentlyVisibleBuilder<'w, 's> {
     pub envir: Envir<'w, 's>,
     relative_segments: Res<'w, RelativeSegments>,
     clock: Clock<'w>,
+    weather: Option<Res<'w, Weather>>,
     player_action_state: Res<'w, State<PlayerActionState>>,
     player: Single<'w, 's, &'static Pos, With<Player>>,
 }
 
 impl CurrentlyVisibleBuilder<'_, '_> {
     pub fn for_npc(&self, pos: Pos) -> CurrentlyVisible<'_> {
-        let viewing_distance = CurrentlyVisible::viewing_distance(&self.clock, None, pos.level);
+        let viewing_distance = CurrentlyVisible::viewing_distance(
+            &self.clock,
+            self.weather.as_deref(),
+            None,
+            pos.level,
+        );
         self.build(viewing_distance, pos, true)
     }
 
@@ -79,6 +86,7 @@ impl CurrentlyVisibleBuilder<'_, '_> {
         };
         let viewing_distance = CurrentlyVisible::viewing_distance(
             &self.clock,
+            self.weather.as_deref(),
             Some(&*self.player_action_state),
             from_pos.level,
         );
@@ -190,23 +198,31 @@ pub struct CurrentlyVisible<'a> {
 impl CurrentlyVisible<'_> {
     const MIN_DISTANCE: f32 = 3.0;
 
+    /// Rain, snow, and fog limit the view above ground
     #[must_use]
     pub fn viewing_distance(
         clock: &Clock,
+        weather: Option<&Weather>,
         player_action_state: Option<&PlayerActionState>,
         level: Level,
     ) -> Option<u8> {
         if let Some(PlayerActionState::Sleeping { .. }) = player_action_state {
             None
         } else {
-            let light = if level < Level::ZERO {
-                0.0
+            let (light, sight) = if level < Level::ZERO {
+                (0.0, 1.0)
+            } else if let Some(weather) = weather {
+                (
+                    clock.sunlight_percentage() * weather.light_factor(),
+                    weather.sight_factor(),
+                )
             } else {
-                clock.sunlight_percentage()
+                (clock.sunlight_percentage(), 1.0)
             };
             Some(
-                (light * VisionDistance::MAX_VISION_TILES as f32
-                    + (1.0 - light) * Self::MIN_DISTANCE) as u8,
+                (light * sight * VisionDistance::MAX_VISION_TILES as f32
+                    + (1.0 - light) * Self::MIN_DISTANCE)
+                    .max(Self::MIN_DISTANCE) as u8,
             )
         }
     }
@@ -221,6 +237,32 @@ impl CurrentlyVisible<'_> {
         }
     }
 
+    /// The positions a projectile passes on its way to a visible target, ending with the target
+    pub fn line_of_fire(&mut self, to: Pos) -> Option<Vec<Pos>> {
+        if self.can_see(to, None) != Visible::Seen {
+            return None;
+        }
+
+        // Each segment starts with its preceding offset, and excludes its own target.
+        let mut offsets = vec![to - self.from];
+        let mut relative_segment = self.segments.get(&(to - self.from))?;
+        loop {
+            offsets.extend(relative_segment.segment.iter().rev());
+            let Some(preceding) = relative_segment.preceding else {
+                break;
+            };
+            relative_segment = self.segments.get(&preceding)?;
+        }
+
+        Some(
+            offsets
+                .into_iter()
+                .rev()
+                .map(|offset| self.from.offset(offset).expect("Valid offset"))
+                .collect(),
+        )
+    }
+
     const fn nearby_pos(&self, pos: Pos, extra: u8) -> bool {
         let Some(viewing_distance) = self.viewing_distance else {
             return false;
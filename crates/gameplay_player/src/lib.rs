@@ -4,7 +4,7 @@ use gameplay_crafting::RecipeSituation;
 use gameplay_location::{CardinalDirection, HorizontalDirection, Pos};
 use gameplay_log::{LogMessageTransience, Severity, Transient};
 use hud::{BAD_TEXT_COLOR, HARD_TEXT_COLOR, WARN_TEXT_COLOR};
-use std::fmt;
+use std::{fmt, sync::Arc};
 use units::Timestamp;
 
 mod plugin;
@@ -20,8 +20,10 @@ pub enum PickingNbor {
     Attacking,
     Smashing,
     Pulping,
+    Butchering,
     Peeking,
     Closing,
+    Disarming,
     Dragging,
     Crafting(RecipeSituation),
 }
@@ -36,6 +38,9 @@ pub enum PlayerActionState {
     Pulping {
         direction: HorizontalDirection,
     },
+    Butchering {
+        direction: HorizontalDirection,
+    },
     Peeking {
         direction: CardinalDirection,
     },
@@ -46,6 +51,23 @@ pub enum PlayerActionState {
         /// The craft item, not the resulting item
         item: Entity,
     },
+    Constructing {
+        /// The unfinished construction
+        site: Entity,
+    },
+    Disassembling {
+        /// The item being disassembled
+        item: Entity,
+    },
+    /// Reading or practicing
+    Training {
+        skill: Arc<str>,
+
+        /// No progress is made at or above this level
+        cap: Option<u8>,
+
+        until: Timestamp,
+    },
     Waiting {
         until: Timestamp,
     },
@@ -64,7 +86,13 @@ impl PlayerActionState {
     pub const fn is_still(&self) -> bool {
         matches!(
             *self,
-            Self::Crafting { .. } | Self::Waiting { .. } | Self::Sleeping { .. }
+            Self::Butchering { .. }
+                | Self::Crafting { .. }
+                | Self::Constructing { .. }
+                | Self::Disassembling { .. }
+                | Self::Training { .. }
+                | Self::Waiting { .. }
+                | Self::Sleeping { .. }
         )
     }
 
@@ -81,9 +109,13 @@ impl PlayerActionState {
             | Self::Sleeping { .. }
             | Self::PickingNbor { .. }
             | Self::Pulping { .. }
+            | Self::Butchering { .. }
             | Self::Peeking { .. }
             | Self::Dragging { .. }
             | Self::Crafting { .. }
+            | Self::Constructing { .. }
+            | Self::Disassembling { .. }
+            | Self::Training { .. }
             | Self::AutoTravel { .. } => WARN_TEXT_COLOR,
             Self::AutoDefend => BAD_TEXT_COLOR,
         }
@@ -93,7 +125,11 @@ impl PlayerActionState {
     pub const fn severity_finishing(&self) -> Severity {
         match self {
             Self::Pulping { .. }
+            | Self::Butchering { .. }
             | Self::Crafting { .. }
+            | Self::Constructing { .. }
+            | Self::Disassembling { .. }
+            | Self::Training { .. }
             | Self::PickingNbor(PickingNbor::Crafting { .. }) => Severity::Success,
             _ => Severity::Neutral,
         }
@@ -111,16 +147,22 @@ impl fmt::Display for PlayerActionState {
                     PickingNbor::Attacking => "Attacking",
                     PickingNbor::Smashing => "Smashing",
                     PickingNbor::Pulping => "Pulping",
+                    PickingNbor::Butchering => "Butchering",
                     PickingNbor::Peeking => "Peeking",
                     PickingNbor::Closing => "Closing",
+                    PickingNbor::Disarming => "Disarming",
                     PickingNbor::Dragging => unreachable!(),
                     PickingNbor::Crafting { .. } => "Crafting",
                 }) + ": pick a direction";
                 picking_nbor_string.as_str()
             }
             Self::Pulping { .. } => "Pulping",
+            Self::Butchering { .. } => "Butchering",
             Self::Peeking { .. } => "Peeking",
             Self::Crafting { .. } => "Crafting",
+            Self::Constructing { .. } => "Constructing",
+            Self::Disassembling { .. } => "Disassembling",
+            Self::Training { .. } => "Training",
             Self::Waiting { .. } => "Waiting",
             Self::Sleeping { .. } => "Sleeping",
             Self::AutoTravel { .. } => "Traveling",
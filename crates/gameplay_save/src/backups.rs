@@ -0,0 +1,99 @@
+use std::ffi::{OsStr, OsString};
+use std::fs::{copy, create_dir_all, read_dir, remove_dir_all, rename};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The amount of older copies of a world that are kept next to that world
+const BACKUP_COUNT: u8 = 3;
+
+const BACKUP_INFIX: &str = ".backup-";
+
+/// Whether this world directory is a backup copy of another world
+#[must_use]
+pub fn is_backup(world_path: &Path) -> bool {
+    world_path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| name.rsplit_once(BACKUP_INFIX))
+        .is_some_and(|(_, number)| number.parse::<u8>().is_ok())
+}
+
+/// Replace the oldest backup of a world with a fresh copy of that world. The other backups are renamed.
+pub(crate) fn rotate_backups(world_path: &Path) -> io::Result<()> {
+    let oldest = backup_path(world_path, BACKUP_COUNT);
+    if oldest.exists() {
+        remove_dir_all(oldest)?;
+    }
+
+    for number in (1..BACKUP_COUNT).rev() {
+        let backup = backup_path(world_path, number);
+        if backup.exists() {
+            rename(backup, backup_path(world_path, number + 1))?;
+        }
+    }
+
+    copy_dir(world_path, &backup_path(world_path, 1))
+}
+
+/// Copy a directory with all its contents
+pub(crate) fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    create_dir_all(to)?;
+    for entry in read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// A new world path, next to the given world, that is not in use yet
+#[must_use]
+pub fn unused_copy_path(world_path: &Path) -> PathBuf {
+    (2..=u16::MAX)
+        .map(|number| {
+            let mut name = world_name(world_path);
+            name.push(format!("-{number}"));
+            world_path.with_file_name(name)
+        })
+        .find(|path| !path.exists())
+        .expect("Some world path should be available")
+}
+
+fn backup_path(world_path: &Path, number: u8) -> PathBuf {
+    let mut name = world_name(world_path);
+    name.push(format!("{BACKUP_INFIX}{number}"));
+    world_path.with_file_name(name)
+}
+
+fn world_name(world_path: &Path) -> OsString {
+    world_path
+        .file_name()
+        .map(OsString::from)
+        .expect("World path should have a directory name")
+}
+
+#[cfg(test)]
+mod backup_tests {
+    use super::*;
+
+    #[test]
+    fn backup_names() {
+        let world_path = Path::new("assets/save/Sometown");
+        let backup = backup_path(world_path, 2);
+        assert_eq!(
+            backup,
+            Path::new("assets/save/Sometown.backup-2"),
+            "{backup:?}"
+        );
+        assert!(is_backup(&backup), "{backup:?}");
+        assert!(!is_backup(world_path), "{world_path:?}");
+        assert!(
+            !is_backup(Path::new("assets/save/Sometown.backup-x")),
+            "Only numbered backups"
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{SubmapState, copy_dir, rotate_backups, unused_copy_path};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::{Query, Res, With, Without, warn};
+use cdda_json_files::{
+    CharacterInfo, FurnitureInfo, Number, PocketType, SubzoneOffset, TerrainInfo, TrapInfo,
+};
+use gameplay_cdda::{Error, JsonObject, SaveWriter};
+use gameplay_cdda_active_sav::ActiveSav;
+use gameplay_character::{BodyTemperature, Effects, Health, Nutrition, Skills, Stamina};
+use gameplay_common::Shared;
+use gameplay_crafting::ConstructionSite;
+use gameplay_item::{
+    BodyContainers, InPocket, Item, ItemHierarchy, ItemItem, ItemTemperature, PocketWrapper,
+};
+use gameplay_location::{Overzone, Pos, SubzoneLevel, ZoneLevel};
+use gameplay_object::Life;
+use gameplay_perception::Explored;
+use gameplay_player::Player;
+use gameplay_relations::{ObjectOn, Tiles};
+use gameplay_terrain::Trap;
+use gameplay_time::Clock;
+use gameplay_world::Hordes;
+use serde_json::{Value as JsonValue, from_value as from_json_value, json};
+use std::{path::Path, sync::Arc};
+use strum::VariantArray as _;
+use util::AssetPaths;
+
+/// Collects the in-game state, and writes it to the active save
+#[derive(SystemParam)]
+pub struct GameSaver<'w, 's> {
+    active_sav: Res<'w, ActiveSav>,
+    clock: Clock<'w>,
+    explored: Res<'w, Explored>,
+    hordes: Res<'w, Hordes>,
+    item_hierarchy: ItemHierarchy<'w, 's>,
+    players: Query<
+        'w,
+        's,
+        (
+            &'static Pos,
+            &'static Health,
+            &'static Stamina,
+            &'static Skills,
+            &'static Effects,
+            &'static BodyTemperature,
+            &'static Nutrition,
+            &'static BodyContainers,
+        ),
+        With<Player>,
+    >,
+    subzone_levels: Query<'w, 's, &'static SubzoneLevel, With<Tiles>>,
+    terrain: Query<'w, 's, (&'static Pos, &'static Shared<TerrainInfo>)>,
+    furniture: Query<'w, 's, (&'static Pos, &'static Shared<FurnitureInfo>)>,
+    traps: Query<'w, 's, (&'static Pos, &'static Shared<TrapInfo>, &'static Trap)>,
+    construction_sites: Query<'w, 's, (&'static Pos, &'static ConstructionSite)>,
+    characters: Query<
+        'w,
+        's,
+        (&'static Pos, &'static Shared<CharacterInfo>),
+        (With<Life>, Without<Player>),
+    >,
+    items: Query<'w, 's, Item, With<ObjectOn>>,
+}
+
+impl GameSaver<'_, '_> {
+    /// CDDA stores the corner of the reality bubble, that extends 5 submaps around the player.
+    const BUBBLE_OFFSET: i32 = 5 * SubzoneLevel::SIZE;
+
+    /// Write the spawned submaps, the map memory, and the player to the active save.
+    ///
+    /// The world is backed up first.
+    ///
+    /// # Errors
+    /// When one of the files could not be read, parsed, copied, or written
+    pub fn save(&self) -> Result<(), Error> {
+        rotate_backups(self.world_path())?;
+        self.write(&SaveWriter::new(&self.active_sav))
+    }
+
+    /// Like [`Self::save`], but without a backup, because autosaves happen often.
+    ///
+    /// The backups keep the state from before the explicit saves.
+    ///
+    /// # Errors
+    /// When one of the files could not be read, parsed, or written
+    pub(crate) fn autosave(&self) -> Result<(), Error> {
+        self.write(&SaveWriter::new(&self.active_sav))
+    }
+
+    /// Copy the world of the active save to a new world, and save the game in that new world.
+    ///
+    /// Returns the save in the new world, that should become the active save.
+    ///
+    /// # Errors
+    /// When one of the files could not be read, parsed, copied, or written
+    pub fn save_as(&self) -> Result<ActiveSav, Error> {
+        let world_path = self.world_path();
+        let copy_path = unused_copy_path(world_path);
+        copy_dir(world_path, &copy_path)?;
+
+        let sav_path = copy_path
+            .join(
+                self.active_sav
+                    .path()
+                    .file_name()
+                    .expect("Sav path should have a file name"),
+            )
+            .strip_prefix(AssetPaths::save())
+            .expect("Worlds should be in the save directory")
+            .to_path_buf();
+        self.write(&SaveWriter::new(&ActiveSav::new(&sav_path)?))?;
+
+        // Reloaded, to reflect the written changes
+        Ok(ActiveSav::new(&sav_path)?)
+    }
+
+    fn world_path(&self) -> &Path {
+        self.active_sav
+            .path()
+            .parent()
+            .expect("Path to sav file should have a parent directory")
+    }
+
+    fn write(&self, writer: &SaveWriter) -> Result<(), Error> {
+        let Ok(player) = self.players.single() else {
+            warn!("Saving skipped, because there is no player character");
+            return Ok(());
+        };
+
+        let submaps = self.submap_states();
+
+        let mut saved = Vec::new();
+        let zone_levels = submaps
+            .keys()
+            .map(|&subzone_level| ZoneLevel::from(subzone_level))
+            .collect::<HashSet<_>>();
+        for zone_level in zone_levels {
+            let complete = zone_level
+                .subzone_levels()
+                .iter()
+                .all(|subzone_level| submaps.contains_key(subzone_level));
+            let written = writer.update_map(zone_level, complete, |subzone_level, submap| {
+                if let Some(submap_state) = submaps.get(&subzone_level) {
+                    submap_state.apply(submap);
+                }
+            })?;
+            if written {
+                saved.extend(
+                    zone_level
+                        .subzone_levels()
+                        .into_iter()
+                        .filter(|subzone_level| submaps.contains_key(subzone_level)),
+                );
+            }
+        }
+
+        for (&subzone_level, submap_state) in &submaps {
+            writer.update_map_memory(subzone_level, &self.seen(subzone_level, submap_state))?;
+        }
+
+        Self::remove_spawned_monsters(writer, &saved)?;
+        self.save_monster_groups(writer)?;
+        self.save_player(writer, player)
+    }
+
+    fn submap_states(&self) -> HashMap<SubzoneLevel, SubmapState> {
+        let mut submaps = self
+            .subzone_levels
+            .iter()
+            .map(|&subzone_level| (subzone_level, SubmapState::default()))
+            .collect::<HashMap<_, _>>();
+
+        for (&pos, terrain_info) in &self.terrain {
+            if let Some((submap_state, at)) = locate(&mut submaps, pos) {
+                submap_state.set_terrain(at, terrain_info.id.fallback_name());
+            }
+        }
+
+        for (&pos, furniture_info) in &self.furniture {
+            if let Some((submap_state, at)) = locate(&mut submaps, pos) {
+                submap_state.add_furniture(at, &furniture_info.id.fallback_name());
+            }
+        }
+
+        for (&pos, trap_info, trap) in &self.traps {
+            // Built-in traps are restored from the terrain
+            if !trap.built_in
+                && let Some((submap_state, at)) = locate(&mut submaps, pos)
+            {
+                submap_state.add_trap(at, &trap_info.id.fallback_name());
+            }
+        }
+
+        for (&pos, construction_site) in &self.construction_sites {
+            if let Some((submap_state, at)) = locate(&mut submaps, pos) {
+                submap_state.add_partial_construction(
+                    at,
+                    pos.level.h,
+                    construction_site.counter(),
+                    &construction_site.construction().id.fallback_name(),
+                );
+            }
+        }
+
+        for item in &self.items {
+            if let Some(&pos) = item.pos
+                && let Some((submap_state, at)) = locate(&mut submaps, pos)
+            {
+                submap_state.add_item(at, self.item_json(&item));
+            }
+        }
+
+        for (&pos, character_info) in &self.characters {
+            if let Some((submap_state, at)) = locate(&mut submaps, pos) {
+                submap_state.add_spawn(at, &character_info.id.fallback_name());
+            }
+        }
+
+        submaps
+    }
+
+    /// The ids of all seen tiles, ordered by z, then x
+    fn seen(
+        &self,
+        subzone_level: SubzoneLevel,
+        submap_state: &SubmapState,
+    ) -> [Option<Arc<str>>; 144] {
+        let base = subzone_level.base_corner();
+        let mut seen = [const { None }; 144];
+        for (index, seen) in seen.iter_mut().enumerate() {
+            let at = (
+                (index % SubzoneLevel::SIZE as usize) as u8,
+                (index / SubzoneLevel::SIZE as usize) as u8,
+            );
+            let pos = base.horizontal_offset(i32::from(at.0), i32::from(at.1));
+            if self.explored.has_pos_been_seen(pos) {
+                *seen = Some(
+                    submap_state
+                        .terrain(at)
+                        .cloned()
+                        .unwrap_or_else(|| Arc::from("t_open_air")),
+                );
+            }
+        }
+        seen
+    }
+
+    /// Monsters on the overmap are spawned in their submap. When saving that submap, these monsters become spawns of that submap.
+    fn remove_spawned_monsters(writer: &SaveWriter, saved: &[SubzoneLevel]) -> Result<(), Error> {
+        let mut offsets = HashMap::<Overzone, Vec<SubzoneOffset>>::default();
+        for &subzone_level in saved {
+            offsets
+                .entry(Overzone::from(ZoneLevel::from(subzone_level).zone))
+                .or_default()
+                .push(SubzoneOffset::from(subzone_level));
+        }
+
+        for (overzone, offsets) in offsets {
+            writer.update_overmap(overzone, |overmap| {
+                let Some(JsonValue::Array(monster_map)) = overmap.get_mut("monster_map") else {
+                    return;
+                };
+                *monster_map = monster_map
+                    .chunks(2)
+                    .filter(|pair| {
+                        !pair
+                            .first()
+                            .and_then(|offset| {
+                                from_json_value::<SubzoneOffset>(offset.clone()).ok()
+                            })
+                            .is_some_and(|offset| offsets.contains(&offset))
+                    })
+                    .flatten()
+                    .cloned()
+                    .collect();
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Spawned monster groups are left out, and hordes are saved where they moved to
+    fn save_monster_groups(&self, writer: &SaveWriter) -> Result<(), Error> {
+        for &overzone in self.hordes.loaded_overzones() {
+            let monster_groups = json!(self.hordes.overmap_groups(overzone));
+            writer.update_overmap(overzone, |overmap| {
+                overmap.insert(String::from("monster_groups"), monster_groups);
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn save_player(
+        &self,
+        writer: &SaveWriter,
+        (&pos, health, stamina, skills, effects, &body_temperature, nutrition, body_containers): (
+            &Pos,
+            &Health,
+            &Stamina,
+            &Skills,
+            &Effects,
+            &BodyTemperature,
+            &Nutrition,
+            &BodyContainers,
+        ),
+    ) -> Result<(), Error> {
+        let bubble_x = (pos.x - Self::BUBBLE_OFFSET).div_euclid(SubzoneLevel::SIZE);
+        let bubble_z = (pos.z - Self::BUBBLE_OFFSET).div_euclid(SubzoneLevel::SIZE);
+        let wielded = self.pocket_json(body_containers.hands);
+        let worn = self.pocket_json(body_containers.clothing);
+
+        writer.update_sav(|sav| {
+            sav.insert(String::from("turn"), json!(self.clock.time().turn()));
+            sav.insert(String::from("om_x"), json!(bubble_x.div_euclid(360)));
+            sav.insert(String::from("om_y"), json!(bubble_z.div_euclid(360)));
+            sav.insert(String::from("levx"), json!(bubble_x.rem_euclid(360)));
+            sav.insert(String::from("levy"), json!(bubble_z.rem_euclid(360)));
+            sav.insert(String::from("levz"), json!(pos.level.h));
+
+            let Some(player) = sav.get_mut("player").and_then(JsonValue::as_object_mut) else {
+                warn!("No player found in the .sav file");
+                return;
+            };
+            player.insert(String::from("location"), json!([pos.x, pos.z, pos.level.h]));
+            if let Some(stamina) = stamina.cdda_value() {
+                player.insert(String::from("stamina"), json!(stamina));
+            }
+            save_body_health(player, health.value().relative());
+            save_body_temperature(player, body_temperature);
+            player.insert(
+                String::from("stored_calories"),
+                json!(nutrition.cdda_stored_calories()),
+            );
+            player.insert(String::from("thirst"), json!(nutrition.cdda_thirst()));
+            save_skills(player, skills);
+            player.insert(String::from("focus_pool"), json!(skills.focus()));
+            save_effects(player, effects);
+
+            if let Some(weapon) = wielded.into_iter().next() {
+                player.insert(String::from("weapon"), weapon);
+            } else {
+                player.remove("weapon");
+            }
+            // Newer CDDA versions wrap the list in an object
+            if let Some(JsonValue::Object(outfit)) = player.get_mut("worn") {
+                outfit.insert(String::from("worn"), JsonValue::Array(worn));
+            } else {
+                player.insert(String::from("worn"), JsonValue::Array(worn));
+            }
+        })
+    }
+
+    fn pocket_json(&self, in_pocket: InPocket) -> Vec<JsonValue> {
+        self.item_hierarchy
+            .items_in_pocket(in_pocket)
+            .map(|item| self.item_json(&item))
+            .collect()
+    }
+
+    /// Starts from the json the item was spawned from, and only updates what the components track
+    fn item_json(&self, item: &ItemItem) -> JsonValue {
+        let mut json = item
+            .saved
+            .map(|saved| (*saved.0).clone())
+            .unwrap_or_default();
+        json.insert(
+            String::from("typeid"),
+            JsonValue::from(&*item.common_info.id.fallback_name()),
+        );
+        if item.amount.0 != 1 || json.contains_key("charges") {
+            json.insert(String::from("charges"), json!(item.amount.0));
+        }
+        if item.integrity.damage() == 0 {
+            json.remove("damaged");
+        } else {
+            json.insert(String::from("damaged"), json!(item.integrity.damage()));
+        }
+
+        let frozen = item
+            .temperature
+            .copied()
+            .is_some_and(ItemTemperature::frozen);
+        let mut item_tags = json
+            .remove("item_tags")
+            .and_then(|tags| from_json_value::<Vec<String>>(tags).ok())
+            .unwrap_or_default();
+        item_tags.retain(|tag| tag != "FILTHY" && tag != "FROZEN");
+        item_tags.extend(
+            [(item.filthy.is_some(), "FILTHY"), (frozen, "FROZEN")]
+                .into_iter()
+                .filter(|(present, _)| *present)
+                .map(|(_, tag)| String::from(tag)),
+        );
+        if !item_tags.is_empty() {
+            json.insert(String::from("item_tags"), json!(item_tags));
+        }
+
+        if let Some(temperature) = item.temperature {
+            json.insert(
+                String::from("temperature"),
+                json!(Number::from_celsius(temperature.celsius())),
+            );
+        }
+        if let Some(contents) = self.contents_json(item) {
+            json.insert(String::from("contents"), contents);
+        }
+        JsonValue::Object(json)
+    }
+
+    /// CDDA expects all pockets from the pocket data, in the same order
+    fn contents_json(&self, item: &ItemItem) -> Option<JsonValue> {
+        let pocket_data = item.common_info.pocket_data.as_ref()?;
+        let pockets = self.item_hierarchy.pockets_in(item);
+        if pockets.iter().all(|pocket| pocket.in_pocket().is_none()) {
+            // No pockets spawned
+            return None;
+        }
+
+        let contents = pocket_data
+            .iter()
+            .map(|pocket_info| {
+                let pocket = pockets
+                    .iter()
+                    .find(|pocket| Arc::ptr_eq(pocket.info(), pocket_info));
+                let items = pocket
+                    .and_then(PocketWrapper::in_pocket)
+                    .map(|in_pocket| self.pocket_json(in_pocket))
+                    .unwrap_or_default();
+                json!({
+                    "pocket_type": pocket_type_index(pocket_info.pocket_type),
+                    "contents": items,
+                    "_sealed": pocket.and_then(PocketWrapper::sealed).is_some(),
+                })
+            })
+            .collect::<Vec<_>>();
+        Some(json!({ "contents": contents }))
+    }
+}
+
+/// The state of the submap containing `pos`, with the position within that submap
+fn locate(
+    submaps: &mut HashMap<SubzoneLevel, SubmapState>,
+    pos: Pos,
+) -> Option<(&mut SubmapState, (u8, u8))> {
+    let subzone_level = SubzoneLevel::from(pos);
+    submaps
+        .get_mut(&subzone_level)
+        .map(|submap_state| (submap_state, SubmapState::local(subzone_level, pos)))
+}
+
+fn pocket_type_index(pocket_type: PocketType) -> usize {
+    PocketType::VARIANTS
+        .iter()
+        .position(|variant| *variant == pocket_type)
+        .expect("All pocket types should be known")
+}
+
+/// Keeps the other skill fields, like 'knowledgeLevel', as they were
+fn save_skills(player: &mut JsonObject, skills: &Skills) {
+    let Some(saved) = player.get_mut("skills").and_then(JsonValue::as_object_mut) else {
+        return;
+    };
+    for (name, skill_level) in skills.iter() {
+        let skill = saved
+            .entry(String::from(&**name))
+            .or_insert_with(|| json!({}));
+        if let Some(skill) = skill.as_object_mut() {
+            skill.insert(String::from("level"), json!(skill_level.level));
+            skill.insert(String::from("exercise"), json!(skill_level.exercise));
+        }
+    }
+}
+
+/// Keeps the other effect fields, like `start_turn`, of effects that are still active
+fn save_effects(player: &mut JsonObject, effects: &Effects) {
+    let previous = player.remove("effects");
+    let mut saved = JsonObject::new();
+    for (id, per_body_part) in effects.to_cdda() {
+        let mut saved_per_body_part = JsonObject::new();
+        for (body_part, saved_effect) in per_body_part {
+            let mut effect = previous
+                .as_ref()
+                .and_then(|previous| previous.get(&*id)?.get(&*body_part)?.as_object())
+                .cloned()
+                .unwrap_or_else(|| {
+                    JsonObject::from_iter([
+                        (String::from("eff_type"), json!(&*id)),
+                        (String::from("bp"), json!(&*body_part)),
+                    ])
+                });
+            effect.insert(String::from("duration"), json!(saved_effect.duration));
+            effect.insert(String::from("intensity"), json!(saved_effect.intensity));
+            saved_per_body_part.insert(String::from(&*body_part), JsonValue::Object(effect));
+        }
+        saved.insert(String::from(&*id), JsonValue::Object(saved_per_body_part));
+    }
+    player.insert(String::from("effects"), JsonValue::Object(saved));
+}
+
+/// CDDA tracks health per body part, so we apply the same fraction to all body parts.
+fn save_body_health(player: &mut JsonObject, relative: f32) {
+    let Some(body) = player.get_mut("body").and_then(JsonValue::as_object_mut) else {
+        return;
+    };
+    for body_part in body.values_mut().filter_map(JsonValue::as_object_mut) {
+        if let Some(hp_max) = body_part.get("hp_max").and_then(JsonValue::as_i64) {
+            let hp_cur = (hp_max as f32 * relative).round() as i64;
+            body_part.insert(String::from("hp_cur"), json!(hp_cur));
+        }
+    }
+}
+
+/// CDDA tracks the temperature per body part, but we use the same temperature for all
+fn save_body_temperature(player: &mut JsonObject, body_temperature: BodyTemperature) {
+    let Some(body) = player.get_mut("body").and_then(JsonValue::as_object_mut) else {
+        return;
+    };
+    let cdda_value = body_temperature.to_cdda();
+    for body_part in body.values_mut().filter_map(JsonValue::as_object_mut) {
+        for key in ["temp_cur", "temp_conv"] {
+            if body_part.contains_key(key) {
+                body_part.insert(String::from(key), json!(cdda_value));
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::Resource;
+use std::time::{Duration as RealDuration, Instant};
+use units::{Duration, Timestamp};
+
+/// When the game was last saved, both in-game and in real life
+#[derive(Resource)]
+pub struct LastSave {
+    /// Unknown until the first check
+    timestamp: Option<Timestamp>,
+    instant: Instant,
+}
+
+impl LastSave {
+    const GAME_INTERVAL: Duration = Duration::HOUR;
+    const REAL_INTERVAL: RealDuration = RealDuration::from_mins(5);
+
+    pub(crate) fn saved(&mut self, timestamp: Timestamp) {
+        self.timestamp = Some(timestamp);
+        self.instant = Instant::now();
+    }
+
+    /// Whether enough in-game time or enough real time has passed
+    pub(crate) fn autosave_due(&mut self, timestamp: Timestamp) -> bool {
+        let last = *self.timestamp.get_or_insert(timestamp);
+        Self::GAME_INTERVAL <= timestamp - last || Self::REAL_INTERVAL <= self.instant.elapsed()
+    }
+}
+
+impl Default for LastSave {
+    fn default() -> Self {
+        Self {
+            timestamp: None,
+            instant: Instant::now(),
+        }
+    }
+}
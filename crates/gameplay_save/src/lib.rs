@@ -0,0 +1,16 @@
+mod backups;
+mod game_saver;
+mod last_save;
+mod plugin;
+mod submap_state;
+mod systems;
+
+pub use self::backups::{is_backup, unused_copy_path};
+pub use self::game_saver::GameSaver;
+pub use self::last_save::LastSave;
+pub use self::plugin::GameplaySavePlugin;
+pub use self::systems::{save_game, save_game_as};
+
+use self::backups::{copy_dir, rotate_backups};
+use self::submap_state::SubmapState;
+use self::systems::autosave;
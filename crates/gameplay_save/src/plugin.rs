@@ -0,0 +1,16 @@
+use crate::{LastSave, autosave};
+use bevy::prelude::{App, IntoScheduleConfigs as _, Plugin, Update, in_state};
+use gameplay_resource::gameplay_resource_plugin;
+use gameplay_screen_state::GameplayScreenState;
+
+/// Saving, both on request and periodically
+pub struct GameplaySavePlugin;
+
+impl Plugin for GameplaySavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(gameplay_resource_plugin::<LastSave>);
+
+        // Only from the base screen, to not save halfway a menu or a modal
+        app.add_systems(Update, autosave.run_if(in_state(GameplayScreenState::Base)));
+    }
+}
@@ -0,0 +1,127 @@
+use gameplay_cdda::{JsonObject, expand_repetitions, repetitions_json};
+use gameplay_location::{Pos, SubzoneLevel};
+use serde_json::{Value as JsonValue, json};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// The spawned terrain, furniture, traps, construction sites, items, and characters of a submap
+pub(crate) struct SubmapState {
+    /// Ordered by z, then x. Air terrain is not spawned, so it is missing.
+    terrain: Vec<Option<Arc<str>>>,
+    furniture: Vec<JsonValue>,
+    traps: Vec<JsonValue>,
+    partial_constructions: Vec<JsonValue>,
+    items: BTreeMap<(u8, u8), Vec<JsonValue>>,
+    spawns: Vec<JsonValue>,
+}
+
+impl SubmapState {
+    const SIZE: usize = SubzoneLevel::SIZE as usize;
+
+    /// Local coordinates within the subzone level
+    pub(crate) const fn local(subzone_level: SubzoneLevel, pos: Pos) -> (u8, u8) {
+        let base = subzone_level.base_corner();
+        ((pos.x - base.x) as u8, (pos.z - base.z) as u8)
+    }
+
+    pub(crate) fn set_terrain(&mut self, (x, z): (u8, u8), id: Arc<str>) {
+        self.terrain[usize::from(z) * Self::SIZE + usize::from(x)] = Some(id);
+    }
+
+    pub(crate) fn terrain(&self, (x, z): (u8, u8)) -> Option<&Arc<str>> {
+        self.terrain[usize::from(z) * Self::SIZE + usize::from(x)].as_ref()
+    }
+
+    pub(crate) fn add_furniture(&mut self, (x, z): (u8, u8), id: &str) {
+        self.furniture.push(json!([x, z, id]));
+    }
+
+    pub(crate) fn add_trap(&mut self, (x, z): (u8, u8), id: &str) {
+        self.traps.push(json!([x, z, id]));
+    }
+
+    /// Flattened, like the other lists
+    pub(crate) fn add_partial_construction(
+        &mut self,
+        (x, z): (u8, u8),
+        level: i8,
+        counter: u32,
+        id: &str,
+    ) {
+        self.partial_constructions.extend([
+            json!(x),
+            json!(z),
+            json!(level),
+            json!(counter),
+            json!(id),
+            json!([]),
+        ]);
+    }
+
+    pub(crate) fn add_item(&mut self, at: (u8, u8), item: JsonValue) {
+        self.items.entry(at).or_default().push(item);
+    }
+
+    pub(crate) fn add_spawn(&mut self, (x, z): (u8, u8), id: &str) {
+        self.spawns.push(json!({
+            "id": id,
+            "count": 1,
+            "x": x,
+            "z": z,
+            "faction_id": -1,
+            "mission_id": -1,
+            "friendly": false,
+            "name": null
+        }));
+    }
+
+    /// Write the state to the JSON of the submap. Unspawned terrain is left as is.
+    pub(crate) fn apply(&self, submap: &mut JsonObject) {
+        let mut terrain = submap
+            .get("terrain")
+            .map(expand_repetitions)
+            .unwrap_or_default();
+        terrain.resize(Self::SIZE * Self::SIZE, JsonValue::from("t_open_air"));
+        for (old, new) in terrain.iter_mut().zip(&self.terrain) {
+            if let Some(new) = new {
+                *old = JsonValue::from(&**new);
+            }
+        }
+
+        submap.insert(String::from("terrain"), repetitions_json(terrain));
+        submap.insert(
+            String::from("furniture"),
+            JsonValue::Array(self.furniture.clone()),
+        );
+        submap.insert(String::from("traps"), JsonValue::Array(self.traps.clone()));
+        submap.insert(
+            String::from("partial_constructions"),
+            JsonValue::Array(self.partial_constructions.clone()),
+        );
+        submap.insert(
+            String::from("items"),
+            JsonValue::Array(
+                self.items
+                    .iter()
+                    .flat_map(|(&(x, z), items)| [json!(x), json!(z), json!(items)])
+                    .collect(),
+            ),
+        );
+        submap.insert(
+            String::from("spawns"),
+            JsonValue::Array(self.spawns.clone()),
+        );
+    }
+}
+
+impl Default for SubmapState {
+    fn default() -> Self {
+        Self {
+            terrain: vec![None; Self::SIZE * Self::SIZE],
+            furniture: Vec::new(),
+            traps: Vec::new(),
+            partial_constructions: Vec::new(),
+            items: BTreeMap::new(),
+            spawns: Vec::new(),
+        }
+    }
+}
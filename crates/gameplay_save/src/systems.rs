@@ -0,0 +1,57 @@
+use crate::{GameSaver, LastSave};
+use bevy::prelude::{Commands, ResMut, error, info};
+use gameplay_time::Clock;
+use std::time::Instant;
+use util::log_if_slow;
+
+#[expect(clippy::needless_pass_by_value)]
+pub fn save_game(game_saver: GameSaver, clock: Clock, mut last_save: ResMut<LastSave>) {
+    let start = Instant::now();
+
+    match game_saver.save() {
+        Ok(()) => info!("Game saved"),
+        Err(error) => error!("Saving failed: {error:?}"),
+    }
+    last_save.saved(clock.time());
+
+    log_if_slow("save_game", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub fn save_game_as(
+    mut commands: Commands,
+    game_saver: GameSaver,
+    clock: Clock,
+    mut last_save: ResMut<LastSave>,
+) {
+    let start = Instant::now();
+
+    match game_saver.save_as() {
+        Ok(active_sav) => {
+            info!("Game saved as {}", active_sav.path().display());
+            commands.insert_resource(active_sav);
+        }
+        Err(error) => error!("Saving as failed: {error:?}"),
+    }
+    last_save.saved(clock.time());
+
+    log_if_slow("save_game_as", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(crate) fn autosave(game_saver: GameSaver, clock: Clock, mut last_save: ResMut<LastSave>) {
+    let start = Instant::now();
+
+    if !last_save.autosave_due(clock.time()) {
+        return;
+    }
+
+    match game_saver.autosave() {
+        Ok(()) => info!("Game autosaved"),
+        Err(error) => error!("Autosaving failed: {error:?}"),
+    }
+    // Also after failure, to prevent retrying every frame
+    last_save.saved(clock.time());
+
+    log_if_slow("autosave", start);
+}
@@ -5,7 +5,7 @@ use bevy::prelude::{
 };
 use gameplay_action_planning::{PlayerDirection, PlayerInstructions, QueuedInstruction};
 use gameplay_camera::{ZoomDirection, ZoomDistance, manage_zoom, reset_camera_angle, toggle_map};
-use gameplay_character::ChangePace;
+use gameplay_character::{ChangePace, Fire};
 use gameplay_focus::{CancelHandling, ElevationVisibility, Focus, FocusState};
 use gameplay_log::LogMessageWriter;
 use gameplay_player::PlayerActionState;
@@ -35,6 +35,22 @@ fn examine_zone_level(focus: Focus, mut next_focus_state: ResMut<NextState<Focus
     log_if_slow("examine_zone_level", start);
 }
 
+/// Start aiming, or fire when already aiming
+#[expect(clippy::needless_pass_by_value)]
+fn aim(
+    focus: Focus,
+    mut player_instructions: ResMut<PlayerInstructions>,
+    mut next_focus_state: ResMut<NextState<FocusState>>,
+) {
+    let start = Instant::now();
+
+    if let Some(target) = focus.toggle_targeting(&mut next_focus_state) {
+        player_instructions.push(QueuedInstruction::Fire(Fire { target }));
+    }
+
+    log_if_slow("aim", start);
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn open_screen(
     In(screen): In<GameplayScreenState>,
@@ -98,6 +114,11 @@ fn handle_queued_instruction(
                 next_focus_state.set(FocusState::ExaminingPos(nbor_target));
             }
         }
+        (FocusState::Targeting(target), QueuedInstruction::Offset(offset)) => {
+            if let Some(nbor_target) = target.raw_nbor(offset.to_nbor()) {
+                next_focus_state.set(FocusState::Targeting(nbor_target));
+            }
+        }
         (FocusState::ExaminingZoneLevel(target), QueuedInstruction::Offset(offset)) => {
             if let Some(nbor_target) = target.nbor(offset.to_nbor()) {
                 next_focus_state.set(FocusState::ExaminingZoneLevel(nbor_target));
@@ -140,9 +161,15 @@ pub(super) fn create_base_key_bindings(
         builder.add('M', (|| ZoomDistance::Far).pipe(toggle_map));
         builder.add('x', examine_pos);
         builder.add('X', examine_zone_level);
+        builder.add('f', aim);
         builder.add('&', (|| GameplayScreenState::Crafting).pipe(open_screen));
+        builder.add(
+            '*',
+            (|| GameplayScreenState::Construction).pipe(open_screen),
+        );
         builder.add('i', (|| GameplayScreenState::Inventory).pipe(open_screen));
         builder.add('q', (|| GameplayScreenState::Quality).pipe(open_screen));
+        builder.add('@', (|| GameplayScreenState::Skills).pipe(open_screen));
         builder.add('t', (|| GameplayScreenState::Tool).pipe(open_screen));
         builder.add('|', (|| GameplayScreenState::Waiting).pipe(open_screen));
         builder.add('z', (|| ZoomDirection::In).pipe(manage_zoom));
@@ -152,13 +179,16 @@ pub(super) fn create_base_key_bindings(
 
         {
             use QueuedInstruction::{
-                Attack, Close, Drag, Peek, Pulp, Sleep, Smash, ToggleAutoDefend, ToggleAutoTravel,
+                Attack, Butcher, Close, Disarm, Drag, Peek, Pulp, Sleep, Smash, ToggleAutoDefend,
+                ToggleAutoTravel,
             };
             builder.add('$', (|| Sleep).pipe(manage_queued_instruction));
             builder.add('a', (|| Attack).pipe(manage_queued_instruction));
             builder.add('s', (|| Smash).pipe(manage_queued_instruction));
             builder.add('p', (|| Pulp).pipe(manage_queued_instruction));
+            builder.add('B', (|| Butcher).pipe(manage_queued_instruction));
             builder.add('c', (|| Close).pipe(manage_queued_instruction));
+            builder.add('D', (|| Disarm).pipe(manage_queued_instruction));
             builder.add('\\', (|| Drag).pipe(manage_queued_instruction));
             builder.add('G', (|| ToggleAutoTravel).pipe(manage_queued_instruction));
             builder.add('A', (|| ToggleAutoDefend).pipe(manage_queued_instruction));
@@ -182,8 +212,11 @@ pub(super) fn create_base_key_bindings(
         ManualSection::new(
             &[
                 ("attack npc", "a"),
+                ("aim/fire", "f"),
                 ("smash furniture", "s"),
                 ("pulp corpse", "p"),
+                ("butcher corpse", "B"),
+                ("disarm trap", "D"),
                 ("walking mode", "+/-"),
                 ("auto defend", "A"),
                 ("peek", "tab"),
@@ -195,7 +228,9 @@ pub(super) fn create_base_key_bindings(
                 ("auto travel", "G"),
                 ("inventory", "i"),
                 ("crafting", "&"),
+                ("construction", "*"),
                 ("qualities", "q"),
+                ("skills", "@"),
                 ("tool actions", "t"),
                 ("toggle map", "m/M"),
                 ("camera angle", "middle mouse button"),
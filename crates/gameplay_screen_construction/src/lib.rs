@@ -0,0 +1,8 @@
+mod messages;
+mod plugin;
+mod resource;
+mod systems;
+
+pub use self::plugin::ConstructionScreenPlugin;
+
+use self::resource::ConstructionScreen;
@@ -0,0 +1,21 @@
+use gameplay_log::{ProtoLogMessage, Severity};
+use std::sync::Arc;
+use text::Phrase;
+
+#[derive(Debug)]
+pub(super) struct YouLackTheMeansToConstruct {
+    pub(super) construction: Arc<str>,
+}
+
+impl ProtoLogMessage for YouLackTheMeansToConstruct {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        Self::you("lack")
+            .soft("the")
+            .hard("means")
+            .soft("to")
+            .hard("construct")
+            .hard(&*self.construction)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::ConstructionScreen;
+use crate::systems::{
+    adapt_to_construction_deselection, adapt_to_construction_selection,
+    create_construction_key_bindings, create_start_construction_system,
+    refresh_construction_screen, remove_construction_resource, spawn_construction_screen,
+};
+use bevy::prelude::{
+    App, IntoScheduleConfigs as _, IntoSystem as _, OnEnter, OnExit, Plugin, SystemCondition as _,
+    Update, in_state, on_message, resource_exists_and_changed,
+};
+use gameplay_behavior::RefreshAfterBehavior;
+use gameplay_screen_state::GameplayScreenState;
+use selection_list::selection_list_plugin;
+
+pub struct ConstructionScreenPlugin;
+
+impl Plugin for ConstructionScreenPlugin {
+    fn build(&self, app: &mut App) {
+        selection_list_plugin::<_, ()>(
+            app,
+            GameplayScreenState::Construction,
+            "select construction",
+        );
+
+        app.add_systems(
+            OnEnter(GameplayScreenState::Construction),
+            (
+                // The screen is refreshed after the resource is added
+                create_start_construction_system.pipe(spawn_construction_screen),
+                create_construction_key_bindings,
+            ),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                adapt_to_construction_deselection,
+                adapt_to_construction_selection,
+                refresh_construction_screen.run_if(
+                    on_message::<RefreshAfterBehavior>
+                        .or_else(resource_exists_and_changed::<ConstructionScreen>),
+                ),
+            )
+                .run_if(in_state(GameplayScreenState::Construction)),
+        );
+
+        app.add_systems(
+            OnExit(GameplayScreenState::Construction),
+            remove_construction_resource,
+        );
+    }
+}
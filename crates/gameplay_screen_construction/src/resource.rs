@@ -0,0 +1,32 @@
+use crate::systems::StartConstructionSystem;
+use bevy::prelude::{Entity, Resource};
+use gameplay_location::HorizontalDirection;
+
+#[derive(Resource)]
+pub(super) struct ConstructionScreen {
+    pub(super) construction_list: Entity,
+    pub(super) construction_details: Entity,
+    start_construction_system: StartConstructionSystem,
+
+    /// Direction of the constructed tile, relative to the player character
+    pub(super) target: HorizontalDirection,
+}
+
+impl ConstructionScreen {
+    pub(crate) const fn new(
+        construction_list: Entity,
+        construction_details: Entity,
+        start_construction_system: StartConstructionSystem,
+    ) -> Self {
+        Self {
+            construction_list,
+            construction_details,
+            start_construction_system,
+            target: HorizontalDirection::North,
+        }
+    }
+
+    pub(super) const fn start_construction_system(&self) -> &StartConstructionSystem {
+        &self.start_construction_system
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{ConstructionScreen, messages::YouLackTheMeansToConstruct};
+use bevy::ecs::{spawn::SpawnIter, system::SystemId};
+use bevy::prelude::{
+    Added, AnyOf, Children, Commands, DespawnOnExit, Entity, In, IntoSystem as _, KeyCode, Local,
+    NextState, Query, RemovedComponents, Res, ResMut, Single, SpawnRelated as _, Text, TextColor,
+    With, World, children, debug,
+};
+use cdda_json_files::{FurnitureInfo, TerrainInfo};
+use gameplay_action_planning::{PlayerInstructions, QueuedInstruction};
+use gameplay_cdda::Infos;
+use gameplay_common::{LastSeen, Shared};
+use gameplay_crafting::{ConstructionSite, ConstructionSituation, shown_constructions};
+use gameplay_item::{BodyContainers, Item, ItemHierarchy};
+use gameplay_location::{HorizontalDirection, LocationCache, Nbor, Pos};
+use gameplay_log::LogMessageWriter;
+use gameplay_player::Player;
+use gameplay_screen_state::GameplayScreenState;
+use hud::{BAD_TEXT_COLOR, ButtonBuilder, WARN_TEXT_COLOR};
+use keyboard::KeyBindings;
+use manual::ManualSection;
+use selection_list::{SelectableItemIn, SelectedItemIn, selection_list_detail_screen};
+use std::time::Instant;
+use strum::VariantArray as _;
+use util::log_if_slow;
+
+#[derive(Debug)]
+pub(super) struct StartConstructionSystem(SystemId<(), ()>);
+
+pub(super) fn create_start_construction_system(world: &mut World) -> StartConstructionSystem {
+    StartConstructionSystem(world.register_system_cached(start_construction))
+}
+
+pub(super) fn spawn_construction_screen(
+    In(start_construction_system): In<StartConstructionSystem>,
+    mut commands: Commands,
+) {
+    let (construction_list, construction_details) =
+        selection_list_detail_screen(&mut commands, GameplayScreenState::Construction);
+
+    commands.insert_resource(ConstructionScreen::new(
+        construction_list,
+        construction_details,
+        start_construction_system,
+    ));
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn create_construction_key_bindings(
+    world: &mut World,
+    fresh_bindings: Local<KeyBindings<GameplayScreenState, (), ()>>,
+) {
+    let start = Instant::now();
+
+    fresh_bindings.spawn(world, GameplayScreenState::Construction, |bindings| {
+        for &direction in HorizontalDirection::VARIANTS {
+            if direction != HorizontalDirection::Here {
+                bindings.add(
+                    Nbor::Horizontal(direction),
+                    (move || direction).pipe(change_target),
+                );
+            }
+        }
+        bindings.add('c', start_construction);
+        bindings.add(KeyCode::Escape, exit_construction);
+        bindings.add('*', exit_construction);
+    });
+
+    world.spawn((
+        ManualSection::new(
+            &[
+                ("target tile", "numpad"),
+                ("construct", "c"),
+                ("close construction", "esc/*"),
+            ],
+            100,
+        ),
+        DespawnOnExit(GameplayScreenState::Construction),
+    ));
+
+    log_if_slow("create_construction_key_bindings", start);
+}
+
+fn change_target(
+    In(target): In<HorizontalDirection>,
+    mut construction_screen: ResMut<ConstructionScreen>,
+) {
+    let start = Instant::now();
+
+    // This triggers a refresh
+    construction_screen.target = target;
+
+    log_if_slow("change_target", start);
+}
+
+fn exit_construction(mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>) {
+    let start = Instant::now();
+
+    next_gameplay_state.set(GameplayScreenState::Base);
+
+    log_if_slow("exit_construction", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn adapt_to_construction_selection(
+    mut commands: Commands,
+    construction_screen: Res<ConstructionScreen>,
+    mut selected_constructions: Query<
+        (&mut TextColor, &ConstructionSituation),
+        Added<SelectedItemIn>,
+    >,
+) {
+    let start = Instant::now();
+
+    for (mut text_color, construction) in &mut selected_constructions {
+        *text_color = construction.color(true);
+
+        show_construction(&mut commands, &construction_screen, construction);
+    }
+
+    log_if_slow("adapt_to_construction_selection", start);
+}
+
+pub(super) fn adapt_to_construction_deselection(
+    mut removed: RemovedComponents<SelectedItemIn>,
+    mut constructions: Query<(&mut TextColor, &ConstructionSituation)>,
+) {
+    let start = Instant::now();
+
+    removed.read().for_each(|deselected_construction| {
+        // The list may have been refreshed
+        if let Ok((mut text_color, construction)) = constructions.get_mut(deselected_construction) {
+            *text_color = construction.color(false);
+        }
+    });
+
+    log_if_slow("adapt_to_construction_deselection", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn refresh_construction_screen(
+    mut commands: Commands,
+    location: Res<LocationCache>,
+    infos: Res<Infos>,
+    construction_screen: Res<ConstructionScreen>,
+    hierarchy: ItemHierarchy,
+    player: Single<(&Pos, &BodyContainers), With<Player>>,
+    items: Query<(Item, &LastSeen)>,
+    infrastructure: Query<(
+        AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
+        &LastSeen,
+    )>,
+    sites: Query<&ConstructionSite>,
+) {
+    let (&player_pos, body_containers) = *player;
+    let target_pos = player_pos.horizontal_nbor(construction_screen.target);
+
+    let mut target_terrain = None;
+    let mut target_furniture = None;
+    let mut target_site = None;
+    for &entity in location.all(target_pos) {
+        if let Ok(((furniture, terrain), _)) = infrastructure.get(entity) {
+            target_furniture = target_furniture.or(furniture);
+            target_terrain = target_terrain.or(terrain);
+        }
+        if let Ok(site) = sites.get(entity) {
+            target_site = Some((entity, site));
+        }
+    }
+
+    let shown_constructions = target_terrain.map_or_else(Vec::new, |target_terrain| {
+        shown_constructions(
+            &location,
+            &infos,
+            &hierarchy,
+            player_pos,
+            body_containers,
+            &items,
+            &infrastructure,
+            target_terrain,
+            target_furniture.map(|furniture| &**furniture),
+            target_site,
+        )
+    });
+
+    let mut construction_entities = Vec::new();
+
+    commands
+        .entity(construction_screen.construction_list)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            parent.spawn((
+                Text(format!(
+                    "Constructions ({}):",
+                    construction_screen.target.succinct()
+                )),
+                WARN_TEXT_COLOR,
+            ));
+
+            for construction in shown_constructions {
+                let construction_entity = parent.spawn(construction.to_text_bundle()).id();
+                construction_entities.push(construction_entity);
+            }
+        })
+        .add_related::<SelectableItemIn>(&construction_entities);
+
+    commands
+        .entity(construction_screen.construction_details)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            parent.spawn((Text::from("Nothing to construct here"), BAD_TEXT_COLOR));
+        });
+}
+
+fn show_construction(
+    commands: &mut Commands,
+    construction_screen: &ConstructionScreen,
+    construction_situation: &ConstructionSituation,
+) {
+    commands
+        .entity(construction_screen.construction_details)
+        .despawn_related::<Children>()
+        .insert(children![
+            ButtonBuilder::new(
+                if construction_situation.site().is_some() {
+                    "Continue"
+                } else {
+                    "Construct"
+                },
+                construction_situation.color(true),
+                construction_screen.start_construction_system().0,
+                (),
+            )
+            .bundle(),
+            (
+                Text::default(),
+                Children::spawn((SpawnIter(
+                    construction_situation.text_sections().into_iter()
+                ),)),
+            ),
+        ]);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn start_construction(
+    mut message_writer: LogMessageWriter,
+    mut player_instructions: ResMut<PlayerInstructions>,
+    mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>,
+    construction_screen: Res<ConstructionScreen>,
+    selected_item_in: Single<Entity, With<SelectedItemIn>>,
+    constructions: Query<&ConstructionSituation>,
+) {
+    let start = Instant::now();
+
+    let selected_construction = *selected_item_in;
+    let construction = constructions
+        .get(selected_construction)
+        .expect("The selected construction should be found");
+    if construction.constructable() {
+        debug!("Construct {construction:?}");
+        player_instructions.push(QueuedInstruction::StartConstruction(
+            construction.clone(),
+            construction_screen.target,
+        ));
+        // Close the construction screen
+        next_gameplay_state.set(GameplayScreenState::Base);
+    } else {
+        message_writer.send(YouLackTheMeansToConstruct {
+            construction: construction.name().clone(),
+        });
+    }
+
+    log_if_slow("start_construction", start);
+}
+
+pub(super) fn remove_construction_resource(mut commands: Commands) {
+    commands.remove_resource::<ConstructionScreen>();
+}
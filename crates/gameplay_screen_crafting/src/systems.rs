@@ -8,7 +8,7 @@ use bevy::prelude::{
 use cdda_json_files::{FurnitureInfo, TerrainInfo};
 use gameplay_action_planning::{PlayerInstructions, QueuedInstruction};
 use gameplay_cdda::Infos;
-use gameplay_cdda_active_sav::ActiveSav;
+use gameplay_character::Skills;
 use gameplay_common::{LastSeen, Shared};
 use gameplay_crafting::{RecipeSituation, shown_recipes};
 use gameplay_item::{BodyContainers, Item, ItemHierarchy};
@@ -113,22 +113,21 @@ pub(super) fn refresh_crafting_screen(
     mut commands: Commands,
     location: Res<LocationCache>,
     infos: Res<Infos>,
-    active_sav: Res<ActiveSav>,
     crafting_screen: Res<CraftingScreen>,
     hierarchy: ItemHierarchy,
-    player: Single<(&Pos, &BodyContainers), With<Player>>,
+    player: Single<(&Pos, &BodyContainers, &Skills), With<Player>>,
     items: Query<(Item, &LastSeen)>,
     infrastructure: Query<(
         AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
         &LastSeen,
     )>,
 ) {
-    let (&player_pos, body_containers) = *player;
+    let (&player_pos, body_containers, skills) = *player;
 
     let shown_recipes = shown_recipes(
         &location,
         &infos,
-        active_sav.sav(),
+        &|skill| skills.level(skill),
         &hierarchy,
         player_pos,
         body_containers,
@@ -15,6 +15,14 @@ pub(super) enum InventoryAction {
 
     Wield,
     Unwield,
+    Wear,
+    TakeOff,
+    Eat,
+    Drink,
+    Reload,
+    Unload,
+    Disassemble,
+    Read,
 }
 
 impl fmt::Display for InventoryAction {
@@ -26,6 +34,14 @@ impl fmt::Display for InventoryAction {
             Self::Move => write!(f, "Move"),
             Self::Wield => write!(f, "Wield"),
             Self::Unwield => write!(f, "Unwield"),
+            Self::Wear => write!(f, "Wear"),
+            Self::TakeOff => write!(f, "Take off"),
+            Self::Eat => write!(f, "Eat"),
+            Self::Drink => write!(f, "Drink"),
+            Self::Reload => write!(f, "Reload"),
+            Self::Unload => write!(f, "Unload"),
+            Self::Disassemble => write!(f, "Disassemble"),
+            Self::Read => write!(f, "Read"),
         }
     }
 }
@@ -39,6 +55,14 @@ impl From<InventoryAction> for Key {
             InventoryAction::Move => 'm',
             InventoryAction::Wield => 'w',
             InventoryAction::Unwield => 'u',
+            InventoryAction::Wear => 'W',
+            InventoryAction::TakeOff => 'T',
+            InventoryAction::Eat => 'E',
+            InventoryAction::Drink => 'q',
+            InventoryAction::Reload => 'R',
+            InventoryAction::Unload => 'U',
+            InventoryAction::Disassemble => 'D',
+            InventoryAction::Read => 'r',
         })
     }
 }
@@ -1,4 +1,5 @@
 mod components;
+mod messages;
 mod plugin;
 mod resource;
 mod row_spawner;
@@ -11,4 +12,4 @@ use self::components::{InventoryAction, InventoryItemRow};
 use self::resource::{ITEM_TEXT_COLOR, InventoryScreen, SELECTED_ITEM_TEXT_COLOR};
 use self::row_spawner::RowSpawner;
 use self::section::InventorySection;
-use self::systems::{InventoryButton, InventorySystem};
+use self::systems::{DisassemblySystem, InventoryButton, InventorySystem, ReadingSystem};
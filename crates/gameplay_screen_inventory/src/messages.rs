@@ -0,0 +1,41 @@
+use gameplay_log::{ProtoLogMessage, Severity};
+use text::{Fragment, Phrase};
+
+#[derive(Debug)]
+pub(super) struct YouCantDisassemble {
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for YouCantDisassemble {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        Self::you("can't disassemble").extend(self.item)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct YouStartReading {
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for YouStartReading {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        Self::you("start reading").extend(self.item)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct YouCantLearnFrom {
+    pub(super) item: Vec<Fragment>,
+}
+
+impl ProtoLogMessage for YouCantLearnFrom {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        Self::you("can't learn anything from").extend(self.item)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::systems::{
     InventoryButton, adapt_to_item_deselection, adapt_to_item_selection,
-    create_inventory_key_bindings, create_inventory_system, refresh_inventory,
+    create_inventory_key_bindings, create_inventory_systems, refresh_inventory,
     remove_inventory_resource, spawn_inventory,
 };
 use bevy::prelude::{
@@ -22,7 +22,7 @@ impl Plugin for InventoryScreenPlugin {
             OnEnter(GameplayScreenState::Inventory),
             (
                 (
-                    create_inventory_system.pipe(spawn_inventory),
+                    create_inventory_systems.pipe(spawn_inventory),
                     refresh_inventory,
                 )
                     .chain(),
@@ -1,4 +1,4 @@
-use crate::{InventorySection, InventorySystem};
+use crate::{DisassemblySystem, InventorySection, InventorySystem, ReadingSystem};
 use bevy::ecs::entity::hash_map::EntityHashMap;
 use bevy::prelude::{BackgroundColor, Button, Children, Entity, Query, Resource, TextColor, With};
 use gameplay_location::HorizontalDirection;
@@ -15,6 +15,8 @@ pub(super) struct InventoryScreen {
     pub(super) section_by_item: EntityHashMap<InventorySection>,
     pub(super) last_time: Timestamp,
     pub(super) inventory_system: InventorySystem,
+    pub(super) disassembly_system: DisassemblySystem,
+    pub(super) reading_system: ReadingSystem,
 }
 
 impl InventoryScreen {
@@ -24,6 +26,8 @@ impl InventoryScreen {
         section_by_item: EntityHashMap<InventorySection>,
         last_time: Timestamp,
         inventory_system: InventorySystem,
+        disassembly_system: DisassemblySystem,
+        reading_system: ReadingSystem,
     ) -> Self {
         Self {
             panel,
@@ -31,6 +35,8 @@ impl InventoryScreen {
             section_by_item,
             last_time,
             inventory_system,
+            disassembly_system,
+            reading_system,
         }
     }
 
@@ -10,16 +10,19 @@ use bevy::prelude::{
     JustifyContent, Node, Overflow, Spawn, SpawnRelated as _, Text, TextColor, Val, debug,
 };
 use cdda_json_files::CommonItemInfo;
-use gameplay_item::{ItemHandler, ItemItem};
+use gameplay_cdda::Infos;
+use gameplay_item::{InPocket, ItemHandler, ItemItem};
 use hud::{ButtonBuilder, HOVERED_BUTTON_COLOR, SMALL_SPACING, SOFT_TEXT_COLOR};
 use text::{Fragment, Phrase};
 use util::Maybe;
 
 struct SectionData<'r> {
+    infos: &'r Infos,
     inventory_system: &'r InventorySystem,
     previous_selected_item: Option<Entity>,
     section: InventorySection,
     drop_section: bool,
+    clothing: InPocket,
 }
 
 impl SectionData<'_> {
@@ -88,6 +91,8 @@ impl SectionData<'_> {
     fn item_action_buttons(
         &self,
         item_entity: Entity,
+        item_info: &CommonItemInfo,
+        worn: bool,
         item_text_color: TextColor,
     ) -> Vec<impl Bundle> {
         let mut actions = vec![InventoryAction::Examine];
@@ -104,6 +109,32 @@ impl SectionData<'_> {
         } else {
             actions.push(InventoryAction::Wield);
         }
+        if worn {
+            actions.push(InventoryAction::TakeOff);
+        } else if item_info.wearable() {
+            actions.push(InventoryAction::Wear);
+        }
+        if let Some(comestible) = item_info.comestible() {
+            actions.push(if comestible.is_drink() {
+                InventoryAction::Drink
+            } else {
+                InventoryAction::Eat
+            });
+        }
+        if item_info.reloadable() {
+            actions.push(InventoryAction::Reload);
+            actions.push(InventoryAction::Unload);
+        }
+        if self.infos.disassembly(&item_info.id).is_some() {
+            actions.push(InventoryAction::Disassemble);
+        }
+        if self
+            .infos
+            .book(&item_info.id)
+            .is_some_and(|book| book.skill.is_some())
+        {
+            actions.push(InventoryAction::Read);
+        }
 
         actions
             .into_iter()
@@ -137,6 +168,7 @@ impl InventoryBuilder<'_, '_> {
         item_entity: Entity,
         item_phrase: &Phrase,
         item_info: &CommonItemInfo,
+        worn: bool,
     ) {
         #[derive(PartialEq)]
         enum Selection {
@@ -188,7 +220,7 @@ impl InventoryBuilder<'_, '_> {
                     SpawnIter(SectionData::item_properties(item_info).into_iter()),
                     SpawnIter(
                         section_data
-                            .item_action_buttons(item_entity, item_text_color)
+                            .item_action_buttons(item_entity, item_info, worn, item_text_color)
                             .into_iter(),
                     ),
                 )),
@@ -215,12 +247,14 @@ where
     'c: 'r,
 {
     pub(super) const fn new(
+        infos: &'r Infos,
         inventory_system: &'r InventorySystem,
         section_by_item: &'r mut EntityHashMap<InventorySection>,
         inventory_panel: &'r mut ChildSpawnerCommands<'c>,
         previous_selected_item: Option<Entity>,
         section: InventorySection,
         drop_section: bool,
+        clothing: InPocket,
         selectable_items: &'r mut Vec<Entity>,
         selected_item: &'r mut Option<Entity>,
     ) -> Self {
@@ -232,10 +266,12 @@ where
                 selected_item,
             },
             section_data: SectionData {
+                infos,
                 inventory_system,
                 previous_selected_item,
                 section,
                 drop_section,
+                clothing,
             },
         }
     }
@@ -248,6 +284,7 @@ impl ItemHandler for RowSpawner<'_, '_> {
             item.entity,
             &Phrase::from_fragments(item_fragments),
             item.common_info,
+            item.in_pocket == Some(&self.section_data.clothing),
         );
     }
 
@@ -1,17 +1,27 @@
+use crate::messages::{YouCantDisassemble, YouCantLearnFrom, YouStartReading};
 use crate::{InventoryAction, InventoryItemRow, InventoryScreen, InventorySection, RowSpawner};
 use bevy::ecs::{entity::hash_map::EntityHashMap, system::SystemId};
 use bevy::platform::collections::HashMap;
 use bevy::prelude::{
-    Added, BackgroundColor, Button, Children, Commands, DespawnOnExit, Entity, In, IntoSystem as _,
-    KeyCode, Local, NextState, Query, RemovedComponents, Res, ResMut, Single, Text, TextColor,
-    TextSpan, With, World, debug, error,
+    Added, AnyOf, BackgroundColor, Button, Children, Commands, DespawnOnExit, Entity, In,
+    IntoSystem as _, KeyCode, Local, NextState, Query, RemovedComponents, Res, ResMut, Single,
+    Text, TextColor, TextSpan, With, World, debug, error,
 };
+use cdda_json_files::{FurnitureInfo, TerrainInfo};
 use gameplay_action_planning::{PlayerInstructions, QueuedInstruction};
-use gameplay_character::{ExamineItem, MoveItem, Pickup, Unwield, Wield};
-use gameplay_item::{BodyContainers, ItemHierarchy, ItemItem};
-use gameplay_location::{HorizontalDirection, Nbor, Pos};
-use gameplay_player::Player;
+use gameplay_cdda::Infos;
+use gameplay_character::{
+    Drink, Eat, ExamineItem, MoveItem, Pickup, Reload, Skills, TakeOff, Unload, Unwield, Wear,
+    Wield,
+};
+use gameplay_common::{LastSeen, Shared};
+use gameplay_crafting::{DisassemblyDetails, disassembly_situation};
+use gameplay_item::{BodyContainers, Item, ItemHierarchy, ItemItem};
+use gameplay_location::{HorizontalDirection, LocationCache, Nbor, Pos};
+use gameplay_log::LogMessageWriter;
+use gameplay_player::{Player, PlayerActionState};
 use gameplay_screen_state::GameplayScreenState;
+use gameplay_time::Clock;
 use gameplay_world::Envir;
 use hud::{HARD_TEXT_COLOR, SOFT_TEXT_COLOR, scroll_screen};
 use keyboard::KeyBindings;
@@ -32,11 +42,30 @@ pub(super) struct InventoryButton {
 #[derive(Debug)]
 pub(super) struct InventorySystem(pub(super) SystemId<In<InventoryButton>, ()>);
 
-pub(super) fn create_inventory_system(world: &mut World) -> InventorySystem {
-    InventorySystem(world.register_system_cached(handle_inventory_action))
+#[derive(Debug)]
+pub(super) struct DisassemblySystem(SystemId<In<Entity>, ()>);
+
+#[derive(Debug)]
+pub(super) struct ReadingSystem(SystemId<In<Entity>, ()>);
+
+pub(super) fn create_inventory_systems(
+    world: &mut World,
+) -> (InventorySystem, DisassemblySystem, ReadingSystem) {
+    (
+        InventorySystem(world.register_system_cached(handle_inventory_action)),
+        DisassemblySystem(world.register_system_cached(disassemble_item)),
+        ReadingSystem(world.register_system_cached(read_item)),
+    )
 }
 
-pub(super) fn spawn_inventory(In(inventory_system): In<InventorySystem>, mut commands: Commands) {
+pub(super) fn spawn_inventory(
+    In((inventory_system, disassembly_system, reading_system)): In<(
+        InventorySystem,
+        DisassemblySystem,
+        ReadingSystem,
+    )>,
+    mut commands: Commands,
+) {
     let start = Instant::now();
 
     let panel = scroll_screen(&mut commands, GameplayScreenState::Inventory);
@@ -47,6 +76,8 @@ pub(super) fn spawn_inventory(In(inventory_system): In<InventorySystem>, mut com
         EntityHashMap::default(),
         Timestamp::ZERO,
         inventory_system,
+        disassembly_system,
+        reading_system,
     ));
 
     log_if_slow("spawn_inventory", start);
@@ -85,6 +116,12 @@ pub(super) fn create_inventory_key_bindings(
                 ("take item", "t"),
                 ("wield item", "w"),
                 ("unwield item", "u"),
+                ("wear item", "W"),
+                ("take off item", "T"),
+                ("reload item", "R"),
+                ("unload item", "U"),
+                ("disassemble item", "D"),
+                ("read item", "r"),
                 ("close inventory", "esc/i"),
             ],
             101,
@@ -154,6 +191,7 @@ fn exit_inventory(mut next_gameplay_state: ResMut<NextState<GameplayScreenState>
 pub(super) fn refresh_inventory(
     mut commands: Commands,
     envir: Envir,
+    infos: Res<Infos>,
     item_hierarchy: ItemHierarchy,
     mut inventory: ResMut<InventoryScreen>,
     player: Single<(&Pos, &BodyContainers), With<Player>>,
@@ -192,12 +230,14 @@ pub(super) fn refresh_inventory(
                     });
 
                 let mut row_spawner = RowSpawner::new(
+                    &infos,
                     &inventory.inventory_system,
                     &mut inventory.section_by_item,
                     parent,
                     previous_selected_item,
                     section,
                     drop_section,
+                    body_containers.clothing,
                     &mut item_entities,
                     &mut selected,
                 );
@@ -245,6 +285,10 @@ fn items_by_section<'i>(
     for items in items_by_section.values_mut() {
         items.sort_by_key(|item| Phrase::from_fragments(item.fragments().collect()).as_string());
     }
+    if let Some(worn) = items_by_section.get_mut(&InventorySection::Clothing) {
+        // From the inside out, with a stable sort to keep the names sorted within a layer
+        worn.sort_by_key(|item| item.common_info.clothing_layer());
+    }
 
     items_by_section
 }
@@ -296,6 +340,32 @@ fn handle_selected_item(
         InventoryAction::Wield => QueuedInstruction::Wield(Wield {
             item_entity: selected_item,
         }),
+        InventoryAction::Wear => QueuedInstruction::Wear(Wear {
+            item_entity: selected_item,
+        }),
+        InventoryAction::TakeOff => QueuedInstruction::TakeOff(TakeOff {
+            item_entity: selected_item,
+        }),
+        InventoryAction::Eat => QueuedInstruction::Eat(Eat {
+            item_entity: selected_item,
+        }),
+        InventoryAction::Drink => QueuedInstruction::Drink(Drink {
+            item_entity: selected_item,
+        }),
+        InventoryAction::Reload => QueuedInstruction::Reload(Reload {
+            item_entity: selected_item,
+        }),
+        InventoryAction::Unload => QueuedInstruction::Unload(Unload {
+            item_entity: selected_item,
+        }),
+        InventoryAction::Disassemble => {
+            commands.run_system_with(inventory.disassembly_system.0, selected_item);
+            return;
+        }
+        InventoryAction::Read => {
+            commands.run_system_with(inventory.reading_system.0, selected_item);
+            return;
+        }
     });
 
     if action != InventoryAction::Examine {
@@ -310,6 +380,7 @@ fn handle_selected_item(
 #[expect(clippy::needless_pass_by_value)]
 pub(super) fn handle_inventory_action(
     In(inventory_button): In<InventoryButton>,
+    mut commands: Commands,
     mut player_instructions: ResMut<PlayerInstructions>,
     inventory: Res<InventoryScreen>,
 ) {
@@ -326,12 +397,128 @@ pub(super) fn handle_inventory_action(
         }),
         InventoryAction::Wield => QueuedInstruction::Wield(Wield { item_entity }),
         InventoryAction::Unwield => QueuedInstruction::Unwield(Unwield { item_entity }),
+        InventoryAction::Wear => QueuedInstruction::Wear(Wear { item_entity }),
+        InventoryAction::TakeOff => QueuedInstruction::TakeOff(TakeOff { item_entity }),
+        InventoryAction::Eat => QueuedInstruction::Eat(Eat { item_entity }),
+        InventoryAction::Drink => QueuedInstruction::Drink(Drink { item_entity }),
+        InventoryAction::Reload => QueuedInstruction::Reload(Reload { item_entity }),
+        InventoryAction::Unload => QueuedInstruction::Unload(Unload { item_entity }),
+        InventoryAction::Disassemble => {
+            commands.run_system_with(inventory.disassembly_system.0, item_entity);
+            return;
+        }
+        InventoryAction::Read => {
+            commands.run_system_with(inventory.reading_system.0, item_entity);
+            return;
+        }
     };
     player_instructions.push(instruction);
 
     log_if_slow("handle_inventory_action", start);
 }
 
+/// Shows the required tools and the returned components, and starts when possible
+#[expect(clippy::needless_pass_by_value)]
+fn disassemble_item(
+    In(item_entity): In<Entity>,
+    mut message_writer: LogMessageWriter,
+    mut player_instructions: ResMut<PlayerInstructions>,
+    mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>,
+    location: Res<LocationCache>,
+    infos: Res<Infos>,
+    hierarchy: ItemHierarchy,
+    player: Single<(&Pos, &BodyContainers), With<Player>>,
+    items: Query<(Item, &LastSeen)>,
+    infrastructure: Query<(
+        AnyOf<(&Shared<FurnitureInfo>, &Shared<TerrainInfo>)>,
+        &LastSeen,
+    )>,
+) {
+    let start = Instant::now();
+
+    let Ok((item, _)) = items.get(item_entity) else {
+        error!("Item {item_entity:?} to disassemble not found");
+        return;
+    };
+    let (&player_pos, body_containers) = *player;
+
+    if let Some(disassembly_situation) = disassembly_situation(
+        &location,
+        &infos,
+        &hierarchy,
+        player_pos,
+        body_containers,
+        &items,
+        &infrastructure,
+        &item,
+    ) {
+        message_writer.send(DisassemblyDetails {
+            disassembly_situation: &disassembly_situation,
+        });
+        if disassembly_situation.disassemblable() {
+            debug!("Disassemble {disassembly_situation:?}");
+            player_instructions.push(QueuedInstruction::StartDisassembly(disassembly_situation));
+            // Close the inventory, to show the progress
+            next_gameplay_state.set(GameplayScreenState::Base);
+        }
+    } else {
+        message_writer.send(YouCantDisassemble {
+            item: item.fragments().collect(),
+        });
+    }
+
+    log_if_slow("disassemble_item", start);
+}
+
+/// Starts training the skill of the book, when the player is within its level range
+#[expect(clippy::needless_pass_by_value)]
+fn read_item(
+    In(item_entity): In<Entity>,
+    mut message_writer: LogMessageWriter,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>,
+    infos: Res<Infos>,
+    clock: Clock,
+    skills: Single<&Skills, With<Player>>,
+    items: Query<(Item, &LastSeen)>,
+) {
+    let start = Instant::now();
+
+    let Ok((item, _)) = items.get(item_entity) else {
+        error!("Item {item_entity:?} to read not found");
+        return;
+    };
+
+    let Some((book, skill)) = infos
+        .book(&item.common_info.id)
+        .and_then(|book| book.skill.clone().map(|skill| (book, skill)))
+    else {
+        error!("Item {item_entity:?} to read is not a skill book");
+        return;
+    };
+
+    let level = skills.level(&skill);
+    if level < book.required_level || book.max_level.is_some_and(|max| max <= level) {
+        message_writer.send(YouCantLearnFrom {
+            item: item.fragments().collect(),
+        });
+        return;
+    }
+
+    message_writer.send(YouStartReading {
+        item: item.fragments().collect(),
+    });
+    next_player_action_state.set(PlayerActionState::Training {
+        skill,
+        cap: book.max_level,
+        until: clock.time() + book.time,
+    });
+    // Close the inventory, to show the progress
+    next_gameplay_state.set(GameplayScreenState::Base);
+
+    log_if_slow("read_item", start);
+}
+
 pub(super) fn remove_inventory_resource(mut commands: Commands) {
     commands.remove_resource::<InventoryScreen>();
 }
@@ -1,9 +1,10 @@
 use application_state::ApplicationState;
 use bevy::prelude::{
     AlignItems, Commands, DespawnOnExit, FlexDirection, In, JustifyContent, KeyCode, Local,
-    Messages, NextState, Node, ResMut, Val, World, children,
+    Messages, NextState, Node, ResMut, Val, World, children, error,
 };
 use bevy::{app::AppExit, ecs::system::SystemId};
+use gameplay_save::{save_game, save_game_as};
 use gameplay_screen_state::GameplayScreenState;
 use hud::{BAD_TEXT_COLOR, ButtonBuilder, Fonts, GOOD_TEXT_COLOR, HARD_TEXT_COLOR, MEDIUM_SPACING};
 use keyboard::KeyBindings;
@@ -14,6 +15,8 @@ use util::log_if_slow;
 #[derive(Debug)]
 pub(super) struct MenuButtonActions {
     return_: SystemId<(), ()>,
+    save_and_quit: SystemId<(), ()>,
+    save_as: SystemId<(), ()>,
     main_menu: SystemId<(), ()>,
     quit: SystemId<(), ()>,
 }
@@ -21,6 +24,8 @@ pub(super) struct MenuButtonActions {
 pub(super) fn create_menu_button_actions(world: &mut World) -> MenuButtonActions {
     MenuButtonActions {
         return_: world.register_system_cached(return_),
+        save_and_quit: world.register_system_cached(save_and_quit),
+        save_as: world.register_system_cached(save_as),
         main_menu: world.register_system_cached(main_menu),
         quit: world.register_system_cached(quit),
     }
@@ -43,6 +48,19 @@ pub(super) fn spawn_menu(In(button_actions): In<MenuButtonActions>, mut commands
                 .large()
                 .with_font(Fonts::large())
                 .bundle(),
+            ButtonBuilder::new(
+                "Save and quit",
+                GOOD_TEXT_COLOR,
+                button_actions.save_and_quit,
+                ()
+            )
+            .large()
+            .with_font(Fonts::large())
+            .bundle(),
+            ButtonBuilder::new("Save as…", GOOD_TEXT_COLOR, button_actions.save_as, ())
+                .large()
+                .with_font(Fonts::large())
+                .bundle(),
             ButtonBuilder::new("Main Menu", HARD_TEXT_COLOR, button_actions.main_menu, ())
                 .large()
                 .with_font(Fonts::large())
@@ -82,6 +100,25 @@ fn return_(mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>) {
     next_gameplay_state.set(GameplayScreenState::Base);
 }
 
+fn save_and_quit(world: &mut World) {
+    if let Err(error) = world.run_system_cached(save_game) {
+        error!("Could not save: {error:?}");
+    }
+    if let Err(error) = world.run_system_cached(main_menu) {
+        error!("Could not go to the main menu: {error:?}");
+    }
+}
+
+/// Continue playing in the copy
+fn save_as(world: &mut World) {
+    if let Err(error) = world.run_system_cached(save_game_as) {
+        error!("Could not save as: {error:?}");
+    }
+    if let Err(error) = world.run_system_cached(return_) {
+        error!("Could not return to the game: {error:?}");
+    }
+}
+
 pub(super) fn main_menu(mut next_application_state: ResMut<NextState<ApplicationState>>) {
     next_application_state.set(ApplicationState::MainMenu);
 }
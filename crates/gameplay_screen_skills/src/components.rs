@@ -0,0 +1,35 @@
+use bevy::prelude::{Bundle, Component, Text, TextColor};
+use hud::{GOOD_TEXT_COLOR, HARD_TEXT_COLOR, SOFT_TEXT_COLOR};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Component)]
+#[component(immutable)]
+pub(super) struct ShownSkill {
+    pub(super) id: Arc<str>,
+    pub(super) name: Arc<str>,
+    pub(super) description: Arc<str>,
+    pub(super) level: u8,
+
+    /// From 0 to 100
+    pub(super) progress: u8,
+}
+
+impl ShownSkill {
+    pub(super) const fn color(&self, selected: bool) -> TextColor {
+        if selected {
+            GOOD_TEXT_COLOR
+        } else if 0 < self.level || 0 < self.progress {
+            HARD_TEXT_COLOR
+        } else {
+            SOFT_TEXT_COLOR
+        }
+    }
+
+    pub(super) fn to_text_bundle(&self) -> impl Bundle {
+        (
+            Text(format!("{} {} ({}%)", self.name, self.level, self.progress)),
+            self.color(false),
+            self.clone(),
+        )
+    }
+}
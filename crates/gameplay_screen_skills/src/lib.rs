@@ -0,0 +1,10 @@
+mod components;
+mod messages;
+mod plugin;
+mod resource;
+mod systems;
+
+pub use self::plugin::SkillsScreenPlugin;
+
+use self::components::ShownSkill;
+use self::resource::SkillsScreen;
@@ -0,0 +1,34 @@
+use gameplay_log::{ProtoLogMessage, Severity};
+use std::sync::Arc;
+use text::Phrase;
+
+#[derive(Debug)]
+pub(super) struct YouStartPracticing {
+    pub(super) practice: Arc<str>,
+}
+
+impl ProtoLogMessage for YouStartPracticing {
+    const SEVERITY: Severity = Severity::Neutral;
+
+    fn phrase(self) -> Phrase {
+        Self::you("start practicing").hard(&*self.practice)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct NoPracticeAvailable {
+    pub(super) skill: Arc<str>,
+}
+
+impl ProtoLogMessage for NoPracticeAvailable {
+    const SEVERITY: Severity = Severity::ImpossibleAction;
+
+    fn phrase(self) -> Phrase {
+        Self::you("know")
+            .soft("no")
+            .hard("practice")
+            .soft("for")
+            .hard(&*self.skill)
+            .soft("at your level")
+    }
+}
@@ -0,0 +1,45 @@
+use crate::SkillsScreen;
+use crate::systems::{
+    adapt_to_skill_deselection, adapt_to_skill_selection, create_skills_key_bindings,
+    create_start_practice_system, refresh_skills_screen, remove_skills_resource,
+    spawn_skills_screen,
+};
+use bevy::prelude::{
+    App, IntoScheduleConfigs as _, IntoSystem as _, OnEnter, OnExit, Plugin, SystemCondition as _,
+    Update, in_state, on_message, resource_exists_and_changed,
+};
+use gameplay_behavior::RefreshAfterBehavior;
+use gameplay_screen_state::GameplayScreenState;
+use selection_list::selection_list_plugin;
+
+pub struct SkillsScreenPlugin;
+
+impl Plugin for SkillsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        selection_list_plugin::<_, ()>(app, GameplayScreenState::Skills, "select skill");
+
+        app.add_systems(
+            OnEnter(GameplayScreenState::Skills),
+            (
+                // The screen is refreshed after the resource is added
+                create_start_practice_system.pipe(spawn_skills_screen),
+                create_skills_key_bindings,
+            ),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                adapt_to_skill_deselection,
+                adapt_to_skill_selection,
+                refresh_skills_screen.run_if(
+                    on_message::<RefreshAfterBehavior>
+                        .or_else(resource_exists_and_changed::<SkillsScreen>),
+                ),
+            )
+                .run_if(in_state(GameplayScreenState::Skills)),
+        );
+
+        app.add_systems(OnExit(GameplayScreenState::Skills), remove_skills_resource);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::systems::StartPracticeSystem;
+use bevy::prelude::{Entity, Resource};
+
+#[derive(Resource)]
+pub(super) struct SkillsScreen {
+    pub(super) skill_list: Entity,
+    pub(super) skill_details: Entity,
+    start_practice_system: StartPracticeSystem,
+}
+
+impl SkillsScreen {
+    pub(crate) const fn new(
+        skill_list: Entity,
+        skill_details: Entity,
+        start_practice_system: StartPracticeSystem,
+    ) -> Self {
+        Self {
+            skill_list,
+            skill_details,
+            start_practice_system,
+        }
+    }
+
+    pub(super) const fn start_practice_system(&self) -> &StartPracticeSystem {
+        &self.start_practice_system
+    }
+}
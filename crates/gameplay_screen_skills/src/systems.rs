@@ -0,0 +1,242 @@
+use crate::messages::{NoPracticeAvailable, YouStartPracticing};
+use crate::{ShownSkill, SkillsScreen};
+use bevy::ecs::system::SystemId;
+use bevy::prelude::{
+    Added, Children, Commands, DespawnOnExit, Entity, In, KeyCode, Local, NextState, Query,
+    RemovedComponents, Res, ResMut, Single, Text, TextColor, With, World, children, debug,
+};
+use cdda_json_files::Practice;
+use gameplay_cdda::Infos;
+use gameplay_character::Skills;
+use gameplay_log::LogMessageWriter;
+use gameplay_player::{Player, PlayerActionState};
+use gameplay_screen_state::GameplayScreenState;
+use gameplay_time::Clock;
+use hud::{ButtonBuilder, SOFT_TEXT_COLOR, WARN_TEXT_COLOR};
+use keyboard::KeyBindings;
+use manual::ManualSection;
+use selection_list::{SelectableItemIn, SelectedItemIn, selection_list_detail_screen};
+use std::sync::Arc;
+use std::time::Instant;
+use util::log_if_slow;
+
+#[derive(Debug)]
+pub(super) struct StartPracticeSystem(SystemId<(), ()>);
+
+pub(super) fn create_start_practice_system(world: &mut World) -> StartPracticeSystem {
+    StartPracticeSystem(world.register_system_cached(start_practice))
+}
+
+pub(super) fn spawn_skills_screen(
+    In(start_practice_system): In<StartPracticeSystem>,
+    mut commands: Commands,
+) {
+    let (skill_list, skill_details) =
+        selection_list_detail_screen(&mut commands, GameplayScreenState::Skills);
+
+    commands.insert_resource(SkillsScreen::new(
+        skill_list,
+        skill_details,
+        start_practice_system,
+    ));
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn create_skills_key_bindings(
+    world: &mut World,
+    fresh_bindings: Local<KeyBindings<GameplayScreenState, (), ()>>,
+) {
+    let start = Instant::now();
+
+    fresh_bindings.spawn(world, GameplayScreenState::Skills, |bindings| {
+        bindings.add('p', start_practice);
+        bindings.add(KeyCode::Escape, exit_skills);
+        bindings.add('@', exit_skills);
+    });
+
+    world.spawn((
+        ManualSection::new(&[("practice", "p"), ("close skills", "esc/@")], 100),
+        DespawnOnExit(GameplayScreenState::Skills),
+    ));
+
+    log_if_slow("create_skills_key_bindings", start);
+}
+
+fn exit_skills(mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>) {
+    let start = Instant::now();
+
+    next_gameplay_state.set(GameplayScreenState::Base);
+
+    log_if_slow("exit_skills", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn adapt_to_skill_selection(
+    mut commands: Commands,
+    skills_screen: Res<SkillsScreen>,
+    mut selected_skills: Query<(&mut TextColor, &ShownSkill), Added<SelectedItemIn>>,
+) {
+    let start = Instant::now();
+
+    for (mut text_color, skill) in &mut selected_skills {
+        *text_color = skill.color(true);
+
+        show_skill(&mut commands, &skills_screen, skill);
+    }
+
+    log_if_slow("adapt_to_skill_selection", start);
+}
+
+pub(super) fn adapt_to_skill_deselection(
+    mut removed: RemovedComponents<SelectedItemIn>,
+    mut skills: Query<(&mut TextColor, &ShownSkill)>,
+) {
+    let start = Instant::now();
+
+    removed.read().for_each(|deselected_skill| {
+        // The list may have been refreshed
+        if let Ok((mut text_color, skill)) = skills.get_mut(deselected_skill) {
+            *text_color = skill.color(false);
+        }
+    });
+
+    log_if_slow("adapt_to_skill_deselection", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn refresh_skills_screen(
+    mut commands: Commands,
+    infos: Res<Infos>,
+    skills_screen: Res<SkillsScreen>,
+    skills: Single<&Skills, With<Player>>,
+) {
+    let start = Instant::now();
+
+    let mut skill_infos = infos
+        .skills
+        .values()
+        .filter(|skill_info| !skill_info.obsolete)
+        .collect::<Vec<_>>();
+    skill_infos.sort_by_key(|skill_info| (skill_info.sort_rank, skill_info.name.single.clone()));
+
+    let mut skill_entities = Vec::new();
+
+    commands
+        .entity(skills_screen.skill_list)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            parent.spawn((
+                Text(format!("Skills (focus {}):", skills.focus())),
+                WARN_TEXT_COLOR,
+            ));
+
+            for skill_info in skill_infos {
+                let id = skill_info.id.fallback_name();
+                let shown_skill = ShownSkill {
+                    level: skills.level(&id),
+                    progress: skills.progress(&id),
+                    id,
+                    name: skill_info.name.single.clone(),
+                    description: skill_info.description.clone(),
+                };
+                let skill_entity = parent.spawn(shown_skill.to_text_bundle()).id();
+                skill_entities.push(skill_entity);
+            }
+        })
+        .add_related::<SelectableItemIn>(&skill_entities);
+
+    commands
+        .entity(skills_screen.skill_details)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            parent.spawn((Text::from("No skill selected"), SOFT_TEXT_COLOR));
+        });
+
+    log_if_slow("refresh_skills_screen", start);
+}
+
+fn show_skill(commands: &mut Commands, skills_screen: &SkillsScreen, skill: &ShownSkill) {
+    commands
+        .entity(skills_screen.skill_details)
+        .despawn_related::<Children>()
+        .insert(children![
+            ButtonBuilder::new(
+                "Practice",
+                skill.color(true),
+                skills_screen.start_practice_system().0,
+                (),
+            )
+            .bundle(),
+            (
+                Text(format!(
+                    "{}\nLevel {} ({}%)\n\n{}",
+                    skill.name, skill.level, skill.progress, skill.description
+                )),
+                SOFT_TEXT_COLOR,
+            ),
+        ]);
+}
+
+/// The most advanced practice that still teaches something at the current skill level
+fn suitable_practice<'a>(infos: &'a Infos, skills: &Skills, skill: &str) -> Option<&'a Practice> {
+    let level = skills.level(skill);
+    infos
+        .practices
+        .values()
+        .filter(|practice| {
+            let data = &practice.practice_data;
+            &*practice.skill_used == skill
+                && data.min_difficulty <= level
+                && level < data.skill_limit.unwrap_or(data.max_difficulty)
+                && practice
+                    .skills_required
+                    .iter()
+                    .all(|(required, required_level)| *required_level <= skills.level(required))
+        })
+        .max_by_key(|practice| practice.practice_data.min_difficulty)
+        .map(|practice| &**practice)
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn start_practice(
+    mut message_writer: LogMessageWriter,
+    mut next_player_action_state: ResMut<NextState<PlayerActionState>>,
+    mut next_gameplay_state: ResMut<NextState<GameplayScreenState>>,
+    infos: Res<Infos>,
+    clock: Clock,
+    player_skills: Single<&Skills, With<Player>>,
+    selected_item_in: Single<Entity, With<SelectedItemIn>>,
+    shown_skills: Query<&ShownSkill>,
+) {
+    let start = Instant::now();
+
+    let skill = shown_skills
+        .get(*selected_item_in)
+        .expect("The selected skill should be found");
+
+    // TODO Require the tools and components of the practice
+    if let Some(practice) = suitable_practice(&infos, &player_skills, &skill.id) {
+        debug!("Practice {practice:?}");
+        message_writer.send(YouStartPracticing {
+            practice: practice.name.clone(),
+        });
+        let data = &practice.practice_data;
+        next_player_action_state.set(PlayerActionState::Training {
+            skill: Arc::clone(&skill.id),
+            cap: Some(data.skill_limit.unwrap_or(data.max_difficulty)),
+            until: clock.time() + practice.time,
+        });
+        // Close the skills screen, to show the progress
+        next_gameplay_state.set(GameplayScreenState::Base);
+    } else {
+        message_writer.send(NoPracticeAvailable {
+            skill: skill.name.clone(),
+        });
+    }
+
+    log_if_slow("start_practice", start);
+}
+
+pub(super) fn remove_skills_resource(mut commands: Commands) {
+    commands.remove_resource::<SkillsScreen>();
+}
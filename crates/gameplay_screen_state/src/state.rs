@@ -15,8 +15,12 @@ pub enum GameplayScreenState {
 
     Crafting,
 
+    Construction,
+
     Quality,
 
+    Skills,
+
     Tool,
 
     Waiting,
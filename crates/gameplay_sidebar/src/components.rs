@@ -9,6 +9,10 @@ pub(super) struct FpsText;
 #[component(immutable)]
 pub(super) struct TimeText;
 
+#[derive(Component)]
+#[component(immutable)]
+pub(super) struct WeatherText;
+
 #[derive(Component)]
 #[component(immutable)]
 pub(super) struct HealthText;
@@ -29,6 +33,18 @@ pub(super) struct WalkingModeTextSpan;
 #[component(immutable)]
 pub(super) struct SpeedTextSpan;
 
+#[derive(Component)]
+#[component(immutable)]
+pub(super) struct NutritionText;
+
+#[derive(Component)]
+#[component(immutable)]
+pub(super) struct EncumbranceText;
+
+#[derive(Component)]
+#[component(immutable)]
+pub(super) struct EffectsText;
+
 #[derive(Component)]
 #[component(immutable)]
 pub(super) struct PlayerActionStateText;
@@ -7,8 +7,9 @@ mod systems;
 pub use self::plugin::SidebarPlugin;
 
 use self::components::{
-    BreathText, DetailsText, EnemiesText, FpsText, HealthText, LastLogMessage, LastLogMessageCount,
-    LogDisplay, PlayerActionStateText, SpeedTextSpan, StaminaText, TimeText, TransientLogMessage,
-    WalkingModeTextSpan, WieldedText,
+    BreathText, DetailsText, EffectsText, EncumbranceText, EnemiesText, FpsText, HealthText,
+    LastLogMessage, LastLogMessageCount, LogDisplay, NutritionText, PlayerActionStateText,
+    SpeedTextSpan, StaminaText, TimeText, TransientLogMessage, WalkingModeTextSpan, WeatherText,
+    WieldedText,
 };
 use self::systems::{spawn_sidebar, update_sidebar_systems, update_status_fps};
@@ -1,7 +1,8 @@
 use crate::{
-    BreathText, DetailsText, EnemiesText, FpsText, HealthText, LastLogMessage, LastLogMessageCount,
-    LogDisplay, PlayerActionStateText, SpeedTextSpan, StaminaText, TimeText, TransientLogMessage,
-    WalkingModeTextSpan, WieldedText,
+    BreathText, DetailsText, EffectsText, EncumbranceText, EnemiesText, FpsText, HealthText,
+    LastLogMessage, LastLogMessageCount, LogDisplay, NutritionText, PlayerActionStateText,
+    SpeedTextSpan, StaminaText, TimeText, TransientLogMessage, WalkingModeTextSpan, WeatherText,
+    WieldedText,
 };
 use application_state::ApplicationState;
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
@@ -15,10 +16,13 @@ use bevy::prelude::{
     UiRect, Val, Vec2, Visibility, With, Without, children, on_message, resource_exists,
     resource_exists_and_changed,
 };
-use cdda_json_files::{CharacterInfo, MoveCost};
+use cdda_json_files::{CharacterInfo, EffectRating, MoveCost, Precipitation};
 use gameplay_action_planning::FactionPlanner as _;
 use gameplay_behavior::RefreshAfterBehavior;
-use gameplay_character::{Actor, BaseSpeed, Breath, Faction, Health, Stamina, WalkingMode};
+use gameplay_character::{
+    Actor, BaseSpeed, Breath, Effects, Encumbrance, Faction, Health, Nutrition, Stamina,
+    WalkingMode,
+};
 use gameplay_common::{LastSeen, Shared};
 use gameplay_focus::FocusState;
 use gameplay_item::{Amount, Item, ItemHandler, ItemHierarchy, ItemItem, WieldedItems};
@@ -29,12 +33,13 @@ use gameplay_object::{ObjectName, StandardIntegrity};
 use gameplay_perception::{CurrentlyVisibleBuilder, Explored, RelativeSegments, SeenFrom};
 use gameplay_player::{Player, PlayerActionState};
 use gameplay_relations::TileIn;
-use gameplay_terrain::{Accessible, OpaqueFloor};
+use gameplay_terrain::{Accessible, Concealed, OpaqueFloor};
 use gameplay_time::{Clock, Timeouts};
+use gameplay_weather::{Weather, Wetness};
 use gameplay_world::ZoneLevelIds;
 use hud::{
-    BAD_TEXT_COLOR, DebugText, HARD_TEXT_COLOR, PANEL_COLOR, SMALL_SPACING, SOFT_TEXT_COLOR,
-    WARN_TEXT_COLOR, scroll_panel, text_color_expect_half,
+    BAD_TEXT_COLOR, DebugText, GOOD_TEXT_COLOR, HARD_TEXT_COLOR, PANEL_COLOR, SMALL_SPACING,
+    SOFT_TEXT_COLOR, WARN_TEXT_COLOR, scroll_panel, text_color_expect_half,
 };
 use std::{iter::once, time::Instant};
 use text::{Fragment, Phrase};
@@ -102,6 +107,7 @@ fn status_display(commands: &mut Commands) -> Spawn<impl Bundle> {
             children![
                 (Text::default(), SOFT_TEXT_COLOR, FpsText),
                 (Text::default(), SOFT_TEXT_COLOR, TimeText),
+                (Text::default(), SOFT_TEXT_COLOR, WeatherText),
                 (
                     Text::default(),
                     SOFT_TEXT_COLOR,
@@ -125,6 +131,9 @@ fn status_display(commands: &mut Commands) -> Spawn<impl Bundle> {
                         (TextSpan::new(" km/h)"), SOFT_TEXT_COLOR)
                     ]
                 ),
+                (Text::default(), SOFT_TEXT_COLOR, NutritionText),
+                (Text::default(), WARN_TEXT_COLOR, EncumbranceText),
+                (Text::default(), SOFT_TEXT_COLOR, EffectsText),
                 (Text::default(), SOFT_TEXT_COLOR, PlayerActionStateText),
                 (Text::new("Weapon: "), SOFT_TEXT_COLOR, WieldedText),
                 (Text::default(), SOFT_TEXT_COLOR, EnemiesText),
@@ -191,12 +200,16 @@ pub(super) fn update_sidebar_systems() -> ScheduleConfigs<ScheduleSystem> {
         // sidebar components, in order:
         // (fps is handled elsewhere)
         update_status_time.run_if(resource_exists_and_changed::<Timeouts>),
+        update_status_weather.run_if(resource_exists_and_changed::<Timeouts>),
         update_status_health.run_if(resource_exists_and_changed::<Timeouts>),
         update_status_stamina.run_if(resource_exists_and_changed::<Timeouts>),
         update_status_speed.run_if(
             on_message::<RefreshAfterBehavior>
                 .or_else(resource_exists_and_changed::<State<PlayerActionState>>),
         ),
+        update_status_nutrition,
+        update_status_encumbrance,
+        update_status_effects,
         update_status_player_action_state
             .run_if(resource_exists_and_changed::<State<PlayerActionState>>),
         update_status_player_wielded.run_if(resource_exists_and_changed::<Timeouts>),
@@ -466,11 +479,46 @@ fn update_status_time(clock: Clock, mut text: Single<&mut Text, With<TimeText>>)
 
     let now = clock.time();
     let sunlight = 100.0 * clock.sunlight_percentage();
-    text.0 = format!("{now} ({sunlight:.0}% sunlight)\n\n");
+    text.0 = format!("{now} ({sunlight:.0}% sunlight)\n");
 
     log_if_slow("update_status_time", start);
 }
 
+fn update_status_weather(
+    weather: Option<Res<Weather>>,
+    player_wetness: Option<Single<&Wetness, With<Player>>>,
+    text: Single<(&mut Text, &mut TextColor), With<WeatherText>>,
+) {
+    let start = Instant::now();
+
+    let (mut text, mut color) = text.into_inner();
+    if let Some(weather) = weather {
+        let info = weather.info();
+        let reading = weather.reading();
+        let wetness = player_wetness
+            .map(|wetness| wetness.percentage())
+            .filter(|percentage| 0 < *percentage)
+            .map(|percentage| format!(", {percentage}% wet"))
+            .unwrap_or_default();
+        text.0 = format!(
+            "{}, {:.0} °C, wind {:.0} km/h{wetness}\n\n",
+            info.name.single, reading.temperature, reading.windpower
+        );
+        *color = if info.dangerous || info.acidic {
+            BAD_TEXT_COLOR
+        } else if info.precip != Precipitation::None {
+            WARN_TEXT_COLOR
+        } else {
+            SOFT_TEXT_COLOR
+        };
+    } else {
+        text.0 = String::from("\n");
+        *color = SOFT_TEXT_COLOR;
+    }
+
+    log_if_slow("update_status_weather", start);
+}
+
 fn update_status_health(
     health: Option<Single<&Health, (With<Player>, Changed<Health>)>>,
     text: Single<(&mut Text, &mut TextColor), With<HealthText>>,
@@ -517,7 +565,14 @@ fn update_status_speed(
             Actor,
             (
                 With<Player>,
-                Or<(Changed<BaseSpeed>, Changed<Stamina>, Changed<WalkingMode>)>,
+                Or<(
+                    Changed<BaseSpeed>,
+                    Changed<Stamina>,
+                    Changed<WalkingMode>,
+                    Changed<Effects>,
+                    Changed<Nutrition>,
+                    Changed<Encumbrance>,
+                )>,
             ),
         >,
     >,
@@ -561,6 +616,92 @@ fn update_status_speed(
     log_if_slow("update_status_speed", start);
 }
 
+/// Only shows hunger and thirst when they matter
+#[expect(clippy::needless_pass_by_value)]
+fn update_status_nutrition(
+    mut commands: Commands,
+    player_nutrition: Option<Single<&Nutrition, (With<Player>, Changed<Nutrition>)>>,
+    text: Single<Entity, With<NutritionText>>,
+) {
+    let start = Instant::now();
+
+    let Some(player_nutrition) = player_nutrition else {
+        return;
+    };
+
+    let hunger = player_nutrition.hunger();
+    let thirst = player_nutrition.thirst();
+    commands
+        .entity(*text)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            for (state, color) in [
+                (hunger.as_str(), hunger.color()),
+                (thirst.as_str(), thirst.color()),
+            ] {
+                if let Some(state) = state {
+                    parent.spawn((TextSpan::new(format!("{state}\n")), color));
+                }
+            }
+        });
+
+    log_if_slow("update_status_nutrition", start);
+}
+
+/// Only shows the most encumbered body part, when there is one
+fn update_status_encumbrance(
+    player_encumbrance: Option<Single<&Encumbrance, (With<Player>, Changed<Encumbrance>)>>,
+    text: Single<&mut Text, With<EncumbranceText>>,
+) {
+    let start = Instant::now();
+
+    let Some(player_encumbrance) = player_encumbrance else {
+        return;
+    };
+
+    let mut text = text.into_inner();
+    text.0 = player_encumbrance
+        .worst()
+        .map_or_else(String::new, |(body_part, encumbrance)| {
+            format!("Encumbered {} ({encumbrance})", body_part.name())
+        });
+
+    log_if_slow("update_status_encumbrance", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn update_status_effects(
+    mut commands: Commands,
+    player_effects: Option<Single<&Effects, (With<Player>, Changed<Effects>)>>,
+    text: Single<Entity, With<EffectsText>>,
+) {
+    let start = Instant::now();
+
+    let Some(player_effects) = player_effects else {
+        return;
+    };
+
+    commands
+        .entity(*text)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            for active in player_effects.iter() {
+                // Effects without a name are not shown
+                if let Some(name) = active.name() {
+                    let color = match active.info.rating {
+                        EffectRating::Good => GOOD_TEXT_COLOR,
+                        EffectRating::Neutral => SOFT_TEXT_COLOR,
+                        EffectRating::Bad => BAD_TEXT_COLOR,
+                        EffectRating::Mixed => WARN_TEXT_COLOR,
+                    };
+                    parent.spawn((TextSpan::new(format!("{}\n", name.single)), color));
+                }
+            }
+        });
+
+    log_if_slow("update_status_effects", start);
+}
+
 #[expect(clippy::needless_pass_by_value)]
 fn update_status_player_action_state(
     player_action_state: Res<State<PlayerActionState>>,
@@ -579,6 +720,7 @@ fn update_status_player_action_state(
 fn update_status_player_wielded(
     mut commands: Commands,
     player_wields: Option<Single<&WieldedItems, With<Player>>>,
+    item_hierarchy: ItemHierarchy,
     items: Query<Item>,
     text: Single<Entity, With<WieldedText>>,
 ) {
@@ -594,7 +736,7 @@ fn update_status_player_wielded(
                 let weapon = items
                     .get(*first_wielded_item)
                     .expect("A wielded object should be an item");
-                let phrase = Phrase::from_fragments(weapon.fragments().collect());
+                let phrase = Phrase::from_fragments(item_hierarchy.loaded_fragments(&weapon));
                 for (span, color, debug) in phrase.as_text_sections() {
                     parent.spawn((span, color, Maybe(debug)));
                 }
@@ -686,7 +828,12 @@ fn update_status_detais(
             Option<&LastSeen>,
             Option<&Visibility>,
         ),
-        (Without<Health>, Without<Amount>, Without<TileIn>),
+        (
+            Without<Health>,
+            Without<Amount>,
+            Without<TileIn>,
+            Without<Concealed>,
+        ),
     >,
     items: Query<Item>,
     text: Single<Entity, With<DetailsText>>,
@@ -695,7 +842,7 @@ fn update_status_detais(
 
     let text_sections = Phrase::from_fragments(match **focus_state {
         FocusState::Normal => vec![Fragment::soft(" ")], // Fragment added as a Bevy 0.15-dev workaround
-        FocusState::ExaminingPos(pos) => {
+        FocusState::ExaminingPos(pos) | FocusState::Targeting(pos) => {
             let mut total = vec![Fragment::soft(format!("\n{pos:?}\n"))];
             if explored.has_pos_been_seen(pos) {
                 total.extend(characters_info(
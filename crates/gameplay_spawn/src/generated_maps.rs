@@ -0,0 +1,11 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::Resource;
+use cdda_json_files::Map;
+use gameplay_location::ZoneLevel;
+
+/// Maps generated for zone levels without a map file.
+///
+/// All subzone levels of a zone level use the same generated map, even when they are spawned at different moments.
+/// Once the zone level is saved, the map file is used instead.
+#[derive(Default, Resource)]
+pub(crate) struct GeneratedMaps(pub(crate) HashMap<ZoneLevel, Map>);
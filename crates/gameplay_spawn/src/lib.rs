@@ -1,4 +1,5 @@
 mod despawn;
+mod generated_maps;
 mod log;
 mod message_buffer;
 mod missing_asset;
@@ -13,12 +14,13 @@ mod zone_spawner;
 pub use self::despawn::despawn_systems;
 pub use self::plugin::SpawnPlugin;
 pub use self::systems::{
-    handle_region_asset_events, handle_zone_levels, spawn_initial_entities, spawn_subzone_levels,
-    spawn_subzones_for_camera, update_explored,
+    handle_region_asset_events, handle_zone_levels, move_hordes, spawn_initial_entities,
+    spawn_subzone_levels, spawn_subzones_for_camera, update_explored,
 };
 pub use self::tile_spawner::TileSpawner;
 pub use self::visible_region::VisibleRegion;
 
+use self::generated_maps::GeneratedMaps;
 use self::log::log_spawn_result;
 use self::message_buffer::MessageBuffer;
 use self::missing_asset::MissingAsset;
@@ -1,22 +1,26 @@
-use crate::{TileSpawner, log_spawn_result};
+use crate::{GeneratedMaps, TileSpawner, log_spawn_result};
 use application_state::ApplicationState;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::{DespawnOnExit, Res, ResMut};
 use cdda_json_files::{
-    CddaAmount, FlatVec, InfoId, OvermapTerrainInfo, RepetitionBlock, RequiredLinkedLater, Submap,
-    SubzoneOffset,
+    CddaAmount, FlatVec, InfoId, OvermapMonsterGroup, OvermapTerrainInfo, RepetitionBlock,
+    RequiredLinkedLater, Submap, SubzoneOffset, TerrainInfo,
 };
+use fastrand::i32 as rand_i32;
 use gameplay_cdda::{
     AssetState, Infos, MapManager, MapMemoryManager, OvermapBufferManager, OvermapManager,
     RepetitionBlockExt as _,
 };
+use gameplay_crafting::ConstructionSite;
 use gameplay_location::{
     LevelOffset, Overzone, PosOffset, SubzoneLevel, SubzoneLevelCache, ZoneLevel,
 };
 use gameplay_relations::TileIn;
+use gameplay_temperature::SubzoneTemperature;
 use gameplay_terrain::LocalTerrain;
+use gameplay_time::Clock;
 use gameplay_vehicle::VehiclePartOf;
-use gameplay_world::ZoneLevelIds;
+use gameplay_world::{Hordes, Noise, ZoneLevelIds};
 use std::sync::OnceLock;
 
 #[derive(SystemParam)]
@@ -24,7 +28,10 @@ pub struct SubzoneSpawner<'w, 's> {
     infos: Res<'w, Infos>,
     zone_level_ids: ResMut<'w, ZoneLevelIds>,
     subzone_level_cache: ResMut<'w, SubzoneLevelCache>,
+    generated_maps: ResMut<'w, GeneratedMaps>,
     overmap_manager: OvermapManager<'w>,
+    hordes: ResMut<'w, Hordes>,
+    clock: Clock<'w>,
     tile_spawner: TileSpawner<'w, 's>,
 }
 
@@ -65,8 +72,27 @@ impl SubzoneSpawner<'_, '_> {
                 // It will be spawned when it's fully loaded.
             }
             AssetState::Nonexistent => {
-                if let Some(object_id) = self.zone_level_ids.get(ZoneLevel::from(subzone_level)) {
-                    let submap = Self::fallback_submap(subzone_level, object_id);
+                let zone_level = ZoneLevel::from(subzone_level);
+                let Some(object_id) = self.zone_level_ids.get(zone_level) else {
+                    return;
+                };
+                let fallback_terrain = Self::fallback_terrain(object_id);
+
+                // Removed during spawning, to allow borrowing it
+                let map = self.generated_maps.0.remove(&zone_level).or_else(|| {
+                    let monster_density = self.monster_density(zone_level);
+                    self.infos.generate_map(
+                        zone_level,
+                        object_id,
+                        &fallback_terrain,
+                        monster_density,
+                    )
+                });
+                if let Some(map) = map {
+                    self.spawn_submap(&map.0[subzone_level.index()], subzone_level);
+                    self.generated_maps.0.insert(zone_level, map);
+                } else {
+                    let submap = Self::fallback_submap(subzone_level, fallback_terrain);
                     self.spawn_submap(&submap, subzone_level);
                 }
             }
@@ -78,7 +104,11 @@ impl SubzoneSpawner<'_, '_> {
         let subzone_level_entity = self
             .tile_spawner
             .commands
-            .spawn((subzone_level, DespawnOnExit(ApplicationState::Gameplay)))
+            .spawn((
+                subzone_level,
+                SubzoneTemperature::from_cdda(submap.temperature),
+                DespawnOnExit(ApplicationState::Gameplay),
+            ))
             .id();
         let tile_in = TileIn {
             subzone_level_entity,
@@ -105,6 +135,12 @@ impl SubzoneSpawner<'_, '_> {
                         .iter()
                         .filter_map(|at| pos_offset.get(at))
                         .filter_map(|required| required.get_option());
+                    let trap_ids = submap
+                        .traps
+                        .iter()
+                        .filter_map(|at| pos_offset.get(at))
+                        .filter_map(|required| required.get_option());
+                    let construction_sites = Self::construction_sites(submap, pos_offset);
                     let item_repetitions =
                         submap.items.0.iter().filter_map(|at| pos_offset.get(at));
                     let spawns = submap
@@ -117,6 +153,8 @@ impl SubzoneSpawner<'_, '_> {
                         pos,
                         local_terrain.as_ref(),
                         furniture_ids,
+                        trap_ids,
+                        construction_sites,
                         item_repetitions,
                         spawns,
                         fields,
@@ -160,15 +198,94 @@ impl SubzoneSpawner<'_, '_> {
                 }
             }
 
+            for (_, group) in self.hordes.take(|at| at == subzone_level) {
+                self.spawn_monster_group(subzone_level, &group);
+            }
+
             //trace!("{:?} done", subzone_level);
         }
     }
 
-    fn fallback_submap(
-        subzone_level: SubzoneLevel,
-        zone_object_id: &InfoId<OvermapTerrainInfo>,
-    ) -> Submap {
-        let terrain_id = InfoId::new(if zone_object_id == &InfoId::new("open_air") {
+    pub(crate) fn hear(&mut self, noise: &Noise) {
+        self.hordes.hear(noise);
+    }
+
+    /// Moves the hordes, and spawns those that entered a spawned subzone level
+    pub(crate) fn advance_hordes(&mut self) {
+        self.hordes.advance();
+
+        let subzone_level_cache = &self.subzone_level_cache;
+        for (subzone_level, group) in self.hordes.take(|at| subzone_level_cache.get(at).is_some()) {
+            self.spawn_monster_group(subzone_level, &group);
+        }
+    }
+
+    /// Spawns the population of the group at random places in the subzone level
+    fn spawn_monster_group(&mut self, subzone_level: SubzoneLevel, group: &OvermapMonsterGroup) {
+        let population = group.population as usize;
+        let mut monster_ids = Vec::new();
+        for _ in 0..population {
+            monster_ids.extend(
+                self.infos
+                    .monsters_from_group(&group.group, self.clock.time()),
+            );
+            if population <= monster_ids.len() {
+                break;
+            }
+        }
+        monster_ids.truncate(population);
+
+        let base_pos = subzone_level.base_corner();
+        for monster_id in monster_ids {
+            let monster = RequiredLinkedLater::from(monster_id);
+            self.infos.link_character(&monster, "monster group");
+            log_spawn_result(self.tile_spawner.spawn_character(
+                base_pos.horizontal_offset(
+                    rand_i32(0..SubzoneLevel::SIZE),
+                    rand_i32(0..SubzoneLevel::SIZE),
+                ),
+                &monster,
+                None,
+            ));
+        }
+    }
+
+    fn construction_sites(
+        submap: &Submap,
+        pos_offset: PosOffset,
+    ) -> impl Iterator<Item = ConstructionSite> {
+        submap
+            .partial_constructions
+            .0
+            .iter()
+            .filter(move |partial| {
+                i32::from(partial.x) == pos_offset.x && i32::from(partial.y) == pos_offset.z
+            })
+            .filter_map(|partial| {
+                partial.construction.get_option().map(|construction| {
+                    ConstructionSite::from_counter(construction, partial.counter)
+                })
+            })
+    }
+
+    /// A guess for the terrain of an overmap terrain, for when its map is not available
+    /// Based on the known zones within 3 zones, like in CDDA
+    fn monster_density(&self, zone_level: ZoneLevel) -> f32 {
+        const RADIUS: i32 = 3;
+
+        let nearby = (-RADIUS..=RADIUS).flat_map(|x| {
+            (-RADIUS..=RADIUS).filter_map(move |z| {
+                self.zone_level_ids.get(ZoneLevel {
+                    zone: zone_level.zone.offset(x, z),
+                    level: zone_level.level,
+                })
+            })
+        });
+        self.infos.monster_density(nearby)
+    }
+
+    fn fallback_terrain(zone_object_id: &InfoId<OvermapTerrainInfo>) -> InfoId<TerrainInfo> {
+        InfoId::new(if zone_object_id == &InfoId::new("open_air") {
             "t_open_air"
         } else if zone_object_id == &InfoId::new("solid_earth") {
             "t_soil"
@@ -184,7 +301,10 @@ impl SubzoneSpawner<'_, '_> {
             "t_pavement"
         } else {
             "t_dirt"
-        });
+        })
+    }
+
+    fn fallback_submap(subzone_level: SubzoneLevel, terrain_id: InfoId<TerrainInfo>) -> Submap {
         Submap {
             version: 0,
             turn_last_touched: 0,
@@ -202,7 +322,7 @@ impl SubzoneSpawner<'_, '_> {
             cosmetics: Vec::new(),
             spawns: Vec::new(),
             vehicles: Vec::new(),
-            partial_constructions: Vec::new(),
+            partial_constructions: FlatVec(Vec::new()),
             computers: Vec::new(),
             linked: OnceLock::default(),
         }
@@ -21,10 +21,12 @@ use gameplay_location::{
     Level, Pos, SubzoneLevel, SubzoneLevelCache, VisionDistance, Zone, ZoneLevel, ZoneLevelCache,
 };
 use gameplay_perception::{Explored, Region, ZoneRegion};
+use gameplay_time::Clock;
 use gameplay_visualization::{Expanded, VisualizationUpdate};
-use gameplay_world::ZoneLevelIds;
+use gameplay_world::{Hordes, Noise, ZoneLevelIds};
 use std::cmp::Ordering;
 use std::time::{Duration, Instant};
+use units::{Duration as GameDuration, Timestamp};
 use util::log_if_slow;
 
 const MAX_EXPAND_DISTANCE: i32 = 10;
@@ -359,6 +361,7 @@ fn handle_overmap_events(
     mut overmap_events: MessageReader<AssetEvent<OvermapAsset>>,
     overmap_assets: Res<Assets<OvermapAsset>>,
     mut zone_level_ids: ResMut<ZoneLevelIds>,
+    mut hordes: ResMut<Hordes>,
     overmap_manager: OvermapManager,
 ) {
     let start = Instant::now();
@@ -373,12 +376,41 @@ fn handle_overmap_events(
 
             let overmap = overmap_assets.get(*id).expect("Overmap loaded");
             zone_level_ids.load(overzone, overmap);
+            hordes.load(overzone, overmap);
         }
     }
 
     log_if_slow("handle_overmap_events", start);
 }
 
+/// Hordes converge on noise, move over time, and spawn when they reach a spawned subzone level
+#[expect(clippy::needless_pass_by_value)]
+pub fn move_hordes(
+    mut noise_reader: MessageReader<Noise>,
+    mut last_move: GameplayLocal<Option<Timestamp>>,
+    clock: Clock,
+    mut subzone_spawner: SubzoneSpawner,
+) {
+    let start = Instant::now();
+
+    for noise in noise_reader.read() {
+        subzone_spawner.hear(noise);
+    }
+
+    // How long hordes take to move to the next subzone level
+    let move_interval = GameDuration::MINUTE * 10;
+
+    let now = clock.time();
+    let last_move = last_move.get().get_or_insert(now);
+    // One move per elapsed interval, so hordes keep their pace when time passes quickly, like while sleeping
+    while *last_move + move_interval <= now {
+        *last_move += move_interval;
+        subzone_spawner.advance_hordes();
+    }
+
+    log_if_slow("move_hordes", start);
+}
+
 fn update_zone_levels_with_missing_assets(
     mut zone_spawner: ZoneSpawner,
     zone_levels: Query<(Entity, &ZoneLevel), With<MissingAsset>>,
@@ -403,17 +435,22 @@ pub fn spawn_initial_entities(
     spawner.spawn_light();
 
     let sav = active_sav.sav();
-    let spawn_pos = Zone {
-        x: i32::from(sav.om_x) * 180 + i32::from(sav.levx) / 2,
-        z: i32::from(sav.om_y) * 180 + i32::from(sav.levy) / 2,
-    }
-    .zone_level(Level::new(sav.levz))
-    .base_corner()
-    .horizontal_offset(
-        12 * i32::from(sav.levx % 2) + 24,
-        12 * i32::from(sav.levy % 2) + 24,
+    let player_pos = sav.player.location.map_or_else(
+        || {
+            Zone {
+                x: i32::from(sav.om_x) * 180 + i32::from(sav.levx) / 2,
+                z: i32::from(sav.om_y) * 180 + i32::from(sav.levy) / 2,
+            }
+            .zone_level(Level::new(sav.levz))
+            .base_corner()
+            .horizontal_offset(
+                12 * i32::from(sav.levx % 2) + 60,
+                12 * i32::from(sav.levy % 2) + 80,
+            )
+        },
+        |(x, z, h)| Pos::new(x, Level::new(h), z),
     );
-    spawner.spawn_characters(spawn_pos, *camera);
+    spawner.spawn_characters(player_pos, *camera);
 }
 
 pub fn update_explored(
@@ -2,6 +2,7 @@ use crate::log_spawn_result;
 use application_state::ApplicationState;
 use bevy::camera::visibility::RenderLayers;
 use bevy::ecs::{relationship::Relationship, system::SystemParam};
+use bevy::platform::collections::HashMap;
 use bevy::prelude::{
     Commands, Component, DespawnOnExit, DirectionalLight, Entity, EulerRot, Mat4, Query, Res,
     TextColor, Transform, Vec3, Visibility, With, debug, error,
@@ -11,21 +12,23 @@ use cdda_json_files::{
     CddaVehiclePart, Character, CharacterInfo, CommonItemInfo, Description, Field, Flags, FlatVec,
     FurnitureInfo, Ignored, InfoId, ItemGroup, ItemName, ItemTypeDetails, MaybeFlatVec,
     MoveCostMod, PocketInfo, PocketType, Recipe, Repetition, RequiredLinkedLater, SpawnItem,
-    TerrainInfo, UntypedInfoId,
+    TerrainInfo, TrapInfo, UntypedInfoId,
 };
 use either::Either;
 use gameplay_cdda::{Error, Infos, ObjectCategory, TileVariant};
 use gameplay_cdda_active_sav::ActiveSav;
 use gameplay_character::{
-    Aquatic, BaseFaction as _, BaseSpeed, Faction, HealingDuration, Health, Melee, Stamina,
-    WalkingMode,
+    Aquatic, BaseFaction as _, BaseSpeed, BodyTemperature, Effects, Faction, HealingDuration,
+    Health, Melee, Nutrition, Perception, Skills, Stamina, WalkingMode,
 };
 use gameplay_common::{LastSeen, Limited, Shared, Tile};
-use gameplay_crafting::{Craft, CraftSpawner};
+use gameplay_crafting::{
+    ConstructionSite, ConstructionSpawner, Craft, CraftSpawner, HarvestSpawner,
+};
 use gameplay_focus::{CameraBase, ExamineCursor};
 use gameplay_item::{
-    Amount, BodyContainers, Containable, Filthy, InPocket, ItemIntegrity, ItemSpawner, Phase,
-    PocketOf, SealedPocket,
+    Amount, BodyContainers, Containable, Filthy, InPocket, ItemIntegrity, ItemSpawner,
+    ItemTemperature, Phase, PocketOf, SavedItem, SealedPocket,
 };
 use gameplay_location::{LevelOffset, LocationCache, Pos, PosOffset, StairsDown, StairsUp};
 use gameplay_model::ModelFactory;
@@ -35,9 +38,11 @@ use gameplay_object::{
 use gameplay_perception::Explored;
 use gameplay_player::Player;
 use gameplay_relations::{ObjectOn, TileIn};
-use gameplay_terrain::{Accessible, LocalTerrain, OpaqueFloor};
+use gameplay_terrain::{Accessible, Concealed, LocalTerrain, OpaqueFloor, Trap, TrapDropSpawner};
 use gameplay_vehicle::{Vehicle, VehiclePart, VehiclePartOf};
+use gameplay_weather::{Weather, Wetness};
 use hud::{BAD_TEXT_COLOR, GOOD_TEXT_COLOR, HARD_TEXT_COLOR, WARN_TEXT_COLOR};
+use serde_json::{Value as JsonValue, from_value as from_json_value};
 use std::f32::consts::{FRAC_PI_4, TAU};
 use std::sync::{Arc, LazyLock};
 use units::{Mass, Volume};
@@ -50,6 +55,7 @@ pub struct TileSpawner<'w, 's> {
     active_sav: Res<'w, ActiveSav>,
     explored: Res<'w, Explored>,
     location_cache: Res<'w, LocationCache>,
+    weather: Option<Res<'w, Weather>>,
     model_factory: ModelFactory<'w>,
     tiles: Query<'w, 's, Entity, With<Tile>>,
 }
@@ -65,6 +71,8 @@ impl<'w> TileSpawner<'w, '_> {
         pos: Pos,
         local_terrain: Option<&LocalTerrain>,
         furniture_infos: impl Iterator<Item = Arc<FurnitureInfo>>,
+        trap_infos: impl Iterator<Item = Arc<TrapInfo>>,
+        construction_sites: impl Iterator<Item = ConstructionSite>,
         item_repetitions: impl Iterator<Item = &'a Vec<Repetition<CddaItem>>>,
         spawns: impl Iterator<Item = &'a Character>,
         fields: impl Iterator<Item = &'a FlatVec<Field, 3>>,
@@ -75,12 +83,23 @@ impl<'w> TileSpawner<'w, '_> {
 
         if let Some(local_terrain) = local_terrain {
             self.spawn_terrain(object_in, pos, local_terrain);
+            if let Some(trap_info) = local_terrain.info.trap.get() {
+                self.spawn_trap(object_in, pos, &trap_info, true);
+            }
         }
 
         for furniture_info in furniture_infos {
             self.spawn_furniture(object_in, pos, &furniture_info);
         }
 
+        for trap_info in trap_infos {
+            self.spawn_trap(object_in, pos, &trap_info, false);
+        }
+
+        for construction_site in construction_sites {
+            self.spawn_construction_site_on(object_in, pos, construction_site);
+        }
+
         for repetitions in item_repetitions {
             for repetition in repetitions {
                 let CddaAmount { obj: item, amount } = repetition.as_amount();
@@ -114,6 +133,16 @@ impl<'w> TileSpawner<'w, '_> {
         character_info: &RequiredLinkedLater<CharacterInfo>,
         name: Option<ObjectName>,
     ) -> Result<Entity, Error> {
+        self.spawn_character_with_body(pos, character_info, name)
+            .map(|(entity, _)| entity)
+    }
+
+    fn spawn_character_with_body(
+        &mut self,
+        pos: Pos,
+        character_info: &RequiredLinkedLater<CharacterInfo>,
+        name: Option<ObjectName>,
+    ) -> Result<(Entity, Option<BodyContainers>), Error> {
         let character_info = character_info.get()?;
         let faction = match &*character_info.default_faction {
             "human" => Faction::Human,
@@ -144,6 +173,7 @@ impl<'w> TileSpawner<'w, '_> {
                 sides: character_info.melee_dice_sides,
             },
             HealingDuration::default(),
+            Effects::default(),
         ));
 
         if let Some(name) = name {
@@ -162,7 +192,7 @@ impl<'w> TileSpawner<'w, '_> {
             item_entity: entity.id(),
         };
 
-        if faction == Faction::Human {
+        let body_containers = (faction == Faction::Human).then(|| {
             let hands = self
                 .commands
                 .spawn((
@@ -189,13 +219,15 @@ impl<'w> TileSpawner<'w, '_> {
             let clothing = InPocket {
                 pocket_entity: clothing,
             };
+            let containers = BodyContainers { hands, clothing };
             self.commands
                 .entity(pocket_of_character.item_entity)
-                .insert(BodyContainers { hands, clothing });
-        }
+                .insert(containers);
+            containers
+        });
 
         debug!("Spawned a {:?} at {pos:?}", character_info.id);
-        Ok(pocket_of_character.item_entity)
+        Ok((pocket_of_character.item_entity, body_containers))
     }
 
     fn spawn_field(&mut self, object_in: ObjectOn, pos: Pos, field: &Field) {
@@ -245,7 +277,7 @@ impl<'w> TileSpawner<'w, '_> {
         self.spawn_items(object_in, pos, item_group.items());
     }
 
-    fn spawn_furniture(
+    pub fn spawn_furniture(
         &mut self,
         object_in: ObjectOn,
         pos: Pos,
@@ -282,6 +314,49 @@ impl<'w> TileSpawner<'w, '_> {
         }
     }
 
+    fn spawn_construction_site_on(
+        &mut self,
+        object_in: ObjectOn,
+        pos: Pos,
+        construction_site: ConstructionSite,
+    ) -> Entity {
+        let object_name = ObjectName::from_str("unfinished construction", WARN_TEXT_COLOR);
+        let entity = self.spawn_object(
+            object_in,
+            Some(pos),
+            &UntypedInfoId::new("tr_unfinished_construction"),
+            ObjectCategory::Trap,
+            object_name,
+            None,
+        );
+        self.commands.entity(entity).insert(construction_site);
+        entity
+    }
+
+    fn spawn_trap(
+        &mut self,
+        object_in: ObjectOn,
+        pos: Pos,
+        trap_info: &Arc<TrapInfo>,
+        built_in: bool,
+    ) {
+        let object_name = ObjectName::new(trap_info.name.clone(), WARN_TEXT_COLOR);
+        let entity = self.spawn_object(
+            object_in,
+            Some(pos),
+            trap_info.id.untyped(),
+            ObjectCategory::Trap,
+            object_name,
+            None,
+        );
+        let mut entity = self.commands.entity(entity);
+        entity.insert((Shared::new(trap_info.clone()), Trap { built_in }));
+
+        if !trap_info.obvious() {
+            entity.insert(Concealed);
+        }
+    }
+
     pub fn spawn_terrain(&mut self, object_in: ObjectOn, pos: Pos, local_terrain: &LocalTerrain) {
         if local_terrain.info.id == InfoId::new("t_open_air")
             || local_terrain.info.id == InfoId::new("t_open_air_rooved")
@@ -516,7 +591,10 @@ impl<'w> TileSpawner<'w, '_> {
         //trace!("{:?}", (&light_transform);
         self.commands.spawn((
             DirectionalLight {
-                illuminance: 10_000.0,
+                illuminance: self
+                    .weather
+                    .as_ref()
+                    .map_or(Weather::CLEAR_ILLUMINANCE, |weather| weather.illuminance()),
                 shadow_maps_enabled: false, // TODO shadow direction does not match building shadows
                 ..DirectionalLight::default()
             },
@@ -525,27 +603,94 @@ impl<'w> TileSpawner<'w, '_> {
         ));
     }
 
-    pub(crate) fn spawn_characters(&mut self, spawn_pos: Pos, camera_entity: Entity) {
+    pub(crate) fn spawn_characters(&mut self, player_pos: Pos, camera_entity: Entity) {
         let human = RequiredLinkedLater::from(InfoId::new("human"));
         self.infos.link_character(&human, "player");
 
         let sav = self.active_sav.sav();
-        let player = self
-            .spawn_character(
-                spawn_pos.horizontal_offset(36, 56),
-                &human,
-                Some(ObjectName::from_str(&sav.player.name, GOOD_TEXT_COLOR)),
-            )
+        let name = ObjectName::from_str(&sav.player.name, GOOD_TEXT_COLOR);
+        let stamina = sav
+            .player
+            .stamina
+            .as_u64()
+            .map_or(Stamina::FULL, Stamina::from_cdda);
+        let relative_health = relative_body_health(&sav.player.body);
+        let body_temperature = average_body_temperature(&sav.player.body).unwrap_or_default();
+        let perception = Perception(sav.player.per_cur.clamp(0, u8::MAX.into()) as u8);
+        let skills = Skills::from_cdda(&sav.player.skills, sav.player.focus_pool.as_u64());
+        let effects = Effects::from_cdda(&sav.player.effects, |id| {
+            self.infos.effects.get(&InfoId::new(id)).ok().cloned()
+        });
+        let nutrition = Nutrition::from_cdda(
+            sav.player.stored_calories.as_u64(),
+            sav.player.thirst.as_i64(),
+        );
+
+        let (player, body_containers) = self
+            .spawn_character_with_body(player_pos, &human, Some(name))
             .expect("Player character should be spawned");
         self.commands.entity(player).insert((
             Player,
-            Stamina::FULL,
+            perception,
+            skills,
+            effects,
+            stamina,
+            body_temperature,
+            nutrition,
+            Wetness::default(),
             WalkingMode::Walking, // override
             DespawnOnExit(ApplicationState::Gameplay),
         ));
+        if let Some(relative_health) = relative_health {
+            let max = human.get().expect("Human info should be linked").hp as u16;
+            self.commands
+                .entity(player)
+                .insert(Health::restored(max, relative_health));
+        }
+        if let Some(body_containers) = body_containers {
+            self.spawn_player_items(body_containers);
+        }
         self.configure_player(player, camera_entity);
     }
 
+    /// Restore the wielded and worn items of the player
+    fn spawn_player_items(&mut self, body_containers: BodyContainers) {
+        let sav = self.active_sav.sav();
+        let wielded = sav
+            .player
+            .extra
+            .get("weapon")
+            .map(|weapon| from_json_value::<CddaItem>(weapon.clone()).map(|item| vec![item]));
+        let worn = sav.player.extra.get("worn").map(|worn| {
+            // Newer CDDA versions wrap the list in an object
+            let worn = worn.get("worn").unwrap_or(worn);
+            from_json_value::<Vec<CddaItem>>(worn.clone())
+        });
+
+        for (in_pocket, items) in [
+            (body_containers.hands, wielded),
+            (body_containers.clothing, worn),
+        ] {
+            match items {
+                Some(Ok(items)) => {
+                    for item in &items {
+                        self.infos.link_item(item);
+                        if let Err(error) = self.spawn_item(
+                            in_pocket,
+                            None,
+                            item,
+                            Amount(item.charges.unwrap_or(1)),
+                        ) {
+                            error!("Spawning a player item failed: {error:#?}");
+                        }
+                    }
+                }
+                Some(Err(error)) => error!("Player items could not be loaded: {error:#?}"),
+                None => {}
+            }
+        }
+    }
+
     pub fn spawn_zombies(&mut self, around_pos: Pos) {
         let human = RequiredLinkedLater::from(InfoId::new("human"));
         self.infos.link_character(&human, "survivor");
@@ -609,6 +754,72 @@ impl<'w> TileSpawner<'w, '_> {
     }
 }
 
+impl ConstructionSpawner for TileSpawner<'_, '_> {
+    fn spawn_construction_site(&mut self, pos: Pos, site: ConstructionSite) -> Entity {
+        let object_in = ObjectOn {
+            tile_entity: self
+                .location_cache
+                .get_first(pos, &self.tiles)
+                .expect("Tile of construction site should be found"),
+        };
+        self.spawn_construction_site_on(object_in, pos, site)
+    }
+}
+
+impl HarvestSpawner for TileSpawner<'_, '_> {
+    fn spawn_harvest(&mut self, pos: Pos, character_info: &CharacterInfo) {
+        let Some(harvest) = character_info.harvest.get() else {
+            return;
+        };
+
+        let object_in = ObjectOn {
+            tile_entity: self
+                .location_cache
+                .get_first(pos, &self.tiles)
+                .expect("Tile of harvest should be found"),
+        };
+
+        for entry in harvest.butchery_entries() {
+            let Some(item_info) = entry.drop.get_option() else {
+                continue;
+            };
+            let amount = entry.amount(character_info.mass, item_info.mass);
+            if 0 < amount
+                && let Err(error) = self.spawn_item(
+                    object_in,
+                    Some(pos),
+                    &CddaItem::new(&item_info),
+                    Amount(amount),
+                )
+            {
+                error!("Spawning a harvest drop failed: {error:#?}");
+            }
+        }
+    }
+}
+
+impl TrapDropSpawner for TileSpawner<'_, '_> {
+    fn spawn_trap_drops(&mut self, pos: Pos, trap_info: &TrapInfo) {
+        let object_in = ObjectOn {
+            tile_entity: self
+                .location_cache
+                .get_first(pos, &self.tiles)
+                .expect("Tile of trap should be found"),
+        };
+
+        for drop in &trap_info.drops {
+            let Some(item_info) = drop.get_option() else {
+                continue;
+            };
+            if let Err(error) =
+                self.spawn_item(object_in, Some(pos), &CddaItem::new(&item_info), Amount(1))
+            {
+                error!("Spawning a trap drop failed: {error:#?}");
+            }
+        }
+    }
+}
+
 impl CraftSpawner for TileSpawner<'_, '_> {
     fn spawn_craft(&mut self, pos: Pos, recipe: Arc<Recipe>) -> Result<Entity, Error> {
         let object_in = ObjectOn {
@@ -622,8 +833,6 @@ impl CraftSpawner for TileSpawner<'_, '_> {
             id: InfoId::new("craft"),
             type_details: ItemTypeDetails::Craft.into(),
             category: None,
-            proportional: None,
-            relative: None,
             count: None,
             stack_size: None,
             range: None,
@@ -640,6 +849,7 @@ impl CraftSpawner for TileSpawner<'_, '_> {
             integral_longest_side: None,
             bashing: None,
             cutting: None,
+            melee_damage: HashMap::default(),
             to_hit: None,
             variant_type: None,
             variants: None,
@@ -668,8 +878,6 @@ impl CraftSpawner for TileSpawner<'_, '_> {
             faults: None,
             qualities: Vec::new(),
             repairs_with: Vec::new(),
-            extend: None,
-            delete: None,
             properties: None,
             techniques: None,
             max_charges: None,
@@ -689,7 +897,7 @@ impl CraftSpawner for TileSpawner<'_, '_> {
             milling: None,
             gunmod_data: None,
             pocket_data: None,
-            armor: None,
+            armor: Vec::new(),
             snippet_category: None,
             ignored: Ignored::default(),
         };
@@ -761,9 +969,19 @@ impl ItemSpawner for TileSpawner<'_, '_> {
             phase,
         ));
 
+        if let Some(saved) = SavedItem::new(item) {
+            entity.insert(saved);
+        }
         if item.item_tags.contains(&Arc::from("FILTHY")) {
             entity.insert(Filthy);
         }
+        if let Some(freezing_point) = item_info.freezing_point() {
+            entity.insert(ItemTemperature::new(
+                item.celsius(),
+                item.item_tags.contains(&Arc::from("FROZEN")),
+                freezing_point,
+            ));
+        }
 
         let entity = entity.id();
         //trace!("Item {entity:?} with parent {parent:?}");
@@ -852,6 +1070,35 @@ fn pocket_info(
     }
 }
 
+/// Average health of all body parts, relative to their maximum
+fn relative_body_health(body: &JsonValue) -> Option<f32> {
+    let (current, max) = body
+        .as_object()?
+        .values()
+        .filter_map(|body_part| {
+            Some((
+                body_part.get("hp_cur")?.as_i64()?,
+                body_part.get("hp_max")?.as_i64()?,
+            ))
+        })
+        .fold((0, 0), |(current, max), (hp_cur, hp_max)| {
+            (current + hp_cur.max(0), max + hp_max.max(0))
+        });
+    (0 < max).then(|| current as f32 / max as f32)
+}
+
+/// CDDA tracks the temperature per body part, but we use the average
+fn average_body_temperature(body: &JsonValue) -> Option<BodyTemperature> {
+    let temperatures = body
+        .as_object()?
+        .values()
+        .filter_map(|body_part| body_part.get("temp_cur")?.as_i64())
+        .collect::<Vec<_>>();
+    (!temperatures.is_empty()).then(|| {
+        BodyTemperature::from_cdda(temperatures.iter().sum::<i64>() / temperatures.len() as i64)
+    })
+}
+
 fn body_pocket_info() -> Shared<PocketInfo> {
     static INFO: LazyLock<Arc<PocketInfo>> = LazyLock::new(|| {
         Arc::new(PocketInfo {
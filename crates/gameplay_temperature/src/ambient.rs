@@ -0,0 +1,144 @@
+use crate::SubzoneTemperature;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{Query, Res};
+use cdda_json_files::FieldInfo;
+use gameplay_common::Shared;
+use gameplay_location::{Level, LevelOffset, Pos, SubzoneLevel, SubzoneLevelCache};
+use gameplay_weather::{Exposure, Weather};
+
+/// The temperature of the surroundings, per position
+#[derive(SystemParam)]
+pub struct AmbientTemperature<'w, 's> {
+    weather: Option<Res<'w, Weather>>,
+    exposure: Exposure<'w, 's>,
+    subzone_level_cache: Res<'w, SubzoneLevelCache>,
+    subzone_temperatures: Query<'w, 's, &'static SubzoneTemperature>,
+    fields: Query<'w, 's, (&'static Pos, &'static Shared<FieldInfo>)>,
+}
+
+impl AmbientTemperature<'_, '_> {
+    /// Used without weather, in degrees Celsius
+    const FALLBACK: f32 = 15.0;
+
+    /// Buildings are kept around this temperature, in degrees Celsius
+    const INDOOR: f32 = 18.0;
+
+    /// How much buildings close the gap between the outdoor and the indoor temperature
+    const SHELTER: f32 = 0.5;
+
+    /// The heat of a fire on its own tile, in degrees Celsius
+    const FIRE_HEAT: f32 = 30.0;
+
+    /// How far the heat of a fire reaches, in tiles
+    const FIRE_RANGE: i32 = 4;
+
+    /// The positions with fire, to pass to [`Self::at`]
+    #[must_use]
+    pub fn fires(&self) -> Vec<Pos> {
+        self.fields
+            .iter()
+            .filter(|(_, field_info)| field_info.has_fire == Some(true))
+            .map(|(&pos, _)| pos)
+            .collect()
+    }
+
+    /// In degrees Celsius
+    ///
+    /// Above ground, this follows the weather. Underground, this is the yearly average.
+    #[must_use]
+    pub fn at(&self, pos: Pos, fires: &[Pos]) -> f32 {
+        let outdoor = self
+            .weather
+            .as_ref()
+            .map_or(Self::FALLBACK, |weather| weather.reading().temperature);
+        let base = if pos.level < Level::ZERO {
+            self.weather
+                .as_ref()
+                .map_or(Self::FALLBACK, |weather| weather.base_temperature())
+        } else if self.exposure.exposed(pos) {
+            outdoor
+        } else {
+            Self::sheltered(outdoor)
+        };
+
+        let subzone_offset = self
+            .subzone_level_cache
+            .get(SubzoneLevel::from(pos))
+            .and_then(|subzone_level| self.subzone_temperatures.get(subzone_level).ok())
+            .map_or(0.0, |subzone_temperature| subzone_temperature.offset());
+
+        base + subzone_offset + Self::fire_heat(pos, fires)
+    }
+
+    /// Inside buildings, in degrees Celsius
+    fn sheltered(outdoor: f32) -> f32 {
+        outdoor + (Self::INDOOR - outdoor) * Self::SHELTER
+    }
+
+    /// How much colder the wind makes it feel, in degrees Celsius
+    #[must_use]
+    pub fn wind_chill(&self, pos: Pos) -> f32 {
+        if self.exposure.exposed(pos) {
+            self.weather
+                .as_ref()
+                .map_or(0.0, |weather| weather.reading().windpower / 5.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// In degrees Celsius, decreasing with the square of the distance
+    #[must_use]
+    pub fn fire_heat(pos: Pos, fires: &[Pos]) -> f32 {
+        fires
+            .iter()
+            .map(|&fire| pos - fire)
+            .filter(|offset| offset.level == LevelOffset::ZERO)
+            .map(|offset| offset.x.abs().max(offset.z.abs()))
+            .filter(|&distance| distance <= Self::FIRE_RANGE)
+            .map(|distance| Self::FIRE_HEAT / (1 + distance * distance) as f32)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod ambient_tests {
+    use super::*;
+
+    #[test]
+    fn fire_heat_falls_off() {
+        let fire = Pos::new(10, Level::ZERO, 10);
+        let heat = |x, z| AmbientTemperature::fire_heat(Pos::new(x, Level::ZERO, z), &[fire]);
+
+        assert!((heat(10, 10) - AmbientTemperature::FIRE_HEAT).abs() < f32::EPSILON);
+        assert!((heat(11, 10) - AmbientTemperature::FIRE_HEAT / 2.0).abs() < f32::EPSILON);
+        assert!((heat(12, 11) - AmbientTemperature::FIRE_HEAT / 5.0).abs() < f32::EPSILON);
+        assert!((heat(14, 14) - AmbientTemperature::FIRE_HEAT / 17.0).abs() < f32::EPSILON);
+        assert!(heat(15, 10).abs() < f32::EPSILON, "Out of range");
+    }
+
+    #[test]
+    fn fire_heat_stays_on_its_level() {
+        let fire = Pos::new(0, Level::ZERO, 0);
+        let above = Pos::new(0, Level::new(1), 0);
+        assert!(AmbientTemperature::fire_heat(above, &[fire]).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fire_heat_adds_up() {
+        let pos = Pos::new(0, Level::ZERO, 0);
+        let fires = [Pos::new(1, Level::ZERO, 0), Pos::new(-1, Level::ZERO, 0)];
+        assert!(
+            (AmbientTemperature::fire_heat(pos, &fires) - AmbientTemperature::FIRE_HEAT).abs()
+                < f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn shelter_moves_towards_indoor() {
+        let indoor = AmbientTemperature::INDOOR;
+        assert!((AmbientTemperature::sheltered(indoor) - indoor).abs() < f32::EPSILON);
+        assert!((AmbientTemperature::sheltered(-2.0) - 8.0).abs() < f32::EPSILON);
+        assert!((AmbientTemperature::sheltered(30.0) - 24.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::Component;
+
+/// Local temperature difference of a subzone level, like a freezer room
+#[derive(Clone, Copy, Debug, PartialEq, Component)]
+#[component(immutable)]
+pub struct SubzoneTemperature {
+    /// In degrees Celsius
+    offset: f32,
+}
+
+impl SubzoneTemperature {
+    /// Convert from the `temperature` of a CDDA submap, which is a difference in degrees Fahrenheit
+    #[must_use]
+    pub fn from_cdda(fahrenheit: i64) -> Self {
+        Self {
+            offset: fahrenheit as f32 * 5.0 / 9.0,
+        }
+    }
+
+    #[must_use]
+    pub const fn offset(self) -> f32 {
+        self.offset
+    }
+}
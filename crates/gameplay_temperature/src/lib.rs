@@ -0,0 +1,12 @@
+//! Ambient, body, and item temperatures
+
+mod ambient;
+mod components;
+mod plugin;
+mod systems;
+
+pub use self::ambient::AmbientTemperature;
+pub use self::components::SubzoneTemperature;
+pub use self::plugin::TemperaturePlugin;
+
+use self::systems::{update_body_temperatures, update_item_temperatures};
@@ -0,0 +1,16 @@
+use crate::{update_body_temperatures, update_item_temperatures};
+use application_state::ApplicationState;
+use bevy::prelude::{App, IntoScheduleConfigs as _, Plugin, Update, in_state};
+
+pub struct TemperaturePlugin;
+
+impl Plugin for TemperaturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (update_body_temperatures, update_item_temperatures)
+                .chain()
+                .run_if(in_state(ApplicationState::Gameplay)),
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::AmbientTemperature;
+use bevy::prelude::{Commands, Entity, Has, MessageWriter, Query, Res, State, With};
+use cdda_json_files::{FurnitureInfo, InfoId, TerrainInfo};
+use gameplay_cdda::Infos;
+use gameplay_character::{ApplyEffect, BodyTemperature, CharacterEvent, Effects};
+use gameplay_common::Shared;
+use gameplay_item::{BodyContainers, Item, ItemHierarchy, ItemItem, ItemTemperature};
+use gameplay_local::GameplayLocal;
+use gameplay_location::{LocationCache, Pos};
+use gameplay_object::Life;
+use gameplay_player::{Player, PlayerActionState};
+use gameplay_relations::ObjectOn;
+use gameplay_time::Clock;
+use gameplay_weather::Wetness;
+use std::time::Instant;
+use units::{Duration, Timestamp};
+use util::log_if_slow;
+
+/// The felt temperature at which the body stays at its normal temperature, in degrees Celsius
+const COMFORTABLE: f32 = 22.0;
+
+/// How many degrees of felt temperature change the body temperature by one degree
+const FELT_PER_BODY_DEGREE: f32 = 4.0;
+
+/// Degrees Celsius per point of clothing warmth
+const WARMTH_FACTOR: f32 = 0.2;
+
+/// CDDA bedding warmth units per degree Celsius of body temperature
+const BEDDING_UNITS_PER_DEGREE: f32 = 500.0;
+
+/// The part of the difference with the target temperature that the body closes per minute
+const BODY_FACTOR_PER_MINUTE: f32 = 0.05;
+
+/// The part of the difference with the ambient temperature that items close per interval
+const ITEM_FACTOR_PER_INTERVAL: f32 = 0.2;
+
+/// Items change temperature slowly, so they are updated less often
+const ITEM_INTERVAL_MINUTES: u64 = 10;
+
+/// The cold and hot effects are kept at this duration while they apply
+const EFFECT_MINUTES: u64 = 2;
+
+/// Returns the amount of whole intervals since the last update, and remembers the update
+fn elapsed_intervals(
+    now: Timestamp,
+    last_update: &mut GameplayLocal<Option<Timestamp>>,
+    interval: Duration,
+) -> u64 {
+    let Some(last) = *last_update.get() else {
+        *last_update.get() = Some(now);
+        return 0;
+    };
+    let intervals = (now - last).milliseconds() / interval.milliseconds();
+    if 0 < intervals {
+        *last_update.get() = Some(last + interval * intervals);
+    }
+    intervals
+}
+
+/// Body temperatures follow the felt temperature, resulting in being cold or hot
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_body_temperatures(
+    mut effect_writer: MessageWriter<CharacterEvent<ApplyEffect>>,
+    clock: Clock,
+    mut last_update: GameplayLocal<Option<Timestamp>>,
+    infos: Res<Infos>,
+    player_action_state: Res<State<PlayerActionState>>,
+    ambient: AmbientTemperature,
+    location: Res<LocationCache>,
+    hierarchy: ItemHierarchy,
+    furniture: Query<&Shared<FurnitureInfo>>,
+    terrain: Query<&Shared<TerrainInfo>>,
+    mut characters: Query<
+        (
+            Entity,
+            &Pos,
+            &mut BodyTemperature,
+            &Effects,
+            Option<&Wetness>,
+            Option<&BodyContainers>,
+            Has<Player>,
+        ),
+        With<Life>,
+    >,
+) {
+    let start = Instant::now();
+
+    let minutes = elapsed_intervals(clock.time(), &mut last_update, Duration::MINUTE);
+    if minutes == 0 {
+        return;
+    }
+    let factor = (BODY_FACTOR_PER_MINUTE * minutes as f32).min(1.0);
+    let sleeping = matches!(**player_action_state, PlayerActionState::Sleeping { .. });
+    let fires = ambient.fires();
+
+    for (entity, &pos, mut body_temperature, effects, wetness, body_containers, player) in
+        &mut characters
+    {
+        let wetness = wetness.map_or(0.0, |wetness| f32::from(wetness.percentage()));
+        let warmth = body_containers.map_or(0.0, |body_containers| {
+            hierarchy
+                .items_in_pocket(body_containers.clothing)
+                .map(|item| f32::from(item.common_info.warmth()))
+                .sum()
+        });
+        let felt = felt_temperature(
+            ambient.at(pos, &fires) - ambient.wind_chill(pos),
+            warmth,
+            wetness,
+        );
+        let bedding = if player && sleeping {
+            let near_fire = 0.0 < AmbientTemperature::fire_heat(pos, &fires);
+            bedding_warmth(&location, pos, &furniture, &terrain, near_fire)
+        } else {
+            0.0
+        };
+        body_temperature.approach(body_target(felt, bedding), factor);
+
+        for (effect_id, intensity) in [
+            ("cold", body_temperature.cold()),
+            ("hot", body_temperature.hot()),
+        ] {
+            if let Some(apply_effect) = temperature_effect(&infos, effects, effect_id, intensity) {
+                effect_writer.write(CharacterEvent::new(entity, apply_effect));
+            }
+        }
+    }
+
+    log_if_slow("update_body_temperatures", start);
+}
+
+/// In degrees Celsius, from the ambient temperature including wind chill
+///
+/// Clothing warms, but less when wet. Being wet cools.
+fn felt_temperature(ambient: f32, warmth: f32, wetness: f32) -> f32 {
+    ambient + warmth * WARMTH_FACTOR * (1.0 - wetness / 200.0) - wetness / 10.0
+}
+
+/// The body temperature that the body moves towards, in degrees Celsius
+fn body_target(felt: f32, bedding: f32) -> f32 {
+    BodyTemperature::NORMAL
+        + (felt - COMFORTABLE) / FELT_PER_BODY_DEGREE
+        + bedding / BEDDING_UNITS_PER_DEGREE
+}
+
+/// In CDDA bedding warmth units
+fn bedding_warmth(
+    location: &LocationCache,
+    pos: Pos,
+    furniture: &Query<&Shared<FurnitureInfo>>,
+    terrain: &Query<&Shared<TerrainInfo>>,
+    near_fire: bool,
+) -> f32 {
+    if let Some(furniture_info) = location.get_first(pos, furniture) {
+        let fire_bonus = furniture_info
+            .bonus_fire_warmth_feet
+            .filter(|_| near_fire)
+            .map_or(0.0, f32::from);
+        furniture_info.floor_bedding_warmth.map_or(0.0, f32::from) + fire_bonus
+    } else {
+        location
+            .get_first(pos, terrain)
+            .and_then(|terrain_info| terrain_info.floor_bedding_warmth)
+            .map_or(0.0, f32::from)
+    }
+}
+
+/// Keeps the effect active with the given intensity, or lets it wear off when the intensity decreased
+fn temperature_effect(
+    infos: &Infos,
+    effects: &Effects,
+    effect_id: &str,
+    intensity: u8,
+) -> Option<ApplyEffect> {
+    let active = effects
+        .iter()
+        .find(|active| active.info.id == InfoId::new(effect_id));
+    let (current_intensity, remaining) = active.map_or((0, Duration::ZERO), |active| {
+        (active.intensity, active.remaining)
+    });
+    if intensity == 0 || intensity < current_intensity {
+        return None;
+    }
+
+    let duration = Duration::MINUTE * EFFECT_MINUTES - remaining;
+    if duration == Duration::ZERO && intensity == current_intensity {
+        return None;
+    }
+
+    let info = infos.effects.get(&InfoId::new(effect_id)).ok()?.clone();
+    Some(ApplyEffect {
+        info,
+        duration,
+        intensity,
+    })
+}
+
+/// Items approach the ambient temperature of the tile or character carrying them, and freeze or thaw
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_item_temperatures(
+    mut commands: Commands,
+    clock: Clock,
+    mut last_update: GameplayLocal<Option<Timestamp>>,
+    ambient: AmbientTemperature,
+    hierarchy: ItemHierarchy,
+    root_items: Query<Item, With<ObjectOn>>,
+    characters: Query<(&Pos, &BodyContainers), With<Life>>,
+) {
+    let start = Instant::now();
+
+    let intervals = elapsed_intervals(
+        clock.time(),
+        &mut last_update,
+        Duration::MINUTE * ITEM_INTERVAL_MINUTES,
+    );
+    if intervals == 0 {
+        return;
+    }
+    let factor = (ITEM_FACTOR_PER_INTERVAL * intervals as f32).min(1.0);
+    let fires = ambient.fires();
+
+    let mut updates = Vec::new();
+    for item in &root_items {
+        if let Some(&pos) = item.pos {
+            let celsius = ambient.at(pos, &fires);
+            item_temperature_updates(&hierarchy, &item, celsius, factor, &mut updates);
+        }
+    }
+    for (&pos, body_containers) in &characters {
+        let celsius = ambient.at(pos, &fires);
+        for in_pocket in [body_containers.hands, body_containers.clothing] {
+            for item in hierarchy.items_in_pocket(in_pocket) {
+                item_temperature_updates(&hierarchy, &item, celsius, factor, &mut updates);
+            }
+        }
+    }
+
+    for (item, temperature) in updates {
+        commands.entity(item).insert(temperature);
+    }
+
+    log_if_slow("update_item_temperatures", start);
+}
+
+/// Also handles the contents of the item
+fn item_temperature_updates(
+    hierarchy: &ItemHierarchy,
+    item: &ItemItem,
+    ambient: f32,
+    factor: f32,
+    updates: &mut Vec<(Entity, ItemTemperature)>,
+) {
+    if let Some(temperature) = item.temperature
+        && let Some(freezing_point) = item.common_info.freezing_point()
+    {
+        let updated = temperature.approach(ambient, factor, freezing_point);
+        // Avoid replacing components for insignificant changes
+        if updated.frozen() != temperature.frozen()
+            || updated.celsius().round() as i32 != temperature.celsius().round() as i32
+        {
+            updates.push((item.entity, updated));
+        }
+    }
+
+    for pocket in hierarchy.pockets_in(item) {
+        if let Some(in_pocket) = pocket.in_pocket() {
+            for content in hierarchy.items_in_pocket(in_pocket) {
+                item_temperature_updates(hierarchy, &content, ambient, factor, updates);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod temperature_systems_tests {
+    use super::*;
+
+    #[test]
+    fn clothing_warms_less_when_wet() {
+        let dry = felt_temperature(10.0, 20.0, 0.0);
+        assert!((dry - 14.0).abs() < f32::EPSILON, "{dry}");
+
+        let soaked = felt_temperature(10.0, 20.0, 100.0);
+        assert!((soaked - 2.0).abs() < f32::EPSILON, "{soaked}");
+    }
+
+    #[test]
+    fn comfortable_keeps_normal_body_temperature() {
+        let target = body_target(COMFORTABLE, 0.0);
+        assert!(
+            (target - BodyTemperature::NORMAL).abs() < f32::EPSILON,
+            "{target}"
+        );
+    }
+
+    #[test]
+    fn cold_lowers_body_target() {
+        let target = body_target(COMFORTABLE - 2.0 * FELT_PER_BODY_DEGREE, 0.0);
+        assert!(
+            (target - (BodyTemperature::NORMAL - 2.0)).abs() < f32::EPSILON,
+            "{target}"
+        );
+    }
+
+    #[test]
+    fn bedding_raises_body_target() {
+        let target = body_target(COMFORTABLE, BEDDING_UNITS_PER_DEGREE);
+        assert!(
+            (target - (BodyTemperature::NORMAL + 1.0)).abs() < f32::EPSILON,
+            "{target}"
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use crate::TerrainChange;
+use cdda_json_files::Construction;
+use std::sync::Arc;
+
+/// Finish a construction, replacing the terrain or furniture
+///
+/// The terrain entity of the event is the construction site.
+#[derive(Clone, Debug)]
+pub struct Construct {
+    pub construction: Arc<Construction>,
+}
+
+impl TerrainChange for Construct {}
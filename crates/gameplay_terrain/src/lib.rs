@@ -1,11 +1,15 @@
 mod components;
+mod construct;
 mod events;
 mod local;
 mod plugin;
 mod toggle;
+mod trap;
 
 pub use self::components::{Accessible, OpaqueFloor};
+pub use self::construct::Construct;
 pub use self::events::{TerrainChange, TerrainEvent};
 pub use self::local::LocalTerrain;
 pub use self::plugin::TerrainPlugin;
 pub use self::toggle::Toggle;
+pub use self::trap::{Concealed, Trap, TrapDropSpawner, TrapEffect, TrapTrigger};
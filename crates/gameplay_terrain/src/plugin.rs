@@ -1,4 +1,4 @@
-use crate::{TerrainEvent, Toggle};
+use crate::{Construct, TerrainEvent, Toggle};
 use application_state::ApplicationState;
 use bevy::prelude::{App, Plugin, StateScopedMessagesAppExt as _};
 use gameplay_object::Damage;
@@ -11,5 +11,7 @@ impl Plugin for TerrainPlugin {
             .clear_messages_on_exit::<TerrainEvent<Damage>>(ApplicationState::Gameplay);
         app.add_message::<TerrainEvent<Toggle>>()
             .clear_messages_on_exit::<TerrainEvent<Toggle>>(ApplicationState::Gameplay);
+        app.add_message::<TerrainEvent<Construct>>()
+            .clear_messages_on_exit::<TerrainEvent<Construct>>(ApplicationState::Gameplay);
     }
 }
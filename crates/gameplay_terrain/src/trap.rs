@@ -0,0 +1,135 @@
+use bevy::prelude::{Component, Entity};
+use cdda_json_files::{TrapAction, TrapInfo};
+use gameplay_location::Pos;
+use gameplay_object::DamageType;
+use std::ops::RangeInclusive;
+
+/// A trap on a tile
+#[derive(Debug, Component)]
+#[component(immutable)]
+pub struct Trap {
+    /// Part of the terrain, like the pit of a pit terrain, instead of placed on the submap
+    pub built_in: bool,
+}
+
+/// A trap that the player character has not detected yet
+#[derive(Debug, Component)]
+#[component(immutable)]
+pub struct Concealed;
+
+/// A character that sets off a trap
+#[derive(Clone, Debug)]
+pub struct TrapTrigger {
+    pub trap_entity: Entity,
+}
+
+pub trait TrapDropSpawner {
+    /// Spawns the items that remain of a disarmed trap on its tile
+    fn spawn_trap_drops(&mut self, pos: Pos, trap_info: &TrapInfo);
+}
+
+/// A simplified version of the trap functions of CDDA
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TrapEffect {
+    /// For benign traps, and for actions that are not supported yet
+    Nothing,
+    Alarm {
+        sound: &'static str,
+    },
+    Damage {
+        amount: RangeInclusive<u16>,
+        damage_type: DamageType,
+    },
+    /// Holds the victim until it struggled free
+    Snare {
+        amount: RangeInclusive<u16>,
+        damage_type: DamageType,
+        struggles: u8,
+    },
+    /// The victim has to climb out
+    Pit {
+        amount: RangeInclusive<u16>,
+        damage_type: DamageType,
+        climbs: u8,
+    },
+}
+
+impl TrapEffect {
+    #[must_use]
+    pub const fn of(trap_info: &TrapInfo) -> Self {
+        if trap_info.benign {
+            return Self::Nothing;
+        }
+
+        match trap_info.action {
+            TrapAction::Bubble => Self::Alarm { sound: "Pop!" },
+            TrapAction::Glass => Self::Alarm { sound: "Crunch!" },
+            TrapAction::Board => Self::Damage {
+                amount: 3..=8,
+                damage_type: DamageType::Stab,
+            },
+            TrapAction::Caltrops | TrapAction::CaltropsGlass => Self::Damage {
+                amount: 3..=9,
+                damage_type: DamageType::Stab,
+            },
+            TrapAction::Tripwire => Self::Damage {
+                amount: 1..=4,
+                damage_type: DamageType::Bash,
+            },
+            TrapAction::Crossbow => Self::Damage {
+                amount: 20..=30,
+                damage_type: DamageType::Stab,
+            },
+            TrapAction::Shotgun => Self::Damage {
+                amount: 40..=60,
+                damage_type: DamageType::Bullet,
+            },
+            TrapAction::Blade => Self::Damage {
+                amount: 12..=30,
+                damage_type: DamageType::Cut,
+            },
+            TrapAction::Landmine | TrapAction::Boobytrap => Self::Damage {
+                amount: 30..=60,
+                damage_type: DamageType::Bash,
+            },
+            TrapAction::Beartrap => Self::Snare {
+                amount: 10..=20,
+                damage_type: DamageType::Cut,
+                struggles: 4,
+            },
+            TrapAction::SnareLight => Self::Snare {
+                amount: 0..=0,
+                damage_type: DamageType::Bash,
+                struggles: 2,
+            },
+            TrapAction::SnareHeavy => Self::Snare {
+                amount: 5..=10,
+                damage_type: DamageType::Bash,
+                struggles: 3,
+            },
+            TrapAction::Pit => Self::Pit {
+                amount: 1..=6,
+                damage_type: DamageType::Bash,
+                climbs: 2,
+            },
+            TrapAction::PitSpikes => Self::Pit {
+                amount: 10..=30,
+                damage_type: DamageType::Stab,
+                climbs: 3,
+            },
+            TrapAction::PitGlass => Self::Pit {
+                amount: 10..=25,
+                damage_type: DamageType::Cut,
+                climbs: 3,
+            },
+            TrapAction::Sinkhole => Self::Pit {
+                amount: 1..=6,
+                damage_type: DamageType::Bash,
+                climbs: 3,
+            },
+            TrapAction::None | TrapAction::Cot | TrapAction::Ledge | TrapAction::Other => {
+                Self::Nothing
+            }
+        }
+    }
+}
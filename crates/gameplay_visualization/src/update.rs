@@ -11,7 +11,7 @@ use gameplay_model::Appearance;
 use gameplay_object::Mobile;
 use gameplay_perception::{CurrentlyVisible, CurrentlyVisibleBuilder, LastSeenExt as _};
 use gameplay_player::Player;
-use gameplay_terrain::Accessible;
+use gameplay_terrain::{Accessible, Concealed};
 use std::time::Instant;
 use util::log_if_slow;
 
@@ -103,13 +103,16 @@ pub fn update_visibility(
     elevation_visibility: Res<ElevationVisibility>,
     mut previous_camera_global_transform: GameplayLocal<GlobalTransform>,
     mut last_elevation_visibility: GameplayLocal<ElevationVisibility>,
-    mut items: Query<(
-        Option<&Player>,
-        &Pos,
-        &mut Visibility,
-        &mut LastSeen,
-        Option<&Mobile>,
-    )>,
+    mut items: Query<
+        (
+            Option<&Player>,
+            &Pos,
+            &mut Visibility,
+            &mut LastSeen,
+            Option<&Mobile>,
+        ),
+        Without<Concealed>,
+    >,
     camera: Single<&GlobalTransform, With<Camera>>,
 ) {
     let start = Instant::now();
@@ -157,7 +160,7 @@ pub fn update_visualization_on_item_move(
             Option<&Mobile>,
             &Children,
         ),
-        Changed<Pos>,
+        (Changed<Pos>, Without<Concealed>),
     >,
     child_items: Query<&Appearance, (With<ChildOf>, Without<Pos>)>,
 ) {
@@ -0,0 +1,31 @@
+use bevy::prelude::Component;
+
+/// How wet a character is, from 0 to 100
+///
+/// Mutable component
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Component)]
+pub struct Wetness(u8);
+
+impl Wetness {
+    const MAX: u8 = 100;
+
+    #[must_use]
+    pub const fn percentage(self) -> u8 {
+        self.0
+    }
+
+    pub(crate) fn soak(&mut self, amount: u8) {
+        self.0 = self.0.saturating_add(amount).min(Self::MAX);
+    }
+
+    pub(crate) const fn dry(&mut self, amount: u8) {
+        self.0 = self.0.saturating_sub(amount);
+    }
+}
+
+/// A rain drop or snow flake around the player character
+#[derive(Debug, Component)]
+#[component(immutable)]
+pub(crate) struct WeatherParticle {
+    pub(crate) snow: bool,
+}
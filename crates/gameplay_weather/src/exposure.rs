@@ -0,0 +1,22 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{Query, Res, With};
+use gameplay_location::{Level, LocationCache, Nbor, Pos};
+use gameplay_terrain::OpaqueFloor;
+
+/// Which positions are exposed to the weather
+#[derive(SystemParam)]
+pub struct Exposure<'w, 's> {
+    location: Res<'w, LocationCache>,
+    opaque_floors: Query<'w, 's, (), With<OpaqueFloor>>,
+}
+
+impl Exposure<'_, '_> {
+    /// Above ground, without a floor or roof directly above
+    #[must_use]
+    pub fn exposed(&self, pos: Pos) -> bool {
+        Level::ZERO <= pos.level
+            && pos
+                .raw_nbor(Nbor::Up)
+                .is_none_or(|up| !self.location.any(up, &self.opaque_floors))
+    }
+}
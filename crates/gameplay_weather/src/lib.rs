@@ -0,0 +1,18 @@
+//! The weather, following the climate of the region
+
+mod components;
+mod exposure;
+mod plugin;
+mod systems;
+mod weather;
+
+pub use self::components::Wetness;
+pub use self::exposure::Exposure;
+pub use self::plugin::WeatherPlugin;
+pub use self::weather::Weather;
+
+use self::components::WeatherParticle;
+use self::systems::{
+    create_weather, remove_weather, update_fog, update_light, update_weather,
+    update_weather_particles, update_wetness,
+};
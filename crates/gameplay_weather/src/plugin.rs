@@ -0,0 +1,35 @@
+use crate::{
+    Weather, create_weather, remove_weather, update_fog, update_light, update_weather,
+    update_weather_particles, update_wetness,
+};
+use application_state::ApplicationState;
+use bevy::prelude::{
+    App, IntoScheduleConfigs as _, OnEnter, OnExit, Plugin, SystemCondition as _, Update, in_state,
+    resource_changed, resource_exists,
+};
+use gameplay_resource::GampelayResourceSet;
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(ApplicationState::Gameplay),
+            create_weather.in_set(GampelayResourceSet),
+        );
+        app.add_systems(OnExit(ApplicationState::Gameplay), remove_weather);
+
+        app.add_systems(
+            Update,
+            (
+                update_weather,
+                update_wetness,
+                update_light.run_if(resource_changed::<Weather>),
+                update_fog,
+                update_weather_particles,
+            )
+                .chain()
+                .run_if(in_state(ApplicationState::Gameplay).and_then(resource_exists::<Weather>)),
+        );
+    }
+}
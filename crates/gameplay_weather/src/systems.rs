@@ -0,0 +1,284 @@
+use crate::{Exposure, Weather, WeatherParticle, Wetness};
+use application_state::ApplicationState;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::{
+    AlphaMode, Assets, Camera3d, Color, Commands, Cuboid, DespawnOnExit, DirectionalLight,
+    DistanceFog, Entity, FogFalloff, GlobalTransform, Handle, Local, Mesh, Mesh3d, MeshMaterial3d,
+    Query, Res, ResMut, Single, Sphere, StandardMaterial, Time, Transform, Vec3, With, debug, warn,
+};
+use cdda_json_files::{InfoId, Precipitation};
+use fastrand::f32 as rand_f32;
+use gameplay_cdda::Infos;
+use gameplay_cdda_active_sav::ActiveSav;
+use gameplay_local::GameplayLocal;
+use gameplay_location::{Level, Pos};
+use gameplay_player::Player;
+use gameplay_time::Clock;
+use std::time::Instant;
+use units::{Duration, Timestamp};
+use util::log_if_slow;
+
+/// Half the width of the area around the player character with particles, in meters
+const PARTICLE_RANGE: f32 = 12.0;
+
+/// Where particles appear, above the player character, in meters
+const PARTICLE_HEIGHT: f32 = 8.0;
+
+/// Falling speed, in meters per second
+const RAIN_SPEED: f32 = 8.0;
+
+/// Falling speed, in meters per second
+const SNOW_SPEED: f32 = 1.0;
+
+#[derive(Debug)]
+pub(super) struct ParticleAssets {
+    rain: (Handle<Mesh>, Handle<StandardMaterial>),
+    snow: (Handle<Mesh>, Handle<StandardMaterial>),
+}
+
+impl ParticleAssets {
+    fn new(meshes: &mut Assets<Mesh>, materials: &mut Assets<StandardMaterial>) -> Self {
+        let material = |color| StandardMaterial {
+            base_color: color,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..StandardMaterial::default()
+        };
+        Self {
+            rain: (
+                meshes.add(Cuboid::new(0.02, 0.4, 0.02)),
+                materials.add(material(Color::srgba(0.6, 0.7, 0.9, 0.5))),
+            ),
+            snow: (
+                meshes.add(Sphere::new(0.04)),
+                materials.add(material(Color::srgba(1.0, 1.0, 1.0, 0.9))),
+            ),
+        }
+    }
+
+    const fn get(&self, snow: bool) -> &(Handle<Mesh>, Handle<StandardMaterial>) {
+        if snow { &self.snow } else { &self.rain }
+    }
+}
+
+/// The weather is seeded from the calendar of the save, so it is the same when loading a world again
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn create_weather(
+    mut commands: Commands,
+    infos: Res<Infos>,
+    active_sav: Res<ActiveSav>,
+) {
+    let start = Instant::now();
+
+    let sav = active_sav.sav();
+    let seed = sav.calendar_start.rotate_left(32) ^ sav.game_start;
+    let weather = infos
+        .region_settings
+        .get(&InfoId::new("default"))
+        .ok()
+        .and_then(|region_settings| region_settings.weather.as_ref())
+        .and_then(|region_weather| Weather::new(seed, region_weather));
+    if let Some(weather) = weather {
+        commands.insert_resource(weather);
+    } else {
+        warn!("No weather types found for the default region");
+    }
+
+    log_if_slow("create_weather", start);
+}
+
+pub(super) fn remove_weather(
+    mut commands: Commands,
+    camera: Option<Single<Entity, With<Camera3d>>>,
+) {
+    commands.remove_resource::<Weather>();
+    if let Some(camera) = camera {
+        commands.entity(*camera).remove::<DistanceFog>();
+    }
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_weather(clock: Clock, mut weather: ResMut<Weather>) {
+    let start = Instant::now();
+
+    let now = clock.time();
+    // Avoid change detection when the weather stays the same
+    if weather.due(now) {
+        weather.update(now);
+        debug!("Weather: {:?}", weather.info().id);
+    }
+
+    log_if_slow("update_weather", start);
+}
+
+/// Characters get wet from rain and snow when exposed, and dry otherwise
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_wetness(
+    clock: Clock,
+    mut last_update: GameplayLocal<Option<Timestamp>>,
+    weather: Res<Weather>,
+    exposure: Exposure,
+    mut characters: Query<(&Pos, &mut Wetness)>,
+) {
+    let start = Instant::now();
+
+    let now = clock.time();
+    let Some(last) = *last_update.get() else {
+        *last_update.get() = Some(now);
+        return;
+    };
+    let minutes = (now - last).milliseconds() / Duration::MINUTE.milliseconds();
+    if minutes == 0 {
+        return;
+    }
+    *last_update.get() = Some(last + Duration::MINUTE * minutes);
+    let minutes = minutes.min(u64::from(u8::MAX)) as u8;
+
+    let info = weather.info();
+    let soak_per_minute: u8 = match info.precip {
+        Precipitation::None => 0,
+        Precipitation::VeryLight => 1,
+        Precipitation::Light => 3,
+        Precipitation::Heavy => 8,
+    } / if info.snow() { 2 } else { 1 };
+
+    for (&pos, mut wetness) in &mut characters {
+        if 0 < soak_per_minute && exposure.exposed(pos) {
+            wetness.soak(soak_per_minute.saturating_mul(minutes));
+        } else if 0 < wetness.percentage() {
+            wetness.dry(minutes);
+        }
+    }
+
+    log_if_slow("update_wetness", start);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_light(weather: Res<Weather>, mut light: Single<&mut DirectionalLight>) {
+    let start = Instant::now();
+
+    light.illuminance = weather.illuminance();
+
+    log_if_slow("update_light", start);
+}
+
+/// Fog starts at the player character, so the distance to the camera is taken into account.
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_fog(
+    mut commands: Commands,
+    weather: Res<Weather>,
+    mut last_fog: GameplayLocal<Option<(u16, u16)>>,
+    player_pos: Single<&Pos, With<Player>>,
+    camera: Single<(Entity, &GlobalTransform), With<Camera3d>>,
+) {
+    let start = Instant::now();
+
+    let (camera_entity, camera_transform) = *camera;
+    let fog = weather
+        .fog_visibility()
+        .filter(|_| Level::ZERO <= player_pos.level)
+        .map(|visibility| {
+            let camera_distance = camera_transform.translation().distance(player_pos.vec3());
+            (
+                camera_distance.round() as u16,
+                (camera_distance + visibility).round() as u16,
+            )
+        });
+
+    if *last_fog.get() != fog {
+        *last_fog.get() = fog;
+
+        if let Some((fog_start, fog_end)) = fog {
+            commands.entity(camera_entity).insert(DistanceFog {
+                color: Color::srgb(0.55, 0.57, 0.6),
+                directional_light_color: Color::NONE,
+                falloff: FogFalloff::Linear {
+                    start: f32::from(fog_start),
+                    end: f32::from(fog_end),
+                },
+                ..DistanceFog::default()
+            });
+        } else {
+            commands.entity(camera_entity).remove::<DistanceFog>();
+        }
+    }
+
+    log_if_slow("update_fog", start);
+}
+
+/// Rain drops and snow flakes fall around the player character, when exposed
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_weather_particles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_assets: Local<Option<ParticleAssets>>,
+    time: Res<Time>,
+    weather: Res<Weather>,
+    exposure: Exposure,
+    player_pos: Single<&Pos, With<Player>>,
+    mut particles: Query<(Entity, &WeatherParticle, &mut Transform)>,
+) {
+    let start = Instant::now();
+
+    let info = weather.info();
+    let amount = if exposure.exposed(**player_pos) {
+        match info.precip {
+            Precipitation::None => 0,
+            Precipitation::VeryLight => 50,
+            Precipitation::Light => 150,
+            Precipitation::Heavy => 400,
+        }
+    } else {
+        0
+    };
+    let snow = info.snow();
+    let speed = if snow { SNOW_SPEED } else { RAIN_SPEED };
+    let center = player_pos.vec3();
+
+    let mut kept = 0;
+    for (entity, particle, mut transform) in &mut particles {
+        if particle.snow != snow || amount <= kept {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        kept += 1;
+
+        transform.translation.y -= speed * time.delta_secs();
+        let offset = transform.translation - center;
+        if offset.y < 0.0 || PARTICLE_RANGE < offset.x.abs() || PARTICLE_RANGE < offset.z.abs() {
+            transform.translation = particle_position(center, PARTICLE_HEIGHT);
+        }
+    }
+
+    if kept < amount {
+        let (mesh, material) = particle_assets
+            .get_or_insert_with(|| ParticleAssets::new(&mut meshes, &mut materials))
+            .get(snow);
+        for _ in kept..amount {
+            commands.spawn((
+                WeatherParticle { snow },
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(particle_position(
+                    center,
+                    rand_f32() * PARTICLE_HEIGHT,
+                )),
+                // Only shown with the tiles, not with the map
+                RenderLayers::layer(1),
+                DespawnOnExit(ApplicationState::Gameplay),
+            ));
+        }
+    }
+
+    log_if_slow("update_weather_particles", start);
+}
+
+fn particle_position(center: Vec3, height: f32) -> Vec3 {
+    center
+        + Vec3::new(
+            (2.0 * rand_f32() - 1.0) * PARTICLE_RANGE,
+            height,
+            (2.0 * rand_f32() - 1.0) * PARTICLE_RANGE,
+        )
+}
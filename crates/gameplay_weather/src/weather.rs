@@ -0,0 +1,175 @@
+use bevy::prelude::Resource;
+use cdda_json_files::{RegionWeather, WeatherInfo, WeatherReading};
+use fastrand::Rng;
+use gameplay_location::VisionDistance;
+use std::sync::Arc;
+use units::{Duration, Timestamp};
+
+/// How long the weather type lasts, when the weather type does not specify it
+const DEFAULT_WEATHER_MINUTES: u64 = 5;
+
+/// The current weather, that changes over time following the climate of the region
+///
+/// The weather is a state machine: when the current weather type expires, the last weather type of the region that applies to the new [`WeatherReading`] is picked.
+#[derive(Debug, Resource)]
+pub struct Weather {
+    /// Derived from the calendar of the save
+    seed: u64,
+
+    base_temperature: f32,
+    base_humidity: f32,
+    base_pressure: f32,
+    base_wind: f32,
+
+    /// Not empty
+    weather_types: Vec<Arc<WeatherInfo>>,
+
+    current: Arc<WeatherInfo>,
+    reading: WeatherReading,
+
+    /// `None` until the first update
+    until: Option<Timestamp>,
+}
+
+impl Weather {
+    /// Full daylight in lux, without a light modifier
+    pub const CLEAR_ILLUMINANCE: f32 = 10_000.0;
+
+    /// `None` when the region has no weather types
+    pub(crate) fn new(seed: u64, region_weather: &RegionWeather) -> Option<Self> {
+        let weather_types = region_weather
+            .weather_types
+            .iter()
+            .filter_map(|weather_type| weather_type.get_option())
+            .collect::<Vec<_>>();
+        let current = weather_types.first()?.clone();
+
+        Some(Self {
+            seed,
+            base_temperature: region_weather.base_temperature,
+            base_humidity: region_weather.base_humidity,
+            base_pressure: region_weather.base_pressure,
+            base_wind: region_weather.base_wind,
+            weather_types,
+            current,
+            reading: WeatherReading {
+                temperature: region_weather.base_temperature,
+                humidity: region_weather.base_humidity,
+                pressure: region_weather.base_pressure,
+                windpower: region_weather.base_wind,
+                daylight: true,
+            },
+            until: None,
+        })
+    }
+
+    #[must_use]
+    pub fn info(&self) -> &WeatherInfo {
+        &self.current
+    }
+
+    #[must_use]
+    pub const fn reading(&self) -> &WeatherReading {
+        &self.reading
+    }
+
+    /// The yearly average, in degrees Celsius
+    #[must_use]
+    pub const fn base_temperature(&self) -> f32 {
+        self.base_temperature
+    }
+
+    /// Between 0.0 (dark) and 1.0 (clear)
+    #[must_use]
+    pub fn light_factor(&self) -> f32 {
+        ((100.0 + f32::from(self.current.light_modifier)) / 100.0).clamp(0.0, 1.0)
+    }
+
+    #[must_use]
+    pub fn illuminance(&self) -> f32 {
+        Self::CLEAR_ILLUMINANCE * self.light_factor()
+    }
+
+    /// Between 0.0 (blind) and 1.0 (clear)
+    #[must_use]
+    pub fn sight_factor(&self) -> f32 {
+        1.0 / self.current.sight_penalty.max(1.0)
+    }
+
+    /// In meters, `None` when the weather does not limit the view
+    #[must_use]
+    pub fn fog_visibility(&self) -> Option<f32> {
+        (self.sight_factor() < 0.99)
+            .then(|| VisionDistance::MAX_VISION_TILES as f32 * self.sight_factor())
+    }
+
+    pub(crate) fn due(&self, now: Timestamp) -> bool {
+        self.until.is_none_or(|until| until <= now)
+    }
+
+    /// Picks the next weather type, and how long it lasts
+    pub(crate) fn update(&mut self, now: Timestamp) {
+        self.reading = self.read(now);
+
+        let current_id = &self.current.id;
+        self.current = self
+            .weather_types
+            .iter()
+            .rev()
+            .find(|weather_type| weather_type.applies(&self.reading, current_id))
+            .unwrap_or(&self.weather_types[0])
+            .clone();
+
+        let min = self.current.duration_min.milliseconds();
+        let max = self.current.duration_max.milliseconds().max(min);
+        let duration =
+            Duration::MILLISECOND * Rng::with_seed(self.seed ^ now.turn()).u64(min..=max);
+        self.until = Some(
+            now + if duration == Duration::ZERO {
+                Duration::MINUTE * DEFAULT_WEATHER_MINUTES
+            } else {
+                duration
+            },
+        );
+    }
+
+    /// Seasonal and daily cycles, with smooth random variations
+    fn read(&self, now: Timestamp) -> WeatherReading {
+        let hours = now.turn() as f32 / 3600.0;
+        let seasonal = 2.0 * now.solar_summer() - 1.0;
+        let daily = 2.0 * now.sunlight_percentage() - 1.0;
+
+        WeatherReading {
+            temperature: self.base_temperature
+                + 12.0 * seasonal
+                + 4.0 * daily
+                + 5.0 * self.noise(0, hours, 24.0)
+                + 2.0 * self.noise(1, hours, 3.0),
+            humidity: (self.base_humidity
+                + 25.0 * self.noise(2, hours, 12.0)
+                + 10.0 * self.noise(3, hours, 2.0))
+            .clamp(0.0, 100.0),
+            pressure: self.base_pressure
+                + 20.0 * self.noise(4, hours, 36.0)
+                + 5.0 * self.noise(5, hours, 4.0),
+            windpower: (self.base_wind * (1.0 + self.noise(6, hours, 8.0))).max(0.0),
+            daylight: 0.0 < now.sunlight_percentage(),
+        }
+    }
+
+    /// Smooth value between -1.0 and 1.0, that changes noticably every period
+    fn noise(&self, channel: u64, hours: f32, period: f32) -> f32 {
+        let position = hours / period;
+        let index = position.floor();
+        let fraction = position - index;
+        let smooth = fraction * fraction * (3.0 - 2.0 * fraction);
+
+        let knot = |index: u64| {
+            let seed = self.seed ^ (channel << 56) ^ index;
+            2.0 * Rng::with_seed(seed).f32() - 1.0
+        };
+        let before = knot(index as u64);
+        let after = knot(index as u64 + 1);
+        before + (after - before) * smooth
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{NoStairs, WalkingCost};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::{Entity, Query, Res, With, Without, warn};
-use cdda_json_files::MoveCost;
+use cdda_json_files::{CharacterInfo, MoveCost, TrapInfo};
+use gameplay_common::Shared;
 use gameplay_item::{Amount, Item, ItemItem};
 use gameplay_location::{
     HorizontalDirection, Level, LevelOffset, LocationCache, Nbor, NborDistance, Pos, PosOffset,
@@ -9,7 +10,7 @@ use gameplay_location::{
 };
 use gameplay_object::{Closeable, Corpse, Hurdle, Life, Obstacle, Opaque, Openable};
 use gameplay_object::{ObjectName, StandardIntegrity};
-use gameplay_terrain::{Accessible, OpaqueFloor};
+use gameplay_terrain::{Accessible, Concealed, OpaqueFloor, Trap};
 use std::cmp::Ordering;
 
 #[must_use]
@@ -30,14 +31,32 @@ pub struct Envir<'w, 's> {
     closeables: Query<'w, 's, (Entity, &'static ObjectName), With<Closeable>>,
     stairs_up: Query<'w, 's, &'static Pos, With<StairsUp>>,
     stairs_down: Query<'w, 's, &'static Pos, With<StairsDown>>,
-    terrain: Query<'w, 's, &'static ObjectName, (Without<Life>, Without<Corpse>, Without<Amount>)>,
+    terrain: Query<
+        'w,
+        's,
+        &'static ObjectName,
+        (
+            Without<Life>,
+            Without<Corpse>,
+            Without<Amount>,
+            Without<Trap>,
+        ),
+    >,
     obstacles: Query<'w, 's, &'static ObjectName, With<Obstacle>>,
     opaques: Query<'w, 's, &'static ObjectName, With<Opaque>>,
     opaque_floors: Query<'w, 's, &'static OpaqueFloor>,
     characters: Query<'w, 's, (Entity, &'static ObjectName), With<Life>>,
     smashables: Query<'w, 's, Entity, (With<StandardIntegrity>, Without<Corpse>)>,
     pulpables: Query<'w, 's, Entity, (With<StandardIntegrity>, With<Corpse>)>,
+    butcherables: Query<'w, 's, (Entity, &'static Shared<CharacterInfo>), With<Corpse>>,
     items: Query<'w, 's, Item>,
+    traps: Query<'w, 's, Entity, With<Trap>>,
+    detected_traps: Query<
+        'w,
+        's,
+        (Entity, &'static ObjectName, &'static Shared<TrapInfo>),
+        (With<Trap>, Without<Concealed>),
+    >,
 }
 
 impl<'w, 's> Envir<'w, 's> {
@@ -160,6 +179,17 @@ impl<'w, 's> Envir<'w, 's> {
         self.location.get_first(pos, &self.terrain)
     }
 
+    /// Includes traps that the player character has not detected
+    #[must_use]
+    pub fn find_trap(&self, pos: Pos) -> Option<Entity> {
+        self.location.get_first(pos, &self.traps)
+    }
+
+    #[must_use]
+    pub fn find_detected_trap(&self, pos: Pos) -> Option<(Entity, &ObjectName, &Shared<TrapInfo>)> {
+        self.location.get_first(pos, &self.detected_traps)
+    }
+
     #[must_use]
     pub fn find_obstacle(&self, pos: Pos) -> Option<&ObjectName> {
         self.location.get_first(pos, &self.obstacles)
@@ -190,6 +220,15 @@ impl<'w, 's> Envir<'w, 's> {
         self.location.get_first(pos, &self.pulpables)
     }
 
+    /// Corpses with something to harvest
+    #[must_use]
+    pub fn find_butcherable(&self, pos: Pos) -> Option<(Entity, &Shared<CharacterInfo>)> {
+        self.location
+            .all(pos)
+            .filter_map(|&entity| self.butcherables.get(entity).ok())
+            .find(|(_, character_info)| character_info.harvest.get().is_some())
+    }
+
     #[must_use]
     pub fn find_item(&self, pos: Pos) -> Option<ItemItem<'_, '_>> {
         self.location.get_first(pos, &self.items)
@@ -303,9 +342,12 @@ impl<'w, 's> Envir<'w, 's> {
         .map(move |(nbor, _npos, _distance)| nbor)
     }
 
+    /// Terrain, furniture, or else items
     pub fn nbors_to_smash(&'s self, pos: Pos) -> impl Iterator<Item = Nbor> + use<'s> {
-        self.nbors_if(pos, move |nbor| self.find_smashable(nbor).is_some())
-            .map(move |(nbor, _npos, _distance)| nbor)
+        self.nbors_if(pos, move |nbor| {
+            self.find_smashable(nbor).is_some() || self.find_item(nbor).is_some()
+        })
+        .map(move |(nbor, _npos, _distance)| nbor)
     }
 
     pub fn directions_to_pulp(
@@ -316,6 +358,14 @@ impl<'w, 's> Envir<'w, 's> {
             .filter_map(move |(nbor, _npos, _distance)| HorizontalDirection::try_from(nbor).ok())
     }
 
+    pub fn directions_to_butcher(
+        &'s self,
+        pos: Pos,
+    ) -> impl Iterator<Item = HorizontalDirection> + use<'s> {
+        self.nbors_if(pos, move |nbor| self.find_butcherable(nbor).is_some())
+            .filter_map(move |(nbor, _npos, _distance)| HorizontalDirection::try_from(nbor).ok())
+    }
+
     pub fn directions_to_close(
         &'s self,
         pos: Pos,
@@ -324,6 +374,14 @@ impl<'w, 's> Envir<'w, 's> {
             .filter_map(move |(nbor, _npos, _distance)| HorizontalDirection::try_from(nbor).ok())
     }
 
+    pub fn directions_to_disarm(
+        &'s self,
+        pos: Pos,
+    ) -> impl Iterator<Item = HorizontalDirection> + use<'s> {
+        self.nbors_if(pos, move |nbor| self.find_detected_trap(nbor).is_some())
+            .filter_map(move |(nbor, _npos, _distance)| HorizontalDirection::try_from(nbor).ok())
+    }
+
     pub fn directions_to_craft(
         &'s self,
         pos: Pos,
@@ -0,0 +1,130 @@
+use crate::Noise;
+use bevy::prelude::Resource;
+use cdda_json_files::{OvermapMonsterGroup, SubzoneOffset};
+use fastrand::i32 as rand_i32;
+use gameplay_cdda::OvermapAsset;
+use gameplay_location::{Overzone, SubzoneLevel, ZoneLevel};
+
+/// Hordes hear noise from further away than characters
+const HEARING_FACTOR: i32 = 4;
+
+/// The interest of a horde in its target drops with every move
+const INTEREST_DECAY: u8 = 5;
+
+/// Monster groups on the overmap that are not spawned yet
+///
+/// Monster groups spawn when their subzone level spawns. Hordes move between subzone levels, towards noise.
+#[derive(Debug, Default, Resource)]
+pub struct Hordes {
+    groups: Vec<Horde>,
+    loaded_overzones: Vec<Overzone>,
+}
+
+impl Hordes {
+    pub fn load(&mut self, overzone: Overzone, overmap: &OvermapAsset) {
+        if !self.loaded_overzones.contains(&overzone) {
+            for (group, offsets) in &overmap.0.monster_groups {
+                self.groups.extend(offsets.iter().map(|offset| Horde {
+                    group: group.clone(),
+                    subzone_level: SubzoneLevel::from_offset(overzone, *offset),
+                    target: None,
+                }));
+            }
+            self.loaded_overzones.push(overzone);
+        }
+    }
+
+    /// Removes the matching groups, to spawn them
+    pub fn take(
+        &mut self,
+        mut matches: impl FnMut(SubzoneLevel) -> bool,
+    ) -> Vec<(SubzoneLevel, OvermapMonsterGroup)> {
+        self.groups
+            .extract_if(.., |horde| matches(horde.subzone_level))
+            .map(|horde| (horde.subzone_level, horde.group))
+            .collect()
+    }
+
+    /// Hordes within hearing range start moving towards the noise
+    pub fn hear(&mut self, noise: &Noise) {
+        let source = SubzoneLevel::from(noise.pos);
+        let range = i32::from(noise.volume) * HEARING_FACTOR;
+        for horde in self.groups.iter_mut().filter(|horde| horde.group.horde) {
+            let distance = horde.distance(source) * SubzoneLevel::SIZE;
+            if source.level == horde.subzone_level.level && distance < range {
+                let interest = (100 * (range - distance) / range) as u8;
+                if horde.group.interest <= interest {
+                    horde.group.interest = interest;
+                    horde.target = Some(source);
+                }
+            }
+        }
+    }
+
+    /// Moves every horde one subzone level, within the loaded overzones
+    ///
+    /// Hordes without a target wander around. This is a single step, so call it once per elapsed move interval.
+    pub fn advance(&mut self) {
+        for horde in self.groups.iter_mut().filter(|horde| horde.group.horde) {
+            let (dx, dz) = if let Some(target) = horde.target {
+                horde.group.interest = horde.group.interest.saturating_sub(INTEREST_DECAY);
+                (
+                    (target.x - horde.subzone_level.x).signum(),
+                    (target.z - horde.subzone_level.z).signum(),
+                )
+            } else {
+                (rand_i32(-1..=1), rand_i32(-1..=1))
+            };
+
+            let next = SubzoneLevel {
+                x: horde.subzone_level.x + dx,
+                z: horde.subzone_level.z + dz,
+                ..horde.subzone_level
+            };
+            if self
+                .loaded_overzones
+                .contains(&Overzone::from(ZoneLevel::from(next).zone))
+            {
+                horde.subzone_level = next;
+            }
+
+            if horde.target == Some(horde.subzone_level) || horde.group.interest == 0 {
+                horde.target = None;
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn loaded_overzones(&self) -> &[Overzone] {
+        &self.loaded_overzones
+    }
+
+    /// The monster groups of the overzone, in the format of [`cdda_json_files::Overmap::monster_groups`]
+    #[must_use]
+    pub fn overmap_groups(
+        &self,
+        overzone: Overzone,
+    ) -> Vec<(&OvermapMonsterGroup, Vec<SubzoneOffset>)> {
+        self.groups
+            .iter()
+            .filter(|horde| Overzone::from(ZoneLevel::from(horde.subzone_level).zone) == overzone)
+            .map(|horde| (&horde.group, vec![SubzoneOffset::from(horde.subzone_level)]))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Horde {
+    group: OvermapMonsterGroup,
+    subzone_level: SubzoneLevel,
+    target: Option<SubzoneLevel>,
+}
+
+impl Horde {
+    /// In subzone levels, ignoring the level
+    fn distance(&self, other: SubzoneLevel) -> i32 {
+        (self.subzone_level.x - other.x)
+            .abs()
+            .max((self.subzone_level.z - other.z).abs())
+    }
+}
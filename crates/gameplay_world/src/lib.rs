@@ -1,13 +1,17 @@
 //! The gameplay world environment
 
 mod envir;
+mod hordes;
 mod messages;
+mod noise;
 mod plugin;
 mod walking_cost;
 mod zone_level_ids;
 
 pub use envir::{Collision, Envir};
+pub use hordes::Hordes;
 pub use messages::NoStairs;
+pub use noise::Noise;
 pub use plugin::GameplayWorldPlugin;
 pub use walking_cost::WalkingCost;
 pub use zone_level_ids::ZoneLevelIds;
@@ -0,0 +1,18 @@
+use bevy::prelude::Message;
+use gameplay_location::Pos;
+
+/// A loud sound that hordes on the overmap may hear
+#[must_use]
+#[derive(Clone, Copy, Debug, Message)]
+pub struct Noise {
+    pub pos: Pos,
+
+    /// Roughly the distance in tiles where the noise is heard
+    pub volume: u16,
+}
+
+impl Noise {
+    pub const fn new(pos: Pos, volume: u16) -> Self {
+        Self { pos, volume }
+    }
+}
@@ -1,11 +1,18 @@
-use crate::ZoneLevelIds;
-use bevy::prelude::{App, Plugin};
+use crate::{Hordes, Noise, ZoneLevelIds};
+use application_state::ApplicationState;
+use bevy::prelude::{App, Plugin, StateScopedMessagesAppExt as _};
 use gameplay_resource::gameplay_resource_plugin;
 
 pub struct GameplayWorldPlugin;
 
 impl Plugin for GameplayWorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((gameplay_resource_plugin::<ZoneLevelIds>,));
+        app.add_plugins((
+            gameplay_resource_plugin::<ZoneLevelIds>,
+            gameplay_resource_plugin::<Hordes>,
+        ));
+
+        app.add_message::<Noise>()
+            .clear_messages_on_exit::<Noise>(ApplicationState::Gameplay);
     }
 }
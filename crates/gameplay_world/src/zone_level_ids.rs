@@ -1,9 +1,7 @@
 use bevy::{platform::collections::HashMap, prelude::Resource};
-use cdda_json_files::{FlatVec, InfoId, Overmap, OvermapLevel, OvermapTerrainInfo};
+use cdda_json_files::{InfoId, Overmap, OvermapTerrainInfo};
 use gameplay_cdda::{OvermapAsset, RepetitionBlockExt as _};
 use gameplay_location::{Level, Overzone, ZoneLevel};
-use serde_json::Value as JsonValue;
-use std::sync::OnceLock;
 
 #[derive(Default, Resource)]
 pub struct ZoneLevelIds {
@@ -33,47 +31,7 @@ impl ZoneLevelIds {
     }
 
     pub fn create_missing(&mut self, overzone: Overzone) {
-        let fallback = OvermapAsset(Overmap {
-            layers: [
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("deep_rock")),
-                OvermapLevel::all(InfoId::new("empty_rock")),
-                OvermapLevel::all(InfoId::new("empty_rock")),
-                OvermapLevel::all(InfoId::new("solid_earth")),
-                OvermapLevel::all(InfoId::new("field")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-                OvermapLevel::all(InfoId::new("open_air")),
-            ],
-            region_id: JsonValue::Null,
-            monster_groups: JsonValue::Null,
-            cities: JsonValue::Null,
-            connections_out: JsonValue::Null,
-            radios: JsonValue::Null,
-            monster_map: FlatVec(Vec::new()),
-            tracked_vehicles: JsonValue::Null,
-            scent_traces: JsonValue::Null,
-            npcs: JsonValue::Null,
-            camps: JsonValue::Null,
-            overmap_special_placements: JsonValue::Null,
-            mapgen_arg_storage: None,
-            mapgen_arg_index: None,
-            joins_used: None,
-            predecessors: None,
-            linked: OnceLock::new(),
-        });
+        let fallback = OvermapAsset(Overmap::plain());
 
         self.load(overzone, &fallback);
     }
@@ -1,13 +1,15 @@
 mod components;
 mod load_error;
 mod plugin;
+mod save_slot;
 mod systems;
 
 pub use crate::plugin::MainMenuPlugin;
 
 use crate::components::{LoadButtonArea, LogMessageField, LogMessageWrapper};
 use crate::load_error::LoadError;
+use crate::save_slot::SaveSlot;
 use crate::systems::{
-    FoundSav, create_load_systems, create_main_menu_key_bindings, create_quit_system,
+    FoundSav, create_load_systems, create_main_menu_key_bindings, create_main_menu_systems,
     enter_main_menu, spawn_main_menu, update_sav_files,
 };
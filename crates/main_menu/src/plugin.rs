@@ -1,5 +1,5 @@
 use crate::{
-    FoundSav, create_load_systems, create_main_menu_key_bindings, create_quit_system,
+    FoundSav, create_load_systems, create_main_menu_key_bindings, create_main_menu_systems,
     enter_main_menu, spawn_main_menu, update_sav_files,
 };
 use application_state::ApplicationState;
@@ -18,7 +18,7 @@ impl Plugin for MainMenuPlugin {
         app.add_systems(
             OnEnter(ApplicationState::MainMenu),
             (
-                create_quit_system.pipe(spawn_main_menu),
+                create_main_menu_systems.pipe(spawn_main_menu),
                 create_main_menu_key_bindings,
             ),
         );
@@ -0,0 +1,103 @@
+use base64::{Engine as _, engine::general_purpose::STANDARD as base64};
+use cdda_json_files::WorldMods;
+use serde_json::from_str as from_json_str;
+use std::fmt::Write as _;
+use std::fs::{metadata, read_to_string};
+use std::path::{Path, PathBuf};
+use time::OffsetDateTime;
+use util::AssetPaths;
+
+/// A save of a character in a world
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct SaveSlot {
+    /// Relative to [`AssetPaths::save`]
+    pub(super) path: PathBuf,
+    world: String,
+    character: String,
+    modified: Option<OffsetDateTime>,
+    mods: Vec<String>,
+}
+
+impl SaveSlot {
+    pub(super) fn new(path: PathBuf) -> Self {
+        let world = path.parent().expect("World required").display().to_string();
+        let character = decode_character(&path);
+        let modified = metadata(AssetPaths::save().join(&path))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(OffsetDateTime::from);
+        let mods = read_mods(&AssetPaths::save().join(&world));
+        Self {
+            path,
+            world,
+            character,
+            modified,
+            mods,
+        }
+    }
+
+    /// Newest first, then alphabetically
+    pub(super) fn sort(slots: &mut [Self]) {
+        slots.sort_by(|a, b| {
+            b.modified
+                .cmp(&a.modified)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+    }
+
+    pub(super) fn label(&self) -> String {
+        let Self {
+            world, character, ..
+        } = self;
+        let mut label = format!("{character} in {world}");
+        if let Some(modified) = self.modified {
+            write!(
+                label,
+                "\n{}-{:02}-{:02} {:02}:{:02} UTC",
+                modified.year(),
+                u8::from(modified.month()),
+                modified.day(),
+                modified.hour(),
+                modified.minute()
+            )
+            .expect("Writing should succeed");
+        }
+        if !self.mods.is_empty() {
+            write!(label, "\nMods: {}", self.mods.join(", ")).expect("Writing should succeed");
+        }
+        label
+    }
+}
+
+/// The ids of the mods in `mods.json`, except the base game
+fn read_mods(world_path: &Path) -> Vec<String> {
+    read_to_string(world_path.join("mods.json"))
+        .ok()
+        .and_then(|contents| from_json_str::<WorldMods>(&contents).ok())
+        .map(|world_mods| {
+            world_mods
+                .0
+                .iter()
+                .map(|id| String::from(&*id.fallback_name()))
+                .filter(|id| id != "dda")
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Sav files are named after their character: `#<base64 name>.sav`
+fn decode_character(path: &Path) -> String {
+    let encoded_character = path
+        .file_name()
+        .expect("Filename required")
+        .to_str()
+        .expect("Valid utf-8 filename required")
+        .strip_prefix('#')
+        .expect("Expected # prefix")
+        .strip_suffix(".sav")
+        .expect("Expected .sav suffix");
+    let decoded_character = base64
+        .decode(encoded_character)
+        .expect("Valid base64 required");
+    String::from_utf8(decoded_character).expect("Valid utf8 required")
+}
@@ -1,6 +1,5 @@
-use crate::{LoadButtonArea, LoadError, LogMessageField, LogMessageWrapper};
+use crate::{LoadButtonArea, LoadError, LogMessageField, LogMessageWrapper, SaveSlot};
 use application_state::ApplicationState;
-use base64::{Engine as _, engine::general_purpose::STANDARD as base64};
 use bevy::ecs::{spawn::SpawnIter, system::SystemId};
 use bevy::prelude::{
     AlignContent, AlignItems, AppExit, Bundle, Children, Commands, DespawnOnExit, Display, Entity,
@@ -10,14 +9,14 @@ use bevy::prelude::{
 };
 use gameplay_cdda_active_sav::ActiveSav;
 use gameplay_local::GameplayLocal;
+use gameplay_save::is_backup;
 use glob::glob;
 use hud::{
     BAD_TEXT_COLOR, ButtonBuilder, Fonts, GOOD_TEXT_COLOR, HARD_TEXT_COLOR, LARGE_SPACING,
     MEDIUM_SPACING, PANEL_COLOR, trigger_button_action,
 };
 use manual::ManualSection;
-use std::path::{Path, PathBuf};
-use std::{str::from_utf8, time::Instant};
+use std::{path::PathBuf, time::Instant};
 use util::{AssetPaths, log_if_slow};
 
 const FULL_WIDTH: f32 = 720.0;
@@ -43,13 +42,19 @@ pub(super) fn create_load_systems(world: &mut World) -> LoadSystems {
 }
 
 #[derive(Debug)]
-pub(super) struct QuitSystem(SystemId<(), ()>);
+pub(super) struct MainMenuSystems {
+    new_game: SystemId<(), ()>,
+    quit: SystemId<(), ()>,
+}
 
-pub(super) fn create_quit_system(world: &mut World) -> QuitSystem {
-    QuitSystem(world.register_system_cached(quit))
+pub(super) fn create_main_menu_systems(world: &mut World) -> MainMenuSystems {
+    MainMenuSystems {
+        new_game: world.register_system_cached(new_game),
+        quit: world.register_system_cached(quit),
+    }
 }
 
-pub(super) fn spawn_main_menu(In(quit_system): In<QuitSystem>, mut commands: Commands) {
+pub(super) fn spawn_main_menu(In(systems): In<MainMenuSystems>, mut commands: Commands) {
     commands.spawn((
         Node {
             flex_direction: FlexDirection::Column,
@@ -66,7 +71,13 @@ pub(super) fn spawn_main_menu(In(quit_system): In<QuitSystem>, mut commands: Com
             tagline(),
             load_button_area(),
             notification_area(),
-            quit_button(&quit_system),
+            (
+                Node {
+                    column_gap: MEDIUM_SPACING,
+                    ..Node::default()
+                },
+                children![new_game_button(&systems), quit_button(&systems)],
+            ),
         ],
     ));
 }
@@ -150,8 +161,15 @@ fn notification_area() -> impl Bundle {
     )
 }
 
-fn quit_button(quit_system: &QuitSystem) -> impl Bundle {
-    ButtonBuilder::new("Quit", BAD_TEXT_COLOR, quit_system.0, ())
+fn new_game_button(systems: &MainMenuSystems) -> impl Bundle {
+    ButtonBuilder::new("New game", GOOD_TEXT_COLOR, systems.new_game, ())
+        .large()
+        .with_font(Fonts::large())
+        .bundle()
+}
+
+fn quit_button(systems: &MainMenuSystems) -> impl Bundle {
+    ButtonBuilder::new("Quit", BAD_TEXT_COLOR, systems.quit, ())
         .large()
         .with_font(Fonts::large())
         .bundle()
@@ -160,7 +178,7 @@ fn quit_button(quit_system: &QuitSystem) -> impl Bundle {
 pub(super) fn update_sav_files(
     In(load_systems): In<LoadSystems>,
     mut commands: Commands,
-    mut last_list_saves_result: GameplayLocal<Option<Result<Vec<PathBuf>, LoadError>>>,
+    mut last_list_saves_result: GameplayLocal<Option<Result<Vec<SaveSlot>, LoadError>>>,
     mut load_button_areas: Single<
         (Entity, &mut Node),
         (With<LoadButtonArea>, Without<LogMessageWrapper>),
@@ -193,11 +211,11 @@ pub(super) fn update_sav_files(
             commands
                 .entity(load_button_area)
                 .insert(Children::spawn((SpawnIter(
-                    list.into_iter().enumerate().map(move |(index, path)| {
+                    list.into_iter().enumerate().map(move |(index, save_slot)| {
                         load_button(
                             largish.clone(),
                             &load_systems,
-                            &path,
+                            save_slot,
                             u32::try_from(index).ok(),
                         )
                     }),
@@ -216,7 +234,7 @@ pub(super) fn update_sav_files(
     debug!("Updated main menu sav files in {:?}", start.elapsed());
 }
 
-fn list_saves() -> Result<Vec<PathBuf>, LoadError> {
+fn list_saves() -> Result<Vec<SaveSlot>, LoadError> {
     check_directory_structure()?;
 
     let worlds_pattern = AssetPaths::save().join("*");
@@ -236,7 +254,7 @@ fn list_saves() -> Result<Vec<PathBuf>, LoadError> {
 
     if worlds.is_none() {
         Err(LoadError::new(format!(
-            "No Cataclysm: DDA worlds found to load under {}\nCreate a new world using Cataclysm: DDA to continue.",
+            "No Cataclysm: DDA worlds found to load under {}\nStart a new game, or create a new world using Cataclysm: DDA.",
             AssetPaths::save().display()
         )))
     } else {
@@ -244,19 +262,17 @@ fn list_saves() -> Result<Vec<PathBuf>, LoadError> {
         let pattern = savs_pattern
             .to_str()
             .expect("Path pattern should be valid UTF-8");
-        let savs = glob(pattern)
+        let mut savs = glob(pattern)
             .expect("Paths shuld be readable")
-            .map(|sav| {
-                sav.expect("Path should be valid")
-                    .components()
-                    .skip(2)
-                    .collect::<PathBuf>()
-            })
+            .map(|sav| sav.expect("Path should be valid"))
+            .filter(|sav| !sav.parent().is_some_and(is_backup))
+            .map(|sav| SaveSlot::new(sav.components().skip(2).collect::<PathBuf>()))
             .collect::<Vec<_>>();
+        SaveSlot::sort(&mut savs);
 
         if savs.is_empty() {
             Err(LoadError::new(format!(
-                "No Cataclysm: DDA saves found to load in any world directory under {}\nCreate a new save file using Cataclysm: DDA to continue.",
+                "No Cataclysm: DDA saves found to load in any world directory under {}\nStart a new game, or create a new save file using Cataclysm: DDA.",
                 AssetPaths::save().display()
             )))
         } else {
@@ -294,7 +310,7 @@ fn check_directory_structure() -> Result<(), LoadError> {
 fn load_button(
     largish: TextFont,
     load_systems: &LoadSystems,
-    path: &Path,
+    save_slot: SaveSlot,
     index: Option<u32>,
 ) -> impl Bundle + use<> {
     const ALPHABET: [char; 26] = {
@@ -309,26 +325,11 @@ fn load_button(
     };
     let key_binding = index.and_then(|index| ALPHABET.get(index as usize).copied());
 
-    let world_path = path.parent().expect("World required");
-    let encoded_character = path
-        .file_name()
-        .expect("Filename required")
-        .to_str()
-        .expect("Valid utf-8 filename required")
-        .strip_prefix('#')
-        .expect("Expected # prefix")
-        .strip_suffix(".sav")
-        .expect("Expected .sav suffix");
-    let decoded_character = base64
-        .decode(encoded_character)
-        .expect("Valid base64 required");
-    let character = from_utf8(&decoded_character).expect("Valid utf8 required");
-
     ButtonBuilder::new(
-        format!("Load {character} in {}", world_path.display()),
+        format!("Load {}", save_slot.label()),
         GOOD_TEXT_COLOR,
         load_systems.button,
-        FoundSav(path.to_path_buf()),
+        FoundSav(save_slot.path),
     )
     .with_font(largish)
     .with_node(Node {
@@ -362,6 +363,10 @@ pub(super) fn load(
     next_application_state.set(ApplicationState::PreGameplay);
 }
 
+fn new_game(mut next_application_state: ResMut<NextState<ApplicationState>>) {
+    next_application_state.set(ApplicationState::NewGame);
+}
+
 fn quit(mut app_exit_events: ResMut<Messages<AppExit>>) {
     app_exit_events.write(AppExit::Success);
 }
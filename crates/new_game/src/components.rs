@@ -0,0 +1,15 @@
+use crate::Field;
+use bevy::prelude::Component;
+
+/// Shows the current value of a field of the new character
+#[derive(Debug, Component)]
+#[component(immutable)]
+pub(super) struct ChoiceText(pub(super) Field);
+
+/// A text input for one of the names of the new game
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[component(immutable)]
+pub(super) enum NameInput {
+    World,
+    Character,
+}
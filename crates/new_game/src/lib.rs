@@ -0,0 +1,14 @@
+mod components;
+mod new_character;
+mod new_world;
+mod plugin;
+mod systems;
+
+pub use crate::plugin::NewGamePlugin;
+
+use crate::components::{ChoiceText, NameInput};
+use crate::new_character::{Change, Choices, Field, NewCharacter, Stat};
+use crate::new_world::write_new_world;
+use crate::systems::{
+    create_new_game_systems, remove_new_character, spawn_new_game, update_choice_texts,
+};
@@ -0,0 +1,252 @@
+use bevy::prelude::Resource;
+use cdda_json_files::{GenderedText, InfoId, Profession, Scenario, StartLocation};
+use gameplay_cdda::Infos;
+use std::{cmp::Ordering, sync::Arc};
+use strum::VariantArray;
+
+/// The points that remain after paying for the scenario, the profession, and the stats
+const FREE_POINTS: i16 = 6;
+
+const BASE_STAT: i16 = 8;
+const MIN_STAT: i16 = 4;
+const MAX_STAT: i16 = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, VariantArray)]
+pub(crate) enum Stat {
+    Strength,
+    Dexterity,
+    Intelligence,
+    Perception,
+}
+
+impl Stat {
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Strength => "Strength",
+            Self::Dexterity => "Dexterity",
+            Self::Intelligence => "Intelligence",
+            Self::Perception => "Perception",
+        }
+    }
+
+    /// Stats above 12 cost 2 points, above 14 even 3 points. Stats below the base give points back.
+    const fn cost(value: i16) -> i16 {
+        match value {
+            ..=12 => value - BASE_STAT,
+            13..=14 => 4 + 2 * (value - 12),
+            _ => 8 + 3 * (value - 14),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Field {
+    Scenario,
+    Profession,
+    StartLocation,
+    Gender,
+    Stat(Stat),
+    /// Only shown, not changed directly
+    Points,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Change {
+    pub(crate) field: Field,
+    pub(crate) forward: bool,
+}
+
+/// The selected options for the current scenario
+#[derive(Default)]
+pub(crate) struct Choices<'a> {
+    pub(crate) scenario: Option<&'a Arc<Scenario>>,
+    pub(crate) profession: Option<&'a Arc<Profession>>,
+    pub(crate) start_location: Option<&'a Arc<StartLocation>>,
+    scenario_count: usize,
+    profession_count: usize,
+    start_location_count: usize,
+}
+
+/// The character that is being created. Options are stored as indices in their sorted lists.
+#[derive(Debug, Resource)]
+pub(crate) struct NewCharacter {
+    scenario: usize,
+    profession: usize,
+    start_location: usize,
+    male: bool,
+    strength: i16,
+    dexterity: i16,
+    intelligence: i16,
+    perception: i16,
+}
+
+impl NewCharacter {
+    pub(crate) const fn male(&self) -> bool {
+        self.male
+    }
+
+    /// The variant of the text for the gender of this character
+    pub(crate) fn gendered<'a>(&self, text: &'a GenderedText) -> &'a Arc<str> {
+        if self.male {
+            text.male()
+        } else {
+            text.female()
+        }
+    }
+
+    pub(crate) const fn stat(&self, stat: Stat) -> i16 {
+        match stat {
+            Stat::Strength => self.strength,
+            Stat::Dexterity => self.dexterity,
+            Stat::Intelligence => self.intelligence,
+            Stat::Perception => self.perception,
+        }
+    }
+
+    const fn stat_mut(&mut self, stat: Stat) -> &mut i16 {
+        match stat {
+            Stat::Strength => &mut self.strength,
+            Stat::Dexterity => &mut self.dexterity,
+            Stat::Intelligence => &mut self.intelligence,
+            Stat::Perception => &mut self.perception,
+        }
+    }
+
+    pub(crate) fn choices<'a>(&self, infos: &'a Infos) -> Choices<'a> {
+        let default_scenario = InfoId::new("evacuee");
+        let mut scenarios = infos.scenarios.values().collect::<Vec<_>>();
+        scenarios.sort_by(|a, b| {
+            (a.id != default_scenario)
+                .cmp(&(b.id != default_scenario))
+                .then_with(|| a.name.male().cmp(b.name.male()))
+        });
+        let scenario = scenarios.get(self.scenario).copied();
+
+        let mut professions = infos
+            .professions
+            .values()
+            .filter(|profession| !profession.is_hobby())
+            .filter(|profession| scenario.is_none_or(|scenario| scenario.allows(&profession.id)))
+            .collect::<Vec<_>>();
+        professions.sort_by(|a, b| by_points_and_name(a, b));
+
+        let start_locations = scenario
+            .into_iter()
+            .flat_map(|scenario| &scenario.allowed_locs)
+            .filter_map(|id| infos.start_locations.get(id).ok())
+            .collect::<Vec<_>>();
+
+        Choices {
+            scenario,
+            profession: professions.get(self.profession).copied(),
+            start_location: start_locations.get(self.start_location).copied(),
+            scenario_count: scenarios.len(),
+            profession_count: professions.len(),
+            start_location_count: start_locations.len(),
+        }
+    }
+
+    pub(crate) fn change(&mut self, change: Change, choices: &Choices) {
+        let Change { field, forward } = change;
+        match field {
+            Field::Scenario => {
+                self.scenario = cycle(self.scenario, choices.scenario_count, forward);
+                // The available professions and start locations depend on the scenario
+                self.profession = 0;
+                self.start_location = 0;
+            }
+            Field::Profession => {
+                self.profession = cycle(self.profession, choices.profession_count, forward);
+            }
+            Field::StartLocation => {
+                self.start_location =
+                    cycle(self.start_location, choices.start_location_count, forward);
+            }
+            Field::Gender => {
+                self.male = !self.male;
+            }
+            Field::Stat(stat) => {
+                let value = self.stat_mut(stat);
+                *value = if forward {
+                    (*value + 1).min(MAX_STAT)
+                } else {
+                    (*value - 1).max(MIN_STAT)
+                };
+            }
+            Field::Points => {}
+        }
+    }
+
+    /// Negative when too many points are spent
+    pub(crate) fn points_left(&self, choices: &Choices) -> i16 {
+        FREE_POINTS
+            - choices.scenario.map_or(0, |scenario| scenario.points)
+            - choices.profession.map_or(0, |profession| profession.points)
+            - Stat::VARIANTS
+                .iter()
+                .map(|&stat| Stat::cost(self.stat(stat)))
+                .sum::<i16>()
+    }
+}
+
+impl Default for NewCharacter {
+    fn default() -> Self {
+        Self {
+            scenario: 0,
+            profession: 0,
+            start_location: 0,
+            male: true,
+            strength: BASE_STAT,
+            dexterity: BASE_STAT,
+            intelligence: BASE_STAT,
+            perception: BASE_STAT,
+        }
+    }
+}
+
+fn by_points_and_name(a: &Profession, b: &Profession) -> Ordering {
+    a.points
+        .cmp(&b.points)
+        .then_with(|| a.name.male().cmp(b.name.male()))
+}
+
+/// The next or previous index, wrapping around at both ends
+fn cycle(index: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        if len <= index + 1 { 0 } else { index + 1 }
+    } else {
+        index
+            .checked_sub(1)
+            .unwrap_or_else(|| len.saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod new_character_tests {
+    use super::*;
+
+    #[test]
+    fn stat_costs() {
+        assert_eq!(
+            Stat::cost(MIN_STAT),
+            -4,
+            "Low stats should give points back"
+        );
+        assert_eq!(Stat::cost(BASE_STAT), 0, "The base stat should be free");
+        assert_eq!(Stat::cost(12), 4, "Moderate stats should cost 1 point each");
+        assert_eq!(Stat::cost(14), 8, "High stats should cost 2 points each");
+        assert_eq!(
+            Stat::cost(16),
+            14,
+            "Very high stats should cost 3 points each"
+        );
+    }
+
+    #[test]
+    fn cycle_wraps() {
+        assert_eq!(cycle(2, 3, true), 0, "Forward should wrap to the start");
+        assert_eq!(cycle(0, 3, false), 2, "Backward should wrap to the end");
+        assert_eq!(cycle(0, 0, true), 0, "Empty lists should not panic");
+        assert_eq!(cycle(0, 0, false), 0, "Empty lists should not panic");
+    }
+}
@@ -0,0 +1,467 @@
+use crate::{Choices, NewCharacter, Stat};
+use base64::{Engine as _, engine::general_purpose::STANDARD as base64};
+use bevy::{platform::collections::HashMap, prelude::warn};
+use cdda_json_files::{
+    CddaItem, CddaPlayer, CommonItemInfo, InfoId, Overmap, OvermapLevel, OvermapTerrainInfo,
+    PocketType, Sav, Skill, StartLocation, WorldMods,
+};
+use gameplay_cdda::{Infos, ModList};
+use gameplay_save::unused_copy_path;
+use serde::Serialize;
+use serde_json::{Value as JsonValue, json, to_string as to_json_string};
+use std::fs::{create_dir_all, write};
+use std::{io, path::Path, path::PathBuf, sync::Arc};
+use strum::VariantArray as _;
+use units::Volume;
+use util::AssetPaths;
+
+/// The version line that CDDA 0.G writes at the start of .sav and overmap files
+const VERSION_LINE: &str = "# version 33";
+
+/// The maximum stamina of a CDDA character without mutations or bionics
+const FULL_STAMINA: u16 = 10_000;
+
+/// The stored calories of a new CDDA character, that has a healthy weight
+const HEALTHY_CALORIES: u64 = 57_000_000;
+
+/// The body temperature of CDDA, in its own units
+const NORMAL_BODY_TEMPERATURE: u16 = 5000;
+
+/// 8:00 on the first day, in seconds
+const START_TURN: u64 = 8 * 60 * 60;
+
+/// The zone of the start location, in the middle of the first overmap
+const START_ZONE: u16 = 87;
+
+/// The center of the start zone, in tiles. Zones are 24 tiles wide, submaps 12.
+const START_POS: i32 = START_ZONE as i32 * 24 + 12;
+
+/// The first submap of the reality bubble, that has the player 5 submaps from its corner
+const START_SUBMAP: u16 = START_ZONE * 2 - 5;
+
+/// Create a new world directory, containing the mod list, the overmap around the start location, and a save for the new character.
+/// The other overmaps and all maps of the world are filled in when they are first visited.
+///
+/// The start location is placed in the middle of the first overmap, using the first of its overmap terrains.
+///
+/// Returns the path of the save, relative to [`AssetPaths::save`].
+pub(crate) fn write_new_world(
+    new_character: &NewCharacter,
+    choices: &Choices,
+    infos: &Infos,
+    world_name: &str,
+    character_name: &str,
+) -> io::Result<PathBuf> {
+    let world_path = AssetPaths::save().join(world_name);
+    let world_path = if world_path.exists() {
+        unused_copy_path(&world_path)
+    } else {
+        world_path
+    };
+    create_dir_all(&world_path)?;
+
+    // Like the default mod selection of CDDA, new worlds only use the base game
+    let world_mods = WorldMods(
+        ModList::core()
+            .iter()
+            .map(|loaded_mod| loaded_mod.id.clone())
+            .collect(),
+    );
+    let json = to_json_string(&world_mods).map_err(io::Error::other)?;
+    write(world_path.join("mods.json"), json)?;
+
+    if let Some(start_location) = choices.start_location {
+        write_start_overmap(&world_path, start_location)?;
+    }
+
+    let items = starting_items(profession_items(new_character, choices), infos);
+    let sav = new_sav(new_character, choices, items, character_name);
+    let sav_path = world_path.join(format!("#{}.sav", base64.encode(character_name)));
+    write_versioned(&sav_path, &sav)?;
+
+    Ok(sav_path
+        .strip_prefix(AssetPaths::save())
+        .map_err(io::Error::other)?
+        .to_path_buf())
+}
+
+/// The first overmap, with the start location in the start zone
+fn write_start_overmap(world_path: &Path, start_location: &StartLocation) -> io::Result<()> {
+    let Some(start_terrain) = start_location.terrain.first() else {
+        warn!(
+            "Start location {:?} has no overmap terrain",
+            start_location.id
+        );
+        return Ok(());
+    };
+
+    let overmap = start_overmap(start_terrain.om_terrain().clone());
+    write_versioned(&world_path.join("o.0.0"), &overmap)
+}
+
+/// A plain overmap, with the given terrain in the start zone
+fn start_overmap(start_terrain: InfoId<OvermapTerrainInfo>) -> Overmap {
+    let mut overmap = Overmap::plain();
+    overmap.layers[Overmap::GROUND_LAYER] = OvermapLevel::all_except(
+        &InfoId::new("field"),
+        u32::from(START_ZONE),
+        u32::from(START_ZONE),
+        start_terrain,
+    );
+    overmap
+}
+
+/// Writes json with the version line, like .sav and overmap files
+fn write_versioned(path: &Path, value: &impl Serialize) -> io::Result<()> {
+    let json = to_json_string(value).map_err(io::Error::other)?;
+    write(path, format!("{VERSION_LINE}\n{json}"))
+}
+
+fn new_sav(
+    new_character: &NewCharacter,
+    choices: &Choices,
+    items: (Vec<JsonValue>, Option<JsonValue>),
+    character_name: &str,
+) -> Sav {
+    Sav {
+        achievements_tracker: json!({}),
+        active_monsters: json!([]),
+        auto_travel_mode: json!(false),
+        b_v_monster_look_fire: json!(true),
+        calendar_start: 0,
+        game_start: START_TURN,
+        turn: START_TURN,
+        driving_view_offset: json!({ "x": 0, "y": 0 }),
+        global_vals: json!({}),
+        grscent: JsonValue::String(String::new()),
+        inactive_global_effect_on_condition_vector: json!([]),
+        initial_season: json!(0),
+        kill_tracker: json!({}),
+        om_x: 0,
+        om_y: 0,
+        levx: START_SUBMAP,
+        levy: START_SUBMAP,
+        levz: 0,
+        mostseen: json!(0),
+        player: new_player(new_character, choices, items, character_name),
+        player_messages: json!({}),
+        queued_global_effect_on_conditions: json!([]),
+        run_mode: json!(1),
+        stats_tracker: json!({}),
+        turnssincelastmon: json!(0),
+        typescent: JsonValue::String(String::new()),
+        unique_npcs: None,
+        view_offset_x: json!(0),
+        view_offset_y: json!(0),
+        view_offset_z: json!(0),
+    }
+}
+
+fn new_player(
+    new_character: &NewCharacter,
+    choices: &Choices,
+    (worn, wielded): (Vec<JsonValue>, Option<JsonValue>),
+    character_name: &str,
+) -> CddaPlayer {
+    let [str_max, dex_max, int_max, per_max] = [
+        Stat::Strength,
+        Stat::Dexterity,
+        Stat::Intelligence,
+        Stat::Perception,
+    ]
+    .map(|stat| new_character.stat(stat));
+
+    let skills = choices
+        .profession
+        .into_iter()
+        .flat_map(|profession| &profession.skills)
+        .map(|skill| (skill.name.clone(), Skill::new(skill.level)))
+        .collect();
+
+    let mut extra = HashMap::<Arc<str>, JsonValue>::default();
+    extra.insert(Arc::from("male"), json!(new_character.male()));
+    extra.insert(Arc::from("worn"), JsonValue::Array(worn));
+    if let Some(wielded) = wielded {
+        extra.insert(Arc::from("weapon"), wielded);
+    }
+    if let Some(scenario) = choices.scenario {
+        extra.insert(Arc::from("scenario"), json!(scenario.id));
+    }
+    if let Some(profession) = choices.profession {
+        extra.insert(Arc::from("profession"), json!(profession.id));
+    }
+    if let Some(start_location) = choices.start_location {
+        extra.insert(Arc::from("start_location"), json!(start_location.id));
+    }
+
+    CddaPlayer {
+        str_max,
+        str_bonus: 0,
+        str_cur: str_max,
+        dex_max,
+        dex_bonus: 0,
+        dex_cur: dex_max,
+        int_max,
+        int_bonus: 0,
+        int_cur: int_max,
+        per_max,
+        per_bonus: 0,
+        per_cur: per_max,
+        body: new_body(str_max),
+        effects: HashMap::default(),
+        focus_pool: json!(100),
+        id: json!(1),
+        items_identified: json!([]),
+        location: Some((START_POS, START_POS, 0)),
+        name: Arc::from(character_name),
+        omt_path: json!([]),
+        oxygen: json!(30),
+        pain: json!(0),
+        pkill: json!(0),
+        play_name: JsonValue::String(String::from(character_name)),
+        skills,
+        sleep_deprivation: json!(0),
+        stamina: json!(FULL_STAMINA),
+        // Fed and hydrated
+        hunger: json!(0),
+        stomach: json!({}),
+        stored_calories: json!(HEALTHY_CALORIES),
+        thirst: json!(0),
+        traits: json!([]),
+        extra,
+    }
+}
+
+/// All body parts of a new character, at full health and with a normal temperature
+///
+/// Like in CDDA, the main body parts get 60 hit points, and 3 more per point of strength. The head gets less, and the torso more.
+fn new_body(str_max: i16) -> JsonValue {
+    let hp = 60 + 3 * i64::from(str_max);
+    let body = [
+        ("torso", hp * 13 / 10),
+        ("head", hp * 8 / 10),
+        ("eyes", 0),
+        ("mouth", 0),
+        ("arm_l", hp),
+        ("arm_r", hp),
+        ("hand_l", 0),
+        ("hand_r", 0),
+        ("leg_l", hp),
+        ("leg_r", hp),
+        ("foot_l", 0),
+        ("foot_r", 0),
+    ]
+    .into_iter()
+    .map(|(id, hp)| {
+        let body_part = json!({
+            "id": id,
+            "hp_cur": hp,
+            "hp_max": hp,
+            "damage_bandaged": 0,
+            "damage_disinfected": 0,
+            "wetness": 0,
+            "temp_cur": NORMAL_BODY_TEMPERATURE,
+            "temp_conv": NORMAL_BODY_TEMPERATURE,
+            "frostbite_timer": 0,
+        });
+        (String::from(id), body_part)
+    })
+    .collect();
+    JsonValue::Object(body)
+}
+
+/// The items of the profession, for the gender of the character
+fn profession_items<'a>(
+    new_character: &NewCharacter,
+    choices: &Choices<'a>,
+) -> impl Iterator<Item = &'a InfoId<CommonItemInfo>> {
+    let male = new_character.male();
+    choices.profession.into_iter().flat_map(move |profession| {
+        let gendered_items = if male {
+            &profession.items.male
+        } else {
+            &profession.items.female
+        };
+        profession.items.both.0.iter().chain(&gendered_items.0)
+    })
+}
+
+/// Wearable items are worn, other items are stored in the pockets of worn items when they fit.
+/// The first item that does not fit is wielded. Items that are left after that are skipped.
+///
+/// Returns the json of the worn items and of the wielded item.
+fn starting_items<'a>(
+    item_ids: impl Iterator<Item = &'a InfoId<CommonItemInfo>>,
+    infos: &Infos,
+) -> (Vec<JsonValue>, Option<JsonValue>) {
+    let mut worn = Vec::new();
+    let mut carried = Vec::new();
+    for item_id in item_ids {
+        let item = CddaItem::unlinked(item_id.clone());
+        infos.link_item(&item);
+        match item.item_info.get() {
+            Ok(item_info) if item_info.wearable() => worn.push(WornItem::new(item_info)),
+            Ok(item_info) => carried.push(item_info),
+            Err(error) => warn!("Skipping profession item {item_id:?}: {error:#?}"),
+        }
+    }
+
+    let mut wielded = None;
+    for item_info in carried {
+        if !worn.iter_mut().any(|worn_item| worn_item.store(&item_info)) {
+            if wielded.is_none() {
+                wielded = Some(json!({ "typeid": item_info.id }));
+            } else {
+                warn!("No room for profession item {:?}", item_info.id);
+            }
+        }
+    }
+
+    (worn.iter().map(WornItem::json).collect(), wielded)
+}
+
+/// A worn item, with the items that are stored in its pockets
+struct WornItem {
+    item_info: Arc<CommonItemInfo>,
+
+    /// The stored items and the room that is left, for each pocket in [`CommonItemInfo::pocket_data`]
+    pockets: Vec<(Vec<InfoId<CommonItemInfo>>, Volume)>,
+}
+
+impl WornItem {
+    fn new(item_info: Arc<CommonItemInfo>) -> Self {
+        let pockets = item_info
+            .pocket_data
+            .iter()
+            .flatten()
+            .map(|pocket_info| {
+                // Holsters, sealed pockets, and ammo pockets only take specific items
+                let general = pocket_info.pocket_type == PocketType::Container
+                    && !pocket_info.holster
+                    && pocket_info.sealed_data.is_none()
+                    && pocket_info.ammo_restriction.is_none();
+                let room = pocket_info
+                    .max_contains_volume
+                    .filter(|_| general)
+                    .unwrap_or(Volume::ZERO);
+                (Vec::new(), room)
+            })
+            .collect();
+        Self { item_info, pockets }
+    }
+
+    /// Returns whether the item fits in one of the pockets
+    fn store(&mut self, item_info: &CommonItemInfo) -> bool {
+        let volume = item_info.volume.unwrap_or(Volume::ZERO);
+        let pocket_infos = self.item_info.pocket_data.iter().flatten();
+        for (pocket_info, (stored, room)) in pocket_infos.zip(&mut self.pockets) {
+            if volume <= *room
+                && pocket_info.allows(item_info)
+                && pocket_info
+                    .max_item_volume
+                    .is_none_or(|max_item_volume| volume <= max_item_volume)
+            {
+                *room = *room - volume;
+                stored.push(item_info.id.clone());
+                return true;
+            }
+        }
+        false
+    }
+
+    fn json(&self) -> JsonValue {
+        let mut json = json!({ "typeid": self.item_info.id });
+        if self.pockets.iter().any(|(stored, _)| !stored.is_empty()) {
+            // Pockets are matched to the pocket data by their position
+            let pockets = self
+                .item_info
+                .pocket_data
+                .iter()
+                .flatten()
+                .zip(&self.pockets)
+                .map(|(pocket_info, (stored, _))| {
+                    json!({
+                        "pocket_type": PocketType::VARIANTS
+                            .iter()
+                            .position(|&pocket_type| pocket_type == pocket_info.pocket_type),
+                        "contents": stored
+                            .iter()
+                            .map(|item_id| json!({ "typeid": item_id }))
+                            .collect::<Vec<_>>(),
+                        "_sealed": false,
+                    })
+                })
+                .collect::<Vec<_>>();
+            json["contents"] = json!({ "contents": pockets });
+        }
+        json
+    }
+}
+
+#[cfg(test)]
+mod new_world_tests {
+    use super::*;
+    use cdda_json_files::SaveVersion;
+    use gameplay_cdda_active_sav::ActiveSav;
+    use serde_json::from_str as from_json_str;
+    use std::{env, fs::read_to_string};
+
+    /// Splits the version line from the json
+    fn read_versioned(path: &Path) -> (Option<SaveVersion>, String) {
+        let contents = read_to_string(path).expect("The file should be written");
+        let (header, json) = contents
+            .split_once('\n')
+            .expect("The file should have a version line");
+        (SaveVersion::from_sav_header(header), String::from(json))
+    }
+
+    #[test]
+    fn new_world_is_readable() {
+        let world_path = env::temp_dir().join("rustaclysm-new-world-test");
+        create_dir_all(&world_path).expect("The world directory should be created");
+
+        let overmap_path = world_path.join("o.0.0");
+        write_versioned(&overmap_path, &start_overmap(InfoId::new("shelter")))
+            .expect("The overmap should be written");
+        let (version, json) = read_versioned(&overmap_path);
+        assert_eq!(version, Some(SaveVersion::CURRENT), "Overmap version");
+        let overmap = from_json_str::<Overmap>(&json);
+        assert!(overmap.is_ok(), "{overmap:?}");
+        let overmap = from_json_str::<JsonValue>(&json).expect("The overmap should be json");
+        for field in ["cities", "radios", "npcs", "camps", "scent_traces"] {
+            assert_eq!(overmap.get(field), Some(&json!([])), "{field}");
+        }
+        assert_eq!(overmap.get("connections_out"), Some(&json!({})));
+
+        let new_character = NewCharacter::default();
+        let sav = new_sav(
+            &new_character,
+            &Choices::default(),
+            (Vec::new(), None),
+            "Tester",
+        );
+        let sav_path = world_path.join("#VGVzdGVy.sav");
+        write_versioned(&sav_path, &sav).expect("The save should be written");
+        let active_sav = ActiveSav::new(&sav_path).expect("The save should be readable");
+        assert_eq!(active_sav.version(), SaveVersion::CURRENT, "Sav version");
+
+        let player = &active_sav.sav().player;
+        assert_eq!(player.stamina.as_u64(), Some(u64::from(FULL_STAMINA)));
+        assert_eq!(player.stored_calories.as_u64(), Some(HEALTHY_CALORIES));
+        let body = player
+            .body
+            .as_object()
+            .expect("The body should be an object");
+        assert_eq!(body.len(), 12, "All body parts should be present");
+        assert_eq!(
+            player.body.pointer("/arm_l/hp_max"),
+            Some(&json!(60 + 3 * new_character.stat(Stat::Strength))),
+            "Hit points should depend on strength"
+        );
+        assert_eq!(
+            player.body.pointer("/torso/temp_cur"),
+            Some(&json!(NORMAL_BODY_TEMPERATURE)),
+            "The body should have a normal temperature"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    Change, NewCharacter, create_new_game_systems, remove_new_character, spawn_new_game,
+    update_choice_texts,
+};
+use application_state::ApplicationState;
+use bevy::prelude::{
+    App, In, IntoScheduleConfigs as _, IntoSystem as _, OnEnter, OnExit, Plugin,
+    SystemCondition as _, Update, in_state, resource_added, resource_exists_and_changed,
+};
+use gameplay_cdda::Infos;
+use hud::manage_button_input;
+
+/// Lets the player choose a scenario, a profession, a start location, and stats, before starting in a new world.
+pub struct NewGamePlugin;
+
+impl Plugin for NewGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(ApplicationState::NewGame),
+            create_new_game_systems.pipe(spawn_new_game),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                manage_button_input::<In<Change>>,
+                update_choice_texts.run_if(
+                    resource_exists_and_changed::<NewCharacter>.or_else(resource_added::<Infos>),
+                ),
+            )
+                .chain()
+                .run_if(in_state(ApplicationState::NewGame)),
+        );
+
+        app.add_systems(OnExit(ApplicationState::NewGame), remove_new_character);
+    }
+}
@@ -0,0 +1,375 @@
+use crate::{Change, ChoiceText, Field, NameInput, NewCharacter, Stat, write_new_world};
+use application_state::ApplicationState;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::{
+    AlignItems, Bundle, Commands, DespawnOnExit, FlexDirection, GlobalZIndex, In, JustifyContent,
+    NextState, Node, Query, Res, ResMut, Text, TextColor, TextLayout, UiRect, Val, World, children,
+    error, warn,
+};
+use bevy::text::{EditableText, EditableTextFilter, TextCursorStyle};
+use gameplay_cdda::Infos;
+use gameplay_cdda_active_sav::{ActiveSav, Error as ActiveSavError};
+use hud::{
+    BAD_TEXT_COLOR, ButtonBuilder, DEFAULT_BUTTON_COLOR, Fonts, GOOD_TEXT_COLOR, HARD_TEXT_COLOR,
+    LARGE_SPACING, MEDIUM_SPACING, SMALL_SPACING, SOFT_TEXT_COLOR, WARN_TEXT_COLOR,
+};
+use std::{cmp::Ordering, time::Instant};
+use strum::VariantArray as _;
+use util::log_if_slow;
+
+const LABEL_WIDTH: f32 = 500.0;
+const NAME_LABEL_WIDTH: f32 = 250.0;
+
+const DEFAULT_WORLD_NAME: &str = "New world";
+const DEFAULT_CHARACTER_NAME: &str = "Survivor";
+
+#[derive(Debug)]
+pub(super) struct NewGameSystems {
+    change: SystemId<In<Change>, ()>,
+    start: SystemId<(), ()>,
+    back: SystemId<(), ()>,
+}
+
+pub(super) fn create_new_game_systems(world: &mut World) -> NewGameSystems {
+    NewGameSystems {
+        change: world.register_system_cached(change),
+        start: world.register_system_cached(start_new_game),
+        back: world.register_system_cached(back),
+    }
+}
+
+pub(super) fn spawn_new_game(In(systems): In<NewGameSystems>, mut commands: Commands) {
+    commands.init_resource::<NewCharacter>();
+
+    let root = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Node::default()
+            },
+            GlobalZIndex(3),
+            DespawnOnExit(ApplicationState::NewGame),
+            children![(
+                Text::from("New game"),
+                HARD_TEXT_COLOR,
+                Fonts::huge(),
+                Node {
+                    margin: UiRect {
+                        bottom: LARGE_SPACING,
+                        ..UiRect::default()
+                    },
+                    ..Node::default()
+                },
+            )],
+        ))
+        .id();
+
+    for (name_input, label, initial) in [
+        (NameInput::World, "World name", DEFAULT_WORLD_NAME),
+        (
+            NameInput::Character,
+            "Character name",
+            DEFAULT_CHARACTER_NAME,
+        ),
+    ] {
+        let row = commands.spawn(name_row(name_input, label, initial)).id();
+        commands.entity(root).add_child(row);
+    }
+
+    let fields = [
+        Field::Scenario,
+        Field::Profession,
+        Field::StartLocation,
+        Field::Gender,
+    ]
+    .into_iter()
+    .chain(Stat::VARIANTS.iter().copied().map(Field::Stat));
+    for field in fields {
+        let row = commands.spawn(choice_row(&systems, field)).id();
+        commands.entity(root).add_child(row);
+    }
+    let points = commands.spawn(choice_text(Field::Points)).id();
+    commands.entity(root).add_child(points);
+
+    let buttons = commands
+        .spawn((
+            Node {
+                margin: UiRect {
+                    top: LARGE_SPACING,
+                    ..UiRect::default()
+                },
+                column_gap: MEDIUM_SPACING,
+                ..Node::default()
+            },
+            children![
+                ButtonBuilder::new("Back", BAD_TEXT_COLOR, systems.back, ())
+                    .large()
+                    .with_font(Fonts::large())
+                    .bundle(),
+                ButtonBuilder::new("Start", GOOD_TEXT_COLOR, systems.start, ())
+                    .large()
+                    .with_font(Fonts::large())
+                    .bundle(),
+            ],
+        ))
+        .id();
+    commands.entity(root).add_child(buttons);
+}
+
+fn name_row(name_input: NameInput, label: &str, initial: &str) -> impl Bundle + use<> {
+    let filter = if name_input == NameInput::World {
+        // The world name is used as directory name
+        EditableTextFilter::new(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+    } else {
+        EditableTextFilter::new(|c| !c.is_control())
+    };
+
+    (
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: MEDIUM_SPACING,
+            margin: UiRect {
+                bottom: MEDIUM_SPACING,
+                ..UiRect::default()
+            },
+            ..Node::default()
+        },
+        children![
+            (
+                Text::from(format!("{label}:")),
+                HARD_TEXT_COLOR,
+                Fonts::largish(),
+                Node {
+                    width: Val::Px(NAME_LABEL_WIDTH),
+                    justify_content: JustifyContent::End,
+                    ..Node::default()
+                },
+            ),
+            (
+                EditableText::new(initial),
+                filter,
+                TextLayout::no_wrap(),
+                HARD_TEXT_COLOR,
+                Fonts::largish(),
+                TextCursorStyle {
+                    color: HARD_TEXT_COLOR.0,
+                    ..TextCursorStyle::default()
+                },
+                DEFAULT_BUTTON_COLOR,
+                Node {
+                    width: Val::Px(LABEL_WIDTH),
+                    padding: UiRect::all(SMALL_SPACING),
+                    ..Node::default()
+                },
+                name_input,
+            ),
+        ],
+    )
+}
+
+fn choice_row(systems: &NewGameSystems, field: Field) -> impl Bundle + use<> {
+    let (previous, next) = if matches!(field, Field::Stat(_)) {
+        ("-", "+")
+    } else {
+        ("<", ">")
+    };
+    (
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: MEDIUM_SPACING,
+            margin: UiRect {
+                bottom: MEDIUM_SPACING,
+                ..UiRect::default()
+            },
+            ..Node::default()
+        },
+        children![
+            change_button(systems, field, previous, false),
+            choice_text(field),
+            change_button(systems, field, next, true),
+        ],
+    )
+}
+
+fn choice_text(field: Field) -> impl Bundle {
+    (
+        Text::from("Loading..."),
+        SOFT_TEXT_COLOR,
+        Fonts::largish(),
+        Node {
+            width: Val::Px(LABEL_WIDTH),
+            justify_content: JustifyContent::Center,
+            ..Node::default()
+        },
+        ChoiceText(field),
+    )
+}
+
+fn change_button(
+    systems: &NewGameSystems,
+    field: Field,
+    caption: &str,
+    forward: bool,
+) -> impl Bundle + use<> {
+    ButtonBuilder::new(
+        caption,
+        HARD_TEXT_COLOR,
+        systems.change,
+        Change { field, forward },
+    )
+    .with_font(Fonts::large())
+    .bundle()
+}
+
+#[expect(clippy::needless_pass_by_value)]
+pub(super) fn update_choice_texts(
+    infos: Option<Res<Infos>>,
+    new_character: Res<NewCharacter>,
+    mut choice_texts: Query<(&ChoiceText, &mut Text, &mut TextColor)>,
+) {
+    let start = Instant::now();
+
+    let Some(infos) = infos else {
+        return;
+    };
+    let choices = new_character.choices(&infos);
+
+    for (&ChoiceText(field), mut text, mut text_color) in &mut choice_texts {
+        let (label, color) = match field {
+            Field::Scenario => (
+                choices.scenario.map_or_else(
+                    || String::from("No scenarios found"),
+                    |scenario| format!("Scenario: {}", new_character.gendered(&scenario.name)),
+                ),
+                HARD_TEXT_COLOR,
+            ),
+            Field::Profession => (
+                choices.profession.map_or_else(
+                    || String::from("No professions found"),
+                    |profession| {
+                        format!("Profession: {}", new_character.gendered(&profession.name))
+                    },
+                ),
+                HARD_TEXT_COLOR,
+            ),
+            Field::StartLocation => (
+                choices.start_location.map_or_else(
+                    || String::from("No start locations found"),
+                    |start_location| {
+                        format!(
+                            "Start location: {}",
+                            new_character.gendered(&start_location.name)
+                        )
+                    },
+                ),
+                HARD_TEXT_COLOR,
+            ),
+            Field::Gender => (
+                format!(
+                    "Gender: {}",
+                    if new_character.male() {
+                        "Male"
+                    } else {
+                        "Female"
+                    }
+                ),
+                HARD_TEXT_COLOR,
+            ),
+            Field::Stat(stat) => (
+                format!("{}: {}", stat.name(), new_character.stat(stat)),
+                HARD_TEXT_COLOR,
+            ),
+            Field::Points => {
+                let points = new_character.points_left(&choices);
+                (
+                    format!("Points left: {points}"),
+                    match points.cmp(&0) {
+                        Ordering::Less => BAD_TEXT_COLOR,
+                        Ordering::Equal => GOOD_TEXT_COLOR,
+                        Ordering::Greater => WARN_TEXT_COLOR,
+                    },
+                )
+            }
+        };
+        text.0 = label;
+        *text_color = color;
+    }
+
+    log_if_slow("update_choice_texts", start);
+}
+
+fn change(
+    In(change): In<Change>,
+    infos: Option<Res<Infos>>,
+    mut new_character: ResMut<NewCharacter>,
+) {
+    let Some(infos) = infos else {
+        return;
+    };
+    let choices = new_character.choices(&infos);
+    new_character.change(change, &choices);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+fn start_new_game(
+    mut commands: Commands,
+    mut next_application_state: ResMut<NextState<ApplicationState>>,
+    infos: Option<Res<Infos>>,
+    new_character: Res<NewCharacter>,
+    name_inputs: Query<(&NameInput, &EditableText)>,
+) {
+    let Some(infos) = infos else {
+        warn!("The game data is not loaded yet");
+        return;
+    };
+    let choices = new_character.choices(&infos);
+    if new_character.points_left(&choices) < 0 {
+        warn!("Too many points spent on the new character");
+        return;
+    }
+
+    let name = |name_input: NameInput| {
+        name_inputs
+            .iter()
+            .find(|&(&found, _)| found == name_input)
+            .map(|(_, editable_text)| editable_text.value().to_string().trim().to_owned())
+            .filter(|name| !name.is_empty())
+    };
+    let Some(world_name) = name(NameInput::World) else {
+        warn!("The new world needs a name");
+        return;
+    };
+    let Some(character_name) = name(NameInput::Character) else {
+        warn!("The new character needs a name");
+        return;
+    };
+
+    match write_new_world(
+        &new_character,
+        &choices,
+        &infos,
+        &world_name,
+        &character_name,
+    )
+    .map_err(ActiveSavError::from)
+    .and_then(|sav_path| ActiveSav::new(&sav_path))
+    {
+        Ok(active_sav) => {
+            commands.insert_resource(active_sav);
+            next_application_state.set(ApplicationState::PreGameplay);
+        }
+        Err(err) => error!("Creating a new world failed: {err:#?}"),
+    }
+}
+
+pub(super) fn remove_new_character(mut commands: Commands) {
+    commands.remove_resource::<NewCharacter>();
+}
+
+fn back(mut next_application_state: ResMut<NextState<ApplicationState>>) {
+    next_application_state.set(ApplicationState::MainMenu);
+}
@@ -9,5 +9,5 @@ pub use self::distance::Distance;
 pub use self::error::Error;
 pub use self::mass::Mass;
 pub use self::speed::Speed;
-pub use self::time::{Duration, Timestamp};
+pub use self::time::{Duration, Season, Timestamp};
 pub use self::volume::Volume;
@@ -233,8 +233,8 @@ impl Zero for Duration {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, strum::Display)]
-enum Season {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::Display)]
+pub enum Season {
     Spring,
     Summer,
     Autumn,
@@ -260,6 +260,8 @@ impl From<Timestamp> for Season {
     }
 }
 
+const SOLAR_NOON_MINUTE: u64 = 13 * 60;
+
 #[derive(Clone, Copy, Debug, Default, Eq)]
 pub struct Timestamp {
     /// Since start of the first year of the cataclysm
@@ -280,6 +282,12 @@ impl Timestamp {
         }
     }
 
+    /// The CDDA turn, as stored in .sav files
+    #[must_use]
+    pub const fn turn(self) -> u64 {
+        self.offset.milliseconds / 1000
+    }
+
     #[must_use]
     pub const fn start_of_day(self) -> Self {
         Self {
@@ -297,6 +305,36 @@ impl Timestamp {
             / Duration::MINUTE.milliseconds()
     }
 
+    #[must_use]
+    pub fn season(self) -> Season {
+        Season::from(self)
+    }
+
+    /// Before solar noon, at 1 PM
+    #[must_use]
+    pub const fn before_solar_noon(self) -> bool {
+        self.minute_of_day() < SOLAR_NOON_MINUTE
+    }
+
+    /// Roughly matches New England, centered around 1 PM.
+    ///
+    /// Source: <https://www.suncalc.org>
+    #[must_use]
+    pub fn sunlight_percentage(self) -> f32 {
+        // Calculation in minutes
+
+        // We can ignore calculation errors related to solar midnight, because there is no sunlight around that time.
+
+        let solar_summer = self.solar_summer();
+
+        let full_sun_diff = (1.0 + 4.0 * solar_summer) * 60.0; // Full daylight for 1-5 hours away from solar noon
+        let sunset_diff = (5.5 + 3.0 * solar_summer) * 60.0; // No daylight more than 5.5-8.5 hours away from solar noon
+
+        let minutes_from_noon = (SOLAR_NOON_MINUTE as f32 - self.minute_of_day() as f32).abs();
+
+        ((sunset_diff - minutes_from_noon) / (sunset_diff - full_sun_diff)).clamp(0.0, 1.0)
+    }
+
     /// Returns a number between 0.0 (start of winter) and 1.0 (start of summer)
     #[must_use]
     pub fn solar_summer(self) -> f32 {
@@ -14,6 +14,12 @@ impl AssetPaths {
         Self::assets().join("backgrounds")
     }
 
+    /// Generated files that can safely be removed
+    #[must_use]
+    pub fn cache() -> PathBuf {
+        Self::assets().join("cache")
+    }
+
     #[must_use]
     pub fn data() -> PathBuf {
         Self::assets().join("data")
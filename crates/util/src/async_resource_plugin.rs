@@ -22,15 +22,24 @@ pub fn async_resource_plugin<T: AsyncNew<T>>(app: &mut App) {
     );
 }
 
+/// Replace the [`AsyncNew`] resource by the result of the given future, that is loaded in the background
+pub fn reload_async_resource<T: AsyncNew<T>>(
+    commands: &mut Commands,
+    future: impl Future<Output = T> + Send + 'static,
+) {
+    commands.remove_resource::<T>();
+    commands.insert_resource(AsyncResourceLoader::new(future));
+}
+
 #[derive(Debug, Resource)]
 struct AsyncResourceLoader<T: AsyncNew<T>> {
     task: Task<T>,
 }
 
-impl<T: AsyncNew<T> + Send + 'static> Default for AsyncResourceLoader<T> {
-    fn default() -> Self {
+impl<T: AsyncNew<T> + Send + 'static> AsyncResourceLoader<T> {
+    fn new(future: impl Future<Output = T> + Send + 'static) -> Self {
         let thread_pool = AsyncComputeTaskPool::get();
-        let task = thread_pool.spawn(async {
+        let task = thread_pool.spawn(async move {
             static MODULE_PREFIX: LazyLock<Regex> =
                 LazyLock::new(|| Regex::new("[^:<>]+::").expect("Valid regex for module prefix"));
 
@@ -41,7 +50,7 @@ impl<T: AsyncNew<T> + Send + 'static> Default for AsyncResourceLoader<T> {
             let type_name = MODULE_PREFIX.replace_all(type_name::<T>(), "");
             debug!("Started loading {type_name}");
 
-            let result = T::async_new().await;
+            let result = future.await;
 
             let duration = start.elapsed();
             debug!("Finished loading {type_name} in {duration:?}");
@@ -52,6 +61,12 @@ impl<T: AsyncNew<T> + Send + 'static> Default for AsyncResourceLoader<T> {
     }
 }
 
+impl<T: AsyncNew<T> + Send + 'static> Default for AsyncResourceLoader<T> {
+    fn default() -> Self {
+        Self::new(T::async_new())
+    }
+}
+
 fn create_async_resource<T: AsyncNew<T> + Resource>(
     mut commands: Commands,
     mut async_resource_generator: ResMut<AsyncResourceLoader<T>>,
@@ -6,7 +6,7 @@ mod slow;
 mod text;
 
 pub use self::asset_paths::AssetPaths;
-pub use self::async_resource_plugin::{AsyncNew, async_resource_plugin};
+pub use self::async_resource_plugin::{AsyncNew, async_resource_plugin, reload_async_resource};
 pub use self::log_transition::{log_resource_change_plugin, log_transition_plugin};
 pub use self::maybe::Maybe;
 pub use self::slow::log_if_slow;
@@ -21,6 +21,7 @@ use keyboard::KeyboardPlugin;
 use loading::LoadingIndicatorPlugin;
 use main_menu::MainMenuPlugin;
 use manual::ManualPlugin;
+use new_game::NewGamePlugin;
 use pre_gameplay::PreGameplayPlugin;
 use std::{env, time::Duration};
 
@@ -72,6 +73,7 @@ fn main() -> AppExit {
         KeyboardPlugin,
         MainMenuPlugin,
         ManualPlugin,
+        NewGamePlugin,
         BackgroundPlugin,
         GameplayPlugin,
         LoadingIndicatorPlugin,